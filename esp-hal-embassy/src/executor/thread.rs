@@ -8,10 +8,20 @@ use esp_hal::get_core;
 use esp_hal::macros::handler;
 #[cfg(multi_core)]
 use esp_hal::peripherals::SYSTEM;
+#[cfg(any(esp32, esp32s3, esp32c3, esp32c6))]
+use esp_hal::rtc_cntl::{
+    sleep::{RtcSleepConfig, TimerWakeupSource, WakeSource, WakeTriggers},
+    Rtc,
+};
 use portable_atomic::{AtomicBool, Ordering};
 
 pub(crate) const THREAD_MODE_CONTEXT: u8 = 16;
 
+/// Alarms due sooner than this are waited for without entering light sleep,
+/// entering and leaving light sleep takes too long.
+#[cfg(any(esp32, esp32s3, esp32c3, esp32c6))]
+const MIN_LIGHT_SLEEP_US: u64 = 1000;
+
 /// global atomic used to keep track of whether there is work to do since sev()
 /// is not available on either Xtensa or RISC-V
 #[cfg(not(multi_core))]
@@ -127,6 +137,100 @@ impl Executor {
         }
     }
 
+    /// Run the executor, putting the chip into light sleep while all tasks are
+    /// waiting.
+    ///
+    /// This works like [Executor::run], but instead of only waiting for an
+    /// interrupt the chip enters light sleep until the next `embassy-time`
+    /// alarm is due or one of `wake_sources` wakes it up. The timers used by
+    /// `embassy-time` don't count in light sleep, the time spent sleeping is
+    /// measured with the RTC timer and added to the `embassy-time` clock.
+    ///
+    /// Peripheral interrupts don't wake the chip from light sleep, and
+    /// peripherals clocked from the APB or XTAL clock stop while it sleeps.
+    /// Tasks waiting for a peripheral only make progress if the matching
+    /// wakeup source, e.g. a
+    /// [GpioWakeupSource](esp_hal::rtc_cntl::sleep::GpioWakeupSource) for a
+    /// GPIO edge, is configured before the executor runs and passed in
+    /// `wake_sources`.
+    ///
+    /// If the next alarm is due within a millisecond the executor waits for an
+    /// interrupt without entering light sleep.
+    #[cfg_attr(
+        multi_core,
+        doc = r#"
+
+    Light sleep stops both cores, so only use this if the other core doesn't
+    run an executor.
+    "#
+    )]
+    #[cfg(any(esp32, esp32s3, esp32c3, esp32c6))]
+    pub fn run_with_light_sleep(
+        &'static mut self,
+        rtc: &mut Rtc<'_>,
+        wake_sources: &[&dyn WakeSource],
+        init: impl FnOnce(Spawner),
+    ) -> ! {
+        init(self.inner.spawner());
+
+        let cpu = get_core() as usize;
+
+        loop {
+            unsafe {
+                self.inner.poll();
+
+                if !Self::light_sleep(cpu, rtc, wake_sources) {
+                    Self::wait_impl(cpu);
+                }
+            }
+        }
+    }
+
+    /// Enters light sleep if there is no work to do and the next alarm isn't
+    /// due soon, returns whether the chip was sleeping.
+    #[cfg(any(esp32, esp32s3, esp32c3, esp32c6))]
+    fn light_sleep(cpu: usize, rtc: &mut Rtc<'_>, wake_sources: &[&dyn WakeSource]) -> bool {
+        // Interrupts which arrive while the chip is sleeping are handled when the
+        // critical section ends, they don't wake it up.
+        critical_section::with(|cs| {
+            if SIGNAL_WORK_THREAD_MODE[cpu].load(Ordering::SeqCst) {
+                return false;
+            }
+
+            let now = crate::time_driver::now();
+            let next_alarm = crate::time_driver::next_alarm(cs);
+            let timer = if next_alarm == u64::MAX {
+                // Nothing could wake the chip up
+                if wake_sources.is_empty() {
+                    return false;
+                }
+                None
+            } else {
+                let timeout = next_alarm.saturating_sub(now);
+                if timeout < MIN_LIGHT_SLEEP_US {
+                    return false;
+                }
+                Some(TimerWakeupSource::new(core::time::Duration::from_micros(
+                    timeout,
+                )))
+            };
+
+            let rtc_before = rtc.get_time_us();
+            rtc.sleep_light(&[&ExecutorWakeSources {
+                timer,
+                wake_sources,
+            }]);
+            let slept = rtc.get_time_us().saturating_sub(rtc_before);
+
+            // The timers might have counted a part of the time before they were
+            // stopped
+            let counted = crate::time_driver::now() - now;
+            crate::time_driver::add_sleep_time(cs, slept.saturating_sub(counted));
+
+            true
+        })
+    }
+
     #[doc(hidden)]
     #[cfg(xtensa)]
     pub fn wait_impl(cpu: usize) {
@@ -180,6 +284,26 @@ impl Executor {
     }
 }
 
+/// The alarm timer and the wakeup sources passed to
+/// [Executor::run_with_light_sleep]
+#[cfg(any(esp32, esp32s3, esp32c3, esp32c6))]
+struct ExecutorWakeSources<'a> {
+    timer: Option<TimerWakeupSource>,
+    wake_sources: &'a [&'a dyn WakeSource],
+}
+
+#[cfg(any(esp32, esp32s3, esp32c3, esp32c6))]
+impl<'a> WakeSource for ExecutorWakeSources<'a> {
+    fn apply(&self, rtc: &Rtc<'_>, triggers: &mut WakeTriggers, sleep_config: &mut RtcSleepConfig) {
+        if let Some(timer) = &self.timer {
+            timer.apply(rtc, triggers, sleep_config);
+        }
+        for wake_source in self.wake_sources {
+            wake_source.apply(rtc, triggers, sleep_config);
+        }
+    }
+}
+
 impl Default for Executor {
     fn default() -> Self {
         Self::new()
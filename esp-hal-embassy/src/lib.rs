@@ -48,6 +48,9 @@ mod executor;
 mod time_driver;
 
 /// Initialize embassy
+///
+/// The alarms used by `embassy-time` are distributed over the given timers,
+/// a single timer (e.g. a SYSTIMER alarm) is enough to serve all of them.
 pub fn init(clocks: &Clocks, time_driver: &'static mut [Timer]) {
     EmbassyTimer::init(clocks, time_driver)
 }
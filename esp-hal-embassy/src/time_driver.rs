@@ -1,7 +1,9 @@
 use core::cell::{Cell, RefCell};
 
-use critical_section::Mutex;
+use critical_section::{CriticalSection, Mutex};
 use embassy_time_driver::{AlarmHandle, Driver};
+#[cfg(not(any(esp32, esp32s2)))]
+use esp_hal::timer::systimer::SystemTimer;
 use esp_hal::{
     clock::Clocks,
     interrupt::{InterruptHandler, Priority},
@@ -14,12 +16,24 @@ pub const MAX_SUPPORTED_ALARM_COUNT: usize = 7;
 
 pub type Timer = OneShotTimer<ErasedTimer>;
 
+/// Timeouts shorter than this might elapse before the timer is started.
+const MIN_TIMEOUT: u64 = 10;
+
+/// Alarms further in the future are split into multiple timeouts, which keeps
+/// every timeout in the range supported by the timers.
+const MAX_TIMEOUT: u64 = 3_600_000_000;
+
+/// The number of microseconds after which [current_time] wraps around to zero.
+#[cfg(not(any(esp32, esp32s2)))]
+const WRAP_PERIOD: u64 = (SystemTimer::BIT_MASK + 1) / (SystemTimer::TICKS_PER_SECOND / 1_000_000);
+
 static TIMERS: Mutex<RefCell<Option<&'static mut [Timer]>>> = Mutex::new(RefCell::new(None));
 
 #[allow(clippy::type_complexity)]
 struct AlarmState {
     pub callback: Cell<Option<(fn(*mut ()), *mut ())>>,
     pub allocated: Cell<bool>,
+    pub timestamp: Cell<u64>,
 }
 
 unsafe impl Send for AlarmState {}
@@ -29,12 +43,19 @@ impl AlarmState {
         Self {
             callback: Cell::new(None),
             allocated: Cell::new(false),
+            timestamp: Cell::new(u64::MAX),
         }
     }
 }
 
 pub(super) struct EmbassyTimer {
     alarms: Mutex<[AlarmState; MAX_SUPPORTED_ALARM_COUNT]>,
+    /// The last value read from [current_time] and the time accumulated by
+    /// previous wrap-arounds.
+    #[cfg(not(any(esp32, esp32s2)))]
+    time: Mutex<Cell<(u64, u64)>>,
+    /// Time the timers didn't count because the chip was in light sleep.
+    slept: Mutex<Cell<u64>>,
 }
 
 #[allow(clippy::declare_interior_mutable_const)]
@@ -42,10 +63,50 @@ const ALARM_STATE_NONE: AlarmState = AlarmState::new();
 
 embassy_time_driver::time_driver_impl!(static DRIVER: EmbassyTimer = EmbassyTimer {
     alarms: Mutex::new([ALARM_STATE_NONE; MAX_SUPPORTED_ALARM_COUNT]),
+    #[cfg(not(any(esp32, esp32s2)))]
+    time: Mutex::new(Cell::new((0, 0))),
+    slept: Mutex::new(Cell::new(0)),
 });
 
+/// The current `embassy-time` timestamp.
+#[cfg(all(feature = "executors", any(esp32, esp32s3, esp32c3, esp32c6)))]
+pub(crate) fn now() -> u64 {
+    DRIVER.now()
+}
+
+/// The timestamp of the earliest alarm, `u64::MAX` if no alarm is set.
+#[cfg(all(feature = "executors", any(esp32, esp32s3, esp32c3, esp32c6)))]
+pub(crate) fn next_alarm(cs: CriticalSection<'_>) -> u64 {
+    DRIVER
+        .alarms
+        .borrow(cs)
+        .iter()
+        .map(|alarm| alarm.timestamp.get())
+        .min()
+        .unwrap_or(u64::MAX)
+}
+
+/// Adds `duration` microseconds the timers didn't count while the chip was in
+/// light sleep to the time and re-arms the timers for the new time.
+#[cfg(all(feature = "executors", any(esp32, esp32s3, esp32c3, esp32c6)))]
+pub(crate) fn add_sleep_time(cs: CriticalSection<'_>, duration: u64) {
+    let slept = DRIVER.slept.borrow(cs);
+    slept.set(slept.get() + duration);
+
+    let now = DRIVER.now();
+    let mut timers = TIMERS.borrow_ref_mut(cs);
+    let timers = unwrap!(timers.as_mut());
+    for id in 0..timers.len() {
+        DRIVER.arm(cs, timers, id, now);
+    }
+}
+
 impl EmbassyTimer {
     pub(super) fn init(_clocks: &Clocks, timers: &'static mut [Timer]) {
+        if timers.is_empty() {
+            panic!("At least one timer is required.");
+        }
+
         if timers.len() > MAX_SUPPORTED_ALARM_COUNT {
             panic!(
                 "Maximum of {} timers can be used.",
@@ -64,6 +125,11 @@ impl EmbassyTimer {
 
         critical_section::with(|cs| {
             TIMERS.replace(cs, Some(timers));
+
+            // Arm the first timer right away, see `arm` for why.
+            let now = DRIVER.now();
+            let mut timers = TIMERS.borrow_ref_mut(cs);
+            DRIVER.arm(cs, unwrap!(timers.as_mut()), 0, now);
         });
 
         #[handler(priority = Priority::max())]
@@ -97,39 +163,99 @@ impl EmbassyTimer {
     }
 
     fn on_interrupt(&self, id: usize) {
-        let cb = critical_section::with(|cs| {
-            let mut timers = TIMERS.borrow_ref_mut(cs);
-            let timers = timers.as_mut().unwrap();
-            let timer = &mut timers[id];
+        let mut callbacks = [None; MAX_SUPPORTED_ALARM_COUNT];
 
-            timer.clear_interrupt();
+        critical_section::with(|cs| {
+            let mut timers = TIMERS.borrow_ref_mut(cs);
+            let timers = unwrap!(timers.as_mut());
+            let timer_count = timers.len();
 
-            let alarm = &self.alarms.borrow(cs)[id];
+            timers[id].clear_interrupt();
 
-            if let Some((f, ctx)) = alarm.callback.get() {
-                Some((f, ctx))
-            } else {
-                None
+            let now = self.now();
+            for (n, alarm) in self.alarms.borrow(cs).iter().enumerate() {
+                if n % timer_count == id && alarm.timestamp.get() <= now {
+                    alarm.timestamp.set(u64::MAX);
+                    callbacks[n] = alarm.callback.get();
+                }
             }
+
+            self.arm(cs, timers, id, now);
         });
 
-        if let Some((f, ctx)) = cb {
+        for (f, ctx) in callbacks.into_iter().flatten() {
             f(ctx);
         }
     }
 
-    fn arm(timer: &mut Timer, timestamp: u64) {
-        let now = current_time().duration_since_epoch();
-        let ts = timestamp.micros();
-        let timeout = if ts > now { ts - now } else { now };
-        timer.schedule(timeout).unwrap();
+    /// The time counted by the timers, without the time spent in light sleep.
+    fn counter_now(&self, cs: CriticalSection<'_>) -> u64 {
+        // The SYSTIMER counter is 52 bits wide and `current_time` wraps around
+        // after a couple of years, however `embassy-time` expects the time to
+        // never wrap.
+        #[cfg(not(any(esp32, esp32s2)))]
+        {
+            let time = self.time.borrow(cs);
+            let (last, mut offset) = time.get();
+            let now = current_time().ticks();
+
+            if now < last {
+                // Another context may have read a slightly newer value
+                // than ours. Only a large jump means the counter wrapped.
+                if last - now < WRAP_PERIOD / 2 {
+                    return last + offset;
+                }
+
+                offset += WRAP_PERIOD;
+            }
+
+            time.set((now, offset));
+            now + offset
+        }
+
+        #[cfg(any(esp32, esp32s2))]
+        {
+            let _ = cs;
+            current_time().ticks()
+        }
+    }
+
+    /// Arms the timer `id` for the earliest alarm it is responsible for.
+    ///
+    /// Alarms are distributed over the timers, so a single timer can serve all
+    /// of them.
+    fn arm(&self, cs: CriticalSection<'_>, timers: &mut [Timer], id: usize, now: u64) {
+        let timer_count = timers.len();
+        let next = self
+            .alarms
+            .borrow(cs)
+            .iter()
+            .enumerate()
+            .filter(|(n, _)| n % timer_count == id)
+            .map(|(_, alarm)| alarm.timestamp.get())
+            .min()
+            .unwrap_or(u64::MAX);
+
+        let timer = &mut timers[id];
+
+        // The first timer is kept running even without a pending alarm, which
+        // makes sure the time is read often enough to notice the counter
+        // wrapping around.
+        if next == u64::MAX && id != 0 {
+            timer.enable_interrupt(false);
+            timer.stop();
+            return;
+        }
+
+        let timeout = next.saturating_sub(now).clamp(MIN_TIMEOUT, MAX_TIMEOUT);
+        unwrap!(timer.schedule(timeout.micros()));
         timer.enable_interrupt(true);
     }
 }
 
 impl Driver for EmbassyTimer {
     fn now(&self) -> u64 {
-        current_time().ticks()
+        critical_section::with(|cs| self.counter_now(cs) + self.slept.borrow(cs).get())
     }
 
     unsafe fn allocate_alarm(&self) -> Option<AlarmHandle> {
@@ -154,27 +280,25 @@ impl Driver for EmbassyTimer {
     }
 
     fn set_alarm(&self, alarm: AlarmHandle, timestamp: u64) -> bool {
-        // we sometimes get called with `u64::MAX` for apparently not yet initialized
-        // timers which would fail later on
-        if timestamp == u64::MAX {
-            return false;
-        }
+        let n = alarm.id() as usize;
 
-        // The hardware fires the alarm even if timestamp is lower than the current
-        // time. In this case the interrupt handler will pend a wake-up when we exit the
-        // critical section.
-        //
-        // This is correct behavior. See https://docs.rs/embassy-time-driver/0.1.0/embassy_time_driver/trait.Driver.html#tymethod.set_alarm
-        // (... the driver should return true and arrange to call the alarm callback as
-        // soon as possible, but not synchronously.)
         critical_section::with(|cs| {
+            let now = self.now();
+
+            // If the timestamp has already passed the callback must not be
+            // called, `embassy-time` takes care of the expired alarm itself.
+            // A timestamp of `u64::MAX` disarms the alarm.
+            let expired = timestamp <= now;
+            let timestamp = if expired { u64::MAX } else { timestamp };
+            self.alarms.borrow(cs)[n].timestamp.set(timestamp);
+
             let mut timers = TIMERS.borrow_ref_mut(cs);
-            let timers = timers.as_mut().unwrap();
-            let timer = &mut timers[alarm.id() as usize];
+            let timers = unwrap!(timers.as_mut());
+            let id = n % timers.len();
 
-            Self::arm(timer, timestamp);
-        });
+            self.arm(cs, timers, id, now);
 
-        true
+            !expired
+        })
     }
 }
@@ -0,0 +1,126 @@
+//! Implementation of the `cipher` block cipher traits, see [AesCipher].
+
+use cipher::{
+    consts::{U1, U16},
+    inout::InOut,
+    Block,
+    BlockBackend,
+    BlockCipher,
+    BlockClosure,
+    BlockDecryptMut,
+    BlockEncryptMut,
+    BlockSizeUser,
+    ParBlocksSizeUser,
+};
+
+use super::{Aes, Key, Mode};
+
+/// The AES accelerator with a fixed key, implementing the block cipher traits
+/// of the `cipher` crate
+///
+/// This allows to use the block cipher modes of the RustCrypto crates with the
+/// accelerator, e.g. CBC with the `cbc` crate or CTR with the `ctr` crate.
+/// The key size selects AES-128, AES-192 or AES-256.
+///
+/// Only the single blocks are processed by the accelerator, the cipher mode
+/// itself runs on the CPU.
+///
+/// ```rust, ignore
+/// use cbc::cipher::{BlockEncryptMut, InnerIvInit};
+///
+/// let mut aes = Aes::new(peripherals.AES);
+/// let mut encryptor =
+///     cbc::Encryptor::inner_iv_init(AesCipher::new(&mut aes, key), &iv.into());
+/// encryptor.encrypt_blocks_mut(&mut blocks);
+/// ```
+pub struct AesCipher<'a, 'd> {
+    aes: &'a mut Aes<'d>,
+    key: Key,
+}
+
+impl<'a, 'd> AesCipher<'a, 'd> {
+    /// Uses `aes` with `key` for all blocks.
+    pub fn new<K>(aes: &'a mut Aes<'d>, key: K) -> Self
+    where
+        K: Into<Key>,
+    {
+        Self {
+            aes,
+            key: key.into(),
+        }
+    }
+
+    fn encryption_mode(&self) -> Mode {
+        match self.key {
+            Key::Key16(_) => Mode::Encryption128,
+            #[cfg(any(esp32, esp32s2))]
+            Key::Key24(_) => Mode::Encryption192,
+            Key::Key32(_) => Mode::Encryption256,
+        }
+    }
+
+    fn decryption_mode(&self) -> Mode {
+        match self.key {
+            Key::Key16(_) => Mode::Decryption128,
+            #[cfg(any(esp32, esp32s2))]
+            Key::Key24(_) => Mode::Decryption192,
+            Key::Key32(_) => Mode::Decryption256,
+        }
+    }
+
+    fn backend(&mut self, mode: Mode) -> Backend<'_, 'd> {
+        // The key and the mode stay the same for all blocks processed by the
+        // backend
+        self.aes.write_key(self.key.as_slice());
+        self.aes.set_mode(mode as u8);
+
+        Backend { aes: &mut *self.aes }
+    }
+}
+
+impl<'a, 'd> BlockSizeUser for AesCipher<'a, 'd> {
+    type BlockSize = U16;
+}
+
+impl<'a, 'd> BlockCipher for AesCipher<'a, 'd> {}
+
+impl<'a, 'd> BlockEncryptMut for AesCipher<'a, 'd> {
+    fn encrypt_with_backend_mut(&mut self, f: impl BlockClosure<BlockSize = Self::BlockSize>) {
+        let mode = self.encryption_mode();
+        f.call(&mut self.backend(mode));
+    }
+}
+
+impl<'a, 'd> BlockDecryptMut for AesCipher<'a, 'd> {
+    fn decrypt_with_backend_mut(&mut self, f: impl BlockClosure<BlockSize = Self::BlockSize>) {
+        let mode = self.decryption_mode();
+        f.call(&mut self.backend(mode));
+    }
+}
+
+/// Processes blocks with the key and mode written to the accelerator
+struct Backend<'b, 'd> {
+    aes: &'b mut Aes<'d>,
+}
+
+impl<'b, 'd> BlockSizeUser for Backend<'b, 'd> {
+    type BlockSize = U16;
+}
+
+impl<'b, 'd> ParBlocksSizeUser for Backend<'b, 'd> {
+    type ParBlocksSize = U1;
+}
+
+impl<'b, 'd> BlockBackend for Backend<'b, 'd> {
+    fn proc_block(&mut self, mut block: InOut<'_, '_, Block<Self>>) {
+        let mut data = [0u8; 16];
+        data.copy_from_slice(block.get_in());
+
+        self.aes.set_block(&data);
+        self.aes.start();
+        while !(self.aes.is_idle()) {}
+        self.aes.get_block(&mut data);
+
+        block.get_out().copy_from_slice(&data);
+    }
+}
@@ -13,6 +13,10 @@
 //! When using AES-DMA, the peripheral can be configured to use different block
 //! cipher modes such as ECB, CBC, OFB, CTR, CFB8, and CFB128.
 //!
+//! With the `cipher` feature [AesCipher] implements the block cipher traits of
+//! the `cipher` crate, so the block cipher modes of the RustCrypto crates like
+//! `cbc` and `ctr` can be used with the accelerator on all chips.
+//!
 //! ## Examples
 //! ### Encrypting and Decrypting a Message
 //! Simple example of encrypting and decrypting a message using AES-128:
@@ -60,6 +64,12 @@ use crate::{
 #[cfg_attr(esp32h2, path = "esp32cX.rs")]
 mod aes_spec_impl;
 
+#[cfg(feature = "cipher")]
+mod block_cipher;
+
+#[cfg(feature = "cipher")]
+pub use block_cipher::AesCipher;
+
 const ALIGN_SIZE: usize = core::mem::size_of::<u32>();
 
 /// Represents the various key sizes allowed for AES encryption and decryption.
@@ -326,7 +336,7 @@ pub mod dma {
         }
 
         fn peripheral_dma_stop(&mut self) {
-            unreachable!("unsupported")
+            self.finish_transform();
         }
     }
 
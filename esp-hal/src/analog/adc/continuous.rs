@@ -0,0 +1,532 @@
+//! # Continuous ADC sampling using DMA
+//!
+//! ## Overview
+//! In continuous mode the digital controller of the SAR ADC converts the
+//! channels configured in the [AdcConfig] one after another, paced by a timer,
+//! and stores the results in memory via DMA.
+//!
+//! The channels are converted in ascending order of their channel number, the
+//! samples of the different channels are interleaved in the buffer returned by
+//! [AdcContinuous::read_samples].
+//!
+//! To sample without gaps, [AdcContinuous::start] keeps the DMA writing into
+//! the buffer as a ring. The conversion results are taken from the ring with
+//! [AdcContinuousTransfer::read] or [AdcContinuousTransfer::read_async] as
+//! [AdcSample]s, which also tell the channel each result belongs to.
+//!
+//! ## Examples
+//! ```rust, no_run
+#![doc = crate::before_snippet!()]
+//! # use esp_hal::analog::adc::{AdcConfig, AdcContinuous, Attenuation};
+//! # use esp_hal::dma::{Dma, DmaPriority};
+//! # use esp_hal::dma_buffers;
+//! # use esp_hal::gpio::Io;
+//! # use esp_hal::prelude::*;
+//! let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+//! let mut adc1_config = AdcConfig::new();
+//! let _pin = adc1_config.enable_pin(io.pins.gpio2,
+//! Attenuation::Attenuation11dB);
+//!
+//! let dma = Dma::new(peripherals.DMA);
+//! let (_, _, rx_buffer, rx_descriptors) = dma_buffers!(4000);
+//!
+//! // Only the RX half is needed, the TX half can be used by another driver.
+//! let (_tx, rx) = dma
+//!     .channel0
+//!     .configure_for_async(false, DmaPriority::Priority0)
+//!     .split();
+//!
+//! let mut adc = AdcContinuous::new(
+//!     peripherals.ADC1,
+//!     adc1_config,
+//!     rx,
+//!     rx_descriptors,
+//!     rx_buffer,
+//!     20.kHz(),
+//!     &clocks,
+//! )
+//! .unwrap();
+//!
+//! async {
+//!     let samples: &[u16] = adc.read_samples().await.unwrap();
+//! };
+//! # }
+//! ```
+
+use fugit::HertzU32;
+
+use super::{AdcConfig, RegisterAccess};
+use crate::{
+    clock::Clocks,
+    dma::{
+        asynch::{DmaRxDoneChFuture, DmaRxFuture, OnDrop},
+        AdcPeripheral,
+        ChannelRx,
+        DescriptorChain,
+        DmaChannel,
+        DmaDescriptor,
+        DmaError,
+        DmaPeripheral,
+        RxCircularState,
+        RxPrivate,
+    },
+    peripheral::{Peripheral, PeripheralRef},
+    peripherals::{ADC1, APB_SARADC},
+    system::PeripheralClockControl,
+};
+
+/// The number of entries in the pattern table of the digital controller.
+const MAX_PATTERN_LEN: usize = 8;
+
+/// The number of bytes the digital controller writes per conversion.
+const BYTES_PER_SAMPLE: usize = 4;
+
+/// The digital controller's clock divider, the conversion timer runs at the
+/// source clock divided by `2 * (CLKM_DIV_NUM + 1)`.
+const CLKM_DIV_NUM: u8 = 15;
+
+/// The mask of the channel number in the pattern table and the conversion
+/// results.
+#[cfg(esp32s3)]
+const CHANNEL_MASK: u32 = 0b1111;
+#[cfg(not(esp32s3))]
+const CHANNEL_MASK: u32 = 0b111;
+
+/// Errors returned by [AdcContinuous].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ContinuousError {
+    /// General DMA error
+    DmaError(DmaError),
+    /// No channel was enabled in the [AdcConfig]
+    NoChannels,
+    /// More channels were enabled than fit into the pattern table
+    TooManyChannels,
+    /// The sample rate can't be reached with the conversion timer
+    InvalidSampleRate,
+    /// The buffer is empty or too large
+    InvalidBuffer,
+}
+
+impl From<DmaError> for ContinuousError {
+    fn from(value: DmaError) -> Self {
+        ContinuousError::DmaError(value)
+    }
+}
+
+/// A conversion result of the digital controller
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdcSample {
+    /// The channel which was converted
+    pub channel: u8,
+    /// The 12 bit conversion result
+    pub value: u16,
+}
+
+impl AdcSample {
+    /// Unpacks a conversion result as written by the digital controller.
+    ///
+    /// Bits 0..12 hold the result, followed by the channel starting at bit 13
+    /// and the ADC unit. The channel is 4 bits wide on the ESP32-S3 and 3 bits
+    /// wide on the other chips.
+    fn from_raw(word: u32) -> Self {
+        Self {
+            channel: ((word >> 13) & CHANNEL_MASK) as u8,
+            value: (word & 0xfff) as u16,
+        }
+    }
+}
+
+/// Continuously samples the channels of ADC1 into a DMA buffer.
+pub struct AdcContinuous<'d, CH>
+where
+    CH: DmaChannel,
+    CH::P: AdcPeripheral,
+{
+    _adc: PeripheralRef<'d, ADC1>,
+    channel: ChannelRx<'d, CH>,
+    rx_chain: DescriptorChain,
+    buffer: &'static mut [u8],
+}
+
+impl<'d, CH> AdcContinuous<'d, CH>
+where
+    CH: DmaChannel,
+    CH::P: AdcPeripheral,
+{
+    /// Configure the digital controller to sample the channels enabled in
+    /// `config` at `sample_rate`.
+    ///
+    /// The sample rate is the number of conversions per second, i.e. it is
+    /// shared by all enabled channels. Every call to
+    /// [AdcContinuous::read_samples] fills the whole `buffer`, which holds
+    /// `buffer.len() / 4` samples.
+    ///
+    /// `channel` is the RX half of a DMA channel configured for async
+    /// operation, see [crate::dma::Channel::split].
+    pub fn new(
+        adc: impl Peripheral<P = ADC1> + 'd,
+        config: AdcConfig<ADC1>,
+        mut channel: ChannelRx<'d, CH>,
+        descriptors: &'static mut [DmaDescriptor],
+        buffer: &'static mut [u8],
+        sample_rate: HertzU32,
+        clocks: &Clocks<'_>,
+    ) -> Result<Self, ContinuousError> {
+        crate::into_ref!(adc);
+
+        let samples = buffer.len() / BYTES_PER_SAMPLE;
+        if samples == 0 || samples > u16::MAX as usize {
+            return Err(ContinuousError::InvalidBuffer);
+        }
+
+        let enabled = config.attenuations.iter().filter(|a| a.is_some()).count();
+        if enabled == 0 {
+            return Err(ContinuousError::NoChannels);
+        }
+        if enabled > MAX_PATTERN_LEN {
+            return Err(ContinuousError::TooManyChannels);
+        }
+
+        #[cfg(any(esp32c3, esp32s3))]
+        let source_clock = clocks.apb_clock;
+        #[cfg(any(esp32c6, esp32h2))]
+        let source_clock = clocks.xtal_clock;
+
+        let timer_clock = source_clock.to_Hz() / (2 * (CLKM_DIV_NUM as u32 + 1));
+        let interval = timer_clock / sample_rate.to_Hz().max(1);
+        if !(1..=0xfff).contains(&interval) {
+            return Err(ContinuousError::InvalidSampleRate);
+        }
+
+        PeripheralClockControl::enable(crate::system::Peripheral::ApbSarAdc);
+
+        channel.init_channel();
+
+        let sar_adc = unsafe { &*APB_SARADC::PTR };
+
+        #[cfg(any(esp32c3, esp32s3))]
+        sar_adc.clkm_conf().modify(|_, w| unsafe {
+            // APB clock
+            w.clk_sel().bits(2);
+            w.clkm_div_num().bits(CLKM_DIV_NUM);
+            w.clkm_div_b().bits(1);
+            w.clkm_div_a().bits(0);
+            w.clk_en().set_bit()
+        });
+
+        #[cfg(any(esp32c6, esp32h2))]
+        {
+            let pcr = unsafe { &*crate::peripherals::PCR::PTR };
+            pcr.saradc_clkm_conf().modify(|_, w| unsafe {
+                // XTAL clock
+                w.saradc_clkm_sel().bits(0);
+                w.saradc_clkm_div_num().bits(CLKM_DIV_NUM);
+                w.saradc_clkm_div_b().bits(1);
+                w.saradc_clkm_div_a().bits(0);
+                w.saradc_clkm_en().set_bit()
+            });
+        }
+
+        // Hand ADC1 over from the RTC controller to the digital controller
+        #[cfg(esp32s3)]
+        {
+            let sensors = unsafe { &*crate::peripherals::SENS::PTR };
+            sensors
+                .sar_peri_clk_gate_conf()
+                .modify(|_, w| w.saradc_clk_en().set_bit());
+            sensors
+                .sar_meas1_mux()
+                .modify(|_, w| w.sar1_dig_force().set_bit());
+            sensors.sar_meas1_ctrl2().modify(|_, w| {
+                w.meas1_start_force().set_bit();
+                w.sar1_en_pad_force().set_bit()
+            });
+        }
+
+        ADC1::set_init_code(0);
+
+        // Program the channels in ascending order into the pattern table, every
+        // entry is 6 bits wide and the first entry occupies the upper bits.
+        let mut table = [0u32; 2];
+        for (index, (channel, attenuation)) in config
+            .attenuations
+            .iter()
+            .enumerate()
+            .filter_map(|(channel, atten)| atten.map(|atten| (channel, atten)))
+            .enumerate()
+        {
+            let pattern = (attenuation as u32 & 0b11) | ((channel as u32 & CHANNEL_MASK) << 2);
+            table[index / 4] |= (pattern << 18) >> ((index % 4) * 6);
+        }
+
+        #[cfg(not(esp32s3))]
+        {
+            sar_adc.ctrl().modify(|_, w| unsafe {
+                w.start_force().clear_bit();
+                w.sar_clk_gated().set_bit();
+                w.sar_clk_div().bits(1);
+                w.xpd_sar_force().bits(0b11);
+                w.sar_patt_len().bits(enabled as u8 - 1)
+            });
+            sar_adc
+                .sar_patt_tab1()
+                .write(|w| unsafe { w.sar_patt_tab1().bits(table[0]) });
+            sar_adc
+                .sar_patt_tab2()
+                .write(|w| unsafe { w.sar_patt_tab2().bits(table[1]) });
+            sar_adc.ctrl().modify(|_, w| w.sar_patt_p_clear().set_bit());
+            sar_adc
+                .ctrl()
+                .modify(|_, w| w.sar_patt_p_clear().clear_bit());
+        }
+
+        // The ESP32-S3 has a pattern table per ADC unit, only the one of ADC1
+        // is used in single unit mode.
+        #[cfg(esp32s3)]
+        {
+            sar_adc.ctrl().modify(|_, w| unsafe {
+                w.start_force().clear_bit();
+                w.work_mode().bits(0);
+                w.sar_sel().clear_bit();
+                w.sar_clk_gated().set_bit();
+                w.sar_clk_div().bits(1);
+                w.xpd_sar_force().bits(0b11);
+                w.sar1_patt_len().bits(enabled as u8 - 1)
+            });
+            sar_adc
+                .sar1_patt_tab1()
+                .write(|w| unsafe { w.sar1_patt_tab1().bits(table[0]) });
+            sar_adc
+                .sar1_patt_tab2()
+                .write(|w| unsafe { w.sar1_patt_tab2().bits(table[1]) });
+            sar_adc
+                .ctrl()
+                .modify(|_, w| w.sar1_patt_p_clear().set_bit());
+            sar_adc
+                .ctrl()
+                .modify(|_, w| w.sar1_patt_p_clear().clear_bit());
+        }
+
+        sar_adc.ctrl2().modify(|_, w| unsafe {
+            // Pace the conversions with the timer of the digital controller
+            #[cfg(esp32s3)]
+            w.timer_sel().set_bit();
+            w.meas_num_limit().clear_bit();
+            w.timer_target().bits(interval as u16)
+        });
+
+        sar_adc
+            .dma_conf()
+            .modify(|_, w| unsafe { w.adc_eof_num().bits(samples as u16) });
+
+        Ok(Self {
+            _adc: adc,
+            channel,
+            rx_chain: DescriptorChain::new(descriptors),
+            buffer,
+        })
+    }
+
+    /// Fills the buffer with new samples and returns them.
+    ///
+    /// Only the 12 bit conversion results are returned, the samples of the
+    /// enabled channels are interleaved in ascending order of their channel
+    /// number. Conversions happening while no read is in progress are
+    /// discarded.
+    pub async fn read_samples(&mut self) -> Result<&[u16], ContinuousError> {
+        let len = self.buffer.len() - self.buffer.len() % BYTES_PER_SAMPLE;
+
+        self.rx_chain
+            .fill_for_rx(false, self.buffer.as_mut_ptr(), len)?;
+
+        let future = DmaRxFuture::new(&mut self.channel).with_chain(self.rx_chain.span());
+        unsafe {
+            future
+                .rx
+                .prepare_transfer_without_start(DmaPeripheral::Adc, &self.rx_chain)
+                .and_then(|_| future.rx.start_transfer())?;
+        }
+
+        Self::start_conversions();
+        let guard = OnDrop::new(|| Self::stop_conversions());
+        let result = future.await;
+        guard.defuse();
+        Self::stop_conversions();
+        result?;
+
+        // Every conversion result is stored as a 32 bit word, compact the data
+        // part into 16 bit values in place. The buffer isn't necessarily
+        // aligned, so the values start at the first 16 bit boundary. The value
+        // at index `i` ends up at most at byte `2i+2`, which has been read
+        // before.
+        let samples = len / BYTES_PER_SAMPLE;
+        let offset = self.buffer.as_ptr().align_offset(2);
+        for i in 0..samples {
+            let word = u32::from_le_bytes(
+                self.buffer[i * BYTES_PER_SAMPLE..][..BYTES_PER_SAMPLE]
+                    .try_into()
+                    .unwrap(),
+            );
+            let value = (word & 0xfff) as u16;
+            self.buffer[offset + i * 2..][..2].copy_from_slice(&value.to_le_bytes());
+        }
+
+        // SAFETY: the pointer is 16 bit aligned and the following `samples`
+        // values have been initialized above.
+        Ok(unsafe {
+            core::slice::from_raw_parts(self.buffer.as_ptr().add(offset) as *const u16, samples)
+        })
+    }
+
+    /// Starts sampling continuously into the buffer, which is used as a ring.
+    ///
+    /// The samples are taken from the ring with the returned
+    /// [AdcContinuousTransfer], sampling stops when it's dropped.
+    pub fn start(&mut self) -> Result<AdcContinuousTransfer<'_, 'd, CH>, ContinuousError> {
+        let len = self.buffer.len() - self.buffer.len() % BYTES_PER_SAMPLE;
+
+        self.rx_chain
+            .fill_for_rx(true, self.buffer.as_mut_ptr(), len)?;
+
+        unsafe {
+            self.channel
+                .prepare_transfer_without_start(DmaPeripheral::Adc, &self.rx_chain)?;
+        }
+        self.channel.start_transfer()?;
+
+        let state = RxCircularState::new(&mut self.rx_chain);
+        Self::start_conversions();
+
+        Ok(AdcContinuousTransfer { adc: self, state })
+    }
+
+    fn start_conversions() {
+        let sar_adc = unsafe { &*APB_SARADC::PTR };
+
+        sar_adc
+            .dma_conf()
+            .modify(|_, w| w.adc_reset_fsm().set_bit());
+        sar_adc
+            .dma_conf()
+            .modify(|_, w| w.adc_reset_fsm().clear_bit());
+
+        sar_adc.dma_conf().modify(|_, w| w.adc_trans().set_bit());
+        sar_adc.ctrl2().modify(|_, w| w.timer_en().set_bit());
+    }
+
+    fn stop_conversions() {
+        let sar_adc = unsafe { &*APB_SARADC::PTR };
+
+        sar_adc.ctrl2().modify(|_, w| w.timer_en().clear_bit());
+        sar_adc.dma_conf().modify(|_, w| w.adc_trans().clear_bit());
+    }
+}
+
+impl<'d, CH> Drop for AdcContinuous<'d, CH>
+where
+    CH: DmaChannel,
+    CH::P: AdcPeripheral,
+{
+    fn drop(&mut self) {
+        Self::stop_conversions();
+    }
+}
+
+/// Continuous sampling started by [AdcContinuous::start]
+#[must_use]
+pub struct AdcContinuousTransfer<'a, 'd, CH>
+where
+    CH: DmaChannel,
+    CH::P: AdcPeripheral,
+{
+    adc: &'a mut AdcContinuous<'d, CH>,
+    state: RxCircularState,
+}
+
+impl<'a, 'd, CH> AdcContinuousTransfer<'a, 'd, CH>
+where
+    CH: DmaChannel,
+    CH::P: AdcPeripheral,
+{
+    /// Number of samples which can be read without waiting.
+    ///
+    /// Fails with [DmaError::BufferOverrun] if samples got lost because they
+    /// weren't read fast enough. The unread samples are discarded and
+    /// sampling continues.
+    pub fn available(&mut self) -> Result<usize, ContinuousError> {
+        self.state.update()?;
+        Ok(self.state.available / BYTES_PER_SAMPLE)
+    }
+
+    /// Moves the samples converted so far into `samples` without waiting and
+    /// returns how many were read.
+    ///
+    /// Fails with [DmaError::BufferOverrun] if samples got lost because they
+    /// weren't read fast enough.
+    pub fn read(&mut self, samples: &mut [AdcSample]) -> Result<usize, ContinuousError> {
+        self.state.update()?;
+
+        let mut read = 0;
+        while read < samples.len() && self.state.available >= BYTES_PER_SAMPLE {
+            let offset = read;
+            let consumed = self.state.pop_with(|buffer| {
+                // A sample split between two descriptors stays in the ring
+                // until the second one is filled.
+                let count = usize::min(buffer.len() / BYTES_PER_SAMPLE, samples.len() - offset);
+                for (sample, raw) in samples[offset..]
+                    .iter_mut()
+                    .zip(buffer.chunks_exact(BYTES_PER_SAMPLE))
+                    .take(count)
+                {
+                    *sample = AdcSample::from_raw(u32::from_le_bytes(raw.try_into().unwrap()));
+                }
+                count * BYTES_PER_SAMPLE
+            })?;
+
+            if consumed == 0 {
+                break;
+            }
+            read += consumed / BYTES_PER_SAMPLE;
+        }
+
+        Ok(read)
+    }
+
+    /// Moves the samples converted so far into `samples`, waiting for at
+    /// least one sample to be converted, and returns how many were read.
+    ///
+    /// Fails with [DmaError::BufferOverrun] if samples got lost because they
+    /// weren't read fast enough.
+    pub async fn read_async(
+        &mut self,
+        samples: &mut [AdcSample],
+    ) -> Result<usize, ContinuousError> {
+        if samples.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let read = self.read(samples)?;
+            if read > 0 {
+                return Ok(read);
+            }
+
+            DmaRxDoneChFuture::new(&mut self.adc.channel)
+                .with_chain(self.adc.rx_chain.span())
+                .await?;
+        }
+    }
+}
+
+impl<'a, 'd, CH> Drop for AdcContinuousTransfer<'a, 'd, CH>
+where
+    CH: DmaChannel,
+    CH::P: AdcPeripheral,
+{
+    fn drop(&mut self) {
+        AdcContinuous::<'d, CH>::stop_conversions();
+        self.adc.channel.stop_transfer();
+    }
+}
@@ -1,5 +1,6 @@
-use super::{AdcConfig, Attenuation};
+use super::{AdcConfig, Attenuation, Oversampler};
 use crate::{
+    efuse::Efuse,
     peripheral::PeripheralRef,
     peripherals::{ADC1, ADC2, RTC_IO, SENS},
 };
@@ -196,6 +197,7 @@ pub struct Adc<'d, ADC> {
     _adc: PeripheralRef<'d, ADC>,
     attenuations: [Option<Attenuation>; NUM_ATTENS],
     active_channel: Option<u8>,
+    oversampler: Oversampler,
 }
 
 impl<'d, ADCI> Adc<'d, ADCI>
@@ -274,6 +276,7 @@ where
             _adc: adc_instance.into_ref(),
             attenuations: config.attenuations,
             active_channel: None,
+            oversampler: Oversampler::new(config.oversampling),
         }
     }
 
@@ -300,11 +303,9 @@ where
         } else {
             // If no conversions are in progress, start a new one for given channel
             self.active_channel = Some(PIN::CHANNEL);
+            self.oversampler.reset();
 
-            ADCI::set_en_pad(PIN::CHANNEL);
-
-            ADCI::clear_start_sar();
-            ADCI::set_start_sar();
+            Self::start_sample(PIN::CHANNEL);
         }
 
         // Wait for ADC to finish conversion
@@ -316,11 +317,25 @@ where
         // Get converted value
         let converted_value = ADCI::read_data_sar();
 
+        // Keep sampling the same channel until enough conversions have been
+        // accumulated
+        let Some(converted_value) = self.oversampler.push(converted_value) else {
+            Self::start_sample(PIN::CHANNEL);
+            return Err(nb::Error::WouldBlock);
+        };
+
         // Mark that no conversions are currently in progress
         self.active_channel = None;
 
         Ok(converted_value)
     }
+
+    fn start_sample(channel: u8) {
+        ADCI::set_en_pad(channel);
+
+        ADCI::clear_start_sar();
+        ADCI::set_start_sar();
+    }
 }
 
 impl<'d, ADC1> Adc<'d, ADC1> {
@@ -339,6 +354,67 @@ impl<'d, ADC1> Adc<'d, ADC1> {
     }
 }
 
+/// Fixed-point scale of [AdcCalibration]'s gain.
+const COEFF_A_SCALE: u32 = 16;
+
+/// Two point calibration of ADC1, equivalent to `esp_adc_cal` of ESP-IDF.
+///
+/// The ADC characteristics are estimated from the two point calibration values
+/// burned into eFuse during manufacturing. If those aren't available the
+/// reference voltage from eFuse is used, falling back to the nominal 1100 mV.
+///
+/// The conversion expects readings taken at 12 bit resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdcCalibration {
+    /// Gain of the characteristic, a fixed-point number with
+    /// [COEFF_A_SCALE] fractional bits.
+    coeff_a: u32,
+    /// Offset of the characteristic in millivolts.
+    coeff_b: u32,
+}
+
+impl AdcCalibration {
+    /// Create the calibration for readings taken with the given attenuation.
+    pub fn new(atten: Attenuation) -> Self {
+        // see <https://github.com/espressif/esp-idf/blob/903af13e8/components/esp_adc/esp32/adc_cali_line_fitting.c#L33>
+        const TP_ATTEN_SCALE: [u32; 4] = [65504, 86975, 120389, 224310];
+        const TP_ATTEN_OFFSET: [u32; 4] = [0, 1, 27, 54];
+        const VREF_ATTEN_SCALE: [u32; 4] = [57431, 76236, 105481, 196602];
+        const VREF_ATTEN_OFFSET: [u32; 4] = [75, 78, 107, 142];
+
+        const TP_LOW_MV: u32 = 150;
+        const TP_HIGH_MV: u32 = 850;
+
+        let atten = atten as usize;
+
+        if let Some((low, high)) = Efuse::get_adc1_two_point_cal() {
+            let (low, high) = (low as u32, high as u32);
+            let delta_x = high - low;
+            let delta_v = TP_HIGH_MV - TP_LOW_MV;
+
+            Self {
+                coeff_a: (delta_v * TP_ATTEN_SCALE[atten] + delta_x / 2) / delta_x,
+                coeff_b: TP_HIGH_MV - (delta_v * high + delta_x / 2) / delta_x
+                    + TP_ATTEN_OFFSET[atten],
+            }
+        } else {
+            let vref = Efuse::get_adc_vref().unwrap_or(1100) as u32;
+
+            Self {
+                coeff_a: vref * VREF_ATTEN_SCALE[atten] / 4096,
+                coeff_b: VREF_ATTEN_OFFSET[atten],
+            }
+        }
+    }
+
+    /// Convert a raw 12 bit reading to millivolts.
+    pub fn raw_to_mv(&self, raw: u16) -> u16 {
+        let round = 1 << (COEFF_A_SCALE - 1);
+
+        (((self.coeff_a * raw as u32 + round) >> COEFF_A_SCALE) + self.coeff_b) as u16
+    }
+}
+
 #[cfg(feature = "embedded-hal-02")]
 impl<'d, ADCI, PIN> embedded_hal_02::adc::OneShot<ADCI, u16, super::AdcPin<PIN, ADCI>>
     for Adc<'d, ADCI>
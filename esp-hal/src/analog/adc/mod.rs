@@ -16,6 +16,8 @@
 //!
 //! ## Usage
 //! The ADC driver implements the `embedded-hal@0.2.x` ADC traits.
+//! `embedded-hal@1.0` doesn't define ADC traits, the `OneShot` implementation
+//! is only available with the `embedded-hal-02` feature.
 //!
 //! ## Examples
 //! #### Read an analog signal from a pin
@@ -56,6 +58,8 @@
 //! [ADC calibration is not implemented for all targets]: https://github.com/esp-rs/esp-hal/issues/326
 use core::marker::PhantomData;
 
+#[cfg(all(feature = "async", any(esp32c3, esp32c6, esp32h2, esp32s3)))]
+pub use self::continuous::*;
 pub use self::implementation::*;
 use crate::gpio::AnalogPin;
 
@@ -64,6 +68,9 @@ use crate::gpio::AnalogPin;
 #[cfg_attr(any(esp32s2, esp32s3), path = "xtensa.rs")]
 mod implementation;
 
+#[cfg(all(feature = "async", any(esp32c3, esp32c6, esp32h2, esp32s3)))]
+mod continuous;
+
 /// The attenuation of the ADC pin.
 ///
 /// The effective measurement range for a given attuenation is dependent on the
@@ -91,6 +98,71 @@ pub enum AdcCalSource {
     Ref,
 }
 
+/// The number of conversions averaged into a single reading.
+///
+/// Averaging `N` conversions reduces noise and increases the effective
+/// resolution by `log2(N) / 2` bits, e.g. oversampling by 16 gains 2 bits of
+/// accuracy. The average is still reported in the configured resolution.
+///
+/// The conversions are averaged in software, so a reading takes `N` times as
+/// long.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum OversamplingRatio {
+    /// No oversampling
+    #[default]
+    X1  = 1,
+    /// Average 2 conversions
+    X2  = 2,
+    /// Average 4 conversions
+    X4  = 4,
+    /// Average 8 conversions
+    X8  = 8,
+    /// Average 16 conversions
+    X16 = 16,
+    /// Average 64 conversions
+    X64 = 64,
+}
+
+/// Accumulates conversion results according to an [OversamplingRatio].
+#[derive(Default)]
+struct Oversampler {
+    ratio: OversamplingRatio,
+    sum: u32,
+    count: u8,
+}
+
+impl Oversampler {
+    fn new(ratio: OversamplingRatio) -> Self {
+        Self {
+            ratio,
+            ..Default::default()
+        }
+    }
+
+    /// Discard the results accumulated so far.
+    fn reset(&mut self) {
+        self.sum = 0;
+        self.count = 0;
+    }
+
+    /// Add a conversion result, returns the average once enough results
+    /// have been accumulated.
+    fn push(&mut self, value: u16) -> Option<u16> {
+        self.sum += value as u32;
+        self.count += 1;
+
+        if self.count < self.ratio as u8 {
+            return None;
+        }
+
+        let average = self.sum / self.count as u32;
+        self.reset();
+
+        Some(average as u16)
+    }
+}
+
 /// An I/O pin which can be read using the ADC.
 pub struct AdcPin<PIN, ADCI, CS = ()> {
     pub pin: PIN,
@@ -115,6 +187,7 @@ where
 pub struct AdcConfig<ADCI> {
     pub resolution: Resolution,
     pub attenuations: [Option<Attenuation>; NUM_ATTENS],
+    pub oversampling: OversamplingRatio,
     _phantom: PhantomData<ADCI>,
 }
 
@@ -124,6 +197,13 @@ impl<ADCI> AdcConfig<ADCI> {
         Self::default()
     }
 
+    /// Average multiple conversions into every reading, see
+    /// [OversamplingRatio].
+    pub fn oversampling(mut self, ratio: OversamplingRatio) -> Self {
+        self.oversampling = ratio;
+        self
+    }
+
     /// Enable the specified pin with the given attenuation
     pub fn enable_pin<PIN>(&mut self, pin: PIN, attenuation: Attenuation) -> AdcPin<PIN, ADCI>
     where
@@ -170,6 +250,7 @@ impl<ADCI> Default for AdcConfig<ADCI> {
         Self {
             resolution: Resolution::default(),
             attenuations: [None; NUM_ATTENS],
+            oversampling: OversamplingRatio::default(),
             _phantom: PhantomData,
         }
     }
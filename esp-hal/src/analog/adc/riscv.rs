@@ -1,6 +1,6 @@
 #[cfg(not(esp32h2))]
 pub use self::calibration::*;
-use super::{AdcCalSource, AdcConfig, Attenuation};
+use super::{AdcCalSource, AdcConfig, Attenuation, Oversampler};
 #[cfg(any(esp32c6, esp32h2))]
 use crate::clock::clocks_ll::regi2c_write_mask;
 #[cfg(any(esp32c2, esp32c3, esp32c6))]
@@ -395,6 +395,10 @@ pub struct Adc<'d, ADCI> {
     _adc: PeripheralRef<'d, ADCI>,
     attenuations: [Option<Attenuation>; NUM_ATTENS],
     active_channel: Option<u8>,
+    oversampler: Oversampler,
+    /// Whether the next conversion of an oversampled reading still needs to
+    /// be started.
+    restart: bool,
 }
 
 impl<'d, ADCI> Adc<'d, ADCI>
@@ -420,6 +424,8 @@ where
             _adc: adc_instance.into_ref(),
             attenuations: config.attenuations,
             active_channel: None,
+            oversampler: Oversampler::new(config.oversampling),
+            restart: false,
         }
     }
 
@@ -447,25 +453,19 @@ where
             if active_channel != PIN::CHANNEL {
                 return Err(nb::Error::WouldBlock);
             }
+
+            // The previous conversion of an oversampled reading has finished,
+            // start the next one
+            if self.restart {
+                self.restart = false;
+                self.start_sample(pin);
+            }
         } else {
             // If no conversions are in progress, start a new one for given channel
             self.active_channel = Some(PIN::CHANNEL);
+            self.oversampler.reset();
 
-            // Set ADC unit calibration according used scheme for pin
-            ADCI::set_init_code(pin.cal_scheme.adc_cal());
-
-            let channel = self.active_channel.unwrap();
-            let attenuation = self.attenuations[channel as usize].unwrap() as u8;
-            ADCI::config_onetime_sample(channel, attenuation);
-            ADCI::start_onetime_sample();
-
-            // see https://github.com/espressif/esp-idf/blob/b4268c874a4cf8fcf7c0c4153cffb76ad2ddda4e/components/hal/adc_oneshot_hal.c#L105-L107
-            // the delay might be a bit generous but longer delay seem to not cause problems
-            #[cfg(esp32c6)]
-            {
-                crate::rom::ets_delay_us(40);
-                ADCI::start_onetime_sample();
-            }
+            self.start_sample(pin);
         }
 
         // Wait for ADC to finish conversion
@@ -478,6 +478,14 @@ where
         let converted_value = ADCI::read_data();
         ADCI::reset();
 
+        // Keep sampling the same channel until enough conversions have been
+        // accumulated. The next conversion is started by the next call, see
+        // the hardware limitation below.
+        let Some(converted_value) = self.oversampler.push(converted_value) else {
+            self.restart = true;
+            return Err(nb::Error::WouldBlock);
+        };
+
         // Postprocess converted value according to calibration scheme used for pin
         let converted_value = pin.cal_scheme.adc_val(converted_value);
 
@@ -496,6 +504,27 @@ where
 
         Ok(converted_value)
     }
+
+    fn start_sample<PIN, CS>(&mut self, pin: &mut super::AdcPin<PIN, ADCI, CS>)
+    where
+        PIN: super::AdcChannel,
+        CS: super::AdcCalScheme<ADCI>,
+    {
+        // Set ADC unit calibration according used scheme for pin
+        ADCI::set_init_code(pin.cal_scheme.adc_cal());
+
+        let attenuation = self.attenuations[PIN::CHANNEL as usize].unwrap() as u8;
+        ADCI::config_onetime_sample(PIN::CHANNEL, attenuation);
+        ADCI::start_onetime_sample();
+
+        // see https://github.com/espressif/esp-idf/blob/b4268c874a4cf8fcf7c0c4153cffb76ad2ddda4e/components/hal/adc_oneshot_hal.c#L105-L107
+        // the delay might be a bit generous but longer delay seem to not cause problems
+        #[cfg(esp32c6)]
+        {
+            crate::rom::ets_delay_us(40);
+            ADCI::start_onetime_sample();
+        }
+    }
 }
 
 #[cfg(any(esp32c2, esp32c3, esp32c6))]
@@ -1,6 +1,6 @@
 #[cfg(esp32s3)]
 pub use self::calibration::*;
-use super::{AdcCalScheme, AdcCalSource, AdcChannel, AdcConfig, AdcPin, Attenuation};
+use super::{AdcCalScheme, AdcCalSource, AdcChannel, AdcConfig, AdcPin, Attenuation, Oversampler};
 #[cfg(esp32s3)]
 use crate::efuse::Efuse;
 use crate::{
@@ -387,6 +387,7 @@ pub struct Adc<'d, ADC> {
     _adc: PeripheralRef<'d, ADC>,
     active_channel: Option<u8>,
     last_init_code: u16,
+    oversampler: Oversampler,
 }
 
 impl<'d, ADCI> Adc<'d, ADCI>
@@ -467,6 +468,7 @@ where
             _adc: adc_instance.into_ref(),
             active_channel: None,
             last_init_code: 0,
+            oversampler: Oversampler::new(config.oversampling),
         }
     }
 
@@ -477,14 +479,22 @@ where
         PIN: AdcChannel,
         CS: AdcCalScheme<ADCI>,
     {
-        self.start_sample(pin);
+        self.oversampler.reset();
 
-        // Wait for ADC to finish conversion
-        while !ADCI::is_done() {}
+        let converted_value = loop {
+            self.start_sample(pin);
 
-        // Get converted value
-        let converted_value = ADCI::read_data();
-        ADCI::reset();
+            // Wait for ADC to finish conversion
+            while !ADCI::is_done() {}
+
+            // Get converted value
+            let converted_value = ADCI::read_data();
+            ADCI::reset();
+
+            if let Some(average) = self.oversampler.push(converted_value) {
+                break average;
+            }
+        };
 
         // Postprocess converted value according to calibration scheme used for pin
         pin.cal_scheme.adc_val(converted_value)
@@ -513,6 +523,7 @@ where
         } else {
             // If no conversions are in progress, start a new one for given channel
             self.active_channel = Some(PIN::CHANNEL);
+            self.oversampler.reset();
 
             self.start_sample(pin);
         }
@@ -527,6 +538,13 @@ where
         let converted_value = ADCI::read_data();
         ADCI::reset();
 
+        // Keep sampling the same channel until enough conversions have been
+        // accumulated
+        let Some(converted_value) = self.oversampler.push(converted_value) else {
+            self.start_sample(pin);
+            return Err(nb::Error::WouldBlock);
+        };
+
         // Postprocess converted value according to calibration scheme used for pin
         let converted_value = pin.cal_scheme.adc_val(converted_value);
 
@@ -43,9 +43,33 @@
 
 #![deny(missing_docs)]
 
+use fugit::HertzU32;
+
 use crate::{
+    clock::Clock,
     gpio::{self, AnalogPin},
     peripheral::{Peripheral, PeripheralRef},
+    rtc_cntl::RtcFastClock,
+};
+#[cfg(esp32)]
+use crate::{
+    clock::Clocks,
+    dma::{
+        Channel,
+        ChannelTx,
+        DescriptorChain,
+        DmaChannel,
+        DmaDescriptor,
+        DmaError,
+        I2s0Peripheral,
+        I2sPeripheral,
+        TxCircularState,
+        TxPrivate,
+    },
+    i2s::{private::*, DataFormat, Standard},
+    peripherals::{I2S0, SENS},
+    system::PeripheralClockControl,
+    Mode,
 };
 
 // Only specific pins can be used with each DAC peripheral, and of course
@@ -96,10 +120,267 @@ where
     ///
     /// For each DAC channel, the output analog voltage can be calculated as
     /// follows: DACn_OUT = VDD3P3_RTC * PDACn_DAC/256
+    ///
+    /// This switches the channel back from the cosine wave generator.
     pub fn write(&mut self, value: u8) {
         T::set_pad_source();
         T::write_byte(value);
     }
+
+    /// Outputs a cosine wave using the built-in hardware generator.
+    ///
+    /// The generator is clocked by the internal 8 MHz RC oscillator, so the
+    /// frequency is only as accurate as the oscillator. Both DAC channels share
+    /// the frequency setting, the amplitude and phase are set per channel.
+    pub fn enable_cosine_wave(
+        &mut self,
+        frequency: HertzU32,
+        amplitude: DacAmplitude,
+        phase: DacPhase,
+    ) {
+        let rtc_cntl = unsafe { &*crate::peripherals::LPWR::PTR };
+        let sens = unsafe { &*crate::peripherals::SENS::PTR };
+
+        // The generator runs off the digital 8 MHz clock
+        rtc_cntl
+            .clk_conf()
+            .modify(|_, w| w.dig_clk8m_en().set_bit());
+
+        // see <https://github.com/espressif/esp-idf/blob/903af13e8/components/hal/esp32/include/hal/dac_ll.h#L143>
+        let rc_fast = RtcFastClock::RtcFastClock8m.frequency().raw() as u64;
+        let step = ((frequency.raw() as u64) << 16) / rc_fast;
+        let step = u64::min(step, 0xffff) as u16;
+
+        sens.sar_dac_ctrl1()
+            .modify(|_, w| unsafe { w.sw_fstep().bits(step).sw_tone_en().set_bit() });
+
+        sens.sar_dac_ctrl2().modify(|_, w| unsafe {
+            w.dac_scale(T::INDEX as u8)
+                .bits(amplitude as u8)
+                .dac_inv(T::INDEX as u8)
+                .bits(phase as u8)
+                .dac_dc(T::INDEX as u8)
+                .bits(0)
+                .dac_cw_en(T::INDEX as u8)
+                .set_bit()
+        });
+    }
+
+    /// Stops the cosine wave generator for this channel, switching back to the
+    /// value set by [Dac::write].
+    pub fn disable_cosine_wave(&mut self) {
+        T::set_pad_source();
+
+        let sens = unsafe { &*crate::peripherals::SENS::PTR };
+
+        // Only stop the generator if the other channel doesn't use it
+        if sens
+            .sar_dac_ctrl2()
+            .read()
+            .dac_cw_en(1 - T::INDEX as u8)
+            .bit_is_clear()
+        {
+            sens.sar_dac_ctrl1()
+                .modify(|_, w| w.sw_tone_en().clear_bit());
+        }
+    }
+}
+
+/// Amplitude of the cosine wave generator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DacAmplitude {
+    /// Full scale
+    #[default]
+    Full    = 0,
+    /// Half of full scale (-6 dB)
+    Half    = 1,
+    /// A quarter of full scale (-12 dB)
+    Quarter = 2,
+    /// An eighth of full scale (-18 dB)
+    Eighth  = 3,
+}
+
+/// Phase of the cosine wave generator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DacPhase {
+    /// The wave starts at its maximum
+    #[default]
+    Phase0   = 2,
+    /// The wave starts at its minimum
+    Phase180 = 3,
+}
+
+/// Bytes used in the DMA buffer per sample, the I2S peripheral sends two 16 bit
+/// channels per sample and the DAC takes the upper byte of each.
+#[cfg(esp32)]
+const BYTES_PER_SAMPLE: usize = 4;
+
+#[cfg(esp32)]
+impl<'d, T> Dac<'d, T>
+where
+    T: Instance,
+    T::Pin: AnalogPin,
+{
+    /// Streams samples to the DAC channel using circular DMA.
+    ///
+    /// The samples are clocked out by the I2S0 peripheral in its built-in DAC
+    /// mode. `buffer` is used as the circular DMA buffer, each sample takes up
+    /// 4 bytes in it, so its length must be a multiple of 4. The output starts
+    /// at mid scale until samples are written.
+    #[allow(clippy::too_many_arguments)]
+    pub fn into_continuous_dma<CH, DmaMode>(
+        self,
+        i2s: impl Peripheral<P = I2S0> + 'd,
+        channel: Channel<'d, CH, DmaMode>,
+        buffer: &'static mut [u8],
+        descriptors: &'static mut [DmaDescriptor],
+        sample_rate: impl Into<HertzU32>,
+        clocks: &Clocks,
+    ) -> Result<DacContinuous<'d, T, CH>, DmaError>
+    where
+        CH: DmaChannel,
+        CH::P: I2sPeripheral + I2s0Peripheral,
+        DmaMode: Mode,
+    {
+        crate::into_ref!(i2s);
+
+        if buffer.len() % BYTES_PER_SAMPLE != 0 {
+            return Err(DmaError::InvalidAlignment {
+                address: buffer.as_ptr() as usize,
+            });
+        }
+
+        buffer.fill(0x80);
+
+        let mut tx = channel.tx;
+        tx.init_channel();
+
+        PeripheralClockControl::enable(I2S0::get_peripheral());
+        I2S0::set_clock(calculate_clock(sample_rate, 2, 16, clocks));
+        I2S0::configure(&Standard::Philips, &DataFormat::Data16Channel16);
+        I2S0::set_master();
+        I2S0::update();
+
+        // Route the I2S output to the DAC instead of the pins
+        let regs = I2S0::register_block();
+        regs.conf2()
+            .modify(|_, w| w.lcd_en().set_bit().camera_en().clear_bit());
+        regs.conf().modify(|_, w| {
+            w.tx_right_first()
+                .set_bit()
+                .tx_msb_shift()
+                .clear_bit()
+                .tx_short_sync()
+                .clear_bit()
+        });
+
+        T::set_pad_source();
+        unsafe { &*SENS::PTR }
+            .sar_dac_ctrl1()
+            .modify(|_, w| w.dac_dig_force().set_bit().dac_clk_inv().set_bit());
+
+        I2S0::reset_tx();
+
+        let mut chain = DescriptorChain::new(descriptors);
+        chain.fill_for_tx(true, buffer.as_ptr(), buffer.len())?;
+        unsafe {
+            tx.prepare_transfer_without_start(I2S0::get_dma_peripheral(), &chain)
+                .and_then(|_| tx.start_transfer())?;
+        }
+
+        I2S0::tx_start();
+
+        let state = TxCircularState::new(&mut chain);
+
+        Ok(DacContinuous {
+            _dac: self,
+            _i2s: i2s,
+            tx,
+            _chain: chain,
+            state,
+        })
+    }
+}
+
+/// DAC channel continuously fed by circular DMA
+///
+/// Created by [Dac::into_continuous_dma].
+#[cfg(esp32)]
+pub struct DacContinuous<'d, T, CH>
+where
+    T: Instance,
+    T::Pin: AnalogPin,
+    CH: DmaChannel,
+{
+    _dac: Dac<'d, T>,
+    _i2s: PeripheralRef<'d, I2S0>,
+    tx: ChannelTx<'d, CH>,
+    _chain: DescriptorChain,
+    state: TxCircularState,
+}
+
+#[cfg(esp32)]
+impl<'d, T, CH> DacContinuous<'d, T, CH>
+where
+    T: Instance,
+    T::Pin: AnalogPin,
+    CH: DmaChannel,
+{
+    /// Number of samples which can be written without overwriting samples
+    /// which weren't output yet.
+    pub fn available(&mut self) -> usize {
+        self.state.update(&self.tx);
+        self.state.available / BYTES_PER_SAMPLE
+    }
+
+    /// Pushes samples into the circular DMA buffer.
+    ///
+    /// Returns the number of samples written, which is less than
+    /// `samples.len()` if there isn't enough space available.
+    pub fn write_samples(&mut self, samples: &[u8]) -> Result<usize, DmaError> {
+        self.state.update(&self.tx);
+
+        let mut written = 0;
+        while written < samples.len() && self.state.available >= BYTES_PER_SAMPLE {
+            let remaining = &samples[written..];
+            let bytes = self.state.push_with(|buffer| {
+                let count = usize::min(buffer.len() / BYTES_PER_SAMPLE, remaining.len());
+                for (frame, &sample) in buffer
+                    .chunks_exact_mut(BYTES_PER_SAMPLE)
+                    .zip(&remaining[..count])
+                {
+                    // The DAC takes the upper byte of each 16 bit channel
+                    frame.copy_from_slice(&[0, sample, 0, sample]);
+                }
+                count * BYTES_PER_SAMPLE
+            })?;
+            written += bytes / BYTES_PER_SAMPLE;
+        }
+
+        Ok(written)
+    }
+}
+
+#[cfg(esp32)]
+impl<'d, T, CH> Drop for DacContinuous<'d, T, CH>
+where
+    T: Instance,
+    T::Pin: AnalogPin,
+    CH: DmaChannel,
+{
+    fn drop(&mut self) {
+        I2S0::tx_stop();
+        self.tx.stop_transfer();
+
+        unsafe { &*SENS::PTR }
+            .sar_dac_ctrl1()
+            .modify(|_, w| w.dac_dig_force().clear_bit().dac_clk_inv().clear_bit());
+        I2S0::register_block()
+            .conf2()
+            .modify(|_, w| w.lcd_en().clear_bit());
+    }
 }
 
 #[doc(hidden)]
@@ -9,3 +9,5 @@
 pub mod adc;
 #[cfg(dac)]
 pub mod dac;
+#[cfg(tsens)]
+pub mod temperature_sensor;
@@ -0,0 +1,123 @@
+//! # Temperature Sensor
+//!
+//! ## Overview
+//! The chip contains a built-in temperature sensor, which measures the
+//! temperature of the die. The readings are mainly useful to track changes of
+//! the internal temperature, the absolute value is affected by the heat
+//! generated by the chip itself.
+//!
+//! The sensor is calibrated during manufacturing, if the calibration value has
+//! been burned to the eFuses it is applied to the readings.
+//!
+//! ## Examples
+//! ### Read the die temperature
+//! ```rust, no_run
+#![doc = crate::before_snippet!()]
+//! # use esp_hal::analog::temperature_sensor::TemperatureSensor;
+//! let mut sensor = TemperatureSensor::new(peripherals.TSENS);
+//!
+//! let temperature = sensor.read_celsius();
+//! # }
+//! ```
+
+#![deny(missing_docs)]
+
+use crate::{
+    efuse::Efuse,
+    peripheral::{Peripheral, PeripheralRef},
+    peripherals::{SENS, TSENS},
+    regi2c_write_mask,
+    system::{Peripheral as PeripheralEnable, PeripheralClockControl},
+};
+
+const I2C_SAR_ADC: u8 = 0x69;
+const I2C_SAR_ADC_HOSTID: u8 = 1;
+const ADC_SARADC_TSENS_DAC: u8 = 0x6;
+const ADC_SARADC_TSENS_DAC_MSB: u8 = 3;
+const ADC_SARADC_TSENS_DAC_LSB: u8 = 0;
+
+// DAC setting for the measurement range of -10°C to 80°C, which has the
+// smallest error and no DAC offset.
+// see <https://github.com/espressif/esp-idf/blob/903af13e8/components/hal/esp32s3/include/hal/temperature_sensor_ll.h>
+const TSENS_DAC_DEFAULT: u8 = 15;
+const TSENS_CLK_DIV: u8 = 6;
+
+const TSENS_ADC_FACTOR: f32 = 0.4386;
+const TSENS_OFFSET_FACTOR: f32 = 20.52;
+
+/// Driver for the built-in temperature sensor
+pub struct TemperatureSensor<'d> {
+    _tsens: PeripheralRef<'d, TSENS>,
+    delta: f32,
+}
+
+impl<'d> TemperatureSensor<'d> {
+    /// Powers up the temperature sensor.
+    pub fn new(tsens: impl Peripheral<P = TSENS> + 'd) -> Self {
+        crate::into_ref!(tsens);
+
+        let sens = unsafe { &*SENS::PTR };
+
+        PeripheralClockControl::enable(PeripheralEnable::ApbSarAdc);
+
+        sens.sar_peri_clk_gate_conf()
+            .modify(|_, w| w.tsens_clk_en().set_bit());
+
+        sens.sar_tctrl().modify(|_, w| unsafe {
+            w.tsens_clk_div()
+                .bits(TSENS_CLK_DIV)
+                .tsens_power_up_force()
+                .set_bit()
+                .tsens_power_up()
+                .set_bit()
+        });
+        sens.sar_tctrl2()
+            .modify(|_, w| unsafe { w.tsens_xpd_force().bits(1) });
+
+        regi2c_write_mask!(I2C_SAR_ADC, ADC_SARADC_TSENS_DAC, TSENS_DAC_DEFAULT);
+
+        Self {
+            _tsens: tsens,
+            delta: Efuse::get_rtc_calib_tsens_delta().unwrap_or(0.0),
+        }
+    }
+
+    /// Returns the raw value of the sensor.
+    pub fn read_raw(&mut self) -> u8 {
+        let sens = unsafe { &*SENS::PTR };
+
+        sens.sar_tctrl().modify(|_, w| w.tsens_dump_out().set_bit());
+        while sens.sar_tctrl().read().tsens_ready().bit_is_clear() {}
+        sens.sar_tctrl()
+            .modify(|_, w| w.tsens_dump_out().clear_bit());
+
+        sens.sar_tctrl().read().tsens_out().bits()
+    }
+
+    /// Returns the temperature of the die in degrees Celsius.
+    ///
+    /// The raw value is converted as `T = 0.4386 * raw - 20.52 - delta`,
+    /// `delta` being the factory calibration value.
+    pub fn read_celsius(&mut self) -> f32 {
+        let raw = self.read_raw();
+
+        TSENS_ADC_FACTOR * raw as f32 - TSENS_OFFSET_FACTOR - self.delta
+    }
+}
+
+impl<'d> Drop for TemperatureSensor<'d> {
+    fn drop(&mut self) {
+        let sens = unsafe { &*SENS::PTR };
+
+        sens.sar_tctrl2()
+            .modify(|_, w| unsafe { w.tsens_xpd_force().bits(0) });
+        sens.sar_tctrl().modify(|_, w| {
+            w.tsens_power_up_force()
+                .clear_bit()
+                .tsens_power_up()
+                .clear_bit()
+        });
+        sens.sar_peri_clk_gate_conf()
+            .modify(|_, w| w.tsens_clk_en().clear_bit());
+    }
+}
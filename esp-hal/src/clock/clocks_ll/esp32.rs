@@ -1,3 +1,5 @@
+use fugit::HertzU32;
+
 use crate::{
     clock::{Clock, PllClock, XtalClock},
     regi2c_write,
@@ -255,3 +257,26 @@ fn esp32_update_cpu_freq(mhz: u32) {
         (G_TICKS_PER_US_PRO as *mut u32).write_volatile(mhz);
     }
 }
+
+/// Decodes the current CPU clock frequency from the clock source and divider
+/// registers
+pub(crate) fn cpu_clock(xtal_clock: HertzU32) -> HertzU32 {
+    let apb_cntl = unsafe { &*crate::peripherals::APB_CTRL::ptr() };
+    let dport = unsafe { &*crate::peripherals::DPORT::ptr() };
+    let rtc_cntl = unsafe { &*crate::peripherals::RTC_CNTL::ptr() };
+
+    let divider = apb_cntl.sysclk_conf().read().pre_div_cnt().bits() as u32 + 1;
+
+    match rtc_cntl.clk_conf().read().soc_clk_sel().bits() {
+        0 => xtal_clock / divider,
+        1 => match dport.cpu_per_conf().read().cpuperiod_sel().bits() {
+            0 => HertzU32::MHz(80),
+            1 => HertzU32::MHz(160),
+            2 => HertzU32::MHz(240),
+            _ => unreachable!(),
+        },
+        2 => HertzU32::MHz(8) / divider,
+        // The APLL is never used as the CPU clock source
+        _ => unreachable!(),
+    }
+}
@@ -1,3 +1,5 @@
+use fugit::HertzU32;
+
 use crate::{
     clock::{ApbClock, Clock, CpuClock, PllClock, XtalClock},
     regi2c_write,
@@ -229,3 +231,22 @@ pub(crate) fn esp32c3_rtc_apb_freq_update(apb_freq: ApbClock) {
         .store5()
         .modify(|_, w| unsafe { w.scratch5().bits(value) });
 }
+
+/// Decodes the current CPU clock frequency from the clock source and divider
+/// registers
+pub(crate) fn cpu_clock(xtal_clock: HertzU32) -> HertzU32 {
+    let system_control = unsafe { &*crate::peripherals::SYSTEM::ptr() };
+    let sysclk_conf = system_control.sysclk_conf().read();
+    let divider = sysclk_conf.pre_div_cnt().bits() as u32 + 1;
+
+    match sysclk_conf.soc_clk_sel().bits() {
+        0 => xtal_clock / divider,
+        1 => match system_control.cpu_per_conf().read().cpuperiod_sel().bits() {
+            0 => HertzU32::MHz(80),
+            1 => HertzU32::MHz(160),
+            _ => unreachable!(),
+        },
+        // RC_FAST
+        _ => HertzU32::kHz(17_500) / divider,
+    }
+}
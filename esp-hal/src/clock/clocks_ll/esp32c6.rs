@@ -1,3 +1,5 @@
+use fugit::HertzU32;
+
 use crate::{
     clock::{ApbClock, Clock, CpuClock, PllClock, XtalClock},
     rtc_cntl::rtc::CpuClockSource,
@@ -487,3 +489,15 @@ pub(crate) fn esp32c6_bbpll_get_freq_mhz() -> u32 {
 
     CLK_LL_PLL_480M_FREQ_MHZ
 }
+
+/// Decodes the current CPU clock frequency from the clock source and divider
+/// registers
+pub(crate) fn cpu_clock(xtal_clock: HertzU32) -> HertzU32 {
+    match unwrap!(CpuClockSource::current()) {
+        CpuClockSource::Xtal => xtal_clock / esp32c6_cpu_get_ls_divider() as u32,
+        CpuClockSource::Pll => {
+            HertzU32::MHz(esp32c6_bbpll_get_freq_mhz()) / esp32c6_cpu_get_hs_divider() as u32
+        }
+        CpuClockSource::RcFast => HertzU32::kHz(17_500) / esp32c6_cpu_get_ls_divider() as u32,
+    }
+}
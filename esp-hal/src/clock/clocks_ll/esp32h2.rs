@@ -1,3 +1,5 @@
+use fugit::HertzU32;
+
 use crate::clock::{ApbClock, Clock, CpuClock, PllClock, XtalClock};
 
 const I2C_BBPLL: u8 = 0x66;
@@ -337,3 +339,20 @@ pub(crate) fn regi2c_write_mask(block: u8, _host_id: u8, reg_add: u8, msb: u8, l
 
     regi2c_disable_block(block);
 }
+
+/// Decodes the current CPU clock frequency from the clock source and divider
+/// registers
+pub(crate) fn cpu_clock(xtal_clock: HertzU32) -> HertzU32 {
+    let pcr = unsafe { &*crate::peripherals::PCR::PTR };
+    let divider = pcr.cpu_freq_conf().read().cpu_div_num().bits() as u32 + 1;
+
+    let source_clock = match pcr.sysclk_conf().read().soc_clk_sel().bits() {
+        0 => xtal_clock,
+        1 => HertzU32::MHz(96),
+        2 => HertzU32::MHz(8),
+        // FLASH_PLL
+        _ => HertzU32::MHz(64),
+    };
+
+    source_clock / divider
+}
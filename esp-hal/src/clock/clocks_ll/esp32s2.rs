@@ -1,3 +1,5 @@
+use fugit::HertzU32;
+
 use crate::clock::CpuClock;
 
 const MHZ: u32 = 1000000;
@@ -41,3 +43,24 @@ pub(crate) fn set_cpu_clock(cpu_clock_speed: CpuClock) {
         rtc_cntl.store5().modify(|_, w| w.scratch5().bits(value));
     }
 }
+
+/// Decodes the current CPU clock frequency from the clock source and divider
+/// registers
+pub(crate) fn cpu_clock(xtal_clock: HertzU32) -> HertzU32 {
+    let system_control = unsafe { &*crate::peripherals::SYSTEM::PTR };
+    let sysclk_conf = system_control.sysclk_conf().read();
+    let divider = sysclk_conf.pre_div_cnt().bits() as u32 + 1;
+
+    match sysclk_conf.soc_clk_sel().bits() {
+        0 => xtal_clock / divider,
+        1 => match system_control.cpu_per_conf().read().cpuperiod_sel().bits() {
+            0 => HertzU32::MHz(80),
+            1 => HertzU32::MHz(160),
+            2 => HertzU32::MHz(240),
+            _ => unreachable!(),
+        },
+        2 => HertzU32::MHz(8) / divider,
+        // The APLL is never used as the CPU clock source
+        _ => unreachable!(),
+    }
+}
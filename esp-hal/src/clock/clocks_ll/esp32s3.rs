@@ -1,5 +1,7 @@
 use core::ops::Div;
 
+use fugit::HertzU32;
+
 use crate::{
     clock::{Clock, CpuClock},
     rom,
@@ -27,3 +29,23 @@ pub(crate) fn set_cpu_clock(cpu_clock_speed: CpuClock) {
     let ticks_per_us = cpu_clock_speed.frequency().div(1_000_000);
     rom::ets_update_cpu_frequency_rom(ticks_per_us.raw());
 }
+
+/// Decodes the current CPU clock frequency from the clock source and divider
+/// registers
+pub(crate) fn cpu_clock(xtal_clock: HertzU32) -> HertzU32 {
+    let system_control = unsafe { &*crate::peripherals::SYSTEM::PTR };
+    let sysclk_conf = system_control.sysclk_conf().read();
+    let divider = sysclk_conf.pre_div_cnt().bits() as u32 + 1;
+
+    match sysclk_conf.soc_clk_sel().bits() {
+        0 => xtal_clock / divider,
+        1 => match system_control.cpu_per_conf().read().cpuperiod_sel().bits() {
+            0 => HertzU32::MHz(80),
+            1 => HertzU32::MHz(160),
+            2 => HertzU32::MHz(240),
+            _ => unreachable!(),
+        },
+        // RC_FAST
+        _ => HertzU32::kHz(17_500) / divider,
+    }
+}
@@ -70,6 +70,8 @@
 //! # }
 //! ```
 
+use core::sync::atomic::{AtomicU32, Ordering};
+
 use fugit::HertzU32;
 
 #[cfg(any(esp32, esp32c2))]
@@ -88,6 +90,12 @@ use crate::{
 #[cfg_attr(esp32s3, path = "clocks_ll/esp32s3.rs")]
 pub(crate) mod clocks_ll;
 
+#[cfg(esp32)]
+mod output;
+
+#[cfg(esp32)]
+pub use output::{ClockOutput, ClockOutputPin, ClockOutputSource};
+
 pub trait Clock {
     fn frequency(&self) -> HertzU32;
 
@@ -240,10 +248,13 @@ impl Clock for ApbClock {
 /// Frozen clock frequencies
 ///
 /// The existence of this value indicates that the clock configuration can no
-/// longer be changed
+/// longer be changed, except for the CPU clock which can be switched with
+/// [Clocks::set_cpu_clock]
 pub struct Clocks<'d> {
     _private: PeripheralRef<'d, SystemClockControl>,
-    pub cpu_clock: HertzU32,
+    /// The CPU clock in Hz as last decoded by [Clocks::cpu_clock], 0 if it
+    /// has to be decoded again
+    cpu_clock: AtomicU32,
     pub apb_clock: HertzU32,
     pub xtal_clock: HertzU32,
     #[cfg(esp32)]
@@ -272,7 +283,7 @@ impl<'d> Clocks<'d> {
     ) -> Clocks<'d> {
         Self {
             _private: system_clock_control,
-            cpu_clock: raw_clocks.cpu_clock,
+            cpu_clock: AtomicU32::new(0),
             apb_clock: raw_clocks.apb_clock,
             xtal_clock: raw_clocks.xtal_clock,
             #[cfg(esp32)]
@@ -291,6 +302,47 @@ impl<'d> Clocks<'d> {
     }
 }
 
+impl<'d> Clocks<'d> {
+    /// The current CPU clock frequency.
+    ///
+    /// The frequency is decoded from the CPU clock source and divider
+    /// registers. The result is cached until the CPU clock is changed with
+    /// [Clocks::set_cpu_clock].
+    pub fn cpu_clock(&self) -> HertzU32 {
+        let cached = self.cpu_clock.load(Ordering::Relaxed);
+        if cached != 0 {
+            return HertzU32::Hz(cached);
+        }
+
+        let cpu_clock = clocks_ll::cpu_clock(self.xtal_clock);
+        self.cpu_clock.store(cpu_clock.to_Hz(), Ordering::Relaxed);
+
+        cpu_clock
+    }
+
+    /// Switches the CPU clock to `cpu_clock` at runtime.
+    ///
+    /// The PLL is reconfigured as needed, the APB clock and the other
+    /// peripheral clocks have the same frequencies afterwards. Only
+    /// [Clocks::cpu_clock] changes.
+    ///
+    /// While the PLL is reconfigured the CPU and the APB clock run from the
+    /// crystal. Peripherals clocked from the APB clock, like UART, SPI, I2C,
+    /// LEDC and the timers, have to be idle during the switch and have to be
+    /// reconfigured afterwards, e.g. by creating their drivers again.
+    ///
+    /// Drivers which read the CPU clock when they are created, like
+    /// [Delay](crate::delay::Delay) on Xtensa chips, keep using the previous
+    /// frequency and have to be created again.
+    pub fn set_cpu_clock(&mut self, cpu_clock: CpuClock) {
+        let raw_clocks = ClockControl::configure_raw(cpu_clock);
+        debug_assert_eq!(raw_clocks.apb_clock.to_Hz(), self.apb_clock.to_Hz());
+
+        self.cpu_clock.store(0, Ordering::Relaxed);
+        debug_assert_eq!(self.cpu_clock().to_Hz(), raw_clocks.cpu_clock.to_Hz());
+    }
+}
+
 #[doc(hidden)]
 pub struct RawClocks {
     pub cpu_clock: HertzU32,
@@ -322,7 +374,8 @@ pub struct ClockControl<'d> {
 impl<'d> ClockControl<'d> {
     /// Applies the clock configuration and returns a Clocks struct that
     /// signifies that the clocks are frozen, and contains the frequencies
-    /// used. After this function is called, the clocks can not change
+    /// used. After this function is called, only the CPU clock can change, see
+    /// [Clocks::set_cpu_clock]
     pub fn freeze(self) -> Clocks<'d> {
         Clocks::from_raw_clocks(self._private, self.desired_rates)
     }
@@ -357,6 +410,15 @@ impl<'d> ClockControl<'d> {
         clock_control: impl Peripheral<P = SystemClockControl> + 'd,
         cpu_clock_speed: CpuClock,
     ) -> ClockControl<'d> {
+        ClockControl {
+            _private: clock_control.into_ref(),
+            desired_rates: Self::configure_raw(cpu_clock_speed),
+        }
+    }
+
+    /// Switches the CPU to `cpu_clock_speed` and returns the resulting clock
+    /// frequencies.
+    fn configure_raw(cpu_clock_speed: CpuClock) -> RawClocks {
         let xtal_freq = if RtcClock::estimate_xtal_frequency() > 33 {
             XtalClock::RtcXtalFreq40M
         } else {
@@ -374,18 +436,15 @@ impl<'d> ClockControl<'d> {
         clocks_ll::esp32_rtc_bbpll_configure(xtal_freq, pll_freq);
         clocks_ll::set_cpu_freq(cpu_clock_speed);
 
-        ClockControl {
-            _private: clock_control.into_ref(),
-            desired_rates: RawClocks {
-                cpu_clock: cpu_clock_speed.frequency(),
-                apb_clock: HertzU32::MHz(80),
-                xtal_clock: HertzU32::MHz(40),
-                i2c_clock: HertzU32::MHz(80),
-                // The docs are unclear here. pwm_clock seems to be tied to clocks.apb_clock
-                // while simultaneously being fixed at 160 MHz.
-                // Testing showed 160 MHz to be correct for current clock configurations.
-                pwm_clock: HertzU32::MHz(160),
-            },
+        RawClocks {
+            cpu_clock: cpu_clock_speed.frequency(),
+            apb_clock: HertzU32::MHz(80),
+            xtal_clock: HertzU32::MHz(40),
+            i2c_clock: HertzU32::MHz(80),
+            // The docs are unclear here. pwm_clock seems to be tied to clocks.apb_clock
+            // while simultaneously being fixed at 160 MHz.
+            // Testing showed 160 MHz to be correct for current clock configurations.
+            pwm_clock: HertzU32::MHz(160),
         }
     }
 
@@ -422,6 +481,15 @@ impl<'d> ClockControl<'d> {
         clock_control: impl Peripheral<P = SystemClockControl> + 'd,
         cpu_clock_speed: CpuClock,
     ) -> ClockControl<'d> {
+        ClockControl {
+            _private: clock_control.into_ref(),
+            desired_rates: Self::configure_raw(cpu_clock_speed),
+        }
+    }
+
+    /// Switches the CPU to `cpu_clock_speed` and returns the resulting clock
+    /// frequencies.
+    fn configure_raw(cpu_clock_speed: CpuClock) -> RawClocks {
         let apb_freq;
 
         let xtal_freq = if RtcClock::estimate_xtal_frequency() > 33 {
@@ -438,19 +506,19 @@ impl<'d> ClockControl<'d> {
             clocks_ll::esp32c2_rtc_apb_freq_update(apb_freq);
         } else {
             apb_freq = ApbClock::ApbFreq40MHz;
+            // The CPU may be running from the PLL, switch to XTAL while the
+            // PLL is reconfigured
+            clocks_ll::esp32c2_rtc_update_to_xtal(xtal_freq, 1);
             clocks_ll::esp32c2_rtc_bbpll_enable();
             clocks_ll::esp32c2_rtc_bbpll_configure(xtal_freq, pll_freq);
             clocks_ll::esp32c2_rtc_freq_to_pll_mhz(cpu_clock_speed);
             clocks_ll::esp32c2_rtc_apb_freq_update(apb_freq);
         }
 
-        ClockControl {
-            _private: clock_control.into_ref(),
-            desired_rates: RawClocks {
-                cpu_clock: cpu_clock_speed.frequency(),
-                apb_clock: apb_freq.frequency(),
-                xtal_clock: xtal_freq.frequency(),
-            },
+        RawClocks {
+            cpu_clock: cpu_clock_speed.frequency(),
+            apb_clock: apb_freq.frequency(),
+            xtal_clock: xtal_freq.frequency(),
         }
     }
 
@@ -481,6 +549,15 @@ impl<'d> ClockControl<'d> {
         clock_control: impl Peripheral<P = SystemClockControl> + 'd,
         cpu_clock_speed: CpuClock,
     ) -> ClockControl<'d> {
+        ClockControl {
+            _private: clock_control.into_ref(),
+            desired_rates: Self::configure_raw(cpu_clock_speed),
+        }
+    }
+
+    /// Switches the CPU to `cpu_clock_speed` and returns the resulting clock
+    /// frequencies.
+    fn configure_raw(cpu_clock_speed: CpuClock) -> RawClocks {
         let apb_freq;
         let xtal_freq = XtalClock::RtcXtalFreq40M;
         let pll_freq = PllClock::Pll480MHz;
@@ -491,19 +568,19 @@ impl<'d> ClockControl<'d> {
             clocks_ll::esp32c3_rtc_apb_freq_update(apb_freq);
         } else {
             apb_freq = ApbClock::ApbFreq80MHz;
+            // The CPU may be running from the PLL, switch to XTAL while the
+            // PLL is reconfigured
+            clocks_ll::esp32c3_rtc_update_to_xtal(xtal_freq, 1);
             clocks_ll::esp32c3_rtc_bbpll_enable();
             clocks_ll::esp32c3_rtc_bbpll_configure(xtal_freq, pll_freq);
             clocks_ll::esp32c3_rtc_freq_to_pll_mhz(cpu_clock_speed);
             clocks_ll::esp32c3_rtc_apb_freq_update(apb_freq);
         }
 
-        ClockControl {
-            _private: clock_control.into_ref(),
-            desired_rates: RawClocks {
-                cpu_clock: cpu_clock_speed.frequency(),
-                apb_clock: apb_freq.frequency(),
-                xtal_clock: xtal_freq.frequency(),
-            },
+        RawClocks {
+            cpu_clock: cpu_clock_speed.frequency(),
+            apb_clock: apb_freq.frequency(),
+            xtal_clock: xtal_freq.frequency(),
         }
     }
 
@@ -535,6 +612,15 @@ impl<'d> ClockControl<'d> {
         clock_control: impl Peripheral<P = SystemClockControl> + 'd,
         cpu_clock_speed: CpuClock,
     ) -> ClockControl<'d> {
+        ClockControl {
+            _private: clock_control.into_ref(),
+            desired_rates: Self::configure_raw(cpu_clock_speed),
+        }
+    }
+
+    /// Switches the CPU to `cpu_clock_speed` and returns the resulting clock
+    /// frequencies.
+    fn configure_raw(cpu_clock_speed: CpuClock) -> RawClocks {
         let apb_freq;
         let xtal_freq = XtalClock::RtcXtalFreq40M;
         let pll_freq = PllClock::Pll480MHz;
@@ -545,20 +631,20 @@ impl<'d> ClockControl<'d> {
             clocks_ll::esp32c6_rtc_apb_freq_update(apb_freq);
         } else {
             apb_freq = ApbClock::ApbFreq80MHz;
+            // The CPU may be running from the PLL, switch to XTAL while the
+            // PLL is reconfigured
+            clocks_ll::esp32c6_rtc_update_to_xtal(xtal_freq, 1);
             clocks_ll::esp32c6_rtc_bbpll_enable();
             clocks_ll::esp32c6_rtc_bbpll_configure(xtal_freq, pll_freq);
             clocks_ll::esp32c6_rtc_freq_to_pll_mhz(cpu_clock_speed);
             clocks_ll::esp32c6_rtc_apb_freq_update(apb_freq);
         }
 
-        ClockControl {
-            _private: clock_control.into_ref(),
-            desired_rates: RawClocks {
-                cpu_clock: cpu_clock_speed.frequency(),
-                apb_clock: apb_freq.frequency(),
-                xtal_clock: xtal_freq.frequency(),
-                crypto_clock: HertzU32::MHz(160),
-            },
+        RawClocks {
+            cpu_clock: cpu_clock_speed.frequency(),
+            apb_clock: apb_freq.frequency(),
+            xtal_clock: xtal_freq.frequency(),
+            crypto_clock: HertzU32::MHz(160),
         }
     }
 
@@ -592,6 +678,15 @@ impl<'d> ClockControl<'d> {
         clock_control: impl Peripheral<P = SystemClockControl> + 'd,
         cpu_clock_speed: CpuClock,
     ) -> ClockControl<'d> {
+        ClockControl {
+            _private: clock_control.into_ref(),
+            desired_rates: Self::configure_raw(cpu_clock_speed),
+        }
+    }
+
+    /// Switches the CPU to `cpu_clock_speed` and returns the resulting clock
+    /// frequencies.
+    fn configure_raw(cpu_clock_speed: CpuClock) -> RawClocks {
         let apb_freq;
         let xtal_freq = XtalClock::RtcXtalFreq32M;
         let pll_freq = PllClock::Pll96MHz;
@@ -602,22 +697,22 @@ impl<'d> ClockControl<'d> {
             clocks_ll::esp32h2_rtc_apb_freq_update(apb_freq);
         } else {
             apb_freq = ApbClock::ApbFreq32MHz;
+            // The CPU may be running from the PLL, switch to XTAL while the
+            // PLL is reconfigured
+            clocks_ll::esp32h2_rtc_update_to_xtal(xtal_freq, 1);
             clocks_ll::esp32h2_rtc_bbpll_enable();
             clocks_ll::esp32h2_rtc_bbpll_configure(xtal_freq, pll_freq);
             clocks_ll::esp32h2_rtc_freq_to_pll_mhz(cpu_clock_speed);
             clocks_ll::esp32h2_rtc_apb_freq_update(apb_freq);
         }
 
-        ClockControl {
-            _private: clock_control.into_ref(),
-            desired_rates: RawClocks {
-                cpu_clock: cpu_clock_speed.frequency(),
-                apb_clock: apb_freq.frequency(),
-                xtal_clock: xtal_freq.frequency(),
-                pll_48m_clock: HertzU32::MHz(48),
-                crypto_clock: HertzU32::MHz(96),
-                pll_96m_clock: HertzU32::MHz(96),
-            },
+        RawClocks {
+            cpu_clock: cpu_clock_speed.frequency(),
+            apb_clock: apb_freq.frequency(),
+            xtal_clock: xtal_freq.frequency(),
+            pll_48m_clock: HertzU32::MHz(48),
+            crypto_clock: HertzU32::MHz(96),
+            pll_96m_clock: HertzU32::MHz(96),
         }
     }
 
@@ -648,15 +743,21 @@ impl<'d> ClockControl<'d> {
         clock_control: impl Peripheral<P = SystemClockControl> + 'd,
         cpu_clock_speed: CpuClock,
     ) -> ClockControl<'d> {
-        clocks_ll::set_cpu_clock(cpu_clock_speed);
-
         ClockControl {
             _private: clock_control.into_ref(),
-            desired_rates: RawClocks {
-                cpu_clock: cpu_clock_speed.frequency(),
-                apb_clock: HertzU32::MHz(80),
-                xtal_clock: HertzU32::MHz(40),
-            },
+            desired_rates: Self::configure_raw(cpu_clock_speed),
+        }
+    }
+
+    /// Switches the CPU to `cpu_clock_speed` and returns the resulting clock
+    /// frequencies.
+    fn configure_raw(cpu_clock_speed: CpuClock) -> RawClocks {
+        clocks_ll::set_cpu_clock(cpu_clock_speed);
+
+        RawClocks {
+            cpu_clock: cpu_clock_speed.frequency(),
+            apb_clock: HertzU32::MHz(80),
+            xtal_clock: HertzU32::MHz(40),
         }
     }
 
@@ -688,16 +789,22 @@ impl<'d> ClockControl<'d> {
         clock_control: impl Peripheral<P = SystemClockControl> + 'd,
         cpu_clock_speed: CpuClock,
     ) -> ClockControl<'d> {
-        clocks_ll::set_cpu_clock(cpu_clock_speed);
-
         ClockControl {
             _private: clock_control.into_ref(),
-            desired_rates: RawClocks {
-                cpu_clock: cpu_clock_speed.frequency(),
-                apb_clock: HertzU32::MHz(80),
-                xtal_clock: HertzU32::MHz(40),
-                crypto_pwm_clock: HertzU32::MHz(160),
-            },
+            desired_rates: Self::configure_raw(cpu_clock_speed),
+        }
+    }
+
+    /// Switches the CPU to `cpu_clock_speed` and returns the resulting clock
+    /// frequencies.
+    fn configure_raw(cpu_clock_speed: CpuClock) -> RawClocks {
+        clocks_ll::set_cpu_clock(cpu_clock_speed);
+
+        RawClocks {
+            cpu_clock: cpu_clock_speed.frequency(),
+            apb_clock: HertzU32::MHz(80),
+            xtal_clock: HertzU32::MHz(40),
+            crypto_pwm_clock: HertzU32::MHz(160),
         }
     }
 
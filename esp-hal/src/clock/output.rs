@@ -0,0 +1,141 @@
+//! # Clock Output
+//!
+//! ## Overview
+//! The ESP32 can output some of its internal clocks on the `CLK_OUT1`,
+//! `CLK_OUT2` and `CLK_OUT3` functions of GPIO0, GPIO3 and GPIO1. This is
+//! useful to provide a clock reference to an external chip or to check the
+//! clock configuration with an oscilloscope.
+//!
+//! Each pin has its own `CLK_OUTx` channel, so up to three clocks can be
+//! output at the same time.
+//!
+//! ## Examples
+//! ```rust, no_run
+#![doc = crate::before_snippet!()]
+//! # use esp_hal::clock::{ClockOutput, ClockOutputSource};
+//! # use esp_hal::gpio::Io;
+//! let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+//!
+//! // Output the 40 MHz crystal clock on GPIO0
+//! let _clk_out = ClockOutput::new(io.pins.gpio0, ClockOutputSource::Xtal);
+//! # }
+//! ```
+
+use crate::{
+    gpio::{GpioPin, OutputPin, OutputSignal},
+    peripheral::{Peripheral, PeripheralRef},
+    peripherals::{IO_MUX, LPWR},
+    private,
+};
+
+/// A clock which can be output on a pin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ClockOutputSource {
+    /// The PLL clock, 320 MHz or 480 MHz depending on the CPU clock
+    Pll,
+    /// The RTC slow clock, the 150 kHz RC oscillator by default
+    RtcSlow,
+    /// The crystal oscillator clock
+    Xtal,
+    /// The REF_TICK clock, 1 MHz
+    RefTick,
+    /// The 80 MHz clock derived from the PLL
+    Pll80m,
+    /// The 8 MHz RC oscillator clock
+    Rc8m,
+}
+
+impl ClockOutputSource {
+    /// The value of the `CLKx` field in the `PIN_CTRL` register
+    fn bits(self) -> u32 {
+        match self {
+            ClockOutputSource::Pll => 1,
+            ClockOutputSource::RtcSlow => 4,
+            ClockOutputSource::Xtal => 5,
+            ClockOutputSource::RefTick => 12,
+            ClockOutputSource::Pll80m => 13,
+            ClockOutputSource::Rc8m => 14,
+        }
+    }
+}
+
+/// A pin which has a `CLK_OUTx` function
+pub trait ClockOutputPin: OutputPin + private::Sealed {
+    #[doc(hidden)]
+    const CHANNEL: u8;
+
+    #[doc(hidden)]
+    const SIGNAL: OutputSignal;
+}
+
+macro_rules! clock_output_pin {
+    ($gpio:literal, $channel:literal, $signal:ident) => {
+        impl ClockOutputPin for GpioPin<$gpio> {
+            const CHANNEL: u8 = $channel;
+            const SIGNAL: OutputSignal = OutputSignal::$signal;
+        }
+    };
+}
+
+clock_output_pin!(0, 1, CLK_OUT1);
+clock_output_pin!(3, 2, CLK_OUT2);
+clock_output_pin!(1, 3, CLK_OUT3);
+
+/// PIN_CTRL CLKx value which doesn't output a clock
+const PIN_CTRL_CLK_NONE: u32 = 0xf;
+
+/// A clock output on a pin
+///
+/// The clock is output until this is dropped.
+pub struct ClockOutput<'d, P>
+where
+    P: ClockOutputPin,
+{
+    pin: PeripheralRef<'d, P>,
+}
+
+impl<'d, P> ClockOutput<'d, P>
+where
+    P: ClockOutputPin,
+{
+    /// Outputs the clock `source` on `pin`.
+    pub fn new(pin: impl Peripheral<P = P> + 'd, source: ClockOutputSource) -> Self {
+        crate::into_ref!(pin);
+
+        if source == ClockOutputSource::Rc8m {
+            // The 8 MHz clock is only routed to the digital part when enabled
+            unsafe { &*LPWR::PTR }
+                .clk_conf()
+                .modify(|_, w| w.dig_clk8m_en().set_bit());
+        }
+
+        set_pin_ctrl_clk(P::CHANNEL, source.bits());
+
+        pin.set_to_push_pull_output(private::Internal);
+        pin.connect_peripheral_to_output(P::SIGNAL, private::Internal);
+
+        Self { pin }
+    }
+}
+
+impl<'d, P> Drop for ClockOutput<'d, P>
+where
+    P: ClockOutputPin,
+{
+    fn drop(&mut self) {
+        self.pin
+            .disconnect_peripheral_from_output(private::Internal);
+        set_pin_ctrl_clk(P::CHANNEL, PIN_CTRL_CLK_NONE);
+    }
+}
+
+fn set_pin_ctrl_clk(channel: u8, bits: u32) {
+    let shift = (channel as u32 - 1) * 4;
+
+    // SAFETY:
+    // We only modify the CLKx field of our channel
+    unsafe { &*IO_MUX::PTR }
+        .pin_ctrl()
+        .modify(|r, w| unsafe { w.bits((r.bits() & !(0xf << shift)) | (bits << shift)) });
+}
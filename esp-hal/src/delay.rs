@@ -136,7 +136,7 @@ mod implementation {
         /// Create a new `Delay` instance
         pub fn new(clocks: &Clocks) -> Self {
             Self {
-                freq: clocks.cpu_clock.into(),
+                freq: clocks.cpu_clock().into(),
             }
         }
 
@@ -0,0 +1,85 @@
+//! # Memory region queries
+//!
+//! ## Overview
+//! The DMA engine can only access some of the address space of the chip. The
+//! functions in this module tell which memory a pointer refers to, and whether
+//! a buffer can be used for DMA transfers.
+//!
+//! These are the same checks the HAL uses when preparing a transfer, so a
+//! buffer for which [is_dma_capable] returns `true` won't be rejected with
+//! [DmaError::UnsupportedMemoryRegion](super::DmaError::UnsupportedMemoryRegion).
+//!
+//! ## Examples
+//! ```rust, no_run
+#![doc = crate::before_snippet!()]
+//! # use esp_hal::dma::{memory_region, MemoryRegion};
+//! static DATA: [u8; 4] = [1, 2, 3, 4];
+//!
+//! assert_eq!(memory_region(DATA.as_ptr()), MemoryRegion::Flash);
+//! # }
+//! ```
+
+/// The kind of memory an address belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MemoryRegion {
+    /// Internal data RAM
+    InternalRam,
+    /// External RAM (PSRAM) mapped by the [psram](crate::psram) driver
+    ExternalRam,
+    /// Flash, mapped through the instruction or data cache
+    Flash,
+    /// Any other address, e.g. peripheral registers or RTC memory
+    Other,
+}
+
+/// Returns the kind of memory `ptr` points into.
+pub fn memory_region(ptr: *const u8) -> MemoryRegion {
+    let address = ptr as u32;
+
+    // On some chips external RAM and flash share the same address range, so
+    // the mapped external RAM has to be checked first.
+    if crate::soc::is_valid_ram_address(address) {
+        MemoryRegion::InternalRam
+    } else if crate::soc::is_valid_psram_address(address) {
+        MemoryRegion::ExternalRam
+    } else if crate::soc::is_valid_flash_address(address) {
+        MemoryRegion::Flash
+    } else {
+        MemoryRegion::Other
+    }
+}
+
+/// Returns whether `ptr` points into internal data RAM.
+pub fn is_internal(ptr: *const u8) -> bool {
+    memory_region(ptr) == MemoryRegion::InternalRam
+}
+
+/// Returns whether `ptr` points into mapped external RAM (PSRAM).
+pub fn is_external(ptr: *const u8) -> bool {
+    memory_region(ptr) == MemoryRegion::ExternalRam
+}
+
+/// Returns whether `ptr` points into flash.
+pub fn is_flash(ptr: *const u8) -> bool {
+    memory_region(ptr) == MemoryRegion::Flash
+}
+
+/// Returns whether the DMA can access the `len` bytes starting at `ptr`.
+///
+/// This is internal RAM, and the mapped external RAM on chips whose DMA can
+/// access it. An empty buffer is DMA capable if `ptr` is.
+pub fn is_dma_capable(ptr: *const u8, len: usize) -> bool {
+    let last = ptr.wrapping_add(len.saturating_sub(1));
+
+    let first_region = memory_region(ptr);
+    if first_region != memory_region(last) {
+        return false;
+    }
+
+    match first_region {
+        MemoryRegion::InternalRam => true,
+        MemoryRegion::ExternalRam => cfg!(esp32s3),
+        MemoryRegion::Flash | MemoryRegion::Other => false,
+    }
+}
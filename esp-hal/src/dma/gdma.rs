@@ -14,8 +14,10 @@
 //!
 //! ## Usage
 //! This module implements DMA channels, such as `channel0`, `channel1` and so
-//! on. Each channel struct implements the `ChannelTypes` trait, which provides
-//! associated types for peripheral configuration.
+//! on. Each channel struct implements the `DmaChannel` trait, which provides
+//! associated types for peripheral configuration. Configured channels can be
+//! converted into a [`Channel<'_, AnyDmaChannel, _>`](crate::dma::Channel) via
+//! `degrade()`, which erases the channel number from the type.
 //! <em>PS: Note that the number of DMA channels is chip-specific.</em>
 
 use crate::{
@@ -24,10 +26,11 @@ use crate::{
     system::{Peripheral, PeripheralClockControl},
 };
 
+#[doc(hidden)]
 #[non_exhaustive]
-pub struct Channel<const N: u8> {}
+pub struct AnyGdmaChannel(pub(super) u8);
 
-impl<const N: u8> crate::private::Sealed for Channel<N> {}
+impl crate::private::Sealed for AnyGdmaChannel {}
 
 #[doc(hidden)]
 #[non_exhaustive]
@@ -35,66 +38,126 @@ pub struct ChannelInterruptBinder<const N: u8> {}
 
 impl<const N: u8> crate::private::Sealed for ChannelInterruptBinder<N> {}
 
-impl<const N: u8> Channel<N> {
+impl AnyGdmaChannel {
     #[inline(always)]
-    fn ch() -> &'static crate::peripherals::dma::ch::CH {
+    fn ch(&self) -> &'static crate::peripherals::dma::ch::CH {
         let dma = unsafe { &*crate::peripherals::DMA::PTR };
-        dma.ch(N as usize)
+        dma.ch(self.0 as usize)
     }
 
     #[cfg(any(esp32c2, esp32c3))]
     #[inline(always)]
-    fn in_int() -> &'static crate::peripherals::dma::int_ch::INT_CH {
+    fn in_int(&self) -> &'static crate::peripherals::dma::int_ch::INT_CH {
         let dma = unsafe { &*crate::peripherals::DMA::PTR };
-        dma.int_ch(N as usize)
+        dma.int_ch(self.0 as usize)
     }
     #[inline(always)]
     #[cfg(any(esp32c6, esp32h2))]
-    fn in_int() -> &'static crate::peripherals::dma::in_int_ch::IN_INT_CH {
+    fn in_int(&self) -> &'static crate::peripherals::dma::in_int_ch::IN_INT_CH {
         let dma = unsafe { &*crate::peripherals::DMA::PTR };
-        dma.in_int_ch(N as usize)
+        dma.in_int_ch(self.0 as usize)
     }
     #[cfg(esp32s3)]
     #[inline(always)]
-    fn in_int() -> &'static crate::peripherals::dma::ch::in_int::IN_INT {
+    fn in_int(&self) -> &'static crate::peripherals::dma::ch::in_int::IN_INT {
         let dma = unsafe { &*crate::peripherals::DMA::PTR };
-        dma.ch(N as usize).in_int()
+        dma.ch(self.0 as usize).in_int()
     }
 
     #[cfg(any(esp32c2, esp32c3))]
     #[inline(always)]
-    fn out_int() -> &'static crate::peripherals::dma::int_ch::INT_CH {
+    fn out_int(&self) -> &'static crate::peripherals::dma::int_ch::INT_CH {
         let dma = unsafe { &*crate::peripherals::DMA::PTR };
-        dma.int_ch(N as usize)
+        dma.int_ch(self.0 as usize)
     }
     #[inline(always)]
     #[cfg(any(esp32c6, esp32h2))]
-    fn out_int() -> &'static crate::peripherals::dma::out_int_ch::OUT_INT_CH {
+    fn out_int(&self) -> &'static crate::peripherals::dma::out_int_ch::OUT_INT_CH {
         let dma = unsafe { &*crate::peripherals::DMA::PTR };
-        dma.out_int_ch(N as usize)
+        dma.out_int_ch(self.0 as usize)
     }
     #[cfg(esp32s3)]
     #[inline(always)]
-    fn out_int() -> &'static crate::peripherals::dma::ch::out_int::OUT_INT {
+    fn out_int(&self) -> &'static crate::peripherals::dma::ch::out_int::OUT_INT {
         let dma = unsafe { &*crate::peripherals::DMA::PTR };
-        dma.ch(N as usize).out_int()
+        dma.ch(self.0 as usize).out_int()
     }
 }
 
-impl<const N: u8> RegisterAccess for Channel<N> {
-    fn init_channel() {
+impl RegisterAccess for AnyGdmaChannel {
+    fn init_channel(&self) {
         // nothing special to be done here
     }
 
+    fn set_isr(&self, handler: InterruptHandler) {
+        match self.0 {
+            0 => ChannelInterruptBinder::<0>::set_isr(handler),
+            #[cfg(not(esp32c2))]
+            1 => ChannelInterruptBinder::<1>::set_isr(handler),
+            #[cfg(not(esp32c2))]
+            2 => ChannelInterruptBinder::<2>::set_isr(handler),
+            #[cfg(esp32s3)]
+            3 => ChannelInterruptBinder::<3>::set_isr(handler),
+            #[cfg(esp32s3)]
+            4 => ChannelInterruptBinder::<4>::set_isr(handler),
+            _ => unreachable!(),
+        }
+    }
+
+    fn disable_isr(&self) {
+        match self.0 {
+            0 => ChannelInterruptBinder::<0>::disable_isr(),
+            #[cfg(not(esp32c2))]
+            1 => ChannelInterruptBinder::<1>::disable_isr(),
+            #[cfg(not(esp32c2))]
+            2 => ChannelInterruptBinder::<2>::disable_isr(),
+            #[cfg(esp32s3)]
+            3 => ChannelInterruptBinder::<3>::disable_isr(),
+            #[cfg(esp32s3)]
+            4 => ChannelInterruptBinder::<4>::disable_isr(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    fn async_handler(&self) -> InterruptHandler {
+        use super::asynch::interrupt::*;
+
+        match self.0 {
+            0 => interrupt_handler_ch0,
+            #[cfg(not(esp32c2))]
+            1 => interrupt_handler_ch1,
+            #[cfg(not(esp32c2))]
+            2 => interrupt_handler_ch2,
+            #[cfg(esp32s3)]
+            3 => interrupt_handler_ch3,
+            #[cfg(esp32s3)]
+            4 => interrupt_handler_ch4,
+            _ => unreachable!(),
+        }
+    }
+
     #[cfg(gdma)]
-    fn set_mem2mem_mode(value: bool) {
-        Self::ch()
+    fn set_mem2mem_mode(&self, value: bool) {
+        self.ch()
             .in_conf0()
             .modify(|_, w| w.mem_trans_en().bit(value));
     }
 
-    fn set_out_burstmode(burst_mode: bool) {
-        Self::ch().out_conf0().modify(|_, w| {
+    #[cfg(gdma)]
+    fn is_mem2mem_mode(&self) -> bool {
+        self.ch().in_conf0().read().mem_trans_en().bit_is_set()
+    }
+
+    #[cfg(gdma)]
+    fn set_in_check_owner(&self, value: bool) {
+        self.ch()
+            .in_conf1()
+            .modify(|_, w| w.in_check_owner().bit(value));
+    }
+
+    fn set_out_burstmode(&self, burst_mode: bool) {
+        self.ch().out_conf0().modify(|_, w| {
             w.out_data_burst_en()
                 .bit(burst_mode)
                 .outdscr_burst_en()
@@ -102,15 +165,22 @@ impl<const N: u8> RegisterAccess for Channel<N> {
         });
     }
 
-    fn set_out_priority(priority: DmaPriority) {
-        Self::ch()
+    #[cfg(esp32s3)]
+    fn set_out_ext_mem_block_size(&self, size: ExtMemBlkSize) {
+        self.ch()
+            .out_conf1()
+            .modify(|_, w| unsafe { w.out_ext_mem_bk_size().bits(size as u8) });
+    }
+
+    fn set_out_priority(&self, priority: DmaPriority) {
+        self.ch()
             .out_pri()
             .write(|w| unsafe { w.tx_pri().bits(priority as u8) });
     }
 
-    fn clear_out_interrupts() {
+    fn clear_out_interrupts(&self) {
         #[cfg(not(esp32s3))]
-        Self::out_int().clr().write(|w| {
+        self.out_int().clr().write(|w| {
             w.out_eof().clear_bit_by_one();
             w.out_dscr_err().clear_bit_by_one();
             w.out_done().clear_bit_by_one();
@@ -120,7 +190,7 @@ impl<const N: u8> RegisterAccess for Channel<N> {
         });
 
         #[cfg(esp32s3)]
-        Self::out_int().clr().write(|w| {
+        self.out_int().clr().write(|w| {
             w.out_eof().clear_bit_by_one();
             w.out_dscr_err().clear_bit_by_one();
             w.out_done().clear_bit_by_one();
@@ -132,82 +202,101 @@ impl<const N: u8> RegisterAccess for Channel<N> {
         });
     }
 
-    fn reset_out() {
-        let conf0 = Self::ch().out_conf0();
+    fn reset_out(&self) {
+        let conf0 = self.ch().out_conf0();
         conf0.modify(|_, w| w.out_rst().set_bit());
         conf0.modify(|_, w| w.out_rst().clear_bit());
     }
 
-    fn set_out_descriptors(address: u32) {
-        Self::ch()
+    fn set_out_descriptors(&self, address: u32) {
+        self.ch()
             .out_link()
             .modify(|_, w| unsafe { w.outlink_addr().bits(address) });
     }
 
-    fn has_out_descriptor_error() -> bool {
-        Self::out_int().raw().read().out_dscr_err().bit()
+    fn has_out_descriptor_error(&self) -> bool {
+        self.out_int().raw().read().out_dscr_err().bit()
     }
 
-    fn set_out_peripheral(peripheral: u8) {
-        Self::ch()
+    fn set_out_peripheral(&self, peripheral: u8) {
+        self.ch()
             .out_peri_sel()
             .modify(|_, w| unsafe { w.peri_out_sel().bits(peripheral) });
     }
 
-    fn start_out() {
-        Self::ch()
+    fn start_out(&self) {
+        self.ch()
             .out_link()
             .modify(|_, w| w.outlink_start().set_bit());
     }
 
-    fn clear_ch_out_done() {
-        Self::out_int()
+    fn stop_out(&self) {
+        self.ch()
+            .out_link()
+            .modify(|_, w| w.outlink_stop().set_bit());
+    }
+
+    fn restart_out(&self) {
+        self.ch()
+            .out_link()
+            .modify(|_, w| w.outlink_restart().set_bit());
+    }
+
+    fn clear_ch_out_done(&self) {
+        self.out_int()
             .clr()
             .write(|w| w.out_done().clear_bit_by_one());
     }
 
-    fn is_ch_out_done_set() -> bool {
-        Self::out_int().raw().read().out_done().bit()
+    fn is_ch_out_done_set(&self) -> bool {
+        self.out_int().raw().read().out_done().bit()
     }
 
-    fn listen_ch_out_done() {
-        Self::out_int().ena().modify(|_, w| w.out_done().set_bit())
+    fn listen_ch_out_done(&self) {
+        self.out_int().ena().modify(|_, w| w.out_done().set_bit())
     }
 
-    fn unlisten_ch_out_done() {
-        Self::out_int()
-            .ena()
-            .modify(|_, w| w.out_done().clear_bit())
+    fn unlisten_ch_out_done(&self) {
+        self.out_int().ena().modify(|_, w| w.out_done().clear_bit())
     }
 
-    fn is_listening_ch_out_done() -> bool {
-        Self::out_int().ena().read().out_done().bit()
+    fn is_listening_ch_out_done(&self) -> bool {
+        self.out_int().ena().read().out_done().bit()
     }
 
-    fn is_out_done() -> bool {
-        Self::out_int().raw().read().out_total_eof().bit()
+    fn is_out_done(&self) -> bool {
+        self.out_int().raw().read().out_total_eof().bit()
     }
 
-    fn last_out_dscr_address() -> usize {
-        Self::ch()
+    fn last_out_dscr_address(&self) -> usize {
+        self.ch()
             .out_eof_des_addr()
             .read()
             .out_eof_des_addr()
             .bits() as _
     }
 
-    fn is_out_eof_interrupt_set() -> bool {
-        Self::out_int().raw().read().out_eof().bit()
+    fn last_out_err_dscr_address(&self) -> Option<usize> {
+        // the channel stops at the descriptor it rejected
+        Some(self.ch().out_dscr().read().outlink_dscr().bits() as _)
+    }
+
+    fn current_out_dscr_address(&self) -> usize {
+        self.ch().out_dscr().read().outlink_dscr().bits() as _
     }
 
-    fn reset_out_eof_interrupt() {
-        Self::out_int()
+    fn is_out_eof_interrupt_set(&self) -> bool {
+        self.out_int().raw().read().out_eof().bit()
+    }
+
+    fn reset_out_eof_interrupt(&self) {
+        self.out_int()
             .clr()
             .write(|w| w.out_eof().clear_bit_by_one());
     }
 
-    fn set_in_burstmode(burst_mode: bool) {
-        Self::ch().in_conf0().modify(|_, w| {
+    fn set_in_burstmode(&self, burst_mode: bool) {
+        self.ch().in_conf0().modify(|_, w| {
             w.in_data_burst_en()
                 .bit(burst_mode)
                 .indscr_burst_en()
@@ -215,15 +304,22 @@ impl<const N: u8> RegisterAccess for Channel<N> {
         });
     }
 
-    fn set_in_priority(priority: DmaPriority) {
-        Self::ch()
+    #[cfg(esp32s3)]
+    fn set_in_ext_mem_block_size(&self, size: ExtMemBlkSize) {
+        self.ch()
+            .in_conf1()
+            .modify(|_, w| unsafe { w.in_ext_mem_bk_size().bits(size as u8) });
+    }
+
+    fn set_in_priority(&self, priority: DmaPriority) {
+        self.ch()
             .in_pri()
             .write(|w| unsafe { w.rx_pri().bits(priority as u8) });
     }
 
-    fn clear_in_interrupts() {
+    fn clear_in_interrupts(&self) {
         #[cfg(not(esp32s3))]
-        Self::in_int().clr().write(|w| {
+        self.in_int().clr().write(|w| {
             w.in_suc_eof().clear_bit_by_one();
             w.in_err_eof().clear_bit_by_one();
             w.in_dscr_err().clear_bit_by_one();
@@ -234,7 +330,7 @@ impl<const N: u8> RegisterAccess for Channel<N> {
         });
 
         #[cfg(esp32s3)]
-        Self::in_int().clr().write(|w| {
+        self.in_int().clr().write(|w| {
             w.in_suc_eof().clear_bit_by_one();
             w.in_err_eof().clear_bit_by_one();
             w.in_dscr_err().clear_bit_by_one();
@@ -247,188 +343,280 @@ impl<const N: u8> RegisterAccess for Channel<N> {
         });
     }
 
-    fn reset_in() {
-        let conf0 = Self::ch().in_conf0();
+    fn reset_in(&self) {
+        let conf0 = self.ch().in_conf0();
         conf0.modify(|_, w| w.in_rst().set_bit());
         conf0.modify(|_, w| w.in_rst().clear_bit());
     }
 
-    fn set_in_descriptors(address: u32) {
-        Self::ch()
+    fn set_in_descriptors(&self, address: u32) {
+        self.ch()
             .in_link()
             .modify(|_, w| unsafe { w.inlink_addr().bits(address) });
     }
 
-    fn has_in_descriptor_error() -> bool {
-        Self::in_int().raw().read().in_dscr_err().bit()
+    fn has_in_descriptor_error(&self) -> bool {
+        self.in_int().raw().read().in_dscr_err().bit()
+    }
+
+    fn has_in_descriptor_error_dscr_empty(&self) -> bool {
+        self.in_int().raw().read().in_dscr_empty().bit()
     }
 
-    fn has_in_descriptor_error_dscr_empty() -> bool {
-        Self::in_int().raw().read().in_dscr_empty().bit()
+    fn has_in_descriptor_error_err_eof(&self) -> bool {
+        self.in_int().raw().read().in_err_eof().bit()
     }
 
-    fn has_in_descriptor_error_err_eof() -> bool {
-        Self::in_int().raw().read().in_err_eof().bit()
+    fn last_in_err_dscr_address(&self) -> Option<usize> {
+        // the channel stops at the descriptor it rejected
+        Some(self.ch().in_dscr().read().inlink_dscr().bits() as _)
     }
 
-    fn set_in_peripheral(peripheral: u8) {
-        Self::ch()
+    fn current_in_dscr_address(&self) -> usize {
+        self.ch().in_dscr().read().inlink_dscr().bits() as _
+    }
+
+    fn set_in_peripheral(&self, peripheral: u8) {
+        self.ch()
             .in_peri_sel()
             .modify(|_, w| unsafe { w.peri_in_sel().bits(peripheral) });
     }
 
-    fn start_in() {
-        Self::ch()
+    fn start_in(&self) {
+        self.ch()
             .in_link()
             .modify(|_, w| w.inlink_start().set_bit());
     }
 
-    fn is_in_done() -> bool {
-        Self::in_int().raw().read().in_suc_eof().bit()
+    fn stop_in(&self) {
+        self.ch().in_link().modify(|_, w| w.inlink_stop().set_bit());
+    }
+
+    fn is_in_done(&self) -> bool {
+        self.in_int().raw().read().in_suc_eof().bit()
     }
 
-    fn is_listening_in_eof() -> bool {
-        Self::in_int().ena().read().in_suc_eof().bit_is_set()
+    fn is_listening_in_eof(&self) -> bool {
+        self.in_int().ena().read().in_suc_eof().bit_is_set()
     }
 
-    fn is_listening_out_eof() -> bool {
-        Self::out_int().ena().read().out_total_eof().bit_is_set()
+    fn is_listening_out_eof(&self) -> bool {
+        self.out_int().ena().read().out_total_eof().bit_is_set()
     }
 
-    fn listen_in_eof() {
-        Self::in_int().ena().modify(|_, w| w.in_suc_eof().set_bit());
+    fn listen_in_eof(&self) {
+        self.in_int().ena().modify(|_, w| w.in_suc_eof().set_bit());
     }
 
-    fn listen_out_eof() {
-        Self::out_int()
+    fn listen_out_eof(&self) {
+        self.out_int()
             .ena()
             .modify(|_, w| w.out_total_eof().set_bit());
     }
 
-    fn unlisten_in_eof() {
-        Self::in_int()
+    fn unlisten_in_eof(&self) {
+        self.in_int()
             .ena()
             .modify(|_, w| w.in_suc_eof().clear_bit());
     }
 
-    fn unlisten_out_eof() {
-        Self::out_int()
+    fn unlisten_out_eof(&self) {
+        self.out_int()
             .ena()
             .modify(|_, w| w.out_total_eof().clear_bit());
     }
 
-    fn listen_ch_in_done() {
-        Self::in_int().ena().modify(|_, w| w.in_done().set_bit())
+    fn is_in_eof_set(&self) -> bool {
+        self.in_int().raw().read().in_suc_eof().bit()
+    }
+
+    fn clear_in_eof(&self) {
+        self.in_int()
+            .clr()
+            .write(|w| w.in_suc_eof().clear_bit_by_one());
+    }
+
+    fn clear_out_eof(&self) {
+        self.out_int()
+            .clr()
+            .write(|w| w.out_total_eof().clear_bit_by_one());
+    }
+
+    fn clear_in_descriptor_error(&self) {
+        self.in_int()
+            .clr()
+            .write(|w| w.in_dscr_err().clear_bit_by_one());
+    }
+
+    fn clear_in_descriptor_error_dscr_empty(&self) {
+        self.in_int()
+            .clr()
+            .write(|w| w.in_dscr_empty().clear_bit_by_one());
+    }
+
+    fn clear_out_descriptor_error(&self) {
+        self.out_int()
+            .clr()
+            .write(|w| w.out_dscr_err().clear_bit_by_one());
+    }
+
+    fn listen_ch_in_done(&self) {
+        self.in_int().ena().modify(|_, w| w.in_done().set_bit())
     }
 
-    fn clear_ch_in_done() {
-        Self::in_int()
+    fn clear_ch_in_done(&self) {
+        self.in_int()
             .clr()
             .write(|w| w.in_done().clear_bit_by_one());
     }
 
-    fn is_ch_in_done_set() -> bool {
-        Self::in_int().raw().read().in_done().bit()
+    fn is_ch_in_done_set(&self) -> bool {
+        self.in_int().raw().read().in_done().bit()
     }
 
-    fn unlisten_ch_in_done() {
-        Self::in_int().ena().modify(|_, w| w.in_done().clear_bit());
+    fn unlisten_ch_in_done(&self) {
+        self.in_int().ena().modify(|_, w| w.in_done().clear_bit());
     }
 
-    fn is_listening_ch_in_done() -> bool {
-        Self::in_int().ena().read().in_done().bit()
+    fn is_listening_ch_in_done(&self) -> bool {
+        self.in_int().ena().read().in_done().bit()
     }
 
-    fn listen_in_descriptor_error() {
-        Self::in_int()
-            .ena()
-            .modify(|_, w| w.in_dscr_err().set_bit())
+    fn listen_in_descriptor_error(&self) {
+        self.in_int().ena().modify(|_, w| w.in_dscr_err().set_bit())
     }
 
-    fn unlisten_in_descriptor_error() {
-        Self::in_int()
+    fn unlisten_in_descriptor_error(&self) {
+        self.in_int()
             .ena()
             .modify(|_, w| w.in_dscr_err().clear_bit())
     }
 
-    fn is_listening_in_descriptor_error() -> bool {
-        Self::in_int().ena().read().in_dscr_err().bit()
+    fn is_listening_in_descriptor_error(&self) -> bool {
+        self.in_int().ena().read().in_dscr_err().bit()
     }
 
-    fn listen_in_descriptor_error_dscr_empty() {
-        Self::in_int()
+    fn listen_in_descriptor_error_dscr_empty(&self) {
+        self.in_int()
             .ena()
             .modify(|_, w| w.in_dscr_empty().set_bit())
     }
 
-    fn unlisten_in_descriptor_error_dscr_empty() {
-        Self::in_int()
+    fn unlisten_in_descriptor_error_dscr_empty(&self) {
+        self.in_int()
             .ena()
             .modify(|_, w| w.in_dscr_empty().clear_bit())
     }
 
-    fn is_listening_in_descriptor_error_dscr_empty() -> bool {
-        Self::in_int().ena().read().in_dscr_empty().bit()
+    fn is_listening_in_descriptor_error_dscr_empty(&self) -> bool {
+        self.in_int().ena().read().in_dscr_empty().bit()
     }
 
-    fn listen_in_descriptor_error_err_eof() {
-        Self::in_int().ena().modify(|_, w| w.in_err_eof().set_bit())
+    fn listen_in_descriptor_error_err_eof(&self) {
+        self.in_int().ena().modify(|_, w| w.in_err_eof().set_bit())
     }
 
-    fn unlisten_in_descriptor_error_err_eof() {
-        Self::in_int()
+    fn unlisten_in_descriptor_error_err_eof(&self) {
+        self.in_int()
             .ena()
             .modify(|_, w| w.in_err_eof().clear_bit())
     }
 
-    fn is_listening_in_descriptor_error_err_eof() -> bool {
-        Self::in_int().ena().read().in_err_eof().bit()
+    fn is_listening_in_descriptor_error_err_eof(&self) -> bool {
+        self.in_int().ena().read().in_err_eof().bit()
     }
 
-    fn listen_out_descriptor_error() {
-        Self::out_int()
+    fn listen_out_descriptor_error(&self) {
+        self.out_int()
             .ena()
             .modify(|_, w| w.out_dscr_err().set_bit())
     }
 
-    fn unlisten_out_descriptor_error() {
-        Self::out_int()
+    fn unlisten_out_descriptor_error(&self) {
+        self.out_int()
             .ena()
             .modify(|_, w| w.out_dscr_err().clear_bit())
     }
 
-    fn is_listening_out_descriptor_error() -> bool {
-        Self::out_int().ena().read().out_dscr_err().bit()
+    fn is_listening_out_descriptor_error(&self) -> bool {
+        self.out_int().ena().read().out_dscr_err().bit()
     }
 }
 
+#[cfg(all(any(feature = "async", feature = "dma-stats"), esp32c2))]
+const CHANNEL_COUNT: usize = 1;
+#[cfg(all(
+    any(feature = "async", feature = "dma-stats"),
+    any(esp32c3, esp32c6, esp32h2)
+))]
+const CHANNEL_COUNT: usize = 3;
+#[cfg(all(any(feature = "async", feature = "dma-stats"), esp32s3))]
+const CHANNEL_COUNT: usize = 5;
+
+#[cfg(feature = "async")]
+#[allow(clippy::declare_interior_mutable_const)]
+const WAKER_INIT: embassy_sync::waitqueue::AtomicWaker =
+    embassy_sync::waitqueue::AtomicWaker::new();
+
+#[cfg(feature = "async")]
+static TX_WAKERS: [embassy_sync::waitqueue::AtomicWaker; CHANNEL_COUNT] =
+    [WAKER_INIT; CHANNEL_COUNT];
+
+#[cfg(feature = "async")]
+static RX_WAKERS: [embassy_sync::waitqueue::AtomicWaker; CHANNEL_COUNT] =
+    [WAKER_INIT; CHANNEL_COUNT];
+
+#[cfg(feature = "dma-stats")]
+#[allow(clippy::declare_interior_mutable_const)]
+const STATS_INIT: DmaStatsCounters = DmaStatsCounters::new();
+
+#[cfg(feature = "dma-stats")]
+static TX_STATS: [DmaStatsCounters; CHANNEL_COUNT] = [STATS_INIT; CHANNEL_COUNT];
+
+#[cfg(feature = "dma-stats")]
+static RX_STATS: [DmaStatsCounters; CHANNEL_COUNT] = [STATS_INIT; CHANNEL_COUNT];
+
 #[non_exhaustive]
 #[doc(hidden)]
-pub struct ChannelTxImpl<const N: u8> {}
+pub struct ChannelTxImpl(pub(super) AnyGdmaChannel);
 
-impl<const N: u8> crate::private::Sealed for ChannelTxImpl<N> {}
+impl crate::private::Sealed for ChannelTxImpl {}
+
+impl TxChannel<AnyGdmaChannel> for ChannelTxImpl {
+    fn register_access(&self) -> &AnyGdmaChannel {
+        &self.0
+    }
 
-impl<const N: u8> TxChannel<Channel<N>> for ChannelTxImpl<N> {
     #[cfg(feature = "async")]
-    fn waker() -> &'static embassy_sync::waitqueue::AtomicWaker {
-        static WAKER: embassy_sync::waitqueue::AtomicWaker =
-            embassy_sync::waitqueue::AtomicWaker::new();
-        &WAKER
+    fn waker(&self) -> &'static embassy_sync::waitqueue::AtomicWaker {
+        &TX_WAKERS[self.0 .0 as usize]
+    }
+
+    #[cfg(feature = "dma-stats")]
+    fn stats(&self) -> &'static DmaStatsCounters {
+        &TX_STATS[self.0 .0 as usize]
     }
 }
 
 #[non_exhaustive]
 #[doc(hidden)]
-pub struct ChannelRxImpl<const N: u8> {}
+pub struct ChannelRxImpl(pub(super) AnyGdmaChannel);
 
-impl<const N: u8> crate::private::Sealed for ChannelRxImpl<N> {}
+impl crate::private::Sealed for ChannelRxImpl {}
+
+impl RxChannel<AnyGdmaChannel> for ChannelRxImpl {
+    fn register_access(&self) -> &AnyGdmaChannel {
+        &self.0
+    }
 
-impl<const N: u8> RxChannel<Channel<N>> for ChannelRxImpl<N> {
     #[cfg(feature = "async")]
-    fn waker() -> &'static embassy_sync::waitqueue::AtomicWaker {
-        static WAKER: embassy_sync::waitqueue::AtomicWaker =
-            embassy_sync::waitqueue::AtomicWaker::new();
-        &WAKER
+    fn waker(&self) -> &'static embassy_sync::waitqueue::AtomicWaker {
+        &RX_WAKERS[self.0 .0 as usize]
+    }
+
+    #[cfg(feature = "dma-stats")]
+    fn stats(&self) -> &'static DmaStatsCounters {
+        &RX_STATS[self.0 .0 as usize]
     }
 }
 
@@ -438,36 +626,33 @@ pub struct ChannelCreator<const N: u8> {}
 
 #[non_exhaustive]
 #[doc(hidden)]
-pub struct SuitablePeripheral<const N: u8> {}
-impl<const N: u8> PeripheralMarker for SuitablePeripheral<N> {}
+pub struct SuitablePeripheral {}
+impl PeripheralMarker for SuitablePeripheral {}
 
 // with GDMA every channel can be used for any peripheral
-impl<const N: u8> SpiPeripheral for SuitablePeripheral<N> {}
-impl<const N: u8> Spi2Peripheral for SuitablePeripheral<N> {}
+impl SpiPeripheral for SuitablePeripheral {}
+impl Spi2Peripheral for SuitablePeripheral {}
 #[cfg(esp32s3)]
-impl<const N: u8> Spi3Peripheral for SuitablePeripheral<N> {}
-impl<const N: u8> I2sPeripheral for SuitablePeripheral<N> {}
-impl<const N: u8> I2s0Peripheral for SuitablePeripheral<N> {}
-impl<const N: u8> I2s1Peripheral for SuitablePeripheral<N> {}
+impl Spi3Peripheral for SuitablePeripheral {}
+impl I2sPeripheral for SuitablePeripheral {}
+impl I2s0Peripheral for SuitablePeripheral {}
+impl I2s1Peripheral for SuitablePeripheral {}
 #[cfg(parl_io)]
-impl<const N: u8> ParlIoPeripheral for SuitablePeripheral<N> {}
+impl ParlIoPeripheral for SuitablePeripheral {}
 #[cfg(aes)]
-impl<const N: u8> AesPeripheral for SuitablePeripheral<N> {}
+impl AesPeripheral for SuitablePeripheral {}
+#[cfg(sha)]
+impl ShaPeripheral for SuitablePeripheral {}
+#[cfg(uhci0)]
+impl UhciPeripheral for SuitablePeripheral {}
 #[cfg(lcd_cam)]
-impl<const N: u8> LcdCamPeripheral for SuitablePeripheral<N> {}
+impl LcdCamPeripheral for SuitablePeripheral {}
+#[cfg(any(esp32c3, esp32c6, esp32h2, esp32s3))]
+impl AdcPeripheral for SuitablePeripheral {}
 
 macro_rules! impl_channel {
     ($num: literal, $async_handler: path, $($interrupt: ident),* ) => {
         paste::paste! {
-            #[doc(hidden)]
-            pub type [<Channel $num>] = Channel<$num>;
-
-            #[doc(hidden)]
-            pub type [<Channel $num TxImpl>] = ChannelTxImpl<$num>;
-
-            #[doc(hidden)]
-            pub type [<Channel $num RxImpl>] = ChannelRxImpl<$num>;
-
             #[doc(hidden)]
             pub type [<ChannelCreator $num>] = ChannelCreator<$num>;
 
@@ -482,10 +667,12 @@ macro_rules! impl_channel {
                         $crate::interrupt::enable($crate::peripherals::Interrupt::$interrupt, handler.priority()).unwrap();
                     )*
                 }
-            }
 
-            impl ChannelTypes for Channel<$num> {
-                type Binder = ChannelInterruptBinder<$num>;
+                fn disable_isr() {
+                    $(
+                        $crate::interrupt::disable($crate::get_core(), $crate::peripherals::Interrupt::$interrupt);
+                    )*
+                }
             }
 
             /// A description of a GDMA channel
@@ -495,10 +682,10 @@ macro_rules! impl_channel {
             impl crate::private::Sealed for [<DmaChannel $num>] {}
 
             impl DmaChannel for [<DmaChannel $num>] {
-                type Channel = Channel<$num>;
-                type Rx = ChannelRxImpl<$num>;
-                type Tx = ChannelTxImpl<$num>;
-                type P = SuitablePeripheral<$num>;
+                type Channel = AnyGdmaChannel;
+                type Rx = ChannelRxImpl;
+                type Tx = ChannelTxImpl;
+                type P = SuitablePeripheral;
             }
 
             impl ChannelCreator<$num> {
@@ -511,15 +698,29 @@ macro_rules! impl_channel {
                     burst_mode: bool,
                     priority: DmaPriority,
                 ) -> crate::dma::Channel<'a, [<DmaChannel $num>], crate::Blocking> {
-                    let mut tx_impl = ChannelTxImpl {};
-                    tx_impl.init(burst_mode, priority);
+                    self.configure_with(burst_mode.into(), priority)
+                }
 
-                    let mut rx_impl = ChannelRxImpl {};
-                    rx_impl.init(burst_mode, priority);
+                /// Configure the channel for use with blocking APIs
+                ///
+                /// Descriptors should be sized as `(CHUNK_SIZE + 4091) / 4092`. I.e., to
+                /// transfer buffers of size `1..=4092`, you need 1 descriptor.
+                ///
+                /// Allows configuring the burst mode of each direction separately.
+                pub fn configure_with<'a>(
+                    self,
+                    burst: DmaBurstConfig,
+                    priority: DmaPriority,
+                ) -> crate::dma::Channel<'a, [<DmaChannel $num>], crate::Blocking> {
+                    let mut tx_impl = ChannelTxImpl(AnyGdmaChannel($num));
+                    tx_impl.init(burst, priority);
+
+                    let mut rx_impl = ChannelRxImpl(AnyGdmaChannel($num));
+                    rx_impl.init(burst, priority);
 
                     crate::dma::Channel {
-                        tx: ChannelTx::new(tx_impl, burst_mode),
-                        rx: ChannelRx::new(rx_impl, burst_mode),
+                        tx: ChannelTx::new(tx_impl, burst),
+                        rx: ChannelRx::new(rx_impl, burst),
                         phantom: PhantomData,
                     }
                 }
@@ -534,17 +735,61 @@ macro_rules! impl_channel {
                     burst_mode: bool,
                     priority: DmaPriority,
                 ) -> crate::dma::Channel<'a, [<DmaChannel $num>], $crate::Async> {
-                    let mut tx_impl = ChannelTxImpl {};
-                    tx_impl.init(burst_mode, priority);
+                    self.configure_for_async_with(burst_mode.into(), priority)
+                }
 
-                    let mut rx_impl = ChannelRxImpl {};
-                    rx_impl.init(burst_mode, priority);
+                /// Configure the channel for use with async APIs
+                ///
+                /// Descriptors should be sized as `(CHUNK_SIZE + 4091) / 4092`. I.e., to
+                /// transfer buffers of size `1..=4092`, you need 1 descriptor.
+                ///
+                /// Allows configuring the burst mode of each direction separately.
+                #[cfg(feature = "async")]
+                pub fn configure_for_async_with<'a>(
+                    self,
+                    burst: DmaBurstConfig,
+                    priority: DmaPriority,
+                ) -> crate::dma::Channel<'a, [<DmaChannel $num>], $crate::Async> {
+                    self.configure_for_async_with_priority(
+                        burst,
+                        priority,
+                        $crate::interrupt::Priority::max(),
+                    )
+                }
+
+                /// Configure the channel for use with async APIs
+                ///
+                /// Descriptors should be sized as `(CHUNK_SIZE + 4091) / 4092`. I.e., to
+                /// transfer buffers of size `1..=4092`, you need 1 descriptor.
+                ///
+                /// Allows configuring the burst mode of each direction separately and
+                /// choosing the priority of the interrupt which wakes the async tasks
+                /// waiting for this channel.
+                #[cfg_attr(
+                    multi_core,
+                    doc = "The interrupt is handled on the core which calls this function."
+                )]
+                #[cfg(feature = "async")]
+                pub fn configure_for_async_with_priority<'a>(
+                    self,
+                    burst: DmaBurstConfig,
+                    priority: DmaPriority,
+                    interrupt_priority: $crate::interrupt::Priority,
+                ) -> crate::dma::Channel<'a, [<DmaChannel $num>], $crate::Async> {
+                    let mut tx_impl = ChannelTxImpl(AnyGdmaChannel($num));
+                    tx_impl.init(burst, priority);
+
+                    let mut rx_impl = ChannelRxImpl(AnyGdmaChannel($num));
+                    rx_impl.init(burst, priority);
 
-                    <Channel<$num> as ChannelTypes>::Binder::set_isr($async_handler);
+                    ChannelInterruptBinder::<$num>::set_isr($crate::interrupt::InterruptHandler::new(
+                        $async_handler.handler(),
+                        interrupt_priority,
+                    ));
 
                     crate::dma::Channel {
-                        tx: ChannelTx::new(tx_impl, burst_mode),
-                        rx: ChannelRx::new(rx_impl, burst_mode),
+                        tx: ChannelTx::new(tx_impl, burst),
+                        rx: ChannelRx::new(rx_impl, burst),
                         phantom: PhantomData,
                     }
                 }
@@ -573,6 +818,64 @@ cfg_if::cfg_if! {
   }
 }
 
+/// A type-erased GDMA channel
+///
+/// Any GDMA channel can be converted into this type by calling `degrade()` on
+/// the configured [`Channel`](crate::dma::Channel), which allows drivers using
+/// different DMA channels to be stored in the same place.
+#[non_exhaustive]
+pub struct AnyDmaChannel {}
+
+impl crate::private::Sealed for AnyDmaChannel {}
+
+impl DmaChannel for AnyDmaChannel {
+    type Channel = AnyGdmaChannel;
+    type Rx = ChannelRxImpl;
+    type Tx = ChannelTxImpl;
+    type P = SuitablePeripheral;
+}
+
+/// The RX half of a type-erased GDMA channel
+pub type AnyChannelRx<'a> = ChannelRx<'a, AnyDmaChannel>;
+
+/// The TX half of a type-erased GDMA channel
+pub type AnyChannelTx<'a> = ChannelTx<'a, AnyDmaChannel>;
+
+impl<'d, C, MODE> crate::dma::Channel<'d, C, MODE>
+where
+    C: DmaChannel<Channel = AnyGdmaChannel, Rx = ChannelRxImpl, Tx = ChannelTxImpl>,
+    MODE: crate::Mode,
+{
+    /// Erases the channel number from the type of this channel.
+    pub fn degrade(self) -> crate::dma::Channel<'d, AnyDmaChannel, MODE> {
+        crate::dma::Channel {
+            tx: self.tx.degrade(),
+            rx: self.rx.degrade(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, C> ChannelRx<'a, C>
+where
+    C: DmaChannel<Channel = AnyGdmaChannel, Rx = ChannelRxImpl, Tx = ChannelTxImpl>,
+{
+    /// Erases the channel number from the type of this channel half.
+    pub fn degrade(self) -> AnyChannelRx<'a> {
+        ChannelRx::new(self.rx_impl, self.burst)
+    }
+}
+
+impl<'a, C> ChannelTx<'a, C>
+where
+    C: DmaChannel<Channel = AnyGdmaChannel, Rx = ChannelRxImpl, Tx = ChannelTxImpl>,
+{
+    /// Erases the channel number from the type of this channel half.
+    pub fn degrade(self) -> AnyChannelTx<'a> {
+        ChannelTx::new(self.tx_impl, self.burst)
+    }
+}
+
 /// GDMA Peripheral
 ///
 /// This offers the available DMA channels.
@@ -629,6 +932,7 @@ mod m2m {
     use crate::dma::{
         dma_private::{DmaSupport, DmaSupportRx},
         Channel,
+        ChannelMode,
         ChannelRx,
         DescriptorChain,
         DmaChannel,
@@ -712,19 +1016,19 @@ mod m2m {
             rx_descriptors: &'static mut [DmaDescriptor],
             chunk_size: usize,
         ) -> Result<Self, DmaError> {
-            if !(1..=4092).contains(&chunk_size) {
-                return Err(DmaError::InvalidChunkSize);
-            }
-            if tx_descriptors.is_empty() || rx_descriptors.is_empty() {
-                return Err(DmaError::OutOfDescriptors);
+            let tx_chain = DescriptorChain::new_with_chunk_size(tx_descriptors, chunk_size)?;
+            let rx_chain = DescriptorChain::new_with_chunk_size(rx_descriptors, chunk_size)?;
+            if tx_chain.descriptors.is_empty() || rx_chain.descriptors.is_empty() {
+                return Err(DmaError::OutOfDescriptors { required: 1 });
             }
             channel.tx.init_channel();
             channel.rx.init_channel();
+            channel.set_mode(ChannelMode::Mem2Mem);
             Ok(Mem2Mem {
                 channel,
                 peripheral,
-                tx_chain: DescriptorChain::new_with_chunk_size(tx_descriptors, chunk_size),
-                rx_chain: DescriptorChain::new_with_chunk_size(rx_descriptors, chunk_size),
+                tx_chain,
+                rx_chain,
             })
         }
 
@@ -740,8 +1044,35 @@ mod m2m {
         {
             let (tx_ptr, tx_len) = unsafe { tx_buffer.read_buffer() };
             let (rx_ptr, rx_len) = unsafe { rx_buffer.write_buffer() };
-            self.tx_chain.fill_for_tx(false, tx_ptr, tx_len)?;
-            self.rx_chain.fill_for_rx(false, rx_ptr, rx_len)?;
+            self.start_copy(rx_ptr, rx_len, tx_ptr, tx_len)?;
+            Ok(DmaTransferRx::new(self))
+        }
+
+        /// The largest number of bytes a single transfer can copy with the
+        /// descriptors of this instance, rounded down to whole words so the
+        /// following transfer starts word aligned.
+        fn max_copy_len(&self) -> usize {
+            let descriptors = usize::min(
+                self.tx_chain.descriptors.len(),
+                self.rx_chain.descriptors.len(),
+            );
+            let len = descriptors * self.tx_chain.chunk_size.min(self.rx_chain.chunk_size);
+            if len >= 4 {
+                len & !3
+            } else {
+                len
+            }
+        }
+
+        fn start_copy(
+            &mut self,
+            dst_ptr: *mut u8,
+            dst_len: usize,
+            src_ptr: *const u8,
+            src_len: usize,
+        ) -> Result<(), DmaError> {
+            self.tx_chain.fill_for_tx(false, src_ptr, src_len)?;
+            self.rx_chain.fill_for_rx(false, dst_ptr, dst_len)?;
             unsafe {
                 self.channel
                     .tx
@@ -749,14 +1080,82 @@ mod m2m {
                 self.channel
                     .rx
                     .prepare_transfer_without_start(self.peripheral, &self.rx_chain)?;
-                self.channel.rx.set_mem2mem_mode(true);
             }
             self.channel.tx.start_transfer()?;
-            self.channel.rx.start_transfer()?;
-            Ok(DmaTransferRx::new(self))
+            self.channel.rx.start_transfer()
         }
     }
 
+    /// Copies `src` to the start of `dst` with the DMA, blocking until the
+    /// copy is done.
+    ///
+    /// Buffers larger than the descriptors of `mem2mem` can cover are copied
+    /// in several transfers. This is mostly useful for large copies between
+    /// external and internal RAM, which don't go through the cache.
+    ///
+    /// Returns [DmaError::BufferTooSmall] if `dst` is shorter than `src`.
+    ///
+    /// The ESP32 and ESP32-S2 can't copy memory with the DMA, use
+    /// [slice::copy_from_slice] on these chips.
+    pub fn mem_copy<C>(
+        mem2mem: &mut Mem2Mem<'_, C, crate::Blocking>,
+        dst: &mut [u8],
+        src: &[u8],
+    ) -> Result<(), DmaError>
+    where
+        C: DmaChannel,
+    {
+        if dst.len() < src.len() {
+            return Err(DmaError::BufferTooSmall);
+        }
+
+        let max_len = mem2mem.max_copy_len();
+        for (dst, src) in dst.chunks_mut(max_len).zip(src.chunks(max_len)) {
+            let dst = &mut dst[..src.len()];
+            mem2mem.start_copy(dst.as_mut_ptr(), dst.len(), src.as_ptr(), src.len())?;
+            DmaTransferRx::new(mem2mem).wait()?;
+        }
+
+        Ok(())
+    }
+
+    /// Copies `src` to the start of `dst` with the DMA, see [mem_copy].
+    ///
+    /// Dropping the future stops both channels of `mem2mem`, the remaining
+    /// part of `dst` isn't written.
+    #[cfg(feature = "async")]
+    pub async fn mem_copy_async<C>(
+        mem2mem: &mut Mem2Mem<'_, C, crate::Async>,
+        dst: &mut [u8],
+        src: &[u8],
+    ) -> Result<(), DmaError>
+    where
+        C: DmaChannel,
+    {
+        if dst.len() < src.len() {
+            return Err(DmaError::BufferTooSmall);
+        }
+
+        let max_len = mem2mem.max_copy_len();
+        for (dst, src) in dst.chunks_mut(max_len).zip(src.chunks(max_len)) {
+            let dst = &mut dst[..src.len()];
+            mem2mem.start_copy(dst.as_mut_ptr(), dst.len(), src.as_ptr(), src.len())?;
+
+            // The RX future stops its channel when it's dropped, the TX channel
+            // would keep reading `src`
+            let future = crate::dma::asynch::DmaRxFuture::new(&mut mem2mem.channel.rx)
+                .with_chain(mem2mem.rx_chain.span());
+            let tx = &mut mem2mem.channel.tx;
+            let guard = crate::dma::asynch::OnDrop::new(|| tx.stop_transfer());
+            let res = future.await;
+            guard.defuse();
+            res?;
+            mem2mem.rx_complete();
+        }
+
+        Ok(())
+    }
+
     impl<'d, C, MODE> DmaSupport for Mem2Mem<'d, C, MODE>
     where
         C: DmaChannel,
@@ -767,7 +1166,7 @@ mod m2m {
         }
 
         fn peripheral_dma_stop(&mut self) {
-            unreachable!("unsupported")
+            // no peripheral to stop
         }
     }
 
@@ -783,7 +1182,122 @@ mod m2m {
         }
 
         fn chain(&mut self) -> &mut DescriptorChain {
-            &mut self.tx_chain
+            &mut self.rx_chain
+        }
+    }
+}
+
+/// Event Task Matrix
+///
+/// The events and tasks of the GDMA channels are available on chips with an
+/// ETM peripheral. They are reachable as `esp_hal::dma::etm`.
+///
+/// The events and tasks are created from a [ChannelCreator] before the
+/// channel is configured. Once a start task was created, the corresponding
+/// direction of the channel doesn't start when a transfer is started anymore,
+/// it only starts when the ETM triggers the task.
+#[cfg(soc_etm)]
+pub mod etm {
+    use super::*;
+    use crate::etm::{EtmEvent, EtmTask};
+
+    /// Event Task Matrix event for a GDMA channel.
+    pub struct DmaEtmEvent {
+        id: u8,
+    }
+
+    /// Event Task Matrix task for a GDMA channel.
+    pub struct DmaEtmTask {
+        id: u8,
+    }
+
+    impl EtmEvent for DmaEtmEvent {
+        fn id(&self) -> u8 {
+            self.id
+        }
+    }
+
+    impl crate::private::Sealed for DmaEtmEvent {}
+
+    impl EtmTask for DmaEtmTask {
+        fn id(&self) -> u8 {
+            self.id
+        }
+    }
+
+    impl crate::private::Sealed for DmaEtmTask {}
+
+    /// GDMA channel ETM events.
+    pub trait DmaEtmEvents {
+        /// ETM event triggered when the RX side received the data of a
+        /// descriptor
+        fn on_in_done(&self) -> DmaEtmEvent;
+
+        /// ETM event triggered when the RX side received the data of a
+        /// descriptor marked as EOF
+        fn on_in_suc_eof(&self) -> DmaEtmEvent;
+
+        /// ETM event triggered when the TX side transmitted the data of a
+        /// descriptor
+        fn on_out_done(&self) -> DmaEtmEvent;
+
+        /// ETM event triggered when the TX side read a descriptor marked as
+        /// EOF
+        fn on_out_eof(&self) -> DmaEtmEvent;
+
+        /// ETM event triggered when the TX side transmitted the data of the
+        /// last descriptor
+        fn on_out_total_eof(&self) -> DmaEtmEvent;
+    }
+
+    /// GDMA channel ETM tasks
+    pub trait DmaEtmTasks {
+        /// ETM task to start the RX side of the channel
+        fn task_start_in(&self) -> DmaEtmTask;
+
+        /// ETM task to start the TX side of the channel
+        fn task_start_out(&self) -> DmaEtmTask;
+    }
+
+    impl<const N: u8> DmaEtmEvents for ChannelCreator<N> {
+        fn on_in_done(&self) -> DmaEtmEvent {
+            DmaEtmEvent { id: 153 + N }
+        }
+
+        fn on_in_suc_eof(&self) -> DmaEtmEvent {
+            DmaEtmEvent { id: 156 + N }
+        }
+
+        fn on_out_done(&self) -> DmaEtmEvent {
+            DmaEtmEvent { id: 165 + N }
+        }
+
+        fn on_out_eof(&self) -> DmaEtmEvent {
+            DmaEtmEvent { id: 168 + N }
+        }
+
+        fn on_out_total_eof(&self) -> DmaEtmEvent {
+            DmaEtmEvent { id: 171 + N }
+        }
+    }
+
+    impl<const N: u8> DmaEtmTasks for ChannelCreator<N> {
+        fn task_start_in(&self) -> DmaEtmTask {
+            AnyGdmaChannel(N)
+                .ch()
+                .in_conf0()
+                .modify(|_, w| w.in_etm_en().set_bit());
+
+            DmaEtmTask { id: 148 + N }
+        }
+
+        fn task_start_out(&self) -> DmaEtmTask {
+            AnyGdmaChannel(N)
+                .ch()
+                .out_conf0()
+                .modify(|_, w| w.out_etm_en().set_bit());
+
+            DmaEtmTask { id: 151 + N }
         }
     }
 }
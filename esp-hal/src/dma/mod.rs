@@ -44,6 +44,12 @@
 //! I.e., to transfer buffers of size `1..=CHUNK_SIZE`, you need 1 descriptor.
 //!
 //! For convenience you can use the [crate::dma_buffers] macro.
+//!
+//! ## Cancellation
+//! Async DMA operations can be cancelled by dropping their future, e.g. when
+//! using `embassy_time::with_timeout`. The DMA channel is stopped before the
+//! future is dropped, so the buffer is no longer accessed and the driver can
+//! be used for the next transfer right away.
 #![warn(missing_docs)]
 
 use core::{fmt::Debug, marker::PhantomData, ptr::addr_of_mut, sync::atomic::compiler_fence};
@@ -71,6 +77,20 @@ impl Debug for DmaDescriptorFlags {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for DmaDescriptorFlags {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(
+            fmt,
+            "DmaDescriptorFlags {{ size: {}, length: {}, suc_eof: {}, owner: {} }}",
+            self.size(),
+            self.length(),
+            self.suc_eof(),
+            self.owner()
+        );
+    }
+}
+
 /// A DMA transfer descriptor.
 #[derive(Clone, Copy, Debug)]
 pub struct DmaDescriptor {
@@ -87,23 +107,123 @@ impl DmaDescriptor {
         next: core::ptr::null_mut(),
     };
 
-    fn set_size(&mut self, len: usize) {
-        self.flags.set_size(len as u16)
+    /// The largest buffer size a single descriptor can describe.
+    pub const MAX_SIZE: usize = 4095;
+
+    /// Create a descriptor for the buffer at `buffer` with room for `size`
+    /// bytes.
+    ///
+    /// The descriptor is owned by the CPU, has a length of zero and isn't
+    /// linked to another descriptor. Returns [DmaError::InvalidChunkSize] if
+    /// `size` doesn't fit into the descriptor.
+    pub fn new(buffer: *mut u8, size: usize) -> Result<Self, DmaError> {
+        if size > Self::MAX_SIZE {
+            return Err(DmaError::InvalidChunkSize);
+        }
+
+        let mut descriptor = Self {
+            buffer,
+            ..Self::EMPTY
+        };
+        descriptor.set_size_unchecked(size);
+        Ok(descriptor)
     }
 
-    fn set_length(&mut self, len: usize) {
-        self.flags.set_length(len as u16)
+    /// The size of the buffer described by this descriptor.
+    pub fn size(&self) -> usize {
+        self.flags.size() as usize
     }
 
-    fn len(&self) -> usize {
+    /// The number of valid bytes in the buffer.
+    ///
+    /// For TX this is the number of bytes to send, for RX the number of bytes
+    /// the DMA has written.
+    pub fn len(&self) -> usize {
         self.flags.length() as usize
     }
 
-    fn set_suc_eof(&mut self, suc_eof: bool) {
+    /// Returns whether the buffer contains no valid bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether this descriptor marks the end of a transfer.
+    pub fn has_suc_eof(&self) -> bool {
+        self.flags.suc_eof()
+    }
+
+    /// Whether the descriptor is currently owned by the CPU or the DMA.
+    pub fn owner(&self) -> Owner {
+        match self.flags.owner() {
+            false => Owner::Cpu,
+            true => Owner::Dma,
+        }
+    }
+
+    /// The buffer described by this descriptor.
+    pub fn buffer(&self) -> *mut u8 {
+        self.buffer
+    }
+
+    /// The next descriptor in the chain, or null for the last one.
+    pub fn next(&self) -> *mut DmaDescriptor {
+        self.next
+    }
+
+    /// Set the size of the buffer described by this descriptor.
+    ///
+    /// Returns [DmaError::InvalidChunkSize] if `len` doesn't fit into the
+    /// descriptor.
+    #[doc(hidden)]
+    pub fn set_size(&mut self, len: usize) -> Result<(), DmaError> {
+        if len > Self::MAX_SIZE {
+            return Err(DmaError::InvalidChunkSize);
+        }
+        self.set_size_unchecked(len);
+        Ok(())
+    }
+
+    /// Set the number of valid bytes in the buffer.
+    ///
+    /// Returns [DmaError::InvalidChunkSize] if `len` doesn't fit into the
+    /// descriptor.
+    #[doc(hidden)]
+    pub fn set_length(&mut self, len: usize) -> Result<(), DmaError> {
+        if len > Self::MAX_SIZE {
+            return Err(DmaError::InvalidChunkSize);
+        }
+        self.set_length_unchecked(len);
+        Ok(())
+    }
+
+    /// Like [Self::set_size], for sizes the driver already validated.
+    pub(crate) fn set_size_unchecked(&mut self, len: usize) {
+        // the field is 12 bits wide, larger values would silently be truncated
+        debug_assert!(
+            len <= Self::MAX_SIZE,
+            "descriptor size {} out of range",
+            len
+        );
+        self.flags.set_size(len as u16)
+    }
+
+    /// Like [Self::set_length], for lengths the driver already validated.
+    pub(crate) fn set_length_unchecked(&mut self, len: usize) {
+        debug_assert!(
+            len <= Self::MAX_SIZE,
+            "descriptor length {} out of range",
+            len
+        );
+        self.flags.set_length(len as u16)
+    }
+
+    #[doc(hidden)]
+    pub fn set_suc_eof(&mut self, suc_eof: bool) {
         self.flags.set_suc_eof(suc_eof)
     }
 
-    fn set_owner(&mut self, owner: Owner) {
+    #[doc(hidden)]
+    pub fn set_owner(&mut self, owner: Owner) {
         let owner = match owner {
             Owner::Cpu => false,
             Owner::Dma => true,
@@ -111,40 +231,147 @@ impl DmaDescriptor {
         self.flags.set_owner(owner)
     }
 
-    fn owner(&self) -> Owner {
-        match self.flags.owner() {
-            false => Owner::Cpu,
-            true => Owner::Dma,
-        }
+    /// Link this descriptor to `next`.
+    ///
+    /// # Safety
+    ///
+    /// `next` must either be null or point to a descriptor which stays valid
+    /// for as long as the DMA can follow the link.
+    #[doc(hidden)]
+    pub unsafe fn set_next(&mut self, next: *mut DmaDescriptor) {
+        self.next = next;
+    }
+}
+
+impl core::fmt::Display for DmaDescriptor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "DmaDescriptor {{ buffer: {:?}, size: {}, length: {}, suc_eof: {}, owner: {:?}, next: {:?} }}",
+            self.buffer,
+            self.size(),
+            self.len(),
+            self.has_suc_eof(),
+            self.owner(),
+            self.next
+        )
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for DmaDescriptor {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(
+            fmt,
+            "DmaDescriptor {{ buffer: {=usize:#x}, size: {}, length: {}, suc_eof: {}, owner: {}, next: {=usize:#x} }}",
+            self.buffer as usize,
+            self.size(),
+            self.len(),
+            self.has_suc_eof(),
+            self.owner(),
+            self.next as usize
+        );
     }
 }
 
 use embedded_dma::{ReadBuffer, WriteBuffer};
 use enumset::{EnumSet, EnumSetType};
+use fugit::MicrosDurationU64;
 
 #[cfg(gdma)]
 pub use self::gdma::*;
 #[cfg(pdma)]
 pub use self::pdma::*;
+#[cfg(feature = "dma-stats")]
+pub use self::stats::{DmaChannelStats, DmaDirectionStats, DmaStatsCounters};
+pub use self::{
+    addr::{is_dma_capable, is_external, is_flash, is_internal, memory_region, MemoryRegion},
+    queue::{DmaTransferTxQueue, DmaTxQueue},
+};
 use crate::{interrupt::InterruptHandler, Mode};
 
+pub mod addr;
 #[cfg(gdma)]
 mod gdma;
 #[cfg(pdma)]
 mod pdma;
+mod queue;
+#[cfg(feature = "dma-stats")]
+pub mod stats;
 
 /// Kinds of interrupt to listen to
 #[derive(EnumSetType)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum DmaInterrupt {
     /// TX is done
     TxDone,
     /// RX is done
     RxDone,
+    /// The last descriptor of a TX transfer has been processed
+    TxEof,
+    /// A descriptor with the EOF flag has been received
+    RxEof,
+    /// An invalid TX descriptor was encountered
+    TxDescriptorError,
+    /// An invalid RX descriptor was encountered
+    RxDescriptorError,
+    /// RX ran out of descriptors
+    RxDescriptorEmpty,
 }
 
 /// The default CHUNK_SIZE used for DMA transfers
 pub const CHUNK_SIZE: usize = 4092;
 
+/// The minimum number of descriptors of a circular transfer, which is also
+/// the minimum size of a circular buffer in bytes
+///
+/// The DMA hands a descriptor back while it works on the next one. With at
+/// least three descriptors there's always one between the descriptor the DMA
+/// works on and the one the CPU is reading or refilling.
+pub const CIRCULAR_MIN_DESCRIPTORS: usize = 3;
+
+/// The number of descriptors needed to transfer `len` bytes in chunks of up
+/// to `chunk_size` bytes
+///
+/// A circular buffer of up to two chunks is split evenly into
+/// [CIRCULAR_MIN_DESCRIPTORS] descriptors, larger circular buffers need as many
+/// descriptors as non-circular ones. The circular descriptor macros allocate
+/// this many descriptors.
+pub const fn descriptor_count(len: usize, chunk_size: usize, circular: bool) -> usize {
+    if circular && len <= chunk_size * 2 {
+        CIRCULAR_MIN_DESCRIPTORS
+    } else {
+        len.div_ceil(chunk_size)
+    }
+}
+
+/// The size of the descriptor at `index` of a buffer of `len` bytes split as
+/// described in [descriptor_count]
+fn descriptor_chunk_size(len: usize, chunk_size: usize, circular: bool, index: usize) -> usize {
+    if circular && len <= chunk_size * 2 {
+        len * (index + 1) / CIRCULAR_MIN_DESCRIPTORS - len * index / CIRCULAR_MIN_DESCRIPTORS
+    } else {
+        usize::min(chunk_size, len - index * chunk_size)
+    }
+}
+
+/// Zeroes a buffer created by the DMA macros and returns it.
+///
+/// The macros place their buffers in uninitialized memory, so they neither
+/// take up space in the binary nor get zeroed on startup. Reading
+/// uninitialized memory is undefined behavior even for `u8`, so the buffer is
+/// zeroed when it's handed out.
+///
+/// # Safety
+///
+/// All zero bytes must be a valid `T`.
+#[doc(hidden)]
+pub unsafe fn zeroed_buffer<T>(buffer: &'static mut core::mem::MaybeUninit<T>) -> &'static mut T {
+    // zero in place, a temporary `T` could be larger than the stack
+    buffer.as_mut_ptr().write_bytes(0, 1);
+    buffer.assume_init_mut()
+}
+
 /// Convenience macro to create DMA buffers and descriptors
 ///
 /// ## Usage
@@ -228,15 +455,19 @@ macro_rules! dma_circular_descriptors {
 #[macro_export]
 macro_rules! dma_buffers_chunk_size {
     ($tx_size:expr, $rx_size:expr, $chunk_size:expr) => {{
-        static mut TX_BUFFER: [u8; $tx_size] = [0u8; $tx_size];
-        static mut RX_BUFFER: [u8; $rx_size] = [0u8; $rx_size];
+        #[link_section = ".noinit.dma_buffers"]
+        static mut TX_BUFFER: core::mem::MaybeUninit<[u8; $tx_size]> =
+            core::mem::MaybeUninit::uninit();
+        #[link_section = ".noinit.dma_buffers"]
+        static mut RX_BUFFER: core::mem::MaybeUninit<[u8; $rx_size]> =
+            core::mem::MaybeUninit::uninit();
         let (mut tx_descriptors, mut rx_descriptors) =
             $crate::dma_descriptors_chunk_size!($tx_size, $rx_size, $chunk_size);
         unsafe {
             (
-                &mut TX_BUFFER,
+                $crate::dma::zeroed_buffer(&mut TX_BUFFER),
                 tx_descriptors,
-                &mut RX_BUFFER,
+                $crate::dma::zeroed_buffer(&mut RX_BUFFER),
                 rx_descriptors,
             )
         }
@@ -247,6 +478,90 @@ macro_rules! dma_buffers_chunk_size {
     };
 }
 
+/// Convenience macro to create DMA buffers aligned to `align` bytes and
+/// descriptors
+///
+/// The chunk size of the descriptors is a multiple of the alignment, so every
+/// chunk of the buffers stays aligned. `align` needs to be a power of two
+/// literal.
+///
+/// ## Usage
+/// ```rust,ignore
+/// // TX and RX buffers are 32000 bytes and aligned to 32 bytes - passing only one size makes TX and RX the same size
+/// let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) =
+///     dma_buffers_aligned!(32000, 32000, 32);
+/// ```
+#[macro_export]
+macro_rules! dma_buffers_aligned {
+    ($tx_size:expr, $rx_size:expr, $align:literal) => {{
+        #[repr(C, align($align))]
+        struct Aligned<const N: usize>([u8; N]);
+
+        #[link_section = ".noinit.dma_buffers"]
+        static mut TX_BUFFER: core::mem::MaybeUninit<Aligned<{ $tx_size }>> =
+            core::mem::MaybeUninit::uninit();
+        #[link_section = ".noinit.dma_buffers"]
+        static mut RX_BUFFER: core::mem::MaybeUninit<Aligned<{ $rx_size }>> =
+            core::mem::MaybeUninit::uninit();
+        let (mut tx_descriptors, mut rx_descriptors) = $crate::dma_descriptors_chunk_size!(
+            $tx_size,
+            $rx_size,
+            ($crate::dma::CHUNK_SIZE - $crate::dma::CHUNK_SIZE % $align)
+        );
+        unsafe {
+            (
+                &mut $crate::dma::zeroed_buffer(&mut TX_BUFFER).0,
+                tx_descriptors,
+                &mut $crate::dma::zeroed_buffer(&mut RX_BUFFER).0,
+                rx_descriptors,
+            )
+        }
+    }};
+
+    ($size:expr, $align:literal) => {
+        $crate::dma_buffers_aligned!($size, $size, $align)
+    };
+}
+
+/// Convenience macro to create DMA buffers placed in the given link section
+/// and descriptors
+///
+/// The section needs to be in memory the DMA can access, e.g.
+/// `.dram2_uninit` on the ESP32. The buffers are zeroed when they are
+/// created, the section doesn't need to be initialized on startup. The
+/// descriptors are always placed in internal RAM.
+///
+/// ## Usage
+/// ```rust,ignore
+/// // TX and RX buffers are 32000 bytes - passing only one size makes TX and RX the same size
+/// let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) =
+///     dma_buffers_in_section!(".dram2_uninit", 32000, 32000);
+/// ```
+#[macro_export]
+macro_rules! dma_buffers_in_section {
+    ($section:literal, $tx_size:expr, $rx_size:expr) => {{
+        #[link_section = $section]
+        static mut TX_BUFFER: core::mem::MaybeUninit<[u8; $tx_size]> =
+            core::mem::MaybeUninit::uninit();
+        #[link_section = $section]
+        static mut RX_BUFFER: core::mem::MaybeUninit<[u8; $rx_size]> =
+            core::mem::MaybeUninit::uninit();
+        let (mut tx_descriptors, mut rx_descriptors) = $crate::dma_descriptors!($tx_size, $rx_size);
+        unsafe {
+            (
+                $crate::dma::zeroed_buffer(&mut TX_BUFFER),
+                tx_descriptors,
+                $crate::dma::zeroed_buffer(&mut RX_BUFFER),
+                rx_descriptors,
+            )
+        }
+    }};
+
+    ($section:literal, $size:expr) => {
+        $crate::dma_buffers_in_section!($section, $size, $size)
+    };
+}
+
 /// Convenience macro to create circular DMA buffers and descriptors with
 /// specific chunk size
 ///
@@ -259,15 +574,19 @@ macro_rules! dma_buffers_chunk_size {
 #[macro_export]
 macro_rules! dma_circular_buffers_chunk_size {
     ($tx_size:expr, $rx_size:expr, $chunk_size:expr) => {{
-        static mut TX_BUFFER: [u8; $tx_size] = [0u8; $tx_size];
-        static mut RX_BUFFER: [u8; $rx_size] = [0u8; $rx_size];
+        #[link_section = ".noinit.dma_buffers"]
+        static mut TX_BUFFER: core::mem::MaybeUninit<[u8; $tx_size]> =
+            core::mem::MaybeUninit::uninit();
+        #[link_section = ".noinit.dma_buffers"]
+        static mut RX_BUFFER: core::mem::MaybeUninit<[u8; $rx_size]> =
+            core::mem::MaybeUninit::uninit();
         let (mut tx_descriptors, mut rx_descriptors) =
             $crate::dma_circular_descriptors_chunk_size!($tx_size, $rx_size, $chunk_size);
         unsafe {
             (
-                &mut TX_BUFFER,
+                $crate::dma::zeroed_buffer(&mut TX_BUFFER),
                 tx_descriptors,
-                &mut RX_BUFFER,
+                $crate::dma::zeroed_buffer(&mut RX_BUFFER),
                 rx_descriptors,
             )
         }
@@ -292,9 +611,13 @@ macro_rules! dma_descriptors_chunk_size {
         const _: () = assert!($chunk_size <= 4092, "chunk size must be <= 4092");
         const _: () = assert!($chunk_size > 0, "chunk size must be > 0");
 
+        // the DMA can only read descriptors from internal RAM, the zeroed
+        // descriptors go to `.bss` so they aren't copied from flash at startup
+        #[link_section = ".bss.dma_descriptors"]
         static mut TX_DESCRIPTORS: [$crate::dma::DmaDescriptor;
             ($tx_size + $chunk_size - 1) / $chunk_size] =
             [$crate::dma::DmaDescriptor::EMPTY; ($tx_size + $chunk_size - 1) / $chunk_size];
+        #[link_section = ".bss.dma_descriptors"]
         static mut RX_DESCRIPTORS: [$crate::dma::DmaDescriptor;
             ($rx_size + $chunk_size - 1) / $chunk_size] =
             [$crate::dma::DmaDescriptor::EMPTY; ($rx_size + $chunk_size - 1) / $chunk_size];
@@ -321,20 +644,15 @@ macro_rules! dma_circular_descriptors_chunk_size {
         const _: () = assert!($chunk_size <= 4092, "chunk size must be <= 4092");
         const _: () = assert!($chunk_size > 0, "chunk size must be > 0");
 
-        const tx_descriptor_len: usize = if $tx_size > $chunk_size * 2 {
-            ($tx_size + $chunk_size - 1) / $chunk_size
-        } else {
-            3
-        };
-
-        const rx_descriptor_len: usize = if $rx_size > $chunk_size * 2 {
-            ($rx_size + $chunk_size - 1) / $chunk_size
-        } else {
-            3
-        };
+        const tx_descriptor_len: usize = $crate::dma::descriptor_count($tx_size, $chunk_size, true);
+        const rx_descriptor_len: usize = $crate::dma::descriptor_count($rx_size, $chunk_size, true);
 
+        // the DMA can only read descriptors from internal RAM, the zeroed
+        // descriptors go to `.bss` so they aren't copied from flash at startup
+        #[link_section = ".bss.dma_descriptors"]
         static mut TX_DESCRIPTORS: [$crate::dma::DmaDescriptor; tx_descriptor_len] =
             [$crate::dma::DmaDescriptor::EMPTY; tx_descriptor_len];
+        #[link_section = ".bss.dma_descriptors"]
         static mut RX_DESCRIPTORS: [$crate::dma::DmaDescriptor; rx_descriptor_len] =
             [$crate::dma::DmaDescriptor::EMPTY; rx_descriptor_len];
         unsafe { (&mut TX_DESCRIPTORS, &mut RX_DESCRIPTORS) }
@@ -349,10 +667,17 @@ macro_rules! dma_circular_descriptors_chunk_size {
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum DmaError {
-    /// The alignment of data is invalid
-    InvalidAlignment,
+    /// The buffer at `address` or its length isn't aligned as required by the
+    /// burst mode or the external memory block size.
+    InvalidAlignment {
+        /// Address of the misaligned buffer
+        address: usize,
+    },
     /// More descriptors are needed for the buffer size
-    OutOfDescriptors,
+    OutOfDescriptors {
+        /// Number of descriptors needed, see [descriptor_count]
+        required: usize,
+    },
     /// DescriptorError the DMA rejected the descriptor configuration. This
     /// could be because the source address of the data is not in RAM. Ensure
     /// your source data is in a valid address space, or try using
@@ -366,6 +691,208 @@ pub enum DmaError {
     UnsupportedMemoryRegion,
     /// Invalid DMA chunk size
     InvalidChunkSize,
+    /// The DMA wrapped around a circular buffer and overwrote data which
+    /// wasn't read yet
+    BufferOverrun,
+    /// The transfer didn't finish in time
+    Timeout,
+    /// The DMA rejected the descriptor at `index` in the chain, see
+    /// [DmaError::DescriptorError] for possible reasons.
+    ///
+    /// Only reported by chips which record the address of the failing
+    /// descriptor, others report [DmaError::DescriptorError].
+    DescriptorErrorAt {
+        /// Index of the descriptor in the chain
+        index: usize,
+        /// Address of the descriptor
+        address: usize,
+    },
+    /// No more buffers can be queued, see [DmaTxQueue]
+    QueueFull,
+    /// The [ChannelMode] of the channel doesn't match the peripheral of the
+    /// transfer, e.g. a channel in [ChannelMode::Mem2Mem] was handed to a
+    /// peripheral driver
+    WrongChannelMode,
+    /// The [CircularSnapshot] doesn't belong to the buffer or descriptor
+    /// chain it's resumed with
+    InvalidSnapshot,
+}
+
+impl core::fmt::Display for DmaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidAlignment { address } => write!(
+                f,
+                "The buffer at {address:#x} or its length is not aligned as required"
+            ),
+            Self::OutOfDescriptors { required } => write!(
+                f,
+                "The buffer needs {required} descriptors, more than are available"
+            ),
+            Self::DescriptorError => write!(
+                f,
+                "The DMA rejected the descriptors, the data might not be in RAM, try the FlashSafeDma wrapper"
+            ),
+            Self::Overflow => write!(f, "Not enough free space in the buffer for the data"),
+            Self::BufferTooSmall => write!(f, "The buffer is too small"),
+            Self::UnsupportedMemoryRegion => write!(
+                f,
+                "Descriptors or buffers are not in a memory region the DMA can access"
+            ),
+            Self::InvalidChunkSize => write!(f, "Invalid DMA chunk size"),
+            Self::BufferOverrun => write!(
+                f,
+                "The DMA overwrote data in the circular buffer which was not read yet"
+            ),
+            Self::Timeout => write!(f, "The transfer did not finish in time"),
+            Self::DescriptorErrorAt { index, address } => write!(
+                f,
+                "The DMA rejected descriptor {index} at {address:#x}, the data might not be in RAM, try the FlashSafeDma wrapper"
+            ),
+            Self::QueueFull => write!(f, "No more buffers can be queued"),
+            Self::WrongChannelMode => write!(
+                f,
+                "The channel mode does not match the peripheral of the transfer"
+            ),
+            Self::InvalidSnapshot => write!(
+                f,
+                "The snapshot does not belong to the buffer or descriptors it is resumed with"
+            ),
+        }
+    }
+}
+
+/// Inconsistencies found by [DescriptorChain::validate]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ChainError {
+    /// The descriptor at `index` links to an address which isn't a descriptor
+    /// of the chain
+    LinkOutOfBounds {
+        /// Index of the descriptor
+        index: usize,
+    },
+    /// The links form a loop which doesn't return to the first descriptor
+    InvalidLoop,
+    /// The size of the descriptor at `index` is zero, which is what a size of
+    /// 4096 looks like after being truncated to the 12 bit field
+    SizeOutOfRange {
+        /// Index of the descriptor
+        index: usize,
+    },
+    /// The length of the descriptor at `index` is larger than its size
+    LengthExceedsSize {
+        /// Index of the descriptor
+        index: usize,
+    },
+    /// The buffers of the descriptors at `first` and `second` overlap
+    OverlappingBuffers {
+        /// Index of the first descriptor
+        first: usize,
+        /// Index of the second descriptor
+        second: usize,
+    },
+}
+
+/// Burst mode of one direction of a DMA channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BurstSize {
+    /// Descriptors and data are transferred word by word.
+    #[default]
+    Disabled,
+    /// Descriptors and data are transferred in bursts.
+    ///
+    /// RX buffers and their lengths need to be word-aligned.
+    Enabled,
+}
+
+impl BurstSize {
+    fn is_enabled(self) -> bool {
+        self == BurstSize::Enabled
+    }
+
+    fn alignment(self) -> usize {
+        match self {
+            BurstSize::Disabled => 1,
+            BurstSize::Enabled => 4,
+        }
+    }
+}
+
+/// Block size of DMA transfers to and from external memory.
+///
+/// Buffers in external memory and their lengths need to be aligned to the
+/// block size.
+#[cfg(esp32s3)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ExtMemBlkSize {
+    /// 16 bytes
+    Size16 = 0,
+    /// 32 bytes
+    Size32 = 1,
+    /// 64 bytes
+    Size64 = 2,
+}
+
+#[cfg(esp32s3)]
+impl ExtMemBlkSize {
+    fn size(self) -> usize {
+        match self {
+            ExtMemBlkSize::Size16 => 16,
+            ExtMemBlkSize::Size32 => 32,
+            ExtMemBlkSize::Size64 => 64,
+        }
+    }
+}
+
+/// Burst configuration of a DMA channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DmaBurstConfig {
+    /// Burst mode of the RX direction
+    pub in_burst: BurstSize,
+    /// Burst mode of the TX direction
+    pub out_burst: BurstSize,
+    /// Block size of transfers to and from external memory, the hardware
+    /// default of 16 bytes is used if `None`
+    #[cfg(esp32s3)]
+    pub ext_mem_block: Option<ExtMemBlkSize>,
+}
+
+impl DmaBurstConfig {
+    /// The alignment RX buffers located at `buffer` and their lengths need.
+    fn in_alignment(&self, buffer: *const u8) -> usize {
+        #[cfg(esp32s3)]
+        if addr::is_external(buffer) {
+            let block = self.ext_mem_block.unwrap_or(ExtMemBlkSize::Size16);
+            return usize::max(self.in_burst.alignment(), block.size());
+        }
+
+        #[cfg(not(esp32s3))]
+        let _ = buffer;
+
+        self.in_burst.alignment()
+    }
+}
+
+impl From<bool> for DmaBurstConfig {
+    /// Enables or disables burst mode for both directions.
+    fn from(burst_mode: bool) -> Self {
+        let burst = if burst_mode {
+            BurstSize::Enabled
+        } else {
+            BurstSize::Disabled
+        };
+
+        Self {
+            in_burst: burst,
+            out_burst: burst,
+            #[cfg(esp32s3)]
+            ext_mem_block: None,
+        }
+    }
 }
 
 /// DMA Priorities
@@ -443,9 +970,76 @@ pub enum DmaPeripheral {
     Mem2Mem15 = 15,
 }
 
-#[derive(PartialEq, PartialOrd)]
-enum Owner {
+#[cfg(gdma)]
+impl DmaPeripheral {
+    /// Whether this is one of the IDs reserved for memory to memory transfers
+    fn is_mem2mem(&self) -> bool {
+        cfg_if::cfg_if! {
+            if #[cfg(any(esp32c6, esp32h2))] {
+                matches!(
+                    self,
+                    Self::Mem2Mem1
+                        | Self::Mem2Mem4
+                        | Self::Mem2Mem5
+                        | Self::Mem2Mem10
+                        | Self::Mem2Mem11
+                        | Self::Mem2Mem12
+                        | Self::Mem2Mem13
+                        | Self::Mem2Mem14
+                        | Self::Mem2Mem15
+                )
+            } else {
+                false
+            }
+        }
+    }
+
+    /// Checks that a channel in `mode` can transfer data for this peripheral.
+    fn check_mode(&self, mode: ChannelMode) -> Result<(), DmaError> {
+        let matches = match mode {
+            ChannelMode::PeripheralTrigger => !self.is_mem2mem(),
+            // Chips without reserved IDs use the ID of an unused peripheral
+            ChannelMode::Mem2Mem => self.is_mem2mem() || cfg!(not(any(esp32c6, esp32h2))),
+        };
+
+        if matches {
+            Ok(())
+        } else {
+            Err(DmaError::WrongChannelMode)
+        }
+    }
+}
+
+/// How the transfers of a GDMA channel are triggered
+#[cfg(gdma)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ChannelMode {
+    /// The peripheral the channel is connected to requests the data, this is
+    /// the mode used by all peripheral drivers
+    PeripheralTrigger,
+    /// The RX half receives the data sent by the TX half, see [Mem2Mem]
+    Mem2Mem,
+}
+
+#[cfg(gdma)]
+impl ChannelMode {
+    fn of(channel: &impl RegisterAccess) -> Self {
+        if channel.is_mem2mem_mode() {
+            Self::Mem2Mem
+        } else {
+            Self::PeripheralTrigger
+        }
+    }
+}
+
+/// The owner of a [DmaDescriptor].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Owner {
+    /// The descriptor can be accessed by the CPU.
     Cpu = 0,
+    /// The descriptor belongs to the DMA.
     Dma = 1,
 }
 
@@ -501,10 +1095,22 @@ pub trait ParlIoPeripheral: PeripheralMarker {}
 #[doc(hidden)]
 pub trait AesPeripheral: PeripheralMarker {}
 
+/// Marks channels as useable for SHA
+#[doc(hidden)]
+pub trait ShaPeripheral: PeripheralMarker {}
+
+/// Marks channels as useable for UHCI
+#[doc(hidden)]
+pub trait UhciPeripheral: PeripheralMarker {}
+
 /// Marks channels as usable for LCD_CAM
 #[doc(hidden)]
 pub trait LcdCamPeripheral: PeripheralMarker {}
 
+/// Marks channels as useable for ADC
+#[doc(hidden)]
+pub trait AdcPeripheral: PeripheralMarker {}
+
 /// DMA Rx
 #[doc(hidden)]
 pub trait Rx: RxPrivate {}
@@ -517,29 +1123,221 @@ pub trait Tx: TxPrivate {}
 #[doc(hidden)]
 pub trait PeripheralMarker {}
 
+/// The location of the descriptors of a [DescriptorChain]
+///
+/// Lets the async futures tell which descriptor the DMA rejected while the
+/// chain itself is borrowed together with the channel.
+#[derive(Clone, Copy)]
+pub(crate) struct DescriptorSpan {
+    first: usize,
+    len: usize,
+}
+
+impl DescriptorSpan {
+    fn index_of(&self, address: usize) -> Option<usize> {
+        let size = core::mem::size_of::<DmaDescriptor>();
+        let offset = address.checked_sub(self.first)?;
+        let index = offset / size;
+
+        (offset % size == 0 && index < self.len).then_some(index)
+    }
+
+    /// Maps the address of the descriptor which caused a descriptor error to
+    /// the matching [DmaError].
+    pub(crate) fn descriptor_error(&self, address: Option<usize>) -> DmaError {
+        address
+            .and_then(|address| {
+                self.index_of(address)
+                    .map(|index| DmaError::DescriptorErrorAt { index, address })
+            })
+            .unwrap_or(DmaError::DescriptorError)
+    }
+}
+
 #[doc(hidden)]
-#[derive(Debug)]
 pub struct DescriptorChain {
     pub(crate) descriptors: &'static mut [DmaDescriptor],
     chunk_size: usize,
+    prepared: Option<PreparedFill>,
+}
+
+impl Debug for DescriptorChain {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        struct Descriptors<'a>(&'a DescriptorChain);
+
+        impl Debug for Descriptors<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_list()
+                    .entries(self.0.linked().map(|(index, descriptor)| LinkedDescriptor {
+                        index,
+                        descriptor,
+                        next: self.0.link(index),
+                    }))
+                    .finish()
+            }
+        }
+
+        f.debug_struct("DescriptorChain")
+            .field("chunk_size", &self.chunk_size)
+            .field("descriptors", &Descriptors(self))
+            .finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for DescriptorChain {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(
+            fmt,
+            "DescriptorChain {{ chunk_size: {}, descriptors: [",
+            self.chunk_size
+        );
+        for (index, descriptor) in self.linked() {
+            defmt::write!(
+                fmt,
+                "{}, ",
+                LinkedDescriptor {
+                    index,
+                    descriptor,
+                    next: self.link(index),
+                }
+            );
+        }
+        defmt::write!(fmt, "] }}");
+    }
+}
+
+/// The target of a descriptor's link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Link {
+    /// The descriptor is the last one of the chain
+    End,
+    /// The index of the next descriptor in the chain
+    Index(usize),
+    /// An address which isn't a descriptor of the chain
+    Outside(usize),
+}
+
+/// A descriptor of a chain, formatted with its position in the chain.
+struct LinkedDescriptor<'a> {
+    index: usize,
+    descriptor: &'a DmaDescriptor,
+    next: Link,
+}
+
+impl Debug for LinkedDescriptor<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "#{} {{ buffer: {:?}, size: {}, length: {}, owner: {:?}, suc_eof: {}, next: ",
+            self.index,
+            self.descriptor.buffer,
+            self.descriptor.size(),
+            self.descriptor.len(),
+            self.descriptor.owner(),
+            self.descriptor.has_suc_eof(),
+        )?;
+        match self.next {
+            Link::End => write!(f, "null }}"),
+            Link::Index(index) => write!(f, "#{} }}", index),
+            Link::Outside(address) => write!(f, "{:#x} (outside of the chain) }}", address),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for LinkedDescriptor<'_> {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(
+            fmt,
+            "#{} {{ buffer: {=usize:#x}, size: {}, length: {}, owner: {}, suc_eof: {}, next: ",
+            self.index,
+            self.descriptor.buffer as usize,
+            self.descriptor.size(),
+            self.descriptor.len(),
+            self.descriptor.owner(),
+            self.descriptor.has_suc_eof(),
+        );
+        match self.next {
+            Link::End => defmt::write!(fmt, "null }}"),
+            Link::Index(index) => defmt::write!(fmt, "#{} }}", index),
+            Link::Outside(address) => {
+                defmt::write!(fmt, "{=usize:#x} (outside of the chain) }}", address)
+            }
+        }
+    }
+}
+
+/// The buffer a non-circular chain was last filled for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PreparedFill {
+    tx: bool,
+    address: usize,
+    len: usize,
 }
 
 impl DescriptorChain {
+    /// Creates a chain using the given descriptors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the descriptors are not in internal RAM, the DMA can't read
+    /// them from anywhere else.
     pub fn new(descriptors: &'static mut [DmaDescriptor]) -> Self {
-        Self {
+        let chain = Self {
             descriptors,
             chunk_size: CHUNK_SIZE,
-        }
+            prepared: None,
+        };
+
+        assert!(
+            chain.is_in_internal_ram(),
+            "DMA descriptors must be placed in internal RAM"
+        );
+
+        chain
     }
 
+    /// Creates a chain which splits buffers into chunks of at most
+    /// `chunk_size` bytes.
+    ///
+    /// Returns [DmaError::InvalidChunkSize] if `chunk_size` is zero or larger
+    /// than [CHUNK_SIZE], and [DmaError::UnsupportedMemoryRegion] if the
+    /// descriptors are not in internal RAM.
     pub fn new_with_chunk_size(
         descriptors: &'static mut [DmaDescriptor],
         chunk_size: usize,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, DmaError> {
+        if !(1..=CHUNK_SIZE).contains(&chunk_size) {
+            return Err(DmaError::InvalidChunkSize);
+        }
+
+        let chain = Self {
             descriptors,
             chunk_size,
+            prepared: None,
+        };
+
+        if !chain.is_in_internal_ram() {
+            return Err(DmaError::UnsupportedMemoryRegion);
         }
+
+        Ok(chain)
+    }
+
+    /// Replaces the descriptors of the chain, returning the previous ones.
+    pub(crate) fn replace_descriptors(
+        &mut self,
+        descriptors: &'static mut [DmaDescriptor],
+    ) -> &'static mut [DmaDescriptor] {
+        self.prepared = None;
+        core::mem::replace(&mut self.descriptors, descriptors)
+    }
+
+    /// Takes the descriptors out of the chain, leaving it empty.
+    #[cfg(parl_io)]
+    pub(crate) fn take_descriptors(&mut self) -> &'static mut [DmaDescriptor] {
+        self.replace_descriptors(&mut [])
     }
 
     pub fn first_mut(&mut self) -> *mut DmaDescriptor {
@@ -558,41 +1356,79 @@ impl DescriptorChain {
         self.descriptors.last().unwrap()
     }
 
-    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    /// Returns `true` if the chain is still linked for a non-circular transfer
+    /// of the given buffer, so it can be reused with
+    /// [DescriptorChain::reset_for_reuse] instead of being filled again.
+    pub fn prepared(&self, tx: bool, data: *const u8, len: usize) -> bool {
+        self.prepared
+            == Some(PreparedFill {
+                tx,
+                address: data as usize,
+                len,
+            })
+    }
+
+    /// Hands the descriptors of a prepared chain back to the DMA.
+    ///
+    /// Only the ownership bits, lengths and EOF flags the hardware writes back
+    /// are restored, the buffer pointers and links are left untouched.
+    pub fn reset_for_reuse(&mut self) {
+        let Some(prepared) = self.prepared else {
+            return;
+        };
+
+        for descr in self.descriptors.iter_mut() {
+            let last = descr.next.is_null();
+
+            descr.set_owner(Owner::Dma);
+            if prepared.tx {
+                descr.set_suc_eof(last);
+                descr.set_length_unchecked(descr.size());
+            } else {
+                descr.set_suc_eof(false);
+                descr.set_length_unchecked(0);
+            }
+
+            if last {
+                break;
+            }
+        }
+    }
+
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
     pub fn fill_for_rx(
         &mut self,
         circular: bool,
         data: *mut u8,
         len: usize,
     ) -> Result<(), DmaError> {
-        if !crate::soc::is_valid_ram_address(self.first() as u32)
-            || !crate::soc::is_valid_ram_address(self.last() as u32)
-            || !crate::soc::is_valid_ram_address(data as u32)
-            || !crate::soc::is_valid_ram_address(unsafe { data.add(len) } as u32)
-        {
+        if !self.is_in_internal_ram() || !addr::is_dma_capable(data, len) {
             return Err(DmaError::UnsupportedMemoryRegion);
         }
 
-        if self.descriptors.len() < len.div_ceil(self.chunk_size) {
-            return Err(DmaError::OutOfDescriptors);
+        if !circular && self.prepared(false, data, len) {
+            self.reset_for_reuse();
+            return Ok(());
         }
 
-        if circular && len <= 3 {
+        if circular && len < CIRCULAR_MIN_DESCRIPTORS {
             return Err(DmaError::BufferTooSmall);
         }
 
-        self.descriptors.fill(DmaDescriptor::EMPTY);
+        let rx_chunk_size = self.rx_chunk_size(data);
 
-        let max_chunk_size = if !circular || len > self.chunk_size * 2 {
-            self.chunk_size
-        } else {
-            len / 3 + len % 3
-        };
+        let required = descriptor_count(len, rx_chunk_size, circular);
+        if self.descriptors.len() < required {
+            return Err(DmaError::OutOfDescriptors { required });
+        }
+
+        self.prepared = None;
+        self.descriptors.fill(DmaDescriptor::EMPTY);
 
         let mut processed = 0;
         let mut descr = 0;
         loop {
-            let chunk_size = usize::min(max_chunk_size, len - processed);
+            let chunk_size = descriptor_chunk_size(len, rx_chunk_size, circular, descr);
             let last = processed + chunk_size >= len;
 
             let next = if last {
@@ -610,8 +1446,8 @@ impl DescriptorChain {
 
             dw0.set_suc_eof(false);
             dw0.set_owner(Owner::Dma);
-            dw0.set_size(chunk_size); // align to 32 bits?
-            dw0.set_length(0); // hardware will fill in the received number of bytes
+            dw0.set_size_unchecked(chunk_size); // align to 32 bits?
+            dw0.set_length_unchecked(0); // hardware will fill in the received number of bytes
 
             // pointer to current data
             dw0.buffer = unsafe { data.add(processed) };
@@ -627,9 +1463,235 @@ impl DescriptorChain {
             descr += 1;
         }
 
+        self.prepared = (!circular).then_some(PreparedFill {
+            tx: false,
+            address: data as usize,
+            len,
+        });
+
         Ok(())
     }
 
+    /// Returns the number of bytes written by the DMA, up to and including the
+    /// descriptor which ended the transfer.
+    pub fn received_bytes(&self) -> usize {
+        let first = self.first();
+        let mut descr = first;
+        let mut received = 0;
+
+        while !descr.is_null() {
+            // SAFETY: the descriptors are linked by `fill_for_rx`
+            let d = unsafe { &*descr };
+
+            // The hardware writes back the length of every descriptor it's
+            // done with, the length of the others is still zero.
+            received += d.len();
+
+            if d.flags.suc_eof() || d.next.cast_const() == first {
+                break;
+            }
+
+            descr = d.next;
+        }
+
+        received
+    }
+
+    /// Returns whether the descriptors are located in internal RAM, the DMA
+    /// can't fetch descriptors from external memory.
+    fn is_in_internal_ram(&self) -> bool {
+        self.descriptors.is_empty()
+            || (addr::is_internal(self.first().cast()) && addr::is_internal(self.last().cast()))
+    }
+
+    /// Writes the cached data of buffers in external memory back, so the DMA
+    /// reads what the CPU wrote.
+    #[cfg(esp32s3)]
+    pub(crate) fn writeback_psram_buffers(&self) {
+        self.for_each_psram_buffer(|address, size| unsafe {
+            crate::soc::cache_writeback_addr(address, size)
+        });
+    }
+
+    /// Drops the cached data of buffers in external memory, so the CPU reads
+    /// what the DMA wrote.
+    #[cfg(esp32s3)]
+    pub(crate) fn invalidate_psram_buffers(&self) {
+        self.for_each_psram_buffer(|address, size| unsafe {
+            crate::soc::cache_invalidate_addr(address, size)
+        });
+    }
+
+    /// Returns whether any of the buffers is in external memory.
+    #[cfg(esp32s3)]
+    fn has_psram_buffers(&self) -> bool {
+        let mut found = false;
+        self.for_each_psram_buffer(|_, _| found = true);
+        found
+    }
+
+    #[cfg(esp32s3)]
+    fn for_each_psram_buffer(&self, mut f: impl FnMut(u32, u32)) {
+        for d in self.descriptors.iter() {
+            if !d.buffer.is_null() && addr::is_external(d.buffer) {
+                f(d.buffer as u32, d.size() as u32);
+            }
+        }
+    }
+
+    /// The largest chunk a descriptor of an RX buffer at `data` may describe.
+    ///
+    /// Descriptors of RX buffers in external memory need a size aligned to the
+    /// external memory block size. The block size is only known once the
+    /// transfer is prepared, so the chunks are aligned to the largest one.
+    fn rx_chunk_size(&self, data: *const u8) -> usize {
+        #[cfg(esp32s3)]
+        if addr::is_external(data) {
+            let block = ExtMemBlkSize::Size64.size();
+            if self.chunk_size >= block {
+                return self.chunk_size - self.chunk_size % block;
+            }
+        }
+
+        #[cfg(not(esp32s3))]
+        let _ = data;
+
+        self.chunk_size
+    }
+
+    /// Returns the index of the descriptor at `address`, if it's part of this
+    /// chain.
+    pub fn index_of(&self, address: usize) -> Option<usize> {
+        self.span().index_of(address)
+    }
+
+    /// Where the descriptors of the chain are, see [DescriptorSpan].
+    pub(crate) fn span(&self) -> DescriptorSpan {
+        DescriptorSpan {
+            first: self.first() as usize,
+            len: self.descriptors.len(),
+        }
+    }
+
+    /// Checks that the descriptors form a linear or circular chain starting at
+    /// the first descriptor.
+    ///
+    /// Every link has to point to a descriptor of this chain, the sizes of the
+    /// linked descriptors have to fit the 12 bit field, their lengths have to
+    /// fit their sizes and their buffers must not overlap.
+    ///
+    /// RX chains are validated in debug builds before a transfer is prepared.
+    /// TX chains may legitimately fail the checks, queued chains link to each
+    /// other and several descriptors may send the same data.
+    pub fn validate(&self) -> Result<(), ChainError> {
+        if self.descriptors.is_empty() {
+            return Ok(());
+        }
+
+        let mut index = 0;
+        let mut steps = 0;
+        loop {
+            match self.link(index) {
+                Link::End | Link::Index(0) => break,
+                Link::Index(next) => index = next,
+                Link::Outside(_) => return Err(ChainError::LinkOutOfBounds { index }),
+            }
+
+            // a chain visiting more descriptors than it has loops somewhere
+            // else than the first descriptor
+            steps += 1;
+            if steps >= self.descriptors.len() {
+                return Err(ChainError::InvalidLoop);
+            }
+        }
+
+        for (index, descr) in self.linked() {
+            if descr.size() == 0 && !descr.buffer.is_null() {
+                return Err(ChainError::SizeOutOfRange { index });
+            }
+
+            if descr.len() > descr.size() {
+                return Err(ChainError::LengthExceedsSize { index });
+            }
+
+            let start = descr.buffer as usize;
+            let end = start + descr.size();
+            for (other, other_descr) in self.linked().take_while(|(other, _)| *other != index) {
+                let other_start = other_descr.buffer as usize;
+                let other_end = other_start + other_descr.size();
+
+                if start < other_end && other_start < end {
+                    return Err(ChainError::OverlappingBuffers {
+                        first: other,
+                        second: index,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Where the descriptor at `index` links to
+    fn link(&self, index: usize) -> Link {
+        let next = self.descriptors[index].next;
+        if next.is_null() {
+            Link::End
+        } else {
+            match self.index_of(next as usize) {
+                Some(next) => Link::Index(next),
+                None => Link::Outside(next as usize),
+            }
+        }
+    }
+
+    /// The descriptors in the order they are linked, starting at the first
+    /// one.
+    ///
+    /// Stops at the end of the chain, when returning to the first descriptor
+    /// and at links which don't point to a descriptor of the chain.
+    fn linked(&self) -> impl Iterator<Item = (usize, &DmaDescriptor)> + '_ {
+        let mut next = (!self.descriptors.is_empty()).then_some(0);
+        let mut remaining = self.descriptors.len();
+
+        core::iter::from_fn(move || {
+            let index = next?;
+            remaining = remaining.checked_sub(1)?;
+            next = match self.link(index) {
+                Link::Index(next) if next != 0 => Some(next),
+                _ => None,
+            };
+
+            Some((index, &self.descriptors[index]))
+        })
+    }
+
+    /// Returns the number of bytes of the descriptors linked before the
+    /// descriptor at `address`, or `None` if `address` isn't a descriptor of
+    /// the chain.
+    fn bytes_before(&self, address: usize) -> Option<usize> {
+        let mut bytes = 0;
+        for (_, descriptor) in self.linked() {
+            if descriptor as *const DmaDescriptor as usize == address {
+                return Some(bytes);
+            }
+            bytes += descriptor.len();
+        }
+
+        None
+    }
+
+    /// Returns the number of bytes of all linked descriptors.
+    fn linked_len(&self) -> usize {
+        self.linked().map(|(_, descriptor)| descriptor.len()).sum()
+    }
+
+    /// Maps the address of the descriptor which caused a descriptor error to
+    /// the matching [DmaError].
+    pub(crate) fn descriptor_error(&self, address: Option<usize>) -> DmaError {
+        self.span().descriptor_error(address)
+    }
+
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     pub fn fill_for_tx(
         &mut self,
@@ -637,34 +1699,31 @@ impl DescriptorChain {
         data: *const u8,
         len: usize,
     ) -> Result<(), DmaError> {
-        if !crate::soc::is_valid_ram_address(self.first() as u32)
-            || !crate::soc::is_valid_ram_address(self.last() as u32)
-            || !crate::soc::is_valid_ram_address(data as u32)
-            || !crate::soc::is_valid_ram_address(unsafe { data.add(len) } as u32)
-        {
+        if !self.is_in_internal_ram() || !addr::is_dma_capable(data, len) {
             return Err(DmaError::UnsupportedMemoryRegion);
         }
 
-        if circular && len <= 3 {
+        if !circular && self.prepared(true, data, len) {
+            self.reset_for_reuse();
+            return Ok(());
+        }
+
+        if circular && len < CIRCULAR_MIN_DESCRIPTORS {
             return Err(DmaError::BufferTooSmall);
         }
 
-        if self.descriptors.len() < len.div_ceil(self.chunk_size) {
-            return Err(DmaError::OutOfDescriptors);
+        let required = descriptor_count(len, self.chunk_size, circular);
+        if self.descriptors.len() < required {
+            return Err(DmaError::OutOfDescriptors { required });
         }
 
+        self.prepared = None;
         self.descriptors.fill(DmaDescriptor::EMPTY);
 
-        let max_chunk_size = if !circular || len > self.chunk_size * 2 {
-            self.chunk_size
-        } else {
-            len / 3 + len % 3
-        };
-
         let mut processed = 0;
         let mut descr = 0;
         loop {
-            let chunk_size = usize::min(max_chunk_size, len - processed);
+            let chunk_size = descriptor_chunk_size(len, self.chunk_size, circular, descr);
             let last = processed + chunk_size >= len;
 
             let next = if last {
@@ -686,8 +1745,8 @@ impl DescriptorChain {
             // I2S to track progress of a transfer by checking OUTLINK_DSCR_ADDR.
             dw0.set_suc_eof(circular || last);
             dw0.set_owner(Owner::Dma);
-            dw0.set_size(chunk_size); // align to 32 bits?
-            dw0.set_length(chunk_size); // the hardware will transmit this many bytes
+            dw0.set_size_unchecked(chunk_size); // align to 32 bits?
+            dw0.set_length_unchecked(chunk_size); // the hardware will transmit this many bytes
 
             // pointer to current data
             dw0.buffer = unsafe { data.cast_mut().add(processed) };
@@ -703,10 +1762,256 @@ impl DescriptorChain {
             descr += 1;
         }
 
+        self.prepared = (!circular).then_some(PreparedFill {
+            tx: true,
+            address: data as usize,
+            len,
+        });
+
+        Ok(())
+    }
+
+    /// Fills the chain for a transfer sending all `parts` back to back.
+    ///
+    /// Parts larger than the chunk size are split over multiple descriptors,
+    /// empty parts are skipped. `suc_eof` is only set on the final descriptor,
+    /// unless the chain is circular, which behaves like
+    /// [DescriptorChain::fill_for_tx].
+    pub fn fill_for_tx_scattered(
+        &mut self,
+        circular: bool,
+        parts: &[&[u8]],
+    ) -> Result<(), DmaError> {
+        self.fill_for_regions(
+            circular,
+            true,
+            parts
+                .iter()
+                .map(|part| (part.as_ptr().cast_mut(), part.len())),
+        )
+    }
+
+    /// Fills the chain for a transfer sending `len` bytes of the buffer of
+    /// `buffer_len` bytes at `data`, starting at `offset`.
+    ///
+    /// A circular window spanning the whole buffer may start at any offset, it
+    /// wraps around to the start of the buffer when it reaches its end. This
+    /// allows to start playback of a circular buffer in the middle of it.
+    /// Fails with [DmaError::BufferTooSmall] if the window exceeds the
+    /// buffer.
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn fill_for_tx_range(
+        &mut self,
+        circular: bool,
+        data: *const u8,
+        buffer_len: usize,
+        offset: usize,
+        len: usize,
+    ) -> Result<(), DmaError> {
+        let (first, second) = Self::window(circular, buffer_len, offset, len)?;
+
+        if !addr::is_dma_capable(data, buffer_len) {
+            return Err(DmaError::UnsupportedMemoryRegion);
+        }
+
+        if second == 0 {
+            return self.fill_for_tx(circular, unsafe { data.add(offset) }, first);
+        }
+
+        self.fill_for_regions(
+            circular,
+            true,
+            [
+                (unsafe { data.add(offset) }.cast_mut(), first),
+                (data.cast_mut(), second),
+            ]
+            .into_iter(),
+        )
+    }
+
+    /// Fills the chain for a transfer receiving `len` bytes into the buffer of
+    /// `buffer_len` bytes at `data`, starting at `offset`.
+    ///
+    /// See [DescriptorChain::fill_for_tx_range].
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn fill_for_rx_range(
+        &mut self,
+        circular: bool,
+        data: *mut u8,
+        buffer_len: usize,
+        offset: usize,
+        len: usize,
+    ) -> Result<(), DmaError> {
+        let (first, second) = Self::window(circular, buffer_len, offset, len)?;
+
+        if !addr::is_dma_capable(data, buffer_len) {
+            return Err(DmaError::UnsupportedMemoryRegion);
+        }
+
+        if second == 0 {
+            return self.fill_for_rx(circular, unsafe { data.add(offset) }, first);
+        }
+
+        self.fill_for_regions(
+            circular,
+            false,
+            [(unsafe { data.add(offset) }, first), (data, second)].into_iter(),
+        )
+    }
+
+    /// Splits a window of a buffer into the part up to the end of the buffer
+    /// and the part wrapping around to its start.
+    fn window(
+        circular: bool,
+        buffer_len: usize,
+        offset: usize,
+        len: usize,
+    ) -> Result<(usize, usize), DmaError> {
+        let end = offset.checked_add(len).ok_or(DmaError::BufferTooSmall)?;
+
+        if end <= buffer_len {
+            Ok((len, 0))
+        } else if circular && offset < buffer_len && len == buffer_len {
+            Ok((buffer_len - offset, end - buffer_len))
+        } else {
+            Err(DmaError::BufferTooSmall)
+        }
+    }
+
+    pub(crate) fn fill_for_regions(
+        &mut self,
+        circular: bool,
+        tx: bool,
+        regions: impl Iterator<Item = (*mut u8, usize)> + Clone,
+    ) -> Result<(), DmaError> {
+        if !self.is_in_internal_ram() {
+            return Err(DmaError::UnsupportedMemoryRegion);
+        }
+
+        let mut len = 0;
+        for (ptr, region_len) in regions.clone() {
+            if !addr::is_dma_capable(ptr, region_len) {
+                return Err(DmaError::UnsupportedMemoryRegion);
+            }
+
+            len += region_len;
+        }
+
+        if len == 0 || (circular && len < CIRCULAR_MIN_DESCRIPTORS) {
+            return Err(DmaError::BufferTooSmall);
+        }
+
+        // Chunks of less than half of a small circular buffer make sure there
+        // are at least `CIRCULAR_MIN_DESCRIPTORS` descriptors, the regions can
+        // end in the middle of a chunk so it can take one more per region.
+        let max_chunk_size = if !circular || len > self.chunk_size * 2 {
+            self.chunk_size
+        } else {
+            (len - 1) / 2
+        };
+
+        let required: usize = regions
+            .clone()
+            .map(|(_, region_len)| region_len.div_ceil(max_chunk_size))
+            .sum();
+        if self.descriptors.len() < required {
+            return Err(DmaError::OutOfDescriptors { required });
+        }
+
+        self.prepared = None;
+        self.descriptors.fill(DmaDescriptor::EMPTY);
+
+        let mut descr = 0;
+        for (ptr, region_len) in regions {
+            let mut processed = 0;
+            while processed < region_len {
+                let chunk_size = usize::min(max_chunk_size, region_len - processed);
+
+                if descr > 0 {
+                    self.descriptors[descr - 1].next = addr_of_mut!(self.descriptors[descr]);
+                }
+
+                let dw0 = &mut self.descriptors[descr];
+
+                dw0.set_suc_eof(tx && circular);
+                dw0.set_owner(Owner::Dma);
+                dw0.set_size_unchecked(chunk_size);
+                dw0.set_length_unchecked(if tx { chunk_size } else { 0 });
+                dw0.buffer = unsafe { ptr.add(processed) };
+
+                processed += chunk_size;
+                descr += 1;
+            }
+        }
+
+        let next = if circular {
+            self.first_mut()
+        } else {
+            core::ptr::null_mut()
+        };
+
+        let last = &mut self.descriptors[descr - 1];
+        last.set_suc_eof(tx);
+        last.next = next;
+
         Ok(())
     }
 }
 
+/// A descriptor the DMA finished sending in a circular TX transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CompletedDescriptor {
+    /// Index of the most recently completed descriptor in the chain, its
+    /// buffer is available for pushing
+    pub index: usize,
+    /// Number of descriptors which were completed before `index` since the
+    /// last reported descriptor, i.e. events which weren't picked up in time
+    pub skipped: usize,
+}
+
+/// What a circular TX transfer sends after an underrun, see
+/// [DmaTransferTxCircular::on_underrun]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Fill {
+    /// Send the data in the buffer again
+    #[default]
+    Repeat,
+    /// Clear the data after it was sent, so zeros are sent if no new data
+    /// was pushed in time
+    Zero,
+}
+
+/// The state of a circular TX transfer
+///
+/// Returned by [DmaTransferTxCircular::pause] to resume the transfer later
+/// with the data which wasn't sent yet, and by
+/// [DmaTransferTxCircular::debug_state] for logging.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CircularSnapshot {
+    /// Address of the buffer
+    pub buffer_address: usize,
+    /// Length of the buffer
+    pub buffer_len: usize,
+    /// Offset in the buffer the next pushed byte is written to
+    pub write_offset: usize,
+    /// Number of bytes which can be pushed
+    pub available: usize,
+    /// Address of the descriptor the DMA reported most recently
+    pub last_descriptor: usize,
+    fill: Fill,
+}
+
+impl CircularSnapshot {
+    /// Offset in the buffer of the first byte which wasn't sent yet, a
+    /// resumed transfer starts there.
+    pub fn read_offset(&self) -> usize {
+        (self.write_offset + self.available) % self.buffer_len
+    }
+}
+
 pub(crate) struct TxCircularState {
     write_offset: usize,
     write_descr_ptr: *mut DmaDescriptor,
@@ -714,24 +2019,96 @@ pub(crate) struct TxCircularState {
     last_seen_handled_descriptor_ptr: *mut DmaDescriptor,
     buffer_start: *const u8,
     buffer_len: usize,
+    completed: usize,
+    last_completed: usize,
+    underrun: bool,
+    fill: Fill,
 
     first_desc_ptr: *mut DmaDescriptor,
 }
 
 impl TxCircularState {
     pub(crate) fn new(chain: &mut DescriptorChain) -> Self {
+        // The chain might start in the middle of the buffer and wrap around to
+        // its start, see `DescriptorChain::fill_for_tx_range`.
+        let first_buffer = chain.descriptors[0].buffer.cast_const();
+        let buffer_start = chain
+            .descriptors
+            .iter()
+            .filter(|d| !d.buffer.is_null())
+            .map(|d| d.buffer.cast_const())
+            .min()
+            .unwrap_or(first_buffer);
+
         Self {
-            write_offset: 0,
+            write_offset: first_buffer as usize - buffer_start as usize,
             write_descr_ptr: chain.first_mut(),
             available: 0,
             last_seen_handled_descriptor_ptr: chain.first_mut(),
-            buffer_start: chain.descriptors[0].buffer as _,
+            buffer_start,
             buffer_len: chain.descriptors.iter().map(|d| d.len()).sum(),
+            completed: 0,
+            last_completed: 0,
+            underrun: false,
+            fill: Fill::Repeat,
 
             first_desc_ptr: chain.first_mut(),
         }
     }
 
+    pub(crate) fn snapshot(&self) -> CircularSnapshot {
+        CircularSnapshot {
+            buffer_address: self.buffer_start as usize,
+            buffer_len: self.buffer_len,
+            write_offset: self.write_offset,
+            available: self.available,
+            last_descriptor: self.last_seen_handled_descriptor_ptr as usize,
+            fill: self.fill,
+        }
+    }
+
+    /// Creates the state of a transfer resuming at `snapshot`.
+    ///
+    /// The chain has to be filled for the buffer of the snapshot, starting at
+    /// [CircularSnapshot::read_offset].
+    #[cfg(any(i2s0, i2s1))]
+    pub(crate) fn restore(
+        chain: &mut DescriptorChain,
+        snapshot: &CircularSnapshot,
+    ) -> Result<Self, DmaError> {
+        let mut state = Self::new(chain);
+
+        if state.buffer_start as usize != snapshot.buffer_address
+            || state.buffer_len != snapshot.buffer_len
+            || snapshot.available > snapshot.buffer_len
+            || state.write_offset != snapshot.read_offset()
+        {
+            return Err(DmaError::InvalidSnapshot);
+        }
+
+        // The data which wasn't sent yet comes first in the chain, the write
+        // position is in the descriptor after it.
+        let mut unsent = snapshot.buffer_len - snapshot.available;
+        let mut ptr = state.first_desc_ptr;
+        unsafe {
+            while unsent >= (*ptr).len() {
+                unsent -= (*ptr).len();
+                ptr = if (*ptr).next.is_null() {
+                    state.first_desc_ptr
+                } else {
+                    (*ptr).next
+                };
+            }
+        }
+
+        state.write_descr_ptr = ptr;
+        state.write_offset = snapshot.write_offset;
+        state.available = snapshot.available;
+        state.fill = snapshot.fill;
+
+        Ok(state)
+    }
+
     pub(crate) fn update<T>(&mut self, channel: &T)
     where
         T: TxPrivate,
@@ -740,40 +2117,53 @@ impl TxCircularState {
             channel.reset_descriptors_handled();
             let descr_address = channel.last_out_dscr_address() as *mut DmaDescriptor;
 
+            // The descriptor the DMA reported is released with the next one, the
+            // last descriptor released before it is reported as completed.
+            let mut completed = 0;
+            let mut released = None;
             let mut ptr = self.last_seen_handled_descriptor_ptr;
             if descr_address >= self.last_seen_handled_descriptor_ptr {
                 unsafe {
                     while ptr < descr_address {
-                        let dw0 = ptr.read_volatile();
-                        self.available += dw0.len();
+                        self.release(ptr);
+                        released = Some(ptr);
+                        completed += 1;
                         ptr = ptr.offset(1);
                     }
                 }
             } else {
                 unsafe {
                     while !((*ptr).next.is_null() || (*ptr).next == self.first_desc_ptr) {
-                        let dw0 = ptr.read_volatile();
-                        self.available += dw0.len();
+                        self.release(ptr);
+                        completed += 1;
                         ptr = ptr.offset(1);
                     }
 
                     // add bytes pointed to by the last descriptor
-                    let dw0 = ptr.read_volatile();
-                    self.available += dw0.len();
+                    self.release(ptr);
+                    released = Some(ptr);
+                    completed += 1;
 
                     // in circular mode we need to honor the now available bytes at start
                     if (*ptr).next == self.first_desc_ptr {
                         ptr = self.first_desc_ptr;
                         while ptr < descr_address {
-                            let dw0 = ptr.read_volatile();
-                            self.available += dw0.len();
+                            self.release(ptr);
+                            released = Some(ptr);
+                            completed += 1;
                             ptr = ptr.offset(1);
                         }
                     }
                 }
             }
 
+            // The DMA sent everything that was pushed and is sending the data
+            // at the write position again. Before the first lap `available`
+            // can't reach the buffer length, the data the transfer was started
+            // with is still waiting to be sent.
             if self.available >= self.buffer_len {
+                self.underrun = true;
+
                 unsafe {
                     let dw0 = self.write_descr_ptr.read_volatile();
                     let segment_len = dw0.len();
@@ -789,30 +2179,74 @@ impl TxCircularState {
                 }
             }
 
+            if let Some(released) = released {
+                self.completed += completed;
+                self.last_completed = (released as usize - self.first_desc_ptr as usize)
+                    / core::mem::size_of::<DmaDescriptor>();
+            }
+
             self.last_seen_handled_descriptor_ptr = descr_address;
         }
     }
 
-    pub(crate) fn push(&mut self, data: &[u8]) -> Result<usize, DmaError> {
-        let avail = self.available;
+    /// Makes the buffer of a descriptor the DMA finished sending available
+    /// for pushing.
+    unsafe fn release(&mut self, descriptor: *mut DmaDescriptor) {
+        let dw0 = descriptor.read_volatile();
+        self.available += dw0.len();
 
-        if avail < data.len() {
+        if self.fill == Fill::Zero {
+            core::ptr::write_bytes(dw0.buffer, 0, dw0.len());
+        }
+    }
+
+    pub(crate) fn set_fill(&mut self, fill: Fill) {
+        self.fill = fill;
+    }
+
+    /// Returns whether the DMA ran out of new data since the last call.
+    pub(crate) fn take_underrun(&mut self) -> bool {
+        core::mem::take(&mut self.underrun)
+    }
+
+    /// Takes the descriptors completed since the last call, coalesced into a
+    /// single [CompletedDescriptor].
+    pub(crate) fn take_completed(&mut self) -> Option<CompletedDescriptor> {
+        if self.completed == 0 {
+            return None;
+        }
+
+        let completed = CompletedDescriptor {
+            index: self.last_completed,
+            skipped: self.completed - 1,
+        };
+        self.completed = 0;
+
+        Some(completed)
+    }
+
+    pub(crate) fn push(&mut self, data: &[u8]) -> Result<usize, DmaError> {
+        if self.available < data.len() {
             return Err(DmaError::Overflow);
         }
 
-        let mut remaining = data.len();
-        let mut offset = 0;
-        while self.available >= remaining && remaining > 0 {
-            let written = self.push_with(|buffer| {
+        self.push_partial(data)
+    }
+
+    /// Writes as many bytes of `data` as currently fit, wrapping around the
+    /// end of the buffer if needed.
+    pub(crate) fn push_partial(&mut self, data: &[u8]) -> Result<usize, DmaError> {
+        let mut written = 0;
+        while written < data.len() && self.available > 0 {
+            let offset = written;
+            written += self.push_with(|buffer| {
                 let len = usize::min(buffer.len(), data.len() - offset);
                 buffer[..len].copy_from_slice(&data[offset..][..len]);
                 len
             })?;
-            offset += written;
-            remaining -= written;
         }
 
-        Ok(data.len())
+        Ok(written)
     }
 
     pub(crate) fn push_with(
@@ -852,28 +2286,143 @@ impl TxCircularState {
     }
 }
 
+/// A sample which can be read from a circular RX transfer with
+/// [DmaTransferRxCircular::pop_samples]
+///
+/// The received data is converted while it's copied out of the DMA buffer.
+/// `i16` and `i32` are read from 16 and 32 bit slots as they are, [I24] from
+/// 32 bit slots. [Left] and [Right] read one channel of stereo data.
+pub trait Sample: Copy + crate::private::Sealed {
+    /// Number of bytes the sample takes up in the DMA buffer
+    #[doc(hidden)]
+    const BYTES: usize;
+
+    #[doc(hidden)]
+    fn from_dma_bytes(bytes: &[u8]) -> Self;
+}
+
+/// The largest [Sample::BYTES]
+const MAX_SAMPLE_BYTES: usize = 8;
+
+impl crate::private::Sealed for i16 {}
+
+impl Sample for i16 {
+    const BYTES: usize = 2;
+
+    fn from_dma_bytes(bytes: &[u8]) -> Self {
+        i16::from_le_bytes([bytes[0], bytes[1]])
+    }
+}
+
+impl crate::private::Sealed for i32 {}
+
+impl Sample for i32 {
+    const BYTES: usize = 4;
+
+    fn from_dma_bytes(bytes: &[u8]) -> Self {
+        i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+}
+
+/// A 24 bit sample received in the upper 24 bits of a 32 bit slot, sign
+/// extended to 32 bits
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(transparent)]
+pub struct I24(pub i32);
+
+impl crate::private::Sealed for I24 {}
+
+impl Sample for I24 {
+    const BYTES: usize = 4;
+
+    fn from_dma_bytes(bytes: &[u8]) -> Self {
+        I24(i32::from_dma_bytes(bytes) >> 8)
+    }
+}
+
+/// The left channel, i.e. the first slot, of stereo samples
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(transparent)]
+pub struct Left<S>(pub S);
+
+/// The right channel, i.e. the second slot, of stereo samples
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(transparent)]
+pub struct Right<S>(pub S);
+
+macro_rules! impl_channel_samples {
+    ($($sample:ty),*) => {
+        $(
+            impl crate::private::Sealed for Left<$sample> {}
+
+            impl Sample for Left<$sample> {
+                const BYTES: usize = 2 * <$sample>::BYTES;
+
+                fn from_dma_bytes(bytes: &[u8]) -> Self {
+                    Left(<$sample>::from_dma_bytes(&bytes[..<$sample>::BYTES]))
+                }
+            }
+
+            impl crate::private::Sealed for Right<$sample> {}
+
+            impl Sample for Right<$sample> {
+                const BYTES: usize = 2 * <$sample>::BYTES;
+
+                fn from_dma_bytes(bytes: &[u8]) -> Self {
+                    Right(<$sample>::from_dma_bytes(&bytes[<$sample>::BYTES..]))
+                }
+            }
+        )*
+    };
+}
+
+impl_channel_samples!(i16, i32, I24);
+
 pub(crate) struct RxCircularState {
     read_descr_ptr: *mut DmaDescriptor,
+    read_offset: usize,
     pub(crate) available: usize,
     last_seen_handled_descriptor_ptr: *mut DmaDescriptor,
+    first_descr_ptr: *mut DmaDescriptor,
     last_descr_ptr: *mut DmaDescriptor,
 }
 
 impl RxCircularState {
     pub(crate) fn new(chain: &mut DescriptorChain) -> Self {
+        let first_descr_ptr = chain.first_mut();
+
+        // The ring might not use all descriptors of the chain, find the one linking
+        // back to the first descriptor.
+        let mut last_descr_ptr = first_descr_ptr;
+        unsafe {
+            while !((*last_descr_ptr).next.is_null() || (*last_descr_ptr).next == first_descr_ptr) {
+                last_descr_ptr = (*last_descr_ptr).next;
+            }
+        }
+
         Self {
-            read_descr_ptr: chain.first_mut(),
+            read_descr_ptr: first_descr_ptr,
+            read_offset: 0,
             available: 0,
             last_seen_handled_descriptor_ptr: core::ptr::null_mut(),
-            last_descr_ptr: chain.last_mut(),
+            first_descr_ptr,
+            last_descr_ptr,
         }
     }
 
-    pub(crate) fn update(&mut self) {
-        if self.last_seen_handled_descriptor_ptr.is_null() {
-            // initially start at last descriptor (so that next will be the first
-            // descriptor)
-            self.last_seen_handled_descriptor_ptr = self.last_descr_ptr;
+    /// Updates the amount of available data.
+    ///
+    /// Fails with [DmaError::BufferOverrun] if the DMA wrapped around and
+    /// reclaimed a descriptor which wasn't read yet. In that case all
+    /// unread data is discarded and reading resumes with the next
+    /// descriptor filled by the DMA.
+    pub(crate) fn update(&mut self) -> Result<(), DmaError> {
+        if self.last_seen_handled_descriptor_ptr.is_null() && !self.resync()? {
+            // nothing was received yet
+            return Ok(());
         }
 
         let mut current_in_descr_ptr =
@@ -881,6 +2430,12 @@ impl RxCircularState {
         let mut current_in_descr = unsafe { current_in_descr_ptr.read_volatile() };
 
         while current_in_descr.owner() == Owner::Cpu {
+            if current_in_descr_ptr == self.read_descr_ptr && self.available > 0 {
+                // All descriptors are filled and the DMA is now writing into data we
+                // didn't read yet
+                return Err(self.overrun());
+            }
+
             self.available += current_in_descr.len();
             self.last_seen_handled_descriptor_ptr = current_in_descr_ptr;
 
@@ -888,6 +2443,73 @@ impl RxCircularState {
                 unsafe { self.last_seen_handled_descriptor_ptr.read_volatile() }.next;
             current_in_descr = unsafe { current_in_descr_ptr.read_volatile() };
         }
+
+        Ok(())
+    }
+
+    /// Finds the oldest descriptor filled by the DMA, i.e. the descriptor owned
+    /// by the CPU whose predecessor is still owned by the DMA, and continues
+    /// reading from there.
+    ///
+    /// Returns `false` if no data was received yet.
+    fn resync(&mut self) -> Result<bool, DmaError> {
+        let mut prev_descr_ptr = self.last_descr_ptr;
+        let mut descr_ptr = self.first_descr_ptr;
+        let mut any_dma_owned = false;
+
+        loop {
+            let prev_descr = unsafe { prev_descr_ptr.read_volatile() };
+            let descr = unsafe { descr_ptr.read_volatile() };
+
+            if descr.owner() == Owner::Cpu && prev_descr.owner() == Owner::Dma {
+                self.read_descr_ptr = descr_ptr;
+                self.read_offset = 0;
+                self.last_seen_handled_descriptor_ptr = prev_descr_ptr;
+                self.available = 0;
+                return Ok(true);
+            }
+
+            any_dma_owned |= descr.owner() == Owner::Dma;
+
+            if descr_ptr == self.last_descr_ptr {
+                break;
+            }
+
+            prev_descr_ptr = descr_ptr;
+            descr_ptr = descr.next;
+        }
+
+        if any_dma_owned {
+            Ok(false)
+        } else {
+            // every descriptor got filled before we looked at them
+            Err(self.overrun())
+        }
+    }
+
+    /// Hands all descriptors back to the DMA, discarding the unread data.
+    fn overrun(&mut self) -> DmaError {
+        let mut descr_ptr = self.first_descr_ptr;
+        loop {
+            unsafe {
+                let mut descr = descr_ptr.read_volatile();
+                descr.set_owner(Owner::Dma);
+                descr.set_suc_eof(false);
+                descr.set_length_unchecked(0);
+                descr_ptr.write_volatile(descr);
+
+                if descr_ptr == self.last_descr_ptr {
+                    break;
+                }
+                descr_ptr = descr.next;
+            }
+        }
+
+        self.available = 0;
+        self.read_offset = 0;
+        self.last_seen_handled_descriptor_ptr = core::ptr::null_mut();
+
+        DmaError::BufferOverrun
     }
 
     pub(crate) fn pop(&mut self, data: &mut [u8]) -> Result<usize, DmaError> {
@@ -907,21 +2529,25 @@ impl RxCircularState {
 
         let mut descr = unsafe { descr_ptr.read_volatile() };
 
-        while avail > 0 && !remaining_buffer.is_empty() && remaining_buffer.len() >= descr.len() {
+        while avail > 0
+            && !remaining_buffer.is_empty()
+            && remaining_buffer.len() >= descr.len() - self.read_offset
+        {
             unsafe {
                 let dst = remaining_buffer.as_mut_ptr();
-                let src = descr.buffer;
-                let count = descr.len();
+                let src = descr.buffer.add(self.read_offset);
+                let count = descr.len() - self.read_offset;
                 core::ptr::copy_nonoverlapping(src, dst, count);
 
                 descr.set_owner(Owner::Dma);
                 descr.set_suc_eof(false);
-                descr.set_length(0);
+                descr.set_length_unchecked(0);
                 descr_ptr.write_volatile(descr);
 
                 remaining_buffer = &mut remaining_buffer[count..];
                 avail -= count;
                 descr_ptr = descr.next;
+                self.read_offset = 0;
             }
 
             if descr_ptr.is_null() {
@@ -935,12 +2561,123 @@ impl RxCircularState {
         self.available = avail;
         Ok(len - remaining_buffer.len())
     }
+
+    pub(crate) fn pop_with(&mut self, f: impl FnOnce(&[u8]) -> usize) -> Result<usize, DmaError> {
+        // find the largest contiguous region of received data
+        let mut block_size = 0;
+        let mut block_start: *const u8 = core::ptr::null();
+        if self.available > 0 {
+            unsafe {
+                let mut descr_ptr = self.read_descr_ptr;
+                let mut descr = descr_ptr.read_volatile();
+                block_start = descr.buffer.add(self.read_offset);
+                block_size = descr.len() - self.read_offset;
+
+                while block_size < self.available {
+                    let block_end = descr.buffer.add(descr.len());
+                    descr_ptr = descr.next;
+                    descr = descr_ptr.read_volatile();
+
+                    if descr.buffer.cast_const() != block_end {
+                        break;
+                    }
+
+                    block_size += descr.len();
+                }
+            }
+        }
+
+        let consumed = if block_size == 0 {
+            f(&[])
+        } else {
+            let buffer = unsafe { core::slice::from_raw_parts(block_start, block_size) };
+            f(buffer)
+        };
+        let consumed = usize::min(consumed, block_size);
+
+        // hand back fully consumed descriptors to the DMA
+        let mut remaining = consumed;
+        while remaining > 0 {
+            unsafe {
+                let mut descr = self.read_descr_ptr.read_volatile();
+                let left_in_descr = descr.len() - self.read_offset;
+
+                if remaining < left_in_descr {
+                    self.read_offset += remaining;
+                    break;
+                }
+
+                descr.set_owner(Owner::Dma);
+                descr.set_suc_eof(false);
+                descr.set_length_unchecked(0);
+                self.read_descr_ptr.write_volatile(descr);
+
+                remaining -= left_in_descr;
+                self.read_descr_ptr = descr.next;
+                self.read_offset = 0;
+            }
+        }
+
+        self.available -= consumed;
+
+        Ok(consumed)
+    }
+
+    /// Pops as many whole samples as are available and fit into `out`.
+    pub(crate) fn pop_samples<S: Sample>(&mut self, out: &mut [S]) -> Result<usize, DmaError> {
+        let count = usize::min(out.len(), self.available / S::BYTES);
+        let mut popped = 0;
+
+        // The start of a sample which continues at the start of the buffer
+        let mut split = [0u8; MAX_SAMPLE_BYTES];
+        let mut split_len = 0;
+
+        while popped < count {
+            self.pop_with(|buffer| {
+                let mut consumed = 0;
+
+                if split_len > 0 {
+                    let len = usize::min(S::BYTES - split_len, buffer.len());
+                    split[split_len..][..len].copy_from_slice(&buffer[..len]);
+                    split_len += len;
+                    consumed = len;
+
+                    if split_len < S::BYTES {
+                        return consumed;
+                    }
+
+                    out[popped] = S::from_dma_bytes(&split[..S::BYTES]);
+                    popped += 1;
+                    split_len = 0;
+                }
+
+                let samples = buffer[consumed..].chunks_exact(S::BYTES);
+                for (sample, bytes) in out[popped..count].iter_mut().zip(samples) {
+                    *sample = S::from_dma_bytes(bytes);
+                    consumed += S::BYTES;
+                    popped += 1;
+                }
+
+                // Only whole samples are popped, so the rest of the sample is
+                // available at the start of the buffer.
+                if popped < count {
+                    split_len = buffer.len() - consumed;
+                    split[..split_len].copy_from_slice(&buffer[consumed..]);
+                    consumed = buffer.len();
+                }
+
+                consumed
+            })?;
+        }
+
+        Ok(popped)
+    }
 }
 
 /// A description of a DMA Channel.
 pub trait DmaChannel: crate::private::Sealed {
     #[doc(hidden)]
-    type Channel: ChannelTypes + RegisterAccess;
+    type Channel: RegisterAccess;
 
     /// A description of the RX half of a DMA Channel.
     type Rx: RxChannel<Self::Channel>;
@@ -955,7 +2692,7 @@ pub trait DmaChannel: crate::private::Sealed {
 /// The functions here are not meant to be used outside the HAL
 #[doc(hidden)]
 pub trait RxPrivate: crate::private::Sealed {
-    fn init(&mut self, burst_mode: bool, priority: DmaPriority);
+    fn init(&mut self, burst: DmaBurstConfig, priority: DmaPriority);
 
     fn init_channel(&mut self);
 
@@ -967,8 +2704,23 @@ pub trait RxPrivate: crate::private::Sealed {
 
     fn start_transfer(&mut self) -> Result<(), DmaError>;
 
+    fn stop_transfer(&mut self);
+
+    /// Makes the data the DMA received into external memory visible to the
+    /// CPU, called when an async transfer completed.
+    #[cfg(esp32s3)]
+    fn invalidate_psram_buffers(&mut self);
+
+    #[cfg(gdma)]
+    fn set_mode(&mut self, mode: ChannelMode);
+
+    #[cfg(gdma)]
+    fn mode(&self) -> ChannelMode;
+
+    /// Makes the DMA stop with a descriptor error at a descriptor owned by the
+    /// CPU, instead of overwriting its buffer.
     #[cfg(gdma)]
-    fn set_mem2mem_mode(&mut self, value: bool);
+    fn set_check_owner(&mut self, check: bool);
 
     fn listen_ch_in_done(&self);
 
@@ -991,6 +2743,12 @@ pub trait RxPrivate: crate::private::Sealed {
     /// Descriptor error detected
     fn has_error(&self) -> bool;
 
+    /// Address of the descriptor which caused a descriptor error, if known
+    fn error_descriptor_address(&self) -> Option<usize>;
+
+    /// Address of the descriptor the channel is currently working on
+    fn current_descriptor_address(&self) -> usize;
+
     /// ERR_DSCR_EMPTY error detected
     fn has_dscr_empty_error(&self) -> bool;
 
@@ -1018,7 +2776,10 @@ pub trait RxPrivate: crate::private::Sealed {
     fn clear_interrupts(&self);
 
     #[cfg(feature = "async")]
-    fn waker() -> &'static embassy_sync::waitqueue::AtomicWaker;
+    fn waker(&self) -> &'static embassy_sync::waitqueue::AtomicWaker;
+
+    #[cfg(feature = "dma-stats")]
+    fn stats(&self) -> &'static DmaStatsCounters;
 }
 
 #[doc(hidden)]
@@ -1026,13 +2787,18 @@ pub trait RxChannel<R>: crate::private::Sealed
 where
     R: RegisterAccess,
 {
-    fn init(&mut self, burst_mode: bool, priority: DmaPriority) {
-        R::set_in_burstmode(burst_mode);
-        R::set_in_priority(priority);
+    fn register_access(&self) -> &R;
+
+    fn init(&mut self, burst: DmaBurstConfig, priority: DmaPriority) {
+        let channel = self.register_access();
+        channel.set_in_burstmode(burst.in_burst.is_enabled());
+        #[cfg(esp32s3)]
+        channel.set_in_ext_mem_block_size(burst.ext_mem_block.unwrap_or(ExtMemBlkSize::Size16));
+        channel.set_in_priority(priority);
         // clear the mem2mem mode to avoid failed DMA if this
         // channel was previously used for a mem2mem transfer.
         #[cfg(gdma)]
-        R::set_mem2mem_mode(false);
+        channel.set_mem2mem_mode(false);
     }
 
     unsafe fn prepare_transfer_without_start(
@@ -1042,30 +2808,46 @@ where
     ) -> Result<(), DmaError> {
         compiler_fence(core::sync::atomic::Ordering::SeqCst);
 
-        R::clear_in_interrupts();
-        R::reset_in();
-        R::set_in_descriptors(descriptors.first() as u32);
-        R::set_in_peripheral(peri as u8);
+        let channel = self.register_access();
+
+        channel.clear_in_interrupts();
+        channel.reset_in();
+        channel.set_in_descriptors(descriptors.first() as u32);
+        channel.set_in_peripheral(peri as u8);
 
         Ok(())
     }
 
     fn start_transfer(&mut self) -> Result<(), DmaError> {
-        R::start_in();
+        let channel = self.register_access();
+        channel.start_in();
+
+        #[cfg(feature = "dma-stats")]
+        self.stats().count_transfer();
+
+        if channel.has_in_descriptor_error() {
+            #[cfg(feature = "dma-stats")]
+            self.stats().count_descriptor_error();
 
-        if R::has_in_descriptor_error() {
             Err(DmaError::DescriptorError)
         } else {
             Ok(())
         }
     }
 
+    fn stop_transfer(&mut self) {
+        self.register_access().stop_in();
+    }
+
     fn is_done(&self) -> bool {
-        R::is_in_done()
+        self.register_access().is_in_done()
     }
 
     #[cfg(feature = "async")]
-    fn waker() -> &'static embassy_sync::waitqueue::AtomicWaker;
+    fn waker(&self) -> &'static embassy_sync::waitqueue::AtomicWaker;
+
+    #[cfg(feature = "dma-stats")]
+    fn stats(&self) -> &'static DmaStatsCounters;
 }
 
 // DMA receive channel
@@ -1075,8 +2857,12 @@ pub struct ChannelRx<'a, CH>
 where
     CH: DmaChannel,
 {
-    pub(crate) burst_mode: bool,
+    pub(crate) burst: DmaBurstConfig,
     pub(crate) rx_impl: CH::Rx,
+    /// The address of the first descriptor of the prepared transfer if it
+    /// receives into external memory
+    #[cfg(esp32s3)]
+    pub(crate) psram_chain: Option<usize>,
     pub(crate) _phantom: PhantomData<(&'a (), CH)>,
 }
 
@@ -1084,10 +2870,12 @@ impl<'a, CH> ChannelRx<'a, CH>
 where
     CH: DmaChannel,
 {
-    fn new(rx_impl: CH::Rx, burst_mode: bool) -> Self {
+    fn new(rx_impl: CH::Rx, burst: DmaBurstConfig) -> Self {
         Self {
-            burst_mode,
+            burst,
             rx_impl,
+            #[cfg(esp32s3)]
+            psram_chain: None,
             _phantom: PhantomData,
         }
     }
@@ -1101,8 +2889,8 @@ impl<'a, CH> RxPrivate for ChannelRx<'a, CH>
 where
     CH: DmaChannel,
 {
-    fn init(&mut self, burst_mode: bool, priority: DmaPriority) {
-        self.rx_impl.init(burst_mode, priority);
+    fn init(&mut self, burst: DmaBurstConfig, priority: DmaPriority) {
+        self.rx_impl.init(burst, priority);
     }
 
     unsafe fn prepare_transfer_without_start(
@@ -1110,45 +2898,113 @@ where
         peri: DmaPeripheral,
         chain: &DescriptorChain,
     ) -> Result<(), DmaError> {
-        if self.burst_mode
-            && chain
-                .descriptors
-                .iter()
-                .any(|d| d.len() % 4 != 0 || d.buffer as u32 % 4 != 0)
+        debug_assert_eq!(
+            chain.validate(),
+            Ok(()),
+            "invalid descriptor chain {:?}",
+            chain
+        );
+
+        if let Some(d) = chain.descriptors.iter().find(|d| {
+            let alignment = self.burst.in_alignment(d.buffer);
+            d.size() % alignment != 0 || d.buffer as usize % alignment != 0
+        }) {
+            return Err(DmaError::InvalidAlignment {
+                address: d.buffer as usize,
+            });
+        }
+
+        if !chain.is_in_internal_ram() {
+            return Err(DmaError::UnsupportedMemoryRegion);
+        }
+
+        #[cfg(gdma)]
+        peri.check_mode(self.mode())?;
+
+        // Write back dirty lines sharing a cache line with the buffers and drop
+        // the lines of the buffers, so neither can overwrite the received data
+        #[cfg(esp32s3)]
         {
-            return Err(DmaError::InvalidAlignment);
+            chain.writeback_psram_buffers();
+            chain.invalidate_psram_buffers();
+            self.psram_chain = chain.has_psram_buffers().then(|| chain.first() as usize);
         }
 
         self.rx_impl.prepare_transfer_without_start(chain, peri)
     }
 
+    #[cfg(esp32s3)]
+    fn invalidate_psram_buffers(&mut self) {
+        let Some(first) = self.psram_chain else {
+            return;
+        };
+
+        // The descriptors are still in place, the driver owns the chain until
+        // the transfer completed. A circular chain links back to the first
+        // descriptor.
+        let first = first as *const DmaDescriptor;
+        let mut current = first;
+        while !current.is_null() {
+            let descriptor = unsafe { &*current };
+            if !descriptor.buffer.is_null() && addr::is_external(descriptor.buffer) {
+                unsafe {
+                    crate::soc::cache_invalidate_addr(
+                        descriptor.buffer as u32,
+                        descriptor.size() as u32,
+                    )
+                };
+            }
+
+            current = descriptor.next;
+            if current == first {
+                break;
+            }
+        }
+    }
+
     fn start_transfer(&mut self) -> Result<(), DmaError> {
         self.rx_impl.start_transfer()
     }
 
+    fn stop_transfer(&mut self) {
+        self.rx_impl.stop_transfer()
+    }
+
     #[cfg(gdma)]
-    fn set_mem2mem_mode(&mut self, value: bool) {
-        CH::Channel::set_mem2mem_mode(value);
+    fn set_mode(&mut self, mode: ChannelMode) {
+        self.rx_impl
+            .register_access()
+            .set_mem2mem_mode(mode == ChannelMode::Mem2Mem);
+    }
+
+    #[cfg(gdma)]
+    fn mode(&self) -> ChannelMode {
+        ChannelMode::of(self.rx_impl.register_access())
+    }
+
+    #[cfg(gdma)]
+    fn set_check_owner(&mut self, check: bool) {
+        self.rx_impl.register_access().set_in_check_owner(check);
     }
 
     fn listen_ch_in_done(&self) {
-        CH::Channel::listen_ch_in_done();
+        self.rx_impl.register_access().listen_ch_in_done();
     }
 
     fn clear_ch_in_done(&self) {
-        CH::Channel::clear_ch_in_done();
+        self.rx_impl.register_access().clear_ch_in_done();
     }
 
     fn is_ch_in_done_set(&self) -> bool {
-        CH::Channel::is_ch_in_done_set()
+        self.rx_impl.register_access().is_ch_in_done_set()
     }
 
     fn unlisten_ch_in_done(&self) {
-        CH::Channel::unlisten_ch_in_done();
+        self.rx_impl.register_access().unlisten_ch_in_done();
     }
 
     fn is_listening_ch_in_done(&self) -> bool {
-        CH::Channel::is_listening_ch_in_done()
+        self.rx_impl.register_access().is_listening_ch_in_done()
     }
 
     fn is_done(&self) -> bool {
@@ -1156,83 +3012,116 @@ where
     }
 
     fn init_channel(&mut self) {
-        CH::Channel::init_channel();
+        self.rx_impl.register_access().init_channel();
     }
 
     fn is_listening_eof(&self) -> bool {
-        CH::Channel::is_listening_in_eof()
+        self.rx_impl.register_access().is_listening_in_eof()
     }
 
     fn listen_eof(&self) {
-        CH::Channel::listen_in_eof()
+        self.rx_impl.register_access().listen_in_eof()
     }
 
     fn unlisten_eof(&self) {
-        CH::Channel::unlisten_in_eof()
+        self.rx_impl.register_access().unlisten_in_eof()
     }
 
     fn has_error(&self) -> bool {
-        CH::Channel::has_in_descriptor_error()
+        self.rx_impl.register_access().has_in_descriptor_error()
+    }
+
+    fn error_descriptor_address(&self) -> Option<usize> {
+        self.rx_impl.register_access().last_in_err_dscr_address()
+    }
+
+    fn current_descriptor_address(&self) -> usize {
+        self.rx_impl.register_access().current_in_dscr_address()
     }
 
     fn has_dscr_empty_error(&self) -> bool {
-        CH::Channel::has_in_descriptor_error_dscr_empty()
+        self.rx_impl
+            .register_access()
+            .has_in_descriptor_error_dscr_empty()
     }
 
     fn has_eof_error(&self) -> bool {
-        CH::Channel::has_in_descriptor_error_err_eof()
+        self.rx_impl
+            .register_access()
+            .has_in_descriptor_error_err_eof()
     }
 
     fn is_listening_in_descriptor_error(&self) -> bool {
-        CH::Channel::is_listening_in_descriptor_error()
+        self.rx_impl
+            .register_access()
+            .is_listening_in_descriptor_error()
     }
 
     fn listen_in_descriptor_error(&self) {
-        CH::Channel::listen_in_descriptor_error();
+        self.rx_impl.register_access().listen_in_descriptor_error();
     }
 
     fn unlisten_in_descriptor_error(&self) {
-        CH::Channel::unlisten_in_descriptor_error();
+        self.rx_impl
+            .register_access()
+            .unlisten_in_descriptor_error();
     }
 
     fn is_listening_in_descriptor_error_dscr_empty(&self) -> bool {
-        CH::Channel::is_listening_in_descriptor_error_dscr_empty()
+        self.rx_impl
+            .register_access()
+            .is_listening_in_descriptor_error_dscr_empty()
     }
 
     fn listen_in_descriptor_error_dscr_empty(&self) {
-        CH::Channel::listen_in_descriptor_error_dscr_empty();
+        self.rx_impl
+            .register_access()
+            .listen_in_descriptor_error_dscr_empty();
     }
 
     fn unlisten_in_descriptor_error_dscr_empty(&self) {
-        CH::Channel::unlisten_in_descriptor_error_dscr_empty();
+        self.rx_impl
+            .register_access()
+            .unlisten_in_descriptor_error_dscr_empty();
     }
 
     fn is_listening_in_descriptor_error_err_eof(&self) -> bool {
-        CH::Channel::is_listening_in_descriptor_error_err_eof()
+        self.rx_impl
+            .register_access()
+            .is_listening_in_descriptor_error_err_eof()
     }
 
     fn listen_in_descriptor_error_err_eof(&self) {
-        CH::Channel::listen_in_descriptor_error_err_eof();
+        self.rx_impl
+            .register_access()
+            .listen_in_descriptor_error_err_eof();
     }
 
     fn unlisten_in_descriptor_error_err_eof(&self) {
-        CH::Channel::unlisten_in_descriptor_error_err_eof();
+        self.rx_impl
+            .register_access()
+            .unlisten_in_descriptor_error_err_eof();
     }
 
     fn clear_interrupts(&self) {
-        CH::Channel::clear_in_interrupts();
+        self.rx_impl.register_access().clear_in_interrupts();
     }
 
     #[cfg(feature = "async")]
-    fn waker() -> &'static embassy_sync::waitqueue::AtomicWaker {
-        CH::Rx::waker()
+    fn waker(&self) -> &'static embassy_sync::waitqueue::AtomicWaker {
+        self.rx_impl.waker()
+    }
+
+    #[cfg(feature = "dma-stats")]
+    fn stats(&self) -> &'static DmaStatsCounters {
+        self.rx_impl.stats()
     }
 }
 
 /// The functions here are not meant to be used outside the HAL
 #[doc(hidden)]
 pub trait TxPrivate: crate::private::Sealed {
-    fn init(&mut self, burst_mode: bool, priority: DmaPriority);
+    fn init(&mut self, burst: DmaBurstConfig, priority: DmaPriority);
 
     fn init_channel(&mut self);
 
@@ -1244,6 +3133,13 @@ pub trait TxPrivate: crate::private::Sealed {
 
     fn start_transfer(&mut self) -> Result<(), DmaError>;
 
+    fn stop_transfer(&mut self);
+
+    fn restart_transfer(&mut self);
+
+    #[cfg(gdma)]
+    fn mode(&self) -> ChannelMode;
+
     fn clear_ch_out_done(&self);
 
     fn is_ch_out_done_set(&self) -> bool;
@@ -1270,10 +3166,18 @@ pub trait TxPrivate: crate::private::Sealed {
 
     fn has_error(&self) -> bool;
 
+    fn error_descriptor_address(&self) -> Option<usize>;
+
+    /// Address of the descriptor the channel is currently working on
+    fn current_descriptor_address(&self) -> usize;
+
     fn clear_interrupts(&self);
 
     #[cfg(feature = "async")]
-    fn waker() -> &'static embassy_sync::waitqueue::AtomicWaker;
+    fn waker(&self) -> &'static embassy_sync::waitqueue::AtomicWaker;
+
+    #[cfg(feature = "dma-stats")]
+    fn stats(&self) -> &'static DmaStatsCounters;
 
     fn descriptors_handled(&self) -> bool;
 
@@ -1287,9 +3191,14 @@ pub trait TxChannel<R>: crate::private::Sealed
 where
     R: RegisterAccess,
 {
-    fn init(&mut self, burst_mode: bool, priority: DmaPriority) {
-        R::set_out_burstmode(burst_mode);
-        R::set_out_priority(priority);
+    fn register_access(&self) -> &R;
+
+    fn init(&mut self, burst: DmaBurstConfig, priority: DmaPriority) {
+        let channel = self.register_access();
+        channel.set_out_burstmode(burst.out_burst.is_enabled());
+        #[cfg(esp32s3)]
+        channel.set_out_ext_mem_block_size(burst.ext_mem_block.unwrap_or(ExtMemBlkSize::Size16));
+        channel.set_out_priority(priority);
     }
 
     unsafe fn prepare_transfer_without_start(
@@ -1299,62 +3208,83 @@ where
     ) -> Result<(), DmaError> {
         compiler_fence(core::sync::atomic::Ordering::SeqCst);
 
-        R::clear_out_interrupts();
-        R::reset_out();
-        R::set_out_descriptors(descriptors.first() as u32);
-        R::set_out_peripheral(peri as u8);
+        let channel = self.register_access();
+
+        channel.clear_out_interrupts();
+        channel.reset_out();
+        channel.set_out_descriptors(descriptors.first() as u32);
+        channel.set_out_peripheral(peri as u8);
 
         Ok(())
     }
 
     fn start_transfer(&mut self) -> Result<(), DmaError> {
-        R::start_out();
+        let channel = self.register_access();
+        channel.start_out();
+
+        #[cfg(feature = "dma-stats")]
+        self.stats().count_transfer();
+
+        if channel.has_out_descriptor_error() {
+            #[cfg(feature = "dma-stats")]
+            self.stats().count_descriptor_error();
 
-        if R::has_out_descriptor_error() {
             Err(DmaError::DescriptorError)
         } else {
             Ok(())
         }
     }
 
+    fn stop_transfer(&mut self) {
+        self.register_access().stop_out();
+    }
+
+    fn restart_transfer(&mut self) {
+        compiler_fence(core::sync::atomic::Ordering::SeqCst);
+        self.register_access().restart_out();
+    }
+
     fn clear_ch_out_done(&self) {
-        R::clear_ch_out_done();
+        self.register_access().clear_ch_out_done();
     }
 
     fn is_ch_out_done_set(&self) -> bool {
-        R::is_ch_out_done_set()
+        self.register_access().is_ch_out_done_set()
     }
 
     fn listen_ch_out_done(&self) {
-        R::listen_ch_out_done();
+        self.register_access().listen_ch_out_done();
     }
 
     fn unlisten_ch_out_done(&self) {
-        R::unlisten_ch_out_done();
+        self.register_access().unlisten_ch_out_done();
     }
 
     fn is_listening_ch_out_done(&self) -> bool {
-        R::is_listening_ch_out_done()
+        self.register_access().is_listening_ch_out_done()
     }
 
     fn is_done(&self) -> bool {
-        R::is_out_done()
+        self.register_access().is_out_done()
     }
 
     fn descriptors_handled(&self) -> bool {
-        R::is_out_eof_interrupt_set()
+        self.register_access().is_out_eof_interrupt_set()
     }
 
     fn reset_descriptors_handled(&self) {
-        R::reset_out_eof_interrupt();
+        self.register_access().reset_out_eof_interrupt();
     }
 
     fn last_out_dscr_address(&self) -> usize {
-        R::last_out_dscr_address()
+        self.register_access().last_out_dscr_address()
     }
 
     #[cfg(feature = "async")]
-    fn waker() -> &'static embassy_sync::waitqueue::AtomicWaker;
+    fn waker(&self) -> &'static embassy_sync::waitqueue::AtomicWaker;
+
+    #[cfg(feature = "dma-stats")]
+    fn stats(&self) -> &'static DmaStatsCounters;
 }
 
 /// DMA transmit channel
@@ -1364,7 +3294,7 @@ where
     CH: DmaChannel,
 {
     #[allow(unused)]
-    pub(crate) burst_mode: bool,
+    pub(crate) burst: DmaBurstConfig,
     pub(crate) tx_impl: CH::Tx,
     pub(crate) _phantom: PhantomData<(&'a (), CH)>,
 }
@@ -1373,9 +3303,9 @@ impl<'a, CH> ChannelTx<'a, CH>
 where
     CH: DmaChannel,
 {
-    fn new(tx_impl: CH::Tx, burst_mode: bool) -> Self {
+    fn new(tx_impl: CH::Tx, burst: DmaBurstConfig) -> Self {
         Self {
-            burst_mode,
+            burst,
             tx_impl,
             _phantom: PhantomData,
         }
@@ -1390,12 +3320,12 @@ impl<'a, CH> TxPrivate for ChannelTx<'a, CH>
 where
     CH: DmaChannel,
 {
-    fn init(&mut self, burst_mode: bool, priority: DmaPriority) {
-        self.tx_impl.init(burst_mode, priority);
+    fn init(&mut self, burst: DmaBurstConfig, priority: DmaPriority) {
+        self.tx_impl.init(burst, priority);
     }
 
     fn init_channel(&mut self) {
-        CH::Channel::init_channel();
+        self.tx_impl.register_access().init_channel();
     }
 
     unsafe fn prepare_transfer_without_start(
@@ -1403,6 +3333,18 @@ where
         peri: DmaPeripheral,
         chain: &DescriptorChain,
     ) -> Result<(), DmaError> {
+        if !chain.is_in_internal_ram() {
+            return Err(DmaError::UnsupportedMemoryRegion);
+        }
+
+        #[cfg(gdma)]
+        peri.check_mode(self.mode())?;
+
+        // The DMA reads external memory directly, data the CPU wrote may still
+        // be in the cache
+        #[cfg(esp32s3)]
+        chain.writeback_psram_buffers();
+
         self.tx_impl.prepare_transfer_without_start(chain, peri)
     }
 
@@ -1410,6 +3352,19 @@ where
         self.tx_impl.start_transfer()
     }
 
+    fn stop_transfer(&mut self) {
+        self.tx_impl.stop_transfer()
+    }
+
+    fn restart_transfer(&mut self) {
+        self.tx_impl.restart_transfer()
+    }
+
+    #[cfg(gdma)]
+    fn mode(&self) -> ChannelMode {
+        ChannelMode::of(self.tx_impl.register_access())
+    }
+
     fn clear_ch_out_done(&self) {
         self.tx_impl.clear_ch_out_done();
     }
@@ -1435,40 +3390,57 @@ where
     }
 
     fn is_listening_eof(&self) -> bool {
-        CH::Channel::is_listening_out_eof()
+        self.tx_impl.register_access().is_listening_out_eof()
     }
 
     fn listen_eof(&self) {
-        CH::Channel::listen_out_eof()
+        self.tx_impl.register_access().listen_out_eof()
     }
 
     fn unlisten_eof(&self) {
-        CH::Channel::unlisten_out_eof()
+        self.tx_impl.register_access().unlisten_out_eof()
     }
 
     fn has_error(&self) -> bool {
-        CH::Channel::has_out_descriptor_error()
+        self.tx_impl.register_access().has_out_descriptor_error()
+    }
+
+    fn error_descriptor_address(&self) -> Option<usize> {
+        self.tx_impl.register_access().last_out_err_dscr_address()
+    }
+
+    fn current_descriptor_address(&self) -> usize {
+        self.tx_impl.register_access().current_out_dscr_address()
+    }
+
+    #[cfg(feature = "async")]
+    fn waker(&self) -> &'static embassy_sync::waitqueue::AtomicWaker {
+        self.tx_impl.waker()
     }
 
-    #[cfg(feature = "async")]
-    fn waker() -> &'static embassy_sync::waitqueue::AtomicWaker {
-        CH::Tx::waker()
+    #[cfg(feature = "dma-stats")]
+    fn stats(&self) -> &'static DmaStatsCounters {
+        self.tx_impl.stats()
     }
 
     fn is_listening_out_descriptor_error(&self) -> bool {
-        CH::Channel::is_listening_out_descriptor_error()
+        self.tx_impl
+            .register_access()
+            .is_listening_out_descriptor_error()
     }
 
     fn listen_out_descriptor_error(&self) {
-        CH::Channel::listen_out_descriptor_error();
+        self.tx_impl.register_access().listen_out_descriptor_error();
     }
 
     fn unlisten_out_descriptor_error(&self) {
-        CH::Channel::unlisten_out_descriptor_error();
+        self.tx_impl
+            .register_access()
+            .unlisten_out_descriptor_error();
     }
 
     fn clear_interrupts(&self) {
-        CH::Channel::clear_out_interrupts();
+        self.tx_impl.register_access().clear_out_interrupts();
     }
 
     fn descriptors_handled(&self) -> bool {
@@ -1486,78 +3458,100 @@ where
 
 #[doc(hidden)]
 pub trait RegisterAccess: crate::private::Sealed {
-    fn init_channel();
+    fn init_channel(&self);
+    fn set_isr(&self, handler: InterruptHandler);
+    fn disable_isr(&self);
+    #[cfg(feature = "async")]
+    fn async_handler(&self) -> InterruptHandler;
     #[cfg(gdma)]
-    fn set_mem2mem_mode(value: bool);
-    fn set_out_burstmode(burst_mode: bool);
-    fn set_out_priority(priority: DmaPriority);
-    fn clear_out_interrupts();
-    fn reset_out();
-    fn set_out_descriptors(address: u32);
-    fn has_out_descriptor_error() -> bool;
-    fn set_out_peripheral(peripheral: u8);
-    fn start_out();
-    fn clear_ch_out_done();
-    fn is_ch_out_done_set() -> bool;
-    fn listen_ch_out_done();
-    fn unlisten_ch_out_done();
-    fn is_listening_ch_out_done() -> bool;
-    fn is_out_done() -> bool;
-    fn is_out_eof_interrupt_set() -> bool;
-    fn reset_out_eof_interrupt();
-    fn last_out_dscr_address() -> usize;
-
-    fn set_in_burstmode(burst_mode: bool);
-    fn set_in_priority(priority: DmaPriority);
-    fn clear_in_interrupts();
-    fn reset_in();
-    fn set_in_descriptors(address: u32);
-    fn has_in_descriptor_error() -> bool;
-    fn has_in_descriptor_error_dscr_empty() -> bool;
-    fn has_in_descriptor_error_err_eof() -> bool;
-    fn set_in_peripheral(peripheral: u8);
-    fn start_in();
-    fn is_in_done() -> bool;
-
-    fn is_listening_in_eof() -> bool;
-    fn is_listening_out_eof() -> bool;
-
-    fn listen_in_eof();
-    fn listen_out_eof();
-    fn unlisten_in_eof();
-    fn unlisten_out_eof();
-
-    fn listen_in_descriptor_error();
-    fn unlisten_in_descriptor_error();
-    fn is_listening_in_descriptor_error() -> bool;
-
-    fn listen_in_descriptor_error_dscr_empty();
-    fn unlisten_in_descriptor_error_dscr_empty();
-    fn is_listening_in_descriptor_error_dscr_empty() -> bool;
-
-    fn listen_in_descriptor_error_err_eof();
-    fn unlisten_in_descriptor_error_err_eof();
-    fn is_listening_in_descriptor_error_err_eof() -> bool;
-
-    fn listen_out_descriptor_error();
-    fn unlisten_out_descriptor_error();
-    fn is_listening_out_descriptor_error() -> bool;
-
-    fn listen_ch_in_done();
-    fn clear_ch_in_done();
-    fn is_ch_in_done_set() -> bool;
-    fn unlisten_ch_in_done();
-    fn is_listening_ch_in_done() -> bool;
-}
+    fn set_mem2mem_mode(&self, value: bool);
+    #[cfg(gdma)]
+    fn set_in_check_owner(&self, value: bool);
+    #[cfg(gdma)]
+    fn is_mem2mem_mode(&self) -> bool;
+    fn set_out_burstmode(&self, burst_mode: bool);
+    #[cfg(esp32s3)]
+    fn set_out_ext_mem_block_size(&self, size: ExtMemBlkSize);
+    fn set_out_priority(&self, priority: DmaPriority);
+    fn clear_out_interrupts(&self);
+    fn reset_out(&self);
+    fn set_out_descriptors(&self, address: u32);
+    fn has_out_descriptor_error(&self) -> bool;
+    fn set_out_peripheral(&self, peripheral: u8);
+    fn start_out(&self);
+    fn stop_out(&self);
+    fn restart_out(&self);
+    fn clear_ch_out_done(&self);
+    fn is_ch_out_done_set(&self) -> bool;
+    fn listen_ch_out_done(&self);
+    fn unlisten_ch_out_done(&self);
+    fn is_listening_ch_out_done(&self) -> bool;
+    fn is_out_done(&self) -> bool;
+    fn is_out_eof_interrupt_set(&self) -> bool;
+    fn reset_out_eof_interrupt(&self);
+    fn last_out_dscr_address(&self) -> usize;
+    fn last_out_err_dscr_address(&self) -> Option<usize>;
+    fn current_out_dscr_address(&self) -> usize;
 
-#[doc(hidden)]
-pub trait ChannelTypes: crate::private::Sealed {
-    type Binder: InterruptBinder;
+    fn set_in_burstmode(&self, burst_mode: bool);
+    #[cfg(esp32s3)]
+    fn set_in_ext_mem_block_size(&self, size: ExtMemBlkSize);
+    fn set_in_priority(&self, priority: DmaPriority);
+    fn clear_in_interrupts(&self);
+    fn reset_in(&self);
+    fn set_in_descriptors(&self, address: u32);
+    fn has_in_descriptor_error(&self) -> bool;
+    fn has_in_descriptor_error_dscr_empty(&self) -> bool;
+    fn has_in_descriptor_error_err_eof(&self) -> bool;
+    fn last_in_err_dscr_address(&self) -> Option<usize>;
+    fn current_in_dscr_address(&self) -> usize;
+    fn set_in_peripheral(&self, peripheral: u8);
+    fn start_in(&self);
+    fn stop_in(&self);
+    fn is_in_done(&self) -> bool;
+
+    fn is_listening_in_eof(&self) -> bool;
+    fn is_listening_out_eof(&self) -> bool;
+
+    fn listen_in_eof(&self);
+    fn listen_out_eof(&self);
+    fn unlisten_in_eof(&self);
+    fn unlisten_out_eof(&self);
+    fn is_in_eof_set(&self) -> bool;
+    fn clear_in_eof(&self);
+    fn clear_out_eof(&self);
+
+    fn clear_in_descriptor_error(&self);
+    fn clear_in_descriptor_error_dscr_empty(&self);
+    fn clear_out_descriptor_error(&self);
+
+    fn listen_in_descriptor_error(&self);
+    fn unlisten_in_descriptor_error(&self);
+    fn is_listening_in_descriptor_error(&self) -> bool;
+
+    fn listen_in_descriptor_error_dscr_empty(&self);
+    fn unlisten_in_descriptor_error_dscr_empty(&self);
+    fn is_listening_in_descriptor_error_dscr_empty(&self) -> bool;
+
+    fn listen_in_descriptor_error_err_eof(&self);
+    fn unlisten_in_descriptor_error_err_eof(&self);
+    fn is_listening_in_descriptor_error_err_eof(&self) -> bool;
+
+    fn listen_out_descriptor_error(&self);
+    fn unlisten_out_descriptor_error(&self);
+    fn is_listening_out_descriptor_error(&self) -> bool;
+
+    fn listen_ch_in_done(&self);
+    fn clear_ch_in_done(&self);
+    fn is_ch_in_done_set(&self) -> bool;
+    fn unlisten_ch_in_done(&self);
+    fn is_listening_ch_in_done(&self) -> bool;
 }
 
 #[doc(hidden)]
 pub trait InterruptBinder: crate::private::Sealed {
     fn set_isr(handler: InterruptHandler);
+    fn disable_isr();
 }
 
 /// DMA Channel
@@ -1573,6 +3567,125 @@ where
     phantom: PhantomData<MODE>,
 }
 
+impl<'d, C, MODE> Channel<'d, C, MODE>
+where
+    C: DmaChannel,
+    MODE: Mode,
+{
+    /// Splits the channel into its independently owned TX and RX halves.
+    ///
+    /// Both halves share the channel's interrupt, the halves keep the
+    /// interrupt handler which was set up when configuring the channel. Async
+    /// transfers on one half don't interfere with transfers on the other half.
+    pub fn split(self) -> (ChannelTx<'d, C>, ChannelRx<'d, C>) {
+        (self.tx, self.rx)
+    }
+
+    /// Returns the counters of the channel, see [stats].
+    #[cfg(feature = "dma-stats")]
+    pub fn stats(&self) -> DmaChannelStats {
+        DmaChannelStats {
+            tx: self.tx.tx_impl.stats().snapshot(),
+            rx: self.rx.rx_impl.stats().snapshot(),
+        }
+    }
+
+    /// Clears the counters of the channel, see [stats].
+    #[cfg(feature = "dma-stats")]
+    pub fn reset_stats(&self) {
+        self.tx.tx_impl.stats().reset();
+        self.rx.rx_impl.stats().reset();
+    }
+
+    /// Returns how the transfers of the channel are triggered.
+    #[cfg(gdma)]
+    pub fn mode(&self) -> ChannelMode {
+        self.rx.mode()
+    }
+
+    /// Sets how the transfers of the channel are triggered.
+    ///
+    /// Configuring a channel always selects [ChannelMode::PeripheralTrigger]
+    /// and [Mem2Mem] selects [ChannelMode::Mem2Mem] itself. Starting a
+    /// transfer in a mode which doesn't match the peripheral fails with
+    /// [DmaError::WrongChannelMode].
+    #[cfg(gdma)]
+    pub fn set_mode(&mut self, mode: ChannelMode) {
+        self.rx.set_mode(mode);
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'d, C> Channel<'d, C, crate::Blocking>
+where
+    C: DmaChannel,
+{
+    /// Converts the channel for use with async APIs
+    ///
+    /// This installs the async interrupt handler of the channel with
+    /// [crate::interrupt::Priority::max()], replacing any handler set with
+    /// [Channel::set_interrupt_handler].
+    pub fn into_async(self) -> Channel<'d, C, crate::Async> {
+        self.into_async_with_priority(crate::interrupt::Priority::max())
+    }
+
+    /// Converts the channel for use with async APIs, choosing the priority of
+    /// the interrupt which wakes the async tasks waiting for this channel
+    ///
+    /// See [Channel::into_async].
+    #[cfg_attr(
+        multi_core,
+        doc = "The interrupt is handled on the core which calls this function."
+    )]
+    pub fn into_async_with_priority(
+        self,
+        interrupt_priority: crate::interrupt::Priority,
+    ) -> Channel<'d, C, crate::Async> {
+        let channel = self.rx.rx_impl.register_access();
+        channel.set_isr(InterruptHandler::new(
+            channel.async_handler().handler(),
+            interrupt_priority,
+        ));
+
+        Channel {
+            tx: self.tx,
+            rx: self.rx,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'d, C> Channel<'d, C, crate::Async>
+where
+    C: DmaChannel,
+{
+    /// Converts the channel for use with blocking APIs
+    ///
+    /// This disables the channel's interrupt and stops listening for and
+    /// clears all of its interrupts.
+    pub fn into_blocking(self) -> Channel<'d, C, crate::Blocking> {
+        let channel = self.rx.rx_impl.register_access();
+        channel.disable_isr();
+
+        let mut channel = Channel {
+            tx: self.tx,
+            rx: self.rx,
+            phantom: PhantomData,
+        };
+        channel.unlisten(EnumSet::all());
+        channel
+            .rx
+            .rx_impl
+            .register_access()
+            .unlisten_in_descriptor_error_err_eof();
+        channel.tx.tx_impl.register_access().clear_out_interrupts();
+        channel.rx.rx_impl.register_access().clear_in_interrupts();
+
+        channel
+    }
+}
+
 impl<'d, C> Channel<'d, C, crate::Blocking>
 where
     C: DmaChannel,
@@ -1582,47 +3695,89 @@ where
     ///
     /// Interrupts are not enabled at the peripheral level here.
     pub fn set_interrupt_handler(&mut self, handler: InterruptHandler) {
-        <C::Channel as ChannelTypes>::Binder::set_isr(handler);
+        self.rx.rx_impl.register_access().set_isr(handler);
     }
 
     /// Listen for the given interrupts
     pub fn listen(&mut self, interrupts: EnumSet<DmaInterrupt>) {
+        let tx = self.tx.tx_impl.register_access();
+        let rx = self.rx.rx_impl.register_access();
+
         for interrupt in interrupts {
             match interrupt {
-                DmaInterrupt::TxDone => self.tx.listen_ch_out_done(),
-                DmaInterrupt::RxDone => self.rx.listen_ch_in_done(),
+                DmaInterrupt::TxDone => tx.listen_ch_out_done(),
+                DmaInterrupt::RxDone => rx.listen_ch_in_done(),
+                DmaInterrupt::TxEof => tx.listen_out_eof(),
+                DmaInterrupt::RxEof => rx.listen_in_eof(),
+                DmaInterrupt::TxDescriptorError => tx.listen_out_descriptor_error(),
+                DmaInterrupt::RxDescriptorError => rx.listen_in_descriptor_error(),
+                DmaInterrupt::RxDescriptorEmpty => rx.listen_in_descriptor_error_dscr_empty(),
             }
         }
     }
 
     /// Unlisten the given interrupts
     pub fn unlisten(&mut self, interrupts: EnumSet<DmaInterrupt>) {
+        let tx = self.tx.tx_impl.register_access();
+        let rx = self.rx.rx_impl.register_access();
+
         for interrupt in interrupts {
             match interrupt {
-                DmaInterrupt::TxDone => self.tx.unlisten_ch_out_done(),
-                DmaInterrupt::RxDone => self.rx.unlisten_ch_in_done(),
+                DmaInterrupt::TxDone => tx.unlisten_ch_out_done(),
+                DmaInterrupt::RxDone => rx.unlisten_ch_in_done(),
+                DmaInterrupt::TxEof => tx.unlisten_out_eof(),
+                DmaInterrupt::RxEof => rx.unlisten_in_eof(),
+                DmaInterrupt::TxDescriptorError => tx.unlisten_out_descriptor_error(),
+                DmaInterrupt::RxDescriptorError => rx.unlisten_in_descriptor_error(),
+                DmaInterrupt::RxDescriptorEmpty => rx.unlisten_in_descriptor_error_dscr_empty(),
             }
         }
     }
 
     /// Gets asserted interrupts
     pub fn interrupts(&mut self) -> EnumSet<DmaInterrupt> {
+        let tx = self.tx.tx_impl.register_access();
+        let rx = self.rx.rx_impl.register_access();
+
         let mut res = EnumSet::new();
-        if self.tx.is_done() {
+        if tx.is_ch_out_done_set() {
             res.insert(DmaInterrupt::TxDone);
         }
-        if self.rx.is_done() {
+        if rx.is_ch_in_done_set() {
             res.insert(DmaInterrupt::RxDone);
         }
+        if tx.is_out_done() {
+            res.insert(DmaInterrupt::TxEof);
+        }
+        if rx.is_in_eof_set() {
+            res.insert(DmaInterrupt::RxEof);
+        }
+        if tx.has_out_descriptor_error() {
+            res.insert(DmaInterrupt::TxDescriptorError);
+        }
+        if rx.has_in_descriptor_error() {
+            res.insert(DmaInterrupt::RxDescriptorError);
+        }
+        if rx.has_in_descriptor_error_dscr_empty() {
+            res.insert(DmaInterrupt::RxDescriptorEmpty);
+        }
         res
     }
 
     /// Resets asserted interrupts
     pub fn clear_interrupts(&mut self, interrupts: EnumSet<DmaInterrupt>) {
+        let tx = self.tx.tx_impl.register_access();
+        let rx = self.rx.rx_impl.register_access();
+
         for interrupt in interrupts {
             match interrupt {
-                DmaInterrupt::TxDone => self.tx.clear_ch_out_done(),
-                DmaInterrupt::RxDone => self.rx.clear_ch_in_done(),
+                DmaInterrupt::TxDone => tx.clear_ch_out_done(),
+                DmaInterrupt::RxDone => rx.clear_ch_in_done(),
+                DmaInterrupt::TxEof => tx.clear_out_eof(),
+                DmaInterrupt::RxEof => rx.clear_in_eof(),
+                DmaInterrupt::TxDescriptorError => tx.clear_out_descriptor_error(),
+                DmaInterrupt::RxDescriptorError => rx.clear_in_descriptor_error(),
+                DmaInterrupt::RxDescriptorEmpty => rx.clear_in_descriptor_error_dscr_empty(),
             }
         }
     }
@@ -1644,9 +3799,42 @@ pub(crate) mod dma_private {
         /// by it's [Drop] implementation.
         fn peripheral_wait_dma(&mut self, is_tx: bool, is_rx: bool);
 
-        /// Only used by circular DMA transfers in both, the `stop` function
-        /// _and_ it's [Drop] implementation
+        /// Used by circular DMA transfers in both, the `stop` function
+        /// _and_ it's [Drop] implementation, and when aborting a transfer
         fn peripheral_dma_stop(&mut self);
+
+        /// Called when a dropped transfer was aborted because it didn't finish
+        /// within the drop timeout.
+        ///
+        /// The peripheral might be stuck in the middle of a transaction, e.g.
+        /// because an external clock stopped, and has to be reset before the
+        /// next transfer.
+        fn peripheral_mark_needs_reset(&mut self) {}
+
+        /// Starts the next part of a transfer which the peripheral splits into
+        /// multiple transactions, once the DMA finished the current one.
+        ///
+        /// Returns `true` if another part was started, i.e. the transfer isn't
+        /// done yet.
+        fn peripheral_continue_dma(&mut self) -> bool {
+            false
+        }
+
+        /// Returns and clears the error of a part of the transfer which was
+        /// started by [DmaSupport::peripheral_continue_dma] or
+        /// [DmaSupport::peripheral_wait_dma].
+        fn peripheral_error(&mut self) -> Option<DmaError> {
+            None
+        }
+
+        /// Called when `len` more bytes were queued for a running
+        /// [DmaTransferTxQueue].
+        ///
+        /// Peripherals which need to know the length of a transaction when
+        /// it's started, like SPI, start a transaction for the queued bytes
+        /// here or in [DmaSupport::peripheral_continue_dma] once the current
+        /// one is done.
+        fn peripheral_queued(&mut self, _len: usize) {}
     }
 
     pub trait DmaSupportTx: DmaSupport {
@@ -1655,6 +3843,42 @@ pub(crate) mod dma_private {
         fn tx(&mut self) -> &mut Self::TX;
 
         fn chain(&mut self) -> &mut DescriptorChain;
+
+        /// Returns the error of the last TX transfer, if any.
+        fn tx_error(&mut self) -> Option<DmaError> {
+            if !self.tx().has_error() {
+                return None;
+            }
+
+            let address = self.tx().error_descriptor_address();
+            Some(self.chain().descriptor_error(address))
+        }
+
+        /// Returns the outcome of a finished TX transfer and counts it in the
+        /// statistics of the channel.
+        fn tx_result(&mut self) -> Result<(), DmaError> {
+            let result = self
+                .peripheral_error()
+                .or_else(|| self.tx_error())
+                .map_or(Ok(()), Err);
+
+            #[cfg(feature = "dma-stats")]
+            self.count_tx_result(&result);
+
+            result
+        }
+
+        /// Counts the outcome of a TX transfer which was waited for.
+        #[cfg(feature = "dma-stats")]
+        fn count_tx_result(&mut self, result: &Result<(), DmaError>) {
+            match result {
+                Ok(()) => {
+                    let bytes = self.chain().linked_len();
+                    self.tx().stats().count_bytes(bytes);
+                }
+                Err(_) => self.tx().stats().count_descriptor_error(),
+            }
+        }
     }
 
     pub trait DmaSupportRx: DmaSupport {
@@ -1663,6 +3887,156 @@ pub(crate) mod dma_private {
         fn rx(&mut self) -> &mut Self::RX;
 
         fn chain(&mut self) -> &mut DescriptorChain;
+
+        /// Makes the data received by the DMA visible to the CPU.
+        fn rx_complete(&mut self) {
+            #[cfg(esp32s3)]
+            self.chain().invalidate_psram_buffers();
+        }
+
+        /// Returns the error of the last RX transfer, if any.
+        fn rx_error(&mut self) -> Option<DmaError> {
+            if !self.rx().has_error() {
+                return None;
+            }
+
+            let address = self.rx().error_descriptor_address();
+            Some(self.chain().descriptor_error(address))
+        }
+
+        /// Returns the outcome of a finished RX transfer and counts it in the
+        /// statistics of the channel.
+        fn rx_result(&mut self) -> Result<(), DmaError> {
+            let result = self.rx_error().map_or(Ok(()), Err);
+
+            #[cfg(feature = "dma-stats")]
+            self.count_rx_result(&result);
+
+            result
+        }
+
+        /// Counts the outcome of an RX transfer which was waited for.
+        #[cfg(feature = "dma-stats")]
+        fn count_rx_result(&mut self, result: &Result<(), DmaError>) {
+            match result {
+                Ok(()) => {
+                    let bytes = self.chain().received_bytes();
+                    self.rx().stats().count_bytes(bytes);
+                }
+                Err(_) => self.rx().stats().count_descriptor_error(),
+            }
+        }
+    }
+}
+
+/// Returns the outcome of a finished TX+RX transfer and counts both
+/// directions in the statistics of the channels.
+fn tx_rx_result<I>(instance: &mut I) -> Result<(), DmaError>
+where
+    I: dma_private::DmaSupportTx + dma_private::DmaSupportRx,
+{
+    let tx_result = instance.tx_result();
+    let rx_result = instance.rx_result();
+
+    tx_result.and(rx_result)
+}
+
+// Busy waits until `is_done` returns true, or `timeout` elapsed
+fn wait_until_done(
+    timeout: MicrosDurationU64,
+    mut is_done: impl FnMut() -> bool,
+) -> Result<(), DmaError> {
+    let start = crate::time::current_time();
+
+    while !is_done() {
+        if crate::time::current_time() - start >= timeout {
+            return Err(DmaError::Timeout);
+        }
+    }
+
+    Ok(())
+}
+
+/// Default of [set_drop_timeout]
+const DEFAULT_DROP_TIMEOUT_US: u32 = 1_000_000;
+
+static DROP_TIMEOUT_US: portable_atomic::AtomicU32 =
+    portable_atomic::AtomicU32::new(DEFAULT_DROP_TIMEOUT_US);
+
+/// Sets how long dropping a DMA transfer which wasn't waited for waits for it
+/// to finish, 1 second by default.
+///
+/// If the transfer doesn't finish in time, e.g. because the clock of an SPI
+/// slave or I2S receiver stopped, it's aborted like with the transfer's
+/// `abort` function and the peripheral is stopped. Peripherals which depend on
+/// an external clock, like the SPI slave, are reset before the next transfer.
+pub fn set_drop_timeout(timeout: MicrosDurationU64) {
+    let timeout = timeout.ticks().min(u32::MAX as u64) as u32;
+    DROP_TIMEOUT_US.store(timeout, portable_atomic::Ordering::Relaxed);
+}
+
+// Waits until `is_done` returns true when a transfer is dropped, returns false
+// if the drop timeout elapsed first.
+fn wait_on_drop(mut is_done: impl FnMut() -> bool) -> bool {
+    // no need to read the time if the transfer is done already
+    if is_done() {
+        return true;
+    }
+
+    let timeout = DROP_TIMEOUT_US.load(portable_atomic::Ordering::Relaxed);
+    if wait_until_done(MicrosDurationU64::micros(timeout as u64), is_done).is_ok() {
+        return true;
+    }
+
+    warn!("DMA transfer didn't finish when it was dropped, aborting it");
+    false
+}
+
+fn drop_tx<I: dma_private::DmaSupportTx>(instance: &mut I) {
+    if wait_on_drop(|| {
+        (instance.tx().is_done() && !instance.peripheral_continue_dma())
+            || instance.tx().has_error()
+    }) {
+        instance.peripheral_wait_dma(true, false);
+        // nobody is interested in the result anymore
+        instance.peripheral_error();
+    } else {
+        instance.tx().stop_transfer();
+        instance.peripheral_dma_stop();
+        instance.peripheral_mark_needs_reset();
+        instance.tx().clear_interrupts();
+    }
+}
+
+fn drop_rx<I: dma_private::DmaSupportRx>(instance: &mut I) {
+    if wait_on_drop(|| instance.rx().is_done() || instance.rx().has_error()) {
+        instance.peripheral_wait_dma(false, true);
+        instance.rx_complete();
+    } else {
+        instance.rx().stop_transfer();
+        instance.peripheral_dma_stop();
+        instance.peripheral_mark_needs_reset();
+        instance.rx().clear_interrupts();
+    }
+}
+
+fn drop_tx_rx<I: dma_private::DmaSupportTx + dma_private::DmaSupportRx>(instance: &mut I) {
+    if wait_on_drop(|| {
+        (instance.tx().is_done() && instance.rx().is_done() && !instance.peripheral_continue_dma())
+            || instance.tx().has_error()
+            || instance.rx().has_error()
+    }) {
+        instance.peripheral_wait_dma(true, true);
+        instance.rx_complete();
+        // nobody is interested in the result anymore
+        instance.peripheral_error();
+    } else {
+        instance.tx().stop_transfer();
+        instance.rx().stop_transfer();
+        instance.peripheral_dma_stop();
+        instance.peripheral_mark_needs_reset();
+        instance.tx().clear_interrupts();
+        instance.rx().clear_interrupts();
     }
 }
 
@@ -1688,16 +4062,63 @@ where
     pub fn wait(self) -> Result<(), DmaError> {
         self.instance.peripheral_wait_dma(true, false);
 
-        if self.instance.tx().has_error() {
-            Err(DmaError::DescriptorError)
-        } else {
-            Ok(())
-        }
+        let result = self.instance.tx_result();
+
+        // don't wait for the transfer again in `Drop`
+        core::mem::forget(self);
+
+        result
     }
 
     /// Check if the transfer is finished.
+    ///
+    /// For a transfer which the peripheral splits into multiple transactions
+    /// this also starts the next transaction once the previous one finished,
+    /// so it has to be polled for the transfer to make progress.
     pub fn is_done(&mut self) -> bool {
-        self.instance.tx().is_done()
+        self.instance.tx().is_done() && !self.instance.peripheral_continue_dma()
+    }
+
+    /// Returns the number of bytes sent so far.
+    ///
+    /// This is only accurate to the granularity of the descriptors: the bytes
+    /// of a descriptor are counted once the DMA moved on to the next one. It
+    /// can be called repeatedly while the transfer is running and returns the
+    /// length of the transfer once it's done.
+    pub fn transferred_bytes(&mut self) -> usize {
+        if self.instance.tx().is_done() {
+            return self.instance.chain().linked_len();
+        }
+
+        let address = self.instance.tx().current_descriptor_address();
+        self.instance.chain().bytes_before(address).unwrap_or(0)
+    }
+
+    /// Wait for the transfer to finish, giving up after `timeout`.
+    ///
+    /// If [DmaError::Timeout] is returned the transfer is still in progress
+    /// and can be waited for again or aborted.
+    pub fn wait_with_timeout(&mut self, timeout: MicrosDurationU64) -> Result<(), DmaError> {
+        wait_until_done(timeout, || {
+            (self.instance.tx().is_done() && !self.instance.peripheral_continue_dma())
+                || self.instance.tx().has_error()
+        })?;
+        self.instance.peripheral_wait_dma(true, false);
+
+        self.instance.tx_result()
+    }
+
+    /// Abort the transfer without waiting for it to finish.
+    ///
+    /// The DMA channel and the peripheral are stopped, data which wasn't
+    /// transferred yet is discarded.
+    pub fn abort(self) {
+        self.instance.tx().stop_transfer();
+        self.instance.peripheral_dma_stop();
+        self.instance.tx().clear_interrupts();
+
+        // don't wait for the transfer in `Drop`
+        core::mem::forget(self);
     }
 }
 
@@ -1706,7 +4127,7 @@ where
     I: dma_private::DmaSupportTx,
 {
     fn drop(&mut self) {
-        self.instance.peripheral_wait_dma(true, false);
+        drop_tx(self.instance);
     }
 }
 
@@ -1731,18 +4152,71 @@ where
     /// Wait for the transfer to finish.
     pub fn wait(self) -> Result<(), DmaError> {
         self.instance.peripheral_wait_dma(false, true);
+        self.instance.rx_complete();
 
-        if self.instance.rx().has_error() {
-            Err(DmaError::DescriptorError)
-        } else {
-            Ok(())
-        }
+        let result = self.instance.rx_result();
+
+        // don't wait for the transfer again in `Drop`
+        core::mem::forget(self);
+
+        result
     }
 
     /// Check if the transfer is finished.
     pub fn is_done(&mut self) -> bool {
         self.instance.rx().is_done()
     }
+
+    /// Returns the number of bytes received so far.
+    ///
+    /// This is only accurate to the granularity of the descriptors: the bytes
+    /// of a descriptor are counted once the DMA moved on to the next one. It
+    /// can be called repeatedly while the transfer is running and returns the
+    /// same as [DmaTransferRx::received_bytes] once it's done.
+    pub fn transferred_bytes(&mut self) -> usize {
+        if self.instance.rx().is_done() {
+            return self.instance.chain().received_bytes();
+        }
+
+        let address = self.instance.rx().current_descriptor_address();
+        self.instance.chain().bytes_before(address).unwrap_or(0)
+    }
+
+    /// Returns the number of bytes received.
+    ///
+    /// A peripheral can end the transfer before the buffer is filled, e.g. an
+    /// SPI slave transaction shorter than the buffer. In this case this is
+    /// the length of the data actually received.
+    pub fn received_bytes(&mut self) -> usize {
+        self.instance.chain().received_bytes()
+    }
+
+    /// Wait for the transfer to finish, giving up after `timeout`.
+    ///
+    /// If [DmaError::Timeout] is returned the transfer is still in progress
+    /// and can be waited for again or aborted.
+    pub fn wait_with_timeout(&mut self, timeout: MicrosDurationU64) -> Result<(), DmaError> {
+        wait_until_done(timeout, || {
+            self.instance.rx().is_done() || self.instance.rx().has_error()
+        })?;
+        self.instance.peripheral_wait_dma(false, true);
+        self.instance.rx_complete();
+
+        self.instance.rx_result()
+    }
+
+    /// Abort the transfer without waiting for it to finish.
+    ///
+    /// The DMA channel and the peripheral are stopped, data which wasn't
+    /// transferred yet is discarded.
+    pub fn abort(self) {
+        self.instance.rx().stop_transfer();
+        self.instance.peripheral_dma_stop();
+        self.instance.rx().clear_interrupts();
+
+        // don't wait for the transfer in `Drop`
+        core::mem::forget(self);
+    }
 }
 
 impl<'a, I> Drop for DmaTransferRx<'a, I>
@@ -1750,7 +4224,7 @@ where
     I: dma_private::DmaSupportRx,
 {
     fn drop(&mut self) {
-        self.instance.peripheral_wait_dma(false, true);
+        drop_rx(self.instance);
     }
 }
 
@@ -1775,17 +4249,67 @@ where
     /// Wait for the transfer to finish.
     pub fn wait(self) -> Result<(), DmaError> {
         self.instance.peripheral_wait_dma(true, true);
+        self.instance.rx_complete();
 
-        if self.instance.tx().has_error() || self.instance.rx().has_error() {
-            Err(DmaError::DescriptorError)
-        } else {
-            Ok(())
-        }
+        let result = tx_rx_result(self.instance);
+
+        // don't wait for the transfer again in `Drop`
+        core::mem::forget(self);
+
+        result
     }
 
     /// Check if the transfer is finished.
+    ///
+    /// For a transfer which the peripheral splits into multiple transactions
+    /// this also starts the next transaction once the previous one finished,
+    /// so it has to be polled for the transfer to make progress.
     pub fn is_done(&mut self) -> bool {
-        self.instance.tx().is_done() && self.instance.rx().is_done()
+        self.instance.tx().is_done()
+            && self.instance.rx().is_done()
+            && !self.instance.peripheral_continue_dma()
+    }
+
+    /// Returns the number of bytes received.
+    ///
+    /// A peripheral can end the transfer before the buffer is filled, e.g. an
+    /// SPI slave transaction shorter than the buffer. In this case this is
+    /// the length of the data actually received.
+    pub fn received_bytes(&mut self) -> usize {
+        dma_private::DmaSupportRx::chain(&mut *self.instance).received_bytes()
+    }
+
+    /// Wait for the transfer to finish, giving up after `timeout`.
+    ///
+    /// If [DmaError::Timeout] is returned the transfer is still in progress
+    /// and can be waited for again or aborted.
+    pub fn wait_with_timeout(&mut self, timeout: MicrosDurationU64) -> Result<(), DmaError> {
+        wait_until_done(timeout, || {
+            (self.instance.tx().is_done()
+                && self.instance.rx().is_done()
+                && !self.instance.peripheral_continue_dma())
+                || self.instance.tx().has_error()
+                || self.instance.rx().has_error()
+        })?;
+        self.instance.peripheral_wait_dma(true, true);
+        self.instance.rx_complete();
+
+        tx_rx_result(&mut *self.instance)
+    }
+
+    /// Abort the transfer without waiting for it to finish.
+    ///
+    /// The DMA channel and the peripheral are stopped, data which wasn't
+    /// transferred yet is discarded.
+    pub fn abort(self) {
+        self.instance.tx().stop_transfer();
+        self.instance.rx().stop_transfer();
+        self.instance.peripheral_dma_stop();
+        self.instance.tx().clear_interrupts();
+        self.instance.rx().clear_interrupts();
+
+        // don't wait for the transfer in `Drop`
+        core::mem::forget(self);
     }
 }
 
@@ -1794,18 +4318,19 @@ where
     I: dma_private::DmaSupportTx + dma_private::DmaSupportRx,
 {
     fn drop(&mut self) {
-        self.instance.peripheral_wait_dma(true, true);
+        drop_tx_rx(self.instance);
     }
 }
 
 /// DMA transaction for TX transfers with moved-in/moved-out peripheral and
 /// buffer
+///
+/// For scattered transfers the buffer is the array holding all parts.
 #[non_exhaustive]
 #[must_use]
 pub struct DmaTransferTxOwned<I, T>
 where
     I: dma_private::DmaSupportTx,
-    T: ReadBuffer<Word = u8>,
 {
     instance: I,
     tx_buffer: T,
@@ -1814,7 +4339,6 @@ where
 impl<I, T> DmaTransferTxOwned<I, T>
 where
     I: dma_private::DmaSupportTx,
-    T: ReadBuffer<Word = u8>,
 {
     pub(crate) fn new(instance: I, tx_buffer: T) -> Self {
         Self {
@@ -1828,7 +4352,7 @@ where
     pub fn wait(mut self) -> Result<(I, T), (DmaError, I, T)> {
         self.instance.peripheral_wait_dma(true, false);
 
-        let err = self.instance.tx().has_error();
+        let err = self.instance.tx_result().err();
 
         // We need to have a `Drop` implementation, because we accept
         // managed buffers that can free their memory on drop. Because of that
@@ -1846,26 +4370,69 @@ where
             (instance, tx_buffer)
         };
 
-        if err {
-            Err((DmaError::DescriptorError, instance, tx_buffer))
+        if let Some(err) = err {
+            Err((err, instance, tx_buffer))
         } else {
             Ok((instance, tx_buffer))
         }
     }
 
     /// Check if the transfer is finished.
+    ///
+    /// For a transfer which the peripheral splits into multiple transactions
+    /// this also starts the next transaction once the previous one finished,
+    /// so it has to be polled for the transfer to make progress.
     pub fn is_done(&mut self) -> bool {
-        self.instance.tx().is_done()
+        self.instance.tx().is_done() && !self.instance.peripheral_continue_dma()
+    }
+
+    /// Wait for the transfer to finish, giving up after `timeout`.
+    ///
+    /// If [DmaError::Timeout] is returned the transfer is still in progress
+    /// and can be waited for again or aborted.
+    pub fn wait_with_timeout(&mut self, timeout: MicrosDurationU64) -> Result<(), DmaError> {
+        wait_until_done(timeout, || {
+            (self.instance.tx().is_done() && !self.instance.peripheral_continue_dma())
+                || self.instance.tx().has_error()
+        })?;
+        self.instance.peripheral_wait_dma(true, false);
+
+        self.instance.tx_result()
+    }
+
+    /// Abort the transfer without waiting for it to finish.
+    ///
+    /// The DMA channel and the peripheral are stopped, data which wasn't
+    /// transferred yet is discarded. Returns the peripheral and the buffer.
+    pub fn abort(mut self) -> (I, T) {
+        self.instance.tx().stop_transfer();
+        self.instance.peripheral_dma_stop();
+        self.instance.tx().clear_interrupts();
+
+        // We need to have a `Drop` implementation, because we accept
+        // managed buffers that can free their memory on drop. Because of that
+        // we can't move out of the `Transfer`'s fields, so we use `ptr::read`
+        // and `mem::forget`.
+        //
+        // NOTE(unsafe) There is no panic branch between getting the resources
+        // and forgetting `self`.
+
+        unsafe {
+            let instance = core::ptr::read(&self.instance);
+            let tx_buffer = core::ptr::read(&self.tx_buffer);
+            core::mem::forget(self);
+
+            (instance, tx_buffer)
+        }
     }
 }
 
 impl<I, T> Drop for DmaTransferTxOwned<I, T>
 where
     I: dma_private::DmaSupportTx,
-    T: ReadBuffer<Word = u8>,
 {
     fn drop(&mut self) {
-        self.instance.peripheral_wait_dma(true, false);
+        drop_tx(&mut self.instance);
     }
 }
 
@@ -1898,8 +4465,9 @@ where
     /// buffers.
     pub fn wait(mut self) -> Result<(I, R), (DmaError, I, R)> {
         self.instance.peripheral_wait_dma(false, true);
+        self.instance.rx_complete();
 
-        let err = self.instance.rx().has_error();
+        let err = self.instance.rx_result().err();
 
         // We need to have a `Drop` implementation, because we accept
         // managed buffers that can free their memory on drop. Because of that
@@ -1917,8 +4485,8 @@ where
             (instance, rx_buffer)
         };
 
-        if err {
-            Err((DmaError::DescriptorError, instance, rx_buffer))
+        if let Some(err) = err {
+            Err((err, instance, rx_buffer))
         } else {
             Ok((instance, rx_buffer))
         }
@@ -1928,6 +4496,55 @@ where
     pub fn is_done(&mut self) -> bool {
         self.instance.rx().is_done()
     }
+
+    /// Returns the number of bytes received.
+    ///
+    /// A peripheral can end the transfer before the buffer is filled, e.g. an
+    /// SPI slave transaction shorter than the buffer. In this case this is
+    /// the length of the data actually received.
+    pub fn received_bytes(&mut self) -> usize {
+        self.instance.chain().received_bytes()
+    }
+
+    /// Wait for the transfer to finish, giving up after `timeout`.
+    ///
+    /// If [DmaError::Timeout] is returned the transfer is still in progress
+    /// and can be waited for again or aborted.
+    pub fn wait_with_timeout(&mut self, timeout: MicrosDurationU64) -> Result<(), DmaError> {
+        wait_until_done(timeout, || {
+            self.instance.rx().is_done() || self.instance.rx().has_error()
+        })?;
+        self.instance.peripheral_wait_dma(false, true);
+        self.instance.rx_complete();
+
+        self.instance.rx_result()
+    }
+
+    /// Abort the transfer without waiting for it to finish.
+    ///
+    /// The DMA channel and the peripheral are stopped, data which wasn't
+    /// transferred yet is discarded. Returns the peripheral and the buffer.
+    pub fn abort(mut self) -> (I, R) {
+        self.instance.rx().stop_transfer();
+        self.instance.peripheral_dma_stop();
+        self.instance.rx().clear_interrupts();
+
+        // We need to have a `Drop` implementation, because we accept
+        // managed buffers that can free their memory on drop. Because of that
+        // we can't move out of the `Transfer`'s fields, so we use `ptr::read`
+        // and `mem::forget`.
+        //
+        // NOTE(unsafe) There is no panic branch between getting the resources
+        // and forgetting `self`.
+
+        unsafe {
+            let instance = core::ptr::read(&self.instance);
+            let rx_buffer = core::ptr::read(&self.rx_buffer);
+            core::mem::forget(self);
+
+            (instance, rx_buffer)
+        }
+    }
 }
 
 impl<I, R> Drop for DmaTransferRxOwned<I, R>
@@ -1936,7 +4553,7 @@ where
     R: WriteBuffer<Word = u8>,
 {
     fn drop(&mut self) {
-        self.instance.peripheral_wait_dma(false, true);
+        drop_rx(&mut self.instance);
     }
 }
 
@@ -1974,8 +4591,9 @@ where
     #[allow(clippy::type_complexity)]
     pub fn wait(mut self) -> Result<(I, T, R), (DmaError, I, T, R)> {
         self.instance.peripheral_wait_dma(true, true);
+        self.instance.rx_complete();
 
-        let err = self.instance.tx().has_error() || self.instance.rx().has_error();
+        let err = tx_rx_result(&mut self.instance).err();
 
         // We need to have a `Drop` implementation, because we accept
         // managed buffers that can free their memory on drop. Because of that
@@ -1994,16 +4612,78 @@ where
             (instance, tx_buffer, rx_buffer)
         };
 
-        if err {
-            Err((DmaError::DescriptorError, instance, tx_buffer, rx_buffer))
+        if let Some(err) = err {
+            Err((err, instance, tx_buffer, rx_buffer))
         } else {
             Ok((instance, tx_buffer, rx_buffer))
         }
     }
 
     /// Check if the transfer is finished.
+    ///
+    /// For a transfer which the peripheral splits into multiple transactions
+    /// this also starts the next transaction once the previous one finished,
+    /// so it has to be polled for the transfer to make progress.
     pub fn is_done(&mut self) -> bool {
-        self.instance.tx().is_done() && self.instance.rx().is_done()
+        self.instance.tx().is_done()
+            && self.instance.rx().is_done()
+            && !self.instance.peripheral_continue_dma()
+    }
+
+    /// Returns the number of bytes received.
+    ///
+    /// A peripheral can end the transfer before the buffer is filled, e.g. an
+    /// SPI slave transaction shorter than the buffer. In this case this is
+    /// the length of the data actually received.
+    pub fn received_bytes(&mut self) -> usize {
+        dma_private::DmaSupportRx::chain(&mut self.instance).received_bytes()
+    }
+
+    /// Wait for the transfer to finish, giving up after `timeout`.
+    ///
+    /// If [DmaError::Timeout] is returned the transfer is still in progress
+    /// and can be waited for again or aborted.
+    pub fn wait_with_timeout(&mut self, timeout: MicrosDurationU64) -> Result<(), DmaError> {
+        wait_until_done(timeout, || {
+            (self.instance.tx().is_done()
+                && self.instance.rx().is_done()
+                && !self.instance.peripheral_continue_dma())
+                || self.instance.tx().has_error()
+                || self.instance.rx().has_error()
+        })?;
+        self.instance.peripheral_wait_dma(true, true);
+        self.instance.rx_complete();
+
+        tx_rx_result(&mut self.instance)
+    }
+
+    /// Abort the transfer without waiting for it to finish.
+    ///
+    /// The DMA channel and the peripheral are stopped, data which wasn't
+    /// transferred yet is discarded. Returns the peripheral and the buffers.
+    pub fn abort(mut self) -> (I, T, R) {
+        self.instance.tx().stop_transfer();
+        self.instance.rx().stop_transfer();
+        self.instance.peripheral_dma_stop();
+        self.instance.tx().clear_interrupts();
+        self.instance.rx().clear_interrupts();
+
+        // We need to have a `Drop` implementation, because we accept
+        // managed buffers that can free their memory on drop. Because of that
+        // we can't move out of the `Transfer`'s fields, so we use `ptr::read`
+        // and `mem::forget`.
+        //
+        // NOTE(unsafe) There is no panic branch between getting the resources
+        // and forgetting `self`.
+
+        unsafe {
+            let instance = core::ptr::read(&self.instance);
+            let tx_buffer = core::ptr::read(&self.tx_buffer);
+            let rx_buffer = core::ptr::read(&self.rx_buffer);
+            core::mem::forget(self);
+
+            (instance, tx_buffer, rx_buffer)
+        }
     }
 }
 
@@ -2014,7 +4694,7 @@ where
     R: WriteBuffer<Word = u8>,
 {
     fn drop(&mut self) {
-        self.instance.peripheral_wait_dma(true, true);
+        drop_tx_rx(&mut self.instance);
     }
 }
 
@@ -2039,6 +4719,12 @@ where
         Self { instance, state }
     }
 
+    /// Continues a paused transfer, see [TxCircularState::restore].
+    #[cfg(any(i2s0, i2s1))]
+    pub(crate) fn resume(instance: &'a mut I, state: TxCircularState) -> Self {
+        Self { instance, state }
+    }
+
     /// Amount of bytes which can be pushed.
     pub fn available(&mut self) -> usize {
         self.state.update(self.instance.tx());
@@ -2046,11 +4732,22 @@ where
     }
 
     /// Push bytes into the DMA buffer.
+    ///
+    /// Fails with [DmaError::Overflow] if not all bytes fit into the buffer.
     pub fn push(&mut self, data: &[u8]) -> Result<usize, DmaError> {
         self.state.update(self.instance.tx());
         self.state.push(data)
     }
 
+    /// Push as many bytes into the DMA buffer as currently fit.
+    ///
+    /// Returns the number of bytes written, which is less than `data.len()`
+    /// if the buffer is (momentarily) full.
+    pub fn push_partial(&mut self, data: &[u8]) -> Result<usize, DmaError> {
+        self.state.update(self.instance.tx());
+        self.state.push_partial(data)
+    }
+
     /// Push bytes into the DMA buffer via the given closure.
     /// The closure *must* return the actual number of bytes written.
     /// The closure *might* get called with a slice which is smaller than the
@@ -2060,13 +4757,121 @@ where
         self.state.push_with(f)
     }
 
+    /// Push all bytes into the DMA buffer, waiting for space to become
+    /// available.
+    ///
+    /// The DMA channel needs to be configured for async operation.
+    #[cfg(feature = "async")]
+    pub async fn push_async(&mut self, data: &[u8]) -> Result<usize, DmaError> {
+        let mut written = 0;
+        while written < data.len() {
+            self.state.update(self.instance.tx());
+
+            if self.state.available == 0 {
+                let chain = self.instance.chain().span();
+                asynch::DmaTxDoneChFuture::new(self.instance.tx())
+                    .with_chain(chain)
+                    .await?;
+                continue;
+            }
+
+            written += self.state.push_partial(&data[written..])?;
+        }
+
+        Ok(written)
+    }
+
+    /// Returns the descriptor the DMA finished sending most recently, if any
+    /// descriptor was finished since the last call.
+    ///
+    /// The buffer of the completed descriptor can be refilled while the DMA
+    /// sends the following descriptors, e.g. to refill the part of the buffer
+    /// which was played while the rest is played. A circular buffer has at
+    /// least [CIRCULAR_MIN_DESCRIPTORS] descriptors, so it's at least split in
+    /// three parts.
+    pub fn completed_descriptor(&mut self) -> Option<CompletedDescriptor> {
+        self.state.update(self.instance.tx());
+        self.state.take_completed()
+    }
+
+    /// Returns whether the DMA ran out of pushed data since the last call
+    /// and started sending old data again.
+    ///
+    /// The DMA is only checked when the transfer is used, so this has to be
+    /// called (or data pushed) at least once per pass through the buffer to
+    /// notice every underrun.
+    pub fn take_underrun(&mut self) -> bool {
+        self.state.update(self.instance.tx());
+        self.state.take_underrun()
+    }
+
+    /// Sets what is sent after an underrun, the data in the buffer is sent
+    /// again by default.
+    ///
+    /// With [Fill::Zero] the data is cleared after it was sent, so zeros are
+    /// sent instead of old data when the producer falls behind. Data which
+    /// was sent before calling this isn't cleared.
+    pub fn on_underrun(&mut self, fill: Fill) {
+        self.state.update(self.instance.tx());
+        self.state.set_fill(fill);
+    }
+
+    /// Waits until the DMA finished sending a descriptor.
+    ///
+    /// Descriptors which were completed since the last call are reported as
+    /// [CompletedDescriptor::skipped].
+    ///
+    /// The DMA channel needs to be configured for async operation.
+    #[cfg(feature = "async")]
+    pub async fn wait_for_descriptor(&mut self) -> Result<CompletedDescriptor, DmaError> {
+        loop {
+            self.state.update(self.instance.tx());
+
+            if let Some(completed) = self.state.take_completed() {
+                return Ok(completed);
+            }
+
+            let chain = self.instance.chain().span();
+            asynch::DmaTxDoneChFuture::new(self.instance.tx())
+                .with_chain(chain)
+                .await?;
+        }
+    }
+
+    /// Returns the current state of the transfer, e.g. for logging.
+    pub fn debug_state(&mut self) -> CircularSnapshot {
+        self.state.update(self.instance.tx());
+        self.state.snapshot()
+    }
+
+    /// Stop the DMA transfer and return its state, to resume it later with
+    /// the data which wasn't sent yet.
+    ///
+    /// The DMA reports its progress per descriptor, so the data of the
+    /// descriptor it was sending is sent again in full when the transfer is
+    /// resumed. Data the peripheral already took from the DMA, like the
+    /// content of its FIFO, is lost. The pushed data which wasn't sent yet is
+    /// kept, unlike when restarting the transfer.
+    pub fn pause(mut self) -> Result<CircularSnapshot, DmaError> {
+        self.state.update(self.instance.tx());
+        let snapshot = self.state.snapshot();
+
+        self.instance.peripheral_dma_stop();
+
+        if let Some(err) = self.instance.tx_error() {
+            Err(err)
+        } else {
+            Ok(snapshot)
+        }
+    }
+
     /// Stop the DMA transfer
     #[allow(clippy::type_complexity)]
     pub fn stop(self) -> Result<(), DmaError> {
         self.instance.peripheral_dma_stop();
 
-        if self.instance.tx().has_error() {
-            Err(DmaError::DescriptorError)
+        if let Some(err) = self.instance.tx_error() {
+            Err(err)
         } else {
             Ok(())
         }
@@ -2107,9 +4912,13 @@ where
     ///
     /// It's expected to call this before trying to [DmaTransferRxCircular::pop]
     /// data.
-    pub fn available(&mut self) -> usize {
-        self.state.update();
-        self.state.available
+    ///
+    /// Fails with [DmaError::BufferOverrun] if data got lost because it wasn't
+    /// read fast enough. The unread data is discarded and the transfer
+    /// continues with the next data received.
+    pub fn available(&mut self) -> Result<usize, DmaError> {
+        self.state.update()?;
+        Ok(self.state.available)
     }
 
     /// Get available data.
@@ -2119,11 +4928,74 @@ where
     /// all available data.
     ///
     /// Fails with [DmaError::BufferTooSmall] if the given buffer is too small
-    /// to hold all available data
+    /// to hold all available data and with [DmaError::BufferOverrun] if data
+    /// got lost because it wasn't read fast enough.
     pub fn pop(&mut self, data: &mut [u8]) -> Result<usize, DmaError> {
-        self.state.update();
+        self.state.update()?;
         self.state.pop(data)
     }
+
+    /// Get available data without copying it.
+    ///
+    /// The closure is called with the largest contiguous region of received
+    /// data and *must* return the number of bytes it consumed. The closure
+    /// *might* get called with a slice which is smaller than the total
+    /// available data.
+    ///
+    /// Fails with [DmaError::BufferOverrun] if data got lost because it wasn't
+    /// read fast enough.
+    pub fn pop_with(&mut self, f: impl FnOnce(&[u8]) -> usize) -> Result<usize, DmaError> {
+        self.state.update()?;
+        self.state.pop_with(f)
+    }
+
+    /// Get available data converted to samples.
+    ///
+    /// Pops as many whole samples as are available and fit into `out` and
+    /// returns their number. Each byte is only read once, e.g.
+    /// `pop_samples::<Left<I24>>` takes the 24 bit samples of the left channel
+    /// out of stereo 32 bit slots.
+    ///
+    /// Fails with [DmaError::BufferOverrun] if data got lost because it wasn't
+    /// read fast enough.
+    pub fn pop_samples<S: Sample>(&mut self, out: &mut [S]) -> Result<usize, DmaError> {
+        self.state.update()?;
+        self.state.pop_samples(out)
+    }
+
+    /// Get available data, waiting for at least one byte to be received.
+    ///
+    /// In contrast to [DmaTransferRxCircular::pop] the given buffer doesn't
+    /// need to be able to hold all available data.
+    ///
+    /// The DMA channel needs to be configured for async operation.
+    #[cfg(feature = "async")]
+    pub async fn pop_async(&mut self, data: &mut [u8]) -> Result<usize, DmaError> {
+        loop {
+            self.state.update()?;
+
+            if self.state.available > 0 {
+                break;
+            }
+
+            let chain = self.instance.chain().span();
+            asynch::DmaRxDoneChFuture::new(self.instance.rx())
+                .with_chain(chain)
+                .await?;
+        }
+
+        let mut received = 0;
+        while received < data.len() && self.state.available > 0 {
+            let offset = received;
+            received += self.state.pop_with(|buffer| {
+                let len = usize::min(buffer.len(), data.len() - offset);
+                data[offset..][..len].copy_from_slice(&buffer[..len]);
+                len
+            })?;
+        }
+
+        Ok(received)
+    }
 }
 
 impl<'a, I> Drop for DmaTransferRxCircular<'a, I>
@@ -2135,18 +5007,155 @@ where
     }
 }
 
+/// DMA transaction for circular transfers in both directions at the same
+/// time
+///
+/// Both directions run continuously, data is pushed for sending and popped
+/// after receiving like with [DmaTransferTxCircular] and
+/// [DmaTransferRxCircular].
+#[non_exhaustive]
+#[must_use]
+pub struct DmaTransferTxRxCircular<'a, I>
+where
+    I: dma_private::DmaSupportTx + dma_private::DmaSupportRx,
+{
+    instance: &'a mut I,
+    tx_state: TxCircularState,
+    rx_state: RxCircularState,
+}
+
+impl<'a, I> DmaTransferTxRxCircular<'a, I>
+where
+    I: dma_private::DmaSupportTx + dma_private::DmaSupportRx,
+{
+    #[allow(unused)] // currently used by peripherals not available on all chips
+    pub(crate) fn new(instance: &'a mut I) -> Self {
+        let tx_state = TxCircularState::new(dma_private::DmaSupportTx::chain(instance));
+        let rx_state = RxCircularState::new(dma_private::DmaSupportRx::chain(instance));
+        Self {
+            instance,
+            tx_state,
+            rx_state,
+        }
+    }
+
+    /// Amount of bytes which can be pushed.
+    pub fn available_tx(&mut self) -> usize {
+        self.tx_state.update(self.instance.tx());
+        self.tx_state.available
+    }
+
+    /// Push bytes into the TX DMA buffer.
+    ///
+    /// Fails with [DmaError::Overflow] if not all bytes fit into the buffer.
+    pub fn push(&mut self, data: &[u8]) -> Result<usize, DmaError> {
+        self.tx_state.update(self.instance.tx());
+        self.tx_state.push(data)
+    }
+
+    /// Push bytes into the TX DMA buffer via the given closure, see
+    /// [DmaTransferTxCircular::push_with].
+    pub fn push_with(&mut self, f: impl FnOnce(&mut [u8]) -> usize) -> Result<usize, DmaError> {
+        self.tx_state.update(self.instance.tx());
+        self.tx_state.push_with(f)
+    }
+
+    /// Amount of bytes which can be popped.
+    ///
+    /// Fails with [DmaError::BufferOverrun] if received data got lost because
+    /// it wasn't read fast enough, see [DmaTransferRxCircular::available].
+    pub fn available_rx(&mut self) -> Result<usize, DmaError> {
+        self.rx_state.update()?;
+        Ok(self.rx_state.available)
+    }
+
+    /// Get available data, see [DmaTransferRxCircular::pop].
+    pub fn pop(&mut self, data: &mut [u8]) -> Result<usize, DmaError> {
+        self.rx_state.update()?;
+        self.rx_state.pop(data)
+    }
+
+    /// Get available data without copying it, see
+    /// [DmaTransferRxCircular::pop_with].
+    pub fn pop_with(&mut self, f: impl FnOnce(&[u8]) -> usize) -> Result<usize, DmaError> {
+        self.rx_state.update()?;
+        self.rx_state.pop_with(f)
+    }
+
+    /// Get available data converted to samples, see
+    /// [DmaTransferRxCircular::pop_samples].
+    pub fn pop_samples<S: Sample>(&mut self, out: &mut [S]) -> Result<usize, DmaError> {
+        self.rx_state.update()?;
+        self.rx_state.pop_samples(out)
+    }
+
+    /// Stop the DMA transfer in both directions
+    pub fn stop(self) -> Result<(), DmaError> {
+        self.instance.peripheral_dma_stop();
+
+        if let Some(err) = self.instance.tx_error() {
+            Err(err)
+        } else if let Some(err) = self.instance.rx_error() {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'a, I> Drop for DmaTransferTxRxCircular<'a, I>
+where
+    I: dma_private::DmaSupportTx + dma_private::DmaSupportRx,
+{
+    fn drop(&mut self) {
+        self.instance.peripheral_dma_stop();
+    }
+}
+
 #[cfg(feature = "async")]
 pub(crate) mod asynch {
     use core::task::Poll;
 
     use super::*;
 
+    /// Runs a function when it's dropped, unless it was defused.
+    ///
+    /// Drivers use it to stop the peripheral if the future waiting for its
+    /// DMA transfer is dropped before the transfer finished.
+    pub struct OnDrop<F: FnOnce()>(Option<F>);
+
+    impl<F: FnOnce()> OnDrop<F> {
+        pub fn new(f: F) -> Self {
+            Self(Some(f))
+        }
+
+        /// Don't run the function, the transfer finished.
+        pub fn defuse(mut self) {
+            self.0 = None;
+        }
+    }
+
+    impl<F: FnOnce()> Drop for OnDrop<F> {
+        fn drop(&mut self) {
+            if let Some(f) = self.0.take() {
+                f();
+            }
+        }
+    }
+
+    /// Waits for a transfer to finish.
+    ///
+    /// Dropping the future before the transfer finished stops the DMA
+    /// channel, so the buffer is no longer accessed and the channel can be
+    /// used for the next transfer. The driver stops the peripheral with an
+    /// [OnDrop] guard.
     pub struct DmaTxFuture<'a, TX>
     where
         TX: Tx,
     {
         pub(crate) tx: &'a mut TX,
-        _a: (),
+        finished: bool,
+        chain: Option<DescriptorSpan>,
     }
 
     impl<'a, TX> DmaTxFuture<'a, TX>
@@ -2154,11 +5163,18 @@ pub(crate) mod asynch {
         TX: Tx,
     {
         pub fn new(tx: &'a mut TX) -> Self {
-            Self { tx, _a: () }
+            Self {
+                tx,
+                finished: false,
+                chain: None,
+            }
         }
 
-        pub fn tx(&mut self) -> &mut TX {
-            self.tx
+        /// Reports descriptor errors with the index of the rejected
+        /// descriptor in `chain`, see [DmaError::DescriptorErrorAt].
+        pub(crate) fn with_chain(mut self, chain: DescriptorSpan) -> Self {
+            self.chain = Some(chain);
+            self
         }
     }
 
@@ -2172,16 +5188,25 @@ pub(crate) mod asynch {
             self: core::pin::Pin<&mut Self>,
             cx: &mut core::task::Context<'_>,
         ) -> Poll<Self::Output> {
-            TX::waker().register(cx.waker());
-            if self.tx.is_done() {
-                self.tx.clear_interrupts();
+            let this = self.get_mut();
+            this.tx.waker().register(cx.waker());
+
+            // Enable the interrupts before checking the status. Otherwise the
+            // transfer could finish after the check but before the interrupts
+            // are enabled, and the waker would never be woken.
+            this.tx.listen_eof();
+            this.tx.listen_out_descriptor_error();
+
+            if this.tx.is_done() {
+                this.tx.clear_interrupts();
+                this.finished = true;
                 Poll::Ready(Ok(()))
-            } else if self.tx.has_error() {
-                self.tx.clear_interrupts();
-                Poll::Ready(Err(DmaError::DescriptorError))
+            } else if this.tx.has_error() {
+                let error = tx_error(&*this.tx, this.chain);
+                this.tx.clear_interrupts();
+                this.finished = true;
+                Poll::Ready(Err(error))
             } else {
-                self.tx.listen_eof();
-                self.tx.listen_out_descriptor_error();
                 Poll::Pending
             }
         }
@@ -2194,15 +5219,27 @@ pub(crate) mod asynch {
         fn drop(&mut self) {
             self.tx.unlisten_eof();
             self.tx.unlisten_out_descriptor_error();
+
+            if !self.finished {
+                self.tx.stop_transfer();
+                self.tx.clear_interrupts();
+            }
         }
     }
 
+    /// Waits for a transfer to finish.
+    ///
+    /// Dropping the future before the transfer finished stops the DMA
+    /// channel, so the buffer is no longer accessed and the channel can be
+    /// used for the next transfer. The driver stops the peripheral with an
+    /// [OnDrop] guard.
     pub struct DmaRxFuture<'a, RX>
     where
         RX: Rx,
     {
         pub(crate) rx: &'a mut RX,
-        _a: (),
+        finished: bool,
+        chain: Option<DescriptorSpan>,
     }
 
     impl<'a, RX> DmaRxFuture<'a, RX>
@@ -2210,11 +5247,18 @@ pub(crate) mod asynch {
         RX: Rx,
     {
         pub fn new(rx: &'a mut RX) -> Self {
-            Self { rx, _a: () }
+            Self {
+                rx,
+                finished: false,
+                chain: None,
+            }
         }
 
-        pub fn rx(&mut self) -> &mut RX {
-            self.rx
+        /// Reports descriptor errors with the index of the rejected
+        /// descriptor in `chain`, see [DmaError::DescriptorErrorAt].
+        pub(crate) fn with_chain(mut self, chain: DescriptorSpan) -> Self {
+            self.chain = Some(chain);
+            self
         }
     }
 
@@ -2228,21 +5272,30 @@ pub(crate) mod asynch {
             self: core::pin::Pin<&mut Self>,
             cx: &mut core::task::Context<'_>,
         ) -> Poll<Self::Output> {
-            RX::waker().register(cx.waker());
-            if self.rx.is_done() {
-                self.rx.clear_interrupts();
+            let this = self.get_mut();
+            this.rx.waker().register(cx.waker());
+
+            // See `DmaTxFuture` for why the interrupts are enabled first
+            this.rx.listen_eof();
+            this.rx.listen_in_descriptor_error();
+            this.rx.listen_in_descriptor_error_dscr_empty();
+            this.rx.listen_in_descriptor_error_err_eof();
+
+            if this.rx.is_done() {
+                this.rx.clear_interrupts();
+                this.finished = true;
+                #[cfg(esp32s3)]
+                this.rx.invalidate_psram_buffers();
                 Poll::Ready(Ok(()))
-            } else if self.rx.has_error()
-                || self.rx.has_dscr_empty_error()
-                || self.rx.has_eof_error()
+            } else if this.rx.has_error()
+                || this.rx.has_dscr_empty_error()
+                || this.rx.has_eof_error()
             {
-                self.rx.clear_interrupts();
-                Poll::Ready(Err(DmaError::DescriptorError))
+                let error = rx_error(&*this.rx, this.chain);
+                this.rx.clear_interrupts();
+                this.finished = true;
+                Poll::Ready(Err(error))
             } else {
-                self.rx.listen_eof();
-                self.rx.listen_in_descriptor_error();
-                self.rx.listen_in_descriptor_error_dscr_empty();
-                self.rx.listen_in_descriptor_error_err_eof();
                 Poll::Pending
             }
         }
@@ -2257,29 +5310,38 @@ pub(crate) mod asynch {
             self.rx.unlisten_in_descriptor_error();
             self.rx.unlisten_in_descriptor_error_dscr_empty();
             self.rx.unlisten_in_descriptor_error_err_eof();
+
+            if !self.finished {
+                self.rx.stop_transfer();
+                self.rx.clear_interrupts();
+            }
         }
     }
 
-    #[cfg(any(i2s0, i2s1))]
     pub struct DmaTxDoneChFuture<'a, TX>
     where
         TX: Tx,
     {
         pub(crate) tx: &'a mut TX,
-        _a: (),
+        chain: Option<DescriptorSpan>,
     }
 
-    #[cfg(any(i2s0, i2s1))]
     impl<'a, TX> DmaTxDoneChFuture<'a, TX>
     where
         TX: Tx,
     {
         pub fn new(tx: &'a mut TX) -> Self {
-            Self { tx, _a: () }
+            Self { tx, chain: None }
+        }
+
+        /// Reports descriptor errors with the index of the rejected
+        /// descriptor in `chain`, see [DmaError::DescriptorErrorAt].
+        pub(crate) fn with_chain(mut self, chain: DescriptorSpan) -> Self {
+            self.chain = Some(chain);
+            self
         }
     }
 
-    #[cfg(any(i2s0, i2s1))]
     impl<'a, TX> core::future::Future for DmaTxDoneChFuture<'a, TX>
     where
         TX: Tx,
@@ -2290,22 +5352,25 @@ pub(crate) mod asynch {
             self: core::pin::Pin<&mut Self>,
             cx: &mut core::task::Context<'_>,
         ) -> Poll<Self::Output> {
-            TX::waker().register(cx.waker());
+            self.tx.waker().register(cx.waker());
+
+            // See `DmaTxFuture` for why the interrupts are enabled first
+            self.tx.listen_ch_out_done();
+            self.tx.listen_out_descriptor_error();
+
             if self.tx.is_ch_out_done_set() {
                 self.tx.clear_ch_out_done();
                 Poll::Ready(Ok(()))
             } else if self.tx.has_error() {
+                let error = tx_error(&*self.tx, self.chain);
                 self.tx.clear_interrupts();
-                Poll::Ready(Err(DmaError::DescriptorError))
+                Poll::Ready(Err(error))
             } else {
-                self.tx.listen_ch_out_done();
-                self.tx.listen_out_descriptor_error();
                 Poll::Pending
             }
         }
     }
 
-    #[cfg(any(i2s0, i2s1))]
     impl<'a, TX> Drop for DmaTxDoneChFuture<'a, TX>
     where
         TX: Tx,
@@ -2316,26 +5381,30 @@ pub(crate) mod asynch {
         }
     }
 
-    #[cfg(any(i2s0, i2s1))]
     pub struct DmaRxDoneChFuture<'a, RX>
     where
         RX: Rx,
     {
         pub(crate) rx: &'a mut RX,
-        _a: (),
+        chain: Option<DescriptorSpan>,
     }
 
-    #[cfg(any(i2s0, i2s1))]
     impl<'a, RX> DmaRxDoneChFuture<'a, RX>
     where
         RX: Rx,
     {
         pub fn new(rx: &'a mut RX) -> Self {
-            Self { rx, _a: () }
+            Self { rx, chain: None }
+        }
+
+        /// Reports descriptor errors with the index of the rejected
+        /// descriptor in `chain`, see [DmaError::DescriptorErrorAt].
+        pub(crate) fn with_chain(mut self, chain: DescriptorSpan) -> Self {
+            self.chain = Some(chain);
+            self
         }
     }
 
-    #[cfg(any(i2s0, i2s1))]
     impl<'a, RX> core::future::Future for DmaRxDoneChFuture<'a, RX>
     where
         RX: Rx,
@@ -2346,7 +5415,14 @@ pub(crate) mod asynch {
             self: core::pin::Pin<&mut Self>,
             cx: &mut core::task::Context<'_>,
         ) -> Poll<Self::Output> {
-            RX::waker().register(cx.waker());
+            self.rx.waker().register(cx.waker());
+
+            // See `DmaTxFuture` for why the interrupts are enabled first
+            self.rx.listen_ch_in_done();
+            self.rx.listen_in_descriptor_error();
+            self.rx.listen_in_descriptor_error_dscr_empty();
+            self.rx.listen_in_descriptor_error_err_eof();
+
             if self.rx.is_ch_in_done_set() {
                 self.rx.clear_ch_in_done();
                 Poll::Ready(Ok(()))
@@ -2354,19 +5430,15 @@ pub(crate) mod asynch {
                 || self.rx.has_dscr_empty_error()
                 || self.rx.has_eof_error()
             {
+                let error = rx_error(&*self.rx, self.chain);
                 self.rx.clear_interrupts();
-                Poll::Ready(Err(DmaError::DescriptorError))
+                Poll::Ready(Err(error))
             } else {
-                self.rx.listen_ch_in_done();
-                self.rx.listen_in_descriptor_error();
-                self.rx.listen_in_descriptor_error_dscr_empty();
-                self.rx.listen_in_descriptor_error_err_eof();
                 Poll::Pending
             }
         }
     }
 
-    #[cfg(any(i2s0, i2s1))]
     impl<'a, RX> Drop for DmaRxDoneChFuture<'a, RX>
     where
         RX: Rx,
@@ -2379,44 +5451,76 @@ pub(crate) mod asynch {
         }
     }
 
-    fn handle_interrupt<Channel: RegisterAccess, Rx: RxChannel<Channel>, Tx: TxChannel<Channel>>() {
-        if Channel::has_in_descriptor_error()
-            || Channel::has_in_descriptor_error_dscr_empty()
-            || Channel::has_in_descriptor_error_err_eof()
+    /// The error of a failed TX transfer, which has to be read before the
+    /// interrupts are cleared
+    fn tx_error(tx: &impl Tx, chain: Option<DescriptorSpan>) -> DmaError {
+        chain.map_or(DmaError::DescriptorError, |chain| {
+            chain.descriptor_error(tx.error_descriptor_address())
+        })
+    }
+
+    /// The error of a failed RX transfer, which has to be read before the
+    /// interrupts are cleared. Only descriptor errors have the address of a
+    /// descriptor.
+    fn rx_error(rx: &impl Rx, chain: Option<DescriptorSpan>) -> DmaError {
+        match chain {
+            Some(chain) if rx.has_error() => chain.descriptor_error(rx.error_descriptor_address()),
+            _ => DmaError::DescriptorError,
+        }
+    }
+
+    fn handle_interrupt<CH: DmaChannel>(rx: &CH::Rx, tx: &CH::Tx) {
+        let channel = rx.register_access();
+
+        // Only react to interrupts which are enabled, the status bits of the
+        // other direction or of an earlier transfer may still be set.
+        if (channel.has_in_descriptor_error() && channel.is_listening_in_descriptor_error())
+            || (channel.has_in_descriptor_error_dscr_empty()
+                && channel.is_listening_in_descriptor_error_dscr_empty())
+            || (channel.has_in_descriptor_error_err_eof()
+                && channel.is_listening_in_descriptor_error_err_eof())
         {
-            Channel::unlisten_in_descriptor_error();
-            Channel::unlisten_in_descriptor_error_dscr_empty();
-            Channel::unlisten_in_descriptor_error_err_eof();
-            Channel::unlisten_in_eof();
-            Channel::unlisten_ch_in_done();
-            Rx::waker().wake()
+            channel.unlisten_in_descriptor_error();
+            channel.unlisten_in_descriptor_error_dscr_empty();
+            channel.unlisten_in_descriptor_error_err_eof();
+            channel.unlisten_in_eof();
+            channel.unlisten_ch_in_done();
+            #[cfg(feature = "dma-stats")]
+            rx.stats().count_descriptor_error();
+            rx.waker().wake()
         }
 
-        if Channel::has_out_descriptor_error() {
-            Channel::unlisten_out_descriptor_error();
-            Channel::unlisten_out_eof();
-            Channel::unlisten_ch_out_done();
-            Tx::waker().wake()
+        if channel.has_out_descriptor_error() && channel.is_listening_out_descriptor_error() {
+            channel.unlisten_out_descriptor_error();
+            channel.unlisten_out_eof();
+            channel.unlisten_ch_out_done();
+            #[cfg(feature = "dma-stats")]
+            tx.stats().count_descriptor_error();
+            tx.waker().wake()
         }
 
-        if Channel::is_in_done() && Channel::is_listening_in_eof() {
-            Channel::unlisten_in_eof();
-            Rx::waker().wake()
+        if channel.is_in_done() && channel.is_listening_in_eof() {
+            channel.unlisten_in_eof();
+            #[cfg(feature = "dma-stats")]
+            rx.stats().count_eof_interrupt();
+            rx.waker().wake()
         }
 
-        if Channel::is_ch_in_done_set() {
-            Channel::unlisten_ch_in_done();
-            Rx::waker().wake()
+        if channel.is_ch_in_done_set() && channel.is_listening_ch_in_done() {
+            channel.unlisten_ch_in_done();
+            rx.waker().wake()
         }
 
-        if Channel::is_out_done() && Channel::is_listening_out_eof() {
-            Channel::unlisten_out_eof();
-            Tx::waker().wake()
+        if channel.is_out_done() && channel.is_listening_out_eof() {
+            channel.unlisten_out_eof();
+            #[cfg(feature = "dma-stats")]
+            tx.stats().count_eof_interrupt();
+            tx.waker().wake()
         }
 
-        if Channel::is_ch_out_done_set() {
-            Channel::unlisten_ch_out_done();
-            Tx::waker().wake()
+        if channel.is_ch_out_done_set() && channel.is_listening_ch_out_done() {
+            channel.unlisten_ch_out_done();
+            tx.waker().wake()
         }
     }
 
@@ -2428,61 +5532,56 @@ pub(crate) mod asynch {
 
         #[handler(priority = crate::interrupt::Priority::max())]
         pub(crate) fn interrupt_handler_ch0() {
-            use crate::dma::gdma::{
-                Channel0 as Channel,
-                Channel0RxImpl as ChannelRxImpl,
-                Channel0TxImpl as ChannelTxImpl,
-            };
+            use crate::dma::gdma::{AnyGdmaChannel, ChannelRxImpl, ChannelTxImpl, DmaChannel0};
 
-            handle_interrupt::<Channel, ChannelRxImpl, ChannelTxImpl>();
+            handle_interrupt::<DmaChannel0>(
+                &ChannelRxImpl(AnyGdmaChannel(0)),
+                &ChannelTxImpl(AnyGdmaChannel(0)),
+            );
         }
 
         #[cfg(not(esp32c2))]
         #[handler(priority = crate::interrupt::Priority::max())]
         pub(crate) fn interrupt_handler_ch1() {
-            use crate::dma::gdma::{
-                Channel1 as Channel,
-                Channel1RxImpl as ChannelRxImpl,
-                Channel1TxImpl as ChannelTxImpl,
-            };
+            use crate::dma::gdma::{AnyGdmaChannel, ChannelRxImpl, ChannelTxImpl, DmaChannel1};
 
-            handle_interrupt::<Channel, ChannelRxImpl, ChannelTxImpl>();
+            handle_interrupt::<DmaChannel1>(
+                &ChannelRxImpl(AnyGdmaChannel(1)),
+                &ChannelTxImpl(AnyGdmaChannel(1)),
+            );
         }
 
         #[cfg(not(esp32c2))]
         #[handler(priority = crate::interrupt::Priority::max())]
         pub(crate) fn interrupt_handler_ch2() {
-            use crate::dma::gdma::{
-                Channel2 as Channel,
-                Channel2RxImpl as ChannelRxImpl,
-                Channel2TxImpl as ChannelTxImpl,
-            };
+            use crate::dma::gdma::{AnyGdmaChannel, ChannelRxImpl, ChannelTxImpl, DmaChannel2};
 
-            handle_interrupt::<Channel, ChannelRxImpl, ChannelTxImpl>();
+            handle_interrupt::<DmaChannel2>(
+                &ChannelRxImpl(AnyGdmaChannel(2)),
+                &ChannelTxImpl(AnyGdmaChannel(2)),
+            );
         }
 
         #[cfg(esp32s3)]
         #[handler(priority = crate::interrupt::Priority::max())]
         pub(crate) fn interrupt_handler_ch3() {
-            use crate::dma::gdma::{
-                Channel3 as Channel,
-                Channel3RxImpl as ChannelRxImpl,
-                Channel3TxImpl as ChannelTxImpl,
-            };
+            use crate::dma::gdma::{AnyGdmaChannel, ChannelRxImpl, ChannelTxImpl, DmaChannel3};
 
-            handle_interrupt::<Channel, ChannelRxImpl, ChannelTxImpl>();
+            handle_interrupt::<DmaChannel3>(
+                &ChannelRxImpl(AnyGdmaChannel(3)),
+                &ChannelTxImpl(AnyGdmaChannel(3)),
+            );
         }
 
         #[cfg(esp32s3)]
         #[handler(priority = crate::interrupt::Priority::max())]
         pub(crate) fn interrupt_handler_ch4() {
-            use crate::dma::gdma::{
-                Channel4 as Channel,
-                Channel4RxImpl as ChannelRxImpl,
-                Channel4TxImpl as ChannelTxImpl,
-            };
+            use crate::dma::gdma::{AnyGdmaChannel, ChannelRxImpl, ChannelTxImpl, DmaChannel4};
 
-            handle_interrupt::<Channel, ChannelRxImpl, ChannelTxImpl>();
+            handle_interrupt::<DmaChannel4>(
+                &ChannelRxImpl(AnyGdmaChannel(4)),
+                &ChannelTxImpl(AnyGdmaChannel(4)),
+            );
         }
     }
 
@@ -2500,7 +5599,7 @@ pub(crate) mod asynch {
                 Spi2DmaChannelTxImpl as ChannelTxImpl,
             };
 
-            handle_interrupt::<Channel, ChannelRxImpl, ChannelTxImpl>();
+            handle_interrupt::<Channel>(&ChannelRxImpl {}, &ChannelTxImpl {});
         }
 
         #[handler(priority = crate::interrupt::Priority::max())]
@@ -2511,7 +5610,7 @@ pub(crate) mod asynch {
                 Spi3DmaChannelTxImpl as ChannelTxImpl,
             };
 
-            handle_interrupt::<Channel, ChannelRxImpl, ChannelTxImpl>();
+            handle_interrupt::<Channel>(&ChannelRxImpl {}, &ChannelTxImpl {});
         }
 
         #[handler(priority = crate::interrupt::Priority::max())]
@@ -2522,7 +5621,7 @@ pub(crate) mod asynch {
                 I2s0DmaChannelTxImpl as ChannelTxImpl,
             };
 
-            handle_interrupt::<Channel, ChannelRxImpl, ChannelTxImpl>();
+            handle_interrupt::<Channel>(&ChannelRxImpl {}, &ChannelTxImpl {});
         }
 
         #[cfg(i2s1)]
@@ -2534,7 +5633,7 @@ pub(crate) mod asynch {
                 I2s1DmaChannelTxImpl as ChannelTxImpl,
             };
 
-            handle_interrupt::<Channel, ChannelRxImpl, ChannelTxImpl>();
+            handle_interrupt::<Channel>(&ChannelRxImpl {}, &ChannelTxImpl {});
         }
     }
 }
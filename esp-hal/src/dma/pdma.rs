@@ -32,6 +32,10 @@ macro_rules! ImplSpiChannel {
                     spi.[< bind_spi $num _dma_interrupt>](handler.handler());
                     $crate::interrupt::enable($crate::peripherals::Interrupt::[< SPI $num _DMA >], handler.priority()).unwrap();
                 }
+
+                fn disable_isr() {
+                    $crate::interrupt::disable($crate::get_core(), $crate::peripherals::Interrupt::[< SPI $num _DMA >]);
+                }
             }
 
             #[doc = concat!("DMA channel suitable for SPI", $num)]
@@ -47,12 +51,21 @@ macro_rules! ImplSpiChannel {
 
             impl $crate::private::Sealed for [<Spi $num DmaChannel>] {}
 
-            impl ChannelTypes for [<Spi $num DmaChannel>] {
-                type Binder = [<Channel $num InterruptBinder>];
-            }
-
             impl RegisterAccess for [<Spi $num DmaChannel>] {
-                fn init_channel() {
+                fn set_isr(&self, handler: $crate::interrupt::InterruptHandler) {
+                    [<Channel $num InterruptBinder>]::set_isr(handler);
+                }
+
+                fn disable_isr(&self) {
+                    [<Channel $num InterruptBinder>]::disable_isr();
+                }
+
+                #[cfg(feature = "async")]
+                fn async_handler(&self) -> $crate::interrupt::InterruptHandler {
+                    super::asynch::interrupt::[< interrupt_handler_spi $num _dma >]
+                }
+
+                fn init_channel(&self) {
                     // (only) on ESP32 we need to configure DPORT for the SPI DMA channels
                     #[cfg(esp32)]
                     {
@@ -74,15 +87,15 @@ macro_rules! ImplSpiChannel {
                     }
                 }
 
-                fn set_out_burstmode(burst_mode: bool) {
+                fn set_out_burstmode(&self, burst_mode: bool) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_conf()
                         .modify(|_, w| w.outdscr_burst_en().bit(burst_mode));
                 }
 
-                fn set_out_priority(_priority: DmaPriority) {}
+                fn set_out_priority(&self, _priority: DmaPriority) {}
 
-                fn clear_out_interrupts() {
+                fn clear_out_interrupts(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_clr().write(|w| {
                         w.out_done()
@@ -96,73 +109,92 @@ macro_rules! ImplSpiChannel {
                     });
                 }
 
-                fn reset_out() {
+                fn reset_out(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_conf().modify(|_, w| w.out_rst().set_bit());
                     spi.dma_conf().modify(|_, w| w.out_rst().clear_bit());
                 }
 
-                fn set_out_descriptors(address: u32) {
+                fn set_out_descriptors(&self, address: u32) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_out_link()
                         .modify(|_, w| unsafe { w.outlink_addr().bits(address) });
                 }
 
-                fn has_out_descriptor_error() -> bool {
+                fn has_out_descriptor_error(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_raw().read().outlink_dscr_error().bit()
                 }
 
-                fn set_out_peripheral(_peripheral: u8) {
+                fn set_out_peripheral(&self, _peripheral: u8) {
                     // no-op
                 }
 
-                fn start_out() {
+                fn start_out(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_out_link().modify(|_, w| w.outlink_start().set_bit());
                 }
 
-                fn clear_ch_out_done() {
+                fn stop_out(&self) {
+                    let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
+                    spi.dma_out_link().modify(|_, w| w.outlink_stop().set_bit());
+                }
+
+                fn restart_out(&self) {
+                    let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
+                    spi.dma_out_link().modify(|_, w| w.outlink_restart().set_bit());
+                }
+
+                fn clear_ch_out_done(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_clr().write(|w| w.out_done().clear_bit_by_one());
                 }
 
-                fn is_ch_out_done_set() -> bool {
+                fn is_ch_out_done_set(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_raw().read().out_done().bit()
                 }
 
-                fn listen_ch_out_done() {
+                fn listen_ch_out_done(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_, w| w.out_done().set_bit());
                 }
 
-                fn unlisten_ch_out_done() {
+                fn unlisten_ch_out_done(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_, w| w.out_done().clear_bit());
                 }
 
-                fn is_listening_ch_out_done() -> bool {
+                fn is_listening_ch_out_done(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().read().out_done().bit()
                 }
 
-                fn is_out_done() -> bool {
+                fn is_out_done(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_raw().read().out_total_eof().bit()
                 }
 
-                fn last_out_dscr_address() -> usize {
+                fn last_out_dscr_address(&self) -> usize {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.out_eof_des_addr().read().dma_out_eof_des_addr().bits() as usize
                 }
 
-                fn is_out_eof_interrupt_set() -> bool {
+                fn last_out_err_dscr_address(&self) -> Option<usize> {
+                    None
+                }
+
+                fn current_out_dscr_address(&self) -> usize {
+                    let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
+                    spi.outlink_dscr().read().dma_outlink_dscr().bits() as usize
+                }
+
+                fn is_out_eof_interrupt_set(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_raw().read().out_eof().bit()
                 }
 
-                fn reset_out_eof_interrupt() {
+                fn reset_out_eof_interrupt(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_clr().write(|w| {
                         w.out_eof()
@@ -170,15 +202,15 @@ macro_rules! ImplSpiChannel {
                     });
                 }
 
-                fn set_in_burstmode(burst_mode: bool) {
+                fn set_in_burstmode(&self, burst_mode: bool) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_conf()
                         .modify(|_, w| w.indscr_burst_en().bit(burst_mode));
                 }
 
-                fn set_in_priority(_priority: DmaPriority) {}
+                fn set_in_priority(&self, _priority: DmaPriority) {}
 
-                fn clear_in_interrupts() {
+                fn clear_in_interrupts(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_clr().write(|w| {
                         w.in_done()
@@ -192,158 +224,202 @@ macro_rules! ImplSpiChannel {
                     });
                 }
 
-                fn reset_in() {
+                fn reset_in(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_conf().modify(|_, w| w.in_rst().set_bit());
                     spi.dma_conf().modify(|_, w| w.in_rst().clear_bit());
                 }
 
-                fn set_in_descriptors(address: u32) {
+                fn set_in_descriptors(&self, address: u32) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_in_link()
                         .modify(|_, w| unsafe { w.inlink_addr().bits(address) });
                 }
 
-                fn has_in_descriptor_error() -> bool {
+                fn has_in_descriptor_error(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_raw().read().inlink_dscr_error().bit()
                 }
 
-                fn has_in_descriptor_error_dscr_empty() -> bool {
+                fn has_in_descriptor_error_dscr_empty(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_raw().read().inlink_dscr_empty().bit()
                 }
 
-                fn has_in_descriptor_error_err_eof() -> bool {
+                fn has_in_descriptor_error_err_eof(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_raw().read().in_err_eof().bit()
                 }
 
-                fn set_in_peripheral(_peripheral: u8) {
+                fn last_in_err_dscr_address(&self) -> Option<usize> {
+                    None
+                }
+
+                fn current_in_dscr_address(&self) -> usize {
+                    let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
+                    spi.inlink_dscr().read().dma_inlink_dscr().bits() as usize
+                }
+
+                fn set_in_peripheral(&self, _peripheral: u8) {
                     // no-op
                 }
 
-                fn start_in() {
+                fn start_in(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_in_link().modify(|_, w| w.inlink_start().set_bit());
                 }
 
-                fn is_in_done() -> bool {
+                fn stop_in(&self) {
+                    let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
+                    spi.dma_in_link().modify(|_, w| w.inlink_stop().set_bit());
+                }
+
+                fn is_in_done(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_raw().read().in_done().bit()
                 }
 
-                fn is_listening_in_eof() -> bool {
+                fn is_listening_in_eof(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().read().in_suc_eof().bit_is_set()
                 }
 
-                fn is_listening_out_eof() -> bool {
+                fn is_listening_out_eof(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().read().out_total_eof().bit_is_set()
                 }
 
-                fn listen_in_eof() {
+                fn listen_in_eof(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_, w| w.in_suc_eof().set_bit());
                 }
 
-                fn listen_out_eof() {
+                fn listen_out_eof(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_, w| w.out_total_eof().set_bit());
                 }
 
-                fn unlisten_in_eof() {
+                fn unlisten_in_eof(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_, w| w.in_suc_eof().clear_bit());
                 }
 
-                fn unlisten_out_eof() {
+                fn unlisten_out_eof(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_, w| w.out_total_eof().clear_bit());
                 }
 
-                fn listen_ch_in_done(){
+                fn is_in_eof_set(&self) -> bool {
+                    let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
+                    spi.dma_int_raw().read().in_suc_eof().bit()
+                }
+
+                fn clear_in_eof(&self) {
+                    let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
+                    spi.dma_int_clr().write(|w| w.in_suc_eof().clear_bit_by_one());
+                }
+
+                fn clear_out_eof(&self) {
+                    let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
+                    spi.dma_int_clr().write(|w| w.out_total_eof().clear_bit_by_one());
+                }
+
+                fn clear_in_descriptor_error(&self) {
+                    let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
+                    spi.dma_int_clr().write(|w| w.inlink_dscr_error().clear_bit_by_one());
+                }
+
+                fn clear_in_descriptor_error_dscr_empty(&self) {
+                    let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
+                    spi.dma_int_clr().write(|w| w.inlink_dscr_empty().clear_bit_by_one());
+                }
+
+                fn clear_out_descriptor_error(&self) {
+                    let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
+                    spi.dma_int_clr().write(|w| w.outlink_dscr_error().clear_bit_by_one());
+                }
+
+                fn listen_ch_in_done(&self){
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_, w| w.in_done().set_bit());
                 }
 
-                fn clear_ch_in_done(){
+                fn clear_ch_in_done(&self){
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_clr().write(|w| w.in_done().clear_bit_by_one());
                 }
 
-                fn is_ch_in_done_set() -> bool {
+                fn is_ch_in_done_set(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_raw().read().in_done().bit()
                 }
 
-                fn unlisten_ch_in_done() {
+                fn unlisten_ch_in_done(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_, w| w.in_done().clear_bit());
                 }
 
-                fn is_listening_ch_in_done() -> bool {
+                fn is_listening_ch_in_done(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().read().in_done().bit()
                 }
 
-                fn listen_in_descriptor_error() {
+                fn listen_in_descriptor_error(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_,w| w.inlink_dscr_error().set_bit())
                 }
 
-                fn unlisten_in_descriptor_error() {
+                fn unlisten_in_descriptor_error(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_,w| w.inlink_dscr_error().clear_bit())
                 }
 
-                fn is_listening_in_descriptor_error() -> bool {
+                fn is_listening_in_descriptor_error(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().read().inlink_dscr_error().bit()
                 }
 
-                fn listen_in_descriptor_error_dscr_empty() {
+                fn listen_in_descriptor_error_dscr_empty(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_,w| w.inlink_dscr_empty().set_bit())
                 }
 
-                fn unlisten_in_descriptor_error_dscr_empty() {
+                fn unlisten_in_descriptor_error_dscr_empty(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_,w| w.inlink_dscr_empty().clear_bit())
                 }
 
-                fn is_listening_in_descriptor_error_dscr_empty() -> bool {
+                fn is_listening_in_descriptor_error_dscr_empty(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().read().inlink_dscr_empty().bit()
                 }
 
-                fn listen_in_descriptor_error_err_eof() {
+                fn listen_in_descriptor_error_err_eof(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_,w| w.in_err_eof().set_bit())
                 }
 
-                fn unlisten_in_descriptor_error_err_eof() {
+                fn unlisten_in_descriptor_error_err_eof(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_,w| w.in_err_eof().clear_bit())
                 }
 
-                fn is_listening_in_descriptor_error_err_eof() -> bool{
+                fn is_listening_in_descriptor_error_err_eof(&self) -> bool{
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().read().in_err_eof().bit()
                 }
 
-                fn listen_out_descriptor_error() {
+                fn listen_out_descriptor_error(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_,w| w.outlink_dscr_error().set_bit())
                 }
 
-                fn unlisten_out_descriptor_error() {
+                fn unlisten_out_descriptor_error(&self) {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().modify(|_,w| w.outlink_dscr_error().clear_bit())
                 }
 
-                fn is_listening_out_descriptor_error() -> bool {
+                fn is_listening_out_descriptor_error(&self) -> bool {
                     let spi = unsafe { &*crate::peripherals::[<SPI $num>]::PTR };
                     spi.dma_int_ena().read().outlink_dscr_error().bit()
                 }
@@ -356,11 +432,21 @@ macro_rules! ImplSpiChannel {
             impl $crate::private::Sealed for [<Spi $num DmaChannelTxImpl>] {}
 
             impl<'a> TxChannel<[<Spi $num DmaChannel>]> for [<Spi $num DmaChannelTxImpl>] {
+                fn register_access(&self) -> &[<Spi $num DmaChannel>] {
+                    &[<Spi $num DmaChannel>] {}
+                }
+
                 #[cfg(feature = "async")]
-                fn waker() -> &'static embassy_sync::waitqueue::AtomicWaker {
+                fn waker(&self) -> &'static embassy_sync::waitqueue::AtomicWaker {
                     static WAKER: embassy_sync::waitqueue::AtomicWaker = embassy_sync::waitqueue::AtomicWaker::new();
                     &WAKER
                 }
+
+                #[cfg(feature = "dma-stats")]
+                fn stats(&self) -> &'static DmaStatsCounters {
+                    static STATS: DmaStatsCounters = DmaStatsCounters::new();
+                    &STATS
+                }
             }
 
             #[non_exhaustive]
@@ -370,11 +456,21 @@ macro_rules! ImplSpiChannel {
             impl $crate::private::Sealed for [<Spi $num DmaChannelRxImpl>] {}
 
             impl<'a> RxChannel<[<Spi $num DmaChannel>]> for [<Spi $num DmaChannelRxImpl>] {
+                fn register_access(&self) -> &[<Spi $num DmaChannel>] {
+                    &[<Spi $num DmaChannel>] {}
+                }
+
                 #[cfg(feature = "async")]
-                fn waker() -> &'static embassy_sync::waitqueue::AtomicWaker {
+                fn waker(&self) -> &'static embassy_sync::waitqueue::AtomicWaker {
                     static WAKER: embassy_sync::waitqueue::AtomicWaker = embassy_sync::waitqueue::AtomicWaker::new();
                     &WAKER
                 }
+
+                #[cfg(feature = "dma-stats")]
+                fn stats(&self) -> &'static DmaStatsCounters {
+                    static STATS: DmaStatsCounters = DmaStatsCounters::new();
+                    &STATS
+                }
             }
 
             #[doc = concat!("Creates a channel for SPI", $num)]
@@ -390,16 +486,30 @@ macro_rules! ImplSpiChannel {
                     self,
                     burst_mode: bool,
                     priority: DmaPriority,
+                ) -> Channel<'a, [<Spi $num DmaChannel>], $crate::Blocking> {
+                    self.configure_with(burst_mode.into(), priority)
+                }
+
+                /// Configure the channel for use with blocking APIs
+                ///
+                /// Descriptors should be sized as `(CHUNK_SIZE + 4091) / 4092`. I.e., to
+                /// transfer buffers of size `1..=4092`, you need 1 descriptor.
+                ///
+                /// Allows configuring the burst mode of each direction separately.
+                pub fn configure_with<'a>(
+                    self,
+                    burst: DmaBurstConfig,
+                    priority: DmaPriority,
                 ) -> Channel<'a, [<Spi $num DmaChannel>], $crate::Blocking> {
                     let mut tx_impl = [<Spi $num DmaChannelTxImpl>] {};
-                    tx_impl.init(burst_mode, priority);
+                    tx_impl.init(burst, priority);
 
                     let mut rx_impl = [<Spi $num DmaChannelRxImpl>] {};
-                    rx_impl.init(burst_mode, priority);
+                    rx_impl.init(burst, priority);
 
                     Channel {
-                        tx: ChannelTx::new(tx_impl, burst_mode),
-                        rx: ChannelRx::new(rx_impl, burst_mode),
+                        tx: ChannelTx::new(tx_impl, burst),
+                        rx: ChannelRx::new(rx_impl, burst),
                         phantom: PhantomData,
                     }
                 }
@@ -413,18 +523,62 @@ macro_rules! ImplSpiChannel {
                     self,
                     burst_mode: bool,
                     priority: DmaPriority,
+                ) -> Channel<'a, [<Spi $num DmaChannel>], $crate::Async> {
+                    self.configure_for_async_with(burst_mode.into(), priority)
+                }
+
+                /// Configure the channel for use with async APIs
+                ///
+                /// Descriptors should be sized as `(CHUNK_SIZE + 4091) / 4092`. I.e., to
+                /// transfer buffers of size `1..=4092`, you need 1 descriptor.
+                ///
+                /// Allows configuring the burst mode of each direction separately.
+                #[cfg(feature = "async")]
+                pub fn configure_for_async_with<'a>(
+                    self,
+                    burst: DmaBurstConfig,
+                    priority: DmaPriority,
+                ) -> Channel<'a, [<Spi $num DmaChannel>], $crate::Async> {
+                    self.configure_for_async_with_priority(
+                        burst,
+                        priority,
+                        $crate::interrupt::Priority::max(),
+                    )
+                }
+
+                /// Configure the channel for use with async APIs
+                ///
+                /// Descriptors should be sized as `(CHUNK_SIZE + 4091) / 4092`. I.e., to
+                /// transfer buffers of size `1..=4092`, you need 1 descriptor.
+                ///
+                /// Allows configuring the burst mode of each direction separately and
+                /// choosing the priority of the interrupt which wakes the async tasks
+                /// waiting for this channel.
+                #[cfg_attr(
+                    multi_core,
+                    doc = "The interrupt is handled on the core which calls this function."
+                )]
+                #[cfg(feature = "async")]
+                pub fn configure_for_async_with_priority<'a>(
+                    self,
+                    burst: DmaBurstConfig,
+                    priority: DmaPriority,
+                    interrupt_priority: $crate::interrupt::Priority,
                 ) -> Channel<'a, [<Spi $num DmaChannel>], $crate::Async> {
                     let mut tx_impl = [<Spi $num DmaChannelTxImpl>] {};
-                    tx_impl.init(burst_mode, priority);
+                    tx_impl.init(burst, priority);
 
                     let mut rx_impl = [<Spi $num DmaChannelRxImpl>] {};
-                    rx_impl.init(burst_mode, priority);
+                    rx_impl.init(burst, priority);
 
-                    <[<Spi $num DmaChannel>] as ChannelTypes>::Binder::set_isr(super::asynch::interrupt::[< interrupt_handler_spi $num _dma >]);
+                    [<Channel $num InterruptBinder>]::set_isr($crate::interrupt::InterruptHandler::new(
+                        super::asynch::interrupt::[< interrupt_handler_spi $num _dma >].handler(),
+                        interrupt_priority,
+                    ));
 
                     Channel {
-                        tx: ChannelTx::new(tx_impl, burst_mode),
-                        rx: ChannelRx::new(rx_impl, burst_mode),
+                        tx: ChannelTx::new(tx_impl, burst),
+                        rx: ChannelRx::new(rx_impl, burst),
                         phantom: PhantomData,
                     }
                 }
@@ -448,6 +602,10 @@ macro_rules! ImplI2sChannel {
                     i2s.[< bind_i2s $num _interrupt>](handler.handler());
                     $crate::interrupt::enable($crate::peripherals::Interrupt::[< I2S $num  >], handler.priority()).unwrap();
                 }
+
+                fn disable_isr() {
+                    $crate::interrupt::disable($crate::get_core(), $crate::peripherals::Interrupt::[< I2S $num >]);
+                }
             }
 
             #[doc = concat!("DMA channel suitable for I2S", $num)]
@@ -462,24 +620,33 @@ macro_rules! ImplI2sChannel {
                 type P = [<I2s $num DmaSuitablePeripheral>];
             }
 
-            impl ChannelTypes for [<I2s $num DmaChannel>] {
-                type Binder = [<Channel $num InterruptBinder>];
-            }
-
             impl RegisterAccess for [<I2s $num DmaChannel>] {
-                fn init_channel() {
+                fn set_isr(&self, handler: $crate::interrupt::InterruptHandler) {
+                    [<Channel $num InterruptBinder>]::set_isr(handler);
+                }
+
+                fn disable_isr(&self) {
+                    [<Channel $num InterruptBinder>]::disable_isr();
+                }
+
+                #[cfg(feature = "async")]
+                fn async_handler(&self) -> $crate::interrupt::InterruptHandler {
+                    super::asynch::interrupt::[< interrupt_handler_i2s $num >]
+                }
+
+                fn init_channel(&self) {
                     // nothing to do
                 }
 
-                fn set_out_burstmode(burst_mode: bool) {
+                fn set_out_burstmode(&self, burst_mode: bool) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.lc_conf()
                         .modify(|_, w| w.outdscr_burst_en().bit(burst_mode));
                 }
 
-                fn set_out_priority(_priority: DmaPriority) {}
+                fn set_out_priority(&self, _priority: DmaPriority) {}
 
-                fn clear_out_interrupts() {
+                fn clear_out_interrupts(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_clr().write(|w| {
                         w.out_done()
@@ -493,73 +660,92 @@ macro_rules! ImplI2sChannel {
                     });
                 }
 
-                fn reset_out() {
+                fn reset_out(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.lc_conf().modify(|_, w| w.out_rst().set_bit());
                     reg_block.lc_conf().modify(|_, w| w.out_rst().clear_bit());
                 }
 
-                fn set_out_descriptors(address: u32) {
+                fn set_out_descriptors(&self, address: u32) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.out_link()
                         .modify(|_, w| unsafe { w.outlink_addr().bits(address) });
                 }
 
-                fn has_out_descriptor_error() -> bool {
+                fn has_out_descriptor_error(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_raw().read().out_dscr_err().bit()
                 }
 
-                fn set_out_peripheral(_peripheral: u8) {
+                fn set_out_peripheral(&self, _peripheral: u8) {
                     // no-op
                 }
 
-                fn start_out() {
+                fn start_out(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.out_link().modify(|_, w| w.outlink_start().set_bit());
                 }
 
-                fn clear_ch_out_done() {
+                fn stop_out(&self) {
+                    let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
+                    reg_block.out_link().modify(|_, w| w.outlink_stop().set_bit());
+                }
+
+                fn restart_out(&self) {
+                    let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
+                    reg_block.out_link().modify(|_, w| w.outlink_restart().set_bit());
+                }
+
+                fn clear_ch_out_done(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_clr().write(|w| w.out_done().clear_bit_by_one());
                 }
 
-                fn is_ch_out_done_set() -> bool {
+                fn is_ch_out_done_set(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_raw().read().out_done().bit()
                 }
 
-                fn listen_ch_out_done() {
+                fn listen_ch_out_done(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_, w| w.out_done().set_bit());
                 }
 
-                fn unlisten_ch_out_done() {
+                fn unlisten_ch_out_done(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_, w| w.out_done().clear_bit());
                 }
 
-                fn is_listening_ch_out_done() -> bool {
+                fn is_listening_ch_out_done(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().read().out_done().bit()
                 }
 
-                fn is_out_done() -> bool {
+                fn is_out_done(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_raw().read().out_eof().bit()
                 }
 
-                fn last_out_dscr_address() -> usize {
+                fn last_out_dscr_address(&self) -> usize {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.out_eof_des_addr().read().out_eof_des_addr().bits() as usize
                 }
 
-                fn is_out_eof_interrupt_set() -> bool {
+                fn last_out_err_dscr_address(&self) -> Option<usize> {
+                    None
+                }
+
+                fn current_out_dscr_address(&self) -> usize {
+                    let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
+                    reg_block.outlink_dscr().read().outlink_dscr().bits() as usize
+                }
+
+                fn is_out_eof_interrupt_set(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_raw().read().out_eof().bit()
                 }
 
-                fn reset_out_eof_interrupt() {
+                fn reset_out_eof_interrupt(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_clr().write(|w| {
                         w.out_eof()
@@ -567,15 +753,15 @@ macro_rules! ImplI2sChannel {
                     });
                 }
 
-                fn set_in_burstmode(burst_mode: bool) {
+                fn set_in_burstmode(&self, burst_mode: bool) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.lc_conf()
                         .modify(|_, w| w.indscr_burst_en().bit(burst_mode));
                 }
 
-                fn set_in_priority(_priority: DmaPriority) {}
+                fn set_in_priority(&self, _priority: DmaPriority) {}
 
-                fn clear_in_interrupts() {
+                fn clear_in_interrupts(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_clr().write(|w| {
                         w.in_done()
@@ -589,158 +775,202 @@ macro_rules! ImplI2sChannel {
                     });
                 }
 
-                fn reset_in() {
+                fn reset_in(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.lc_conf().modify(|_, w| w.in_rst().set_bit());
                     reg_block.lc_conf().modify(|_, w| w.in_rst().clear_bit());
                 }
 
-                fn set_in_descriptors(address: u32) {
+                fn set_in_descriptors(&self, address: u32) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.in_link()
                         .modify(|_, w| unsafe { w.inlink_addr().bits(address) });
                 }
 
-                fn has_in_descriptor_error() -> bool {
+                fn has_in_descriptor_error(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_raw().read().in_dscr_err().bit()
                 }
 
-                fn has_in_descriptor_error_dscr_empty() -> bool {
+                fn has_in_descriptor_error_dscr_empty(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_raw().read().in_dscr_empty().bit()
                 }
 
-                fn has_in_descriptor_error_err_eof() -> bool {
+                fn has_in_descriptor_error_err_eof(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_raw().read().in_err_eof().bit()
                 }
 
-                fn set_in_peripheral(_peripheral: u8) {
+                fn last_in_err_dscr_address(&self) -> Option<usize> {
+                    None
+                }
+
+                fn current_in_dscr_address(&self) -> usize {
+                    let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
+                    reg_block.inlink_dscr().read().inlink_dscr().bits() as usize
+                }
+
+                fn set_in_peripheral(&self, _peripheral: u8) {
                     // no-op
                 }
 
-                fn start_in() {
+                fn start_in(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.in_link().modify(|_, w| w.inlink_start().set_bit());
                 }
 
-                fn is_in_done() -> bool {
+                fn stop_in(&self) {
+                    let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
+                    reg_block.in_link().modify(|_, w| w.inlink_stop().set_bit());
+                }
+
+                fn is_in_done(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_raw().read().in_done().bit()
                 }
 
-                fn is_listening_in_eof() -> bool {
+                fn is_listening_in_eof(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().read().in_suc_eof().bit()
                 }
 
-                fn is_listening_out_eof() -> bool {
+                fn is_listening_out_eof(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().read().out_eof().bit()
                 }
 
-                fn listen_in_eof() {
+                fn listen_in_eof(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_,w| w.in_suc_eof().set_bit() );
                 }
 
-                fn listen_out_eof() {
+                fn listen_out_eof(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_,w| w.out_eof().set_bit() );
                 }
 
-                fn unlisten_in_eof() {
+                fn unlisten_in_eof(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_,w| w.in_suc_eof().clear_bit() );
                 }
 
-                fn unlisten_out_eof() {
+                fn unlisten_out_eof(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_,w| w.out_eof().clear_bit() );
                 }
 
-                fn listen_ch_in_done(){
+                fn is_in_eof_set(&self) -> bool {
+                    let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
+                    reg_block.int_raw().read().in_suc_eof().bit()
+                }
+
+                fn clear_in_eof(&self) {
+                    let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
+                    reg_block.int_clr().write(|w| w.in_suc_eof().clear_bit_by_one());
+                }
+
+                fn clear_out_eof(&self) {
+                    let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
+                    reg_block.int_clr().write(|w| w.out_eof().clear_bit_by_one());
+                }
+
+                fn clear_in_descriptor_error(&self) {
+                    let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
+                    reg_block.int_clr().write(|w| w.in_dscr_err().clear_bit_by_one());
+                }
+
+                fn clear_in_descriptor_error_dscr_empty(&self) {
+                    let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
+                    reg_block.int_clr().write(|w| w.in_dscr_empty().clear_bit_by_one());
+                }
+
+                fn clear_out_descriptor_error(&self) {
+                    let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
+                    reg_block.int_clr().write(|w| w.out_dscr_err().clear_bit_by_one());
+                }
+
+                fn listen_ch_in_done(&self){
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_, w| w.in_done().set_bit());
                 }
 
-                fn clear_ch_in_done(){
+                fn clear_ch_in_done(&self){
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_clr().write(|w| w.in_done().clear_bit_by_one());
                 }
 
-                fn is_ch_in_done_set() -> bool {
+                fn is_ch_in_done_set(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_raw().read().in_done().bit()
                 }
 
-                fn unlisten_ch_in_done() {
+                fn unlisten_ch_in_done(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_, w| w.in_done().clear_bit());
                 }
 
-                fn is_listening_ch_in_done() -> bool {
+                fn is_listening_ch_in_done(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().read().in_done().bit()
                 }
 
-                fn listen_in_descriptor_error(){
+                fn listen_in_descriptor_error(&self){
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_, w| w.in_dscr_err().set_bit());
                 }
 
-                fn unlisten_in_descriptor_error() {
+                fn unlisten_in_descriptor_error(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_, w| w.in_dscr_err().clear_bit());
                 }
 
-                fn is_listening_in_descriptor_error() -> bool {
+                fn is_listening_in_descriptor_error(&self) -> bool {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().read().in_dscr_err().bit()
                 }
 
-                fn listen_in_descriptor_error_dscr_empty() {
+                fn listen_in_descriptor_error_dscr_empty(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_, w| w.in_dscr_empty().set_bit());
                 }
 
-                fn unlisten_in_descriptor_error_dscr_empty() {
+                fn unlisten_in_descriptor_error_dscr_empty(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_, w| w.in_dscr_empty().clear_bit());
                 }
 
-                fn is_listening_in_descriptor_error_dscr_empty() -> bool{
+                fn is_listening_in_descriptor_error_dscr_empty(&self) -> bool{
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().read().in_dscr_empty().bit()
                 }
 
-                fn listen_in_descriptor_error_err_eof() {
+                fn listen_in_descriptor_error_err_eof(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_, w| w.in_err_eof().set_bit());
                 }
 
-                fn unlisten_in_descriptor_error_err_eof() {
+                fn unlisten_in_descriptor_error_err_eof(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_, w| w.in_err_eof().clear_bit());
                 }
 
-                fn is_listening_in_descriptor_error_err_eof() -> bool{
+                fn is_listening_in_descriptor_error_err_eof(&self) -> bool{
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().read().in_err_eof().bit()
                 }
 
-                fn listen_out_descriptor_error() {
+                fn listen_out_descriptor_error(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_, w| w.out_dscr_err().set_bit());
                 }
 
-                fn unlisten_out_descriptor_error() {
+                fn unlisten_out_descriptor_error(&self) {
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().modify(|_, w| w.out_dscr_err().clear_bit());
                 }
 
-                fn is_listening_out_descriptor_error() -> bool{
+                fn is_listening_out_descriptor_error(&self) -> bool{
                     let reg_block = unsafe { &*crate::peripherals::[<$peripheral>]::PTR };
                     reg_block.int_ena().read().out_dscr_err().bit()
                 }
@@ -752,11 +982,21 @@ macro_rules! ImplI2sChannel {
             impl $crate::private::Sealed for [<I2s $num DmaChannelTxImpl>] {}
 
             impl<'a> TxChannel<[<I2s $num DmaChannel>]> for [<I2s $num DmaChannelTxImpl>] {
+                fn register_access(&self) -> &[<I2s $num DmaChannel>] {
+                    &[<I2s $num DmaChannel>] {}
+                }
+
                 #[cfg(feature = "async")]
-                fn waker() -> &'static embassy_sync::waitqueue::AtomicWaker {
+                fn waker(&self) -> &'static embassy_sync::waitqueue::AtomicWaker {
                     static WAKER: embassy_sync::waitqueue::AtomicWaker = embassy_sync::waitqueue::AtomicWaker::new();
                     &WAKER
                 }
+
+                #[cfg(feature = "dma-stats")]
+                fn stats(&self) -> &'static DmaStatsCounters {
+                    static STATS: DmaStatsCounters = DmaStatsCounters::new();
+                    &STATS
+                }
             }
 
             #[doc(hidden)]
@@ -765,11 +1005,21 @@ macro_rules! ImplI2sChannel {
             impl $crate::private::Sealed for [<I2s $num DmaChannelRxImpl>] {}
 
             impl<'a> RxChannel<[<I2s $num DmaChannel>]> for [<I2s $num DmaChannelRxImpl>] {
+                fn register_access(&self) -> &[<I2s $num DmaChannel>] {
+                    &[<I2s $num DmaChannel>] {}
+                }
+
                 #[cfg(feature = "async")]
-                fn waker() -> &'static embassy_sync::waitqueue::AtomicWaker {
+                fn waker(&self) -> &'static embassy_sync::waitqueue::AtomicWaker {
                     static WAKER: embassy_sync::waitqueue::AtomicWaker = embassy_sync::waitqueue::AtomicWaker::new();
                     &WAKER
                 }
+
+                #[cfg(feature = "dma-stats")]
+                fn stats(&self) -> &'static DmaStatsCounters {
+                    static STATS: DmaStatsCounters = DmaStatsCounters::new();
+                    &STATS
+                }
             }
 
             #[doc = concat!("Creates a channel for I2S", $num)]
@@ -784,16 +1034,30 @@ macro_rules! ImplI2sChannel {
                     self,
                     burst_mode: bool,
                     priority: DmaPriority,
+                ) -> Channel<'a, [<I2s $num DmaChannel>], $crate::Blocking> {
+                    self.configure_with(burst_mode.into(), priority)
+                }
+
+                /// Configure the channel for use with blocking APIs
+                ///
+                /// Descriptors should be sized as `(CHUNK_SIZE + 4091) / 4092`. I.e., to
+                /// transfer buffers of size `1..=4092`, you need 1 descriptor.
+                ///
+                /// Allows configuring the burst mode of each direction separately.
+                pub fn configure_with<'a>(
+                    self,
+                    burst: DmaBurstConfig,
+                    priority: DmaPriority,
                 ) -> Channel<'a, [<I2s $num DmaChannel>], $crate::Blocking> {
                     let mut tx_impl = [<I2s $num DmaChannelTxImpl>] {};
-                    tx_impl.init(burst_mode, priority);
+                    tx_impl.init(burst, priority);
 
                     let mut rx_impl = [<I2s $num DmaChannelRxImpl>] {};
-                    rx_impl.init(burst_mode, priority);
+                    rx_impl.init(burst, priority);
 
                     Channel {
-                        tx: ChannelTx::new(tx_impl, burst_mode),
-                        rx: ChannelRx::new(rx_impl, burst_mode),
+                        tx: ChannelTx::new(tx_impl, burst),
+                        rx: ChannelRx::new(rx_impl, burst),
                         phantom: PhantomData,
                     }
                 }
@@ -807,18 +1071,62 @@ macro_rules! ImplI2sChannel {
                     self,
                     burst_mode: bool,
                     priority: DmaPriority,
+                ) -> Channel<'a, [<I2s $num DmaChannel>], $crate::Async> {
+                    self.configure_for_async_with(burst_mode.into(), priority)
+                }
+
+                /// Configure the channel for use with async APIs
+                ///
+                /// Descriptors should be sized as `(CHUNK_SIZE + 4091) / 4092`. I.e., to
+                /// transfer buffers of size `1..=4092`, you need 1 descriptor.
+                ///
+                /// Allows configuring the burst mode of each direction separately.
+                #[cfg(feature = "async")]
+                pub fn configure_for_async_with<'a>(
+                    self,
+                    burst: DmaBurstConfig,
+                    priority: DmaPriority,
+                ) -> Channel<'a, [<I2s $num DmaChannel>], $crate::Async> {
+                    self.configure_for_async_with_priority(
+                        burst,
+                        priority,
+                        $crate::interrupt::Priority::max(),
+                    )
+                }
+
+                /// Configure the channel for use with async APIs
+                ///
+                /// Descriptors should be sized as `(CHUNK_SIZE + 4091) / 4092`. I.e., to
+                /// transfer buffers of size `1..=4092`, you need 1 descriptor.
+                ///
+                /// Allows configuring the burst mode of each direction separately and
+                /// choosing the priority of the interrupt which wakes the async tasks
+                /// waiting for this channel.
+                #[cfg_attr(
+                    multi_core,
+                    doc = "The interrupt is handled on the core which calls this function."
+                )]
+                #[cfg(feature = "async")]
+                pub fn configure_for_async_with_priority<'a>(
+                    self,
+                    burst: DmaBurstConfig,
+                    priority: DmaPriority,
+                    interrupt_priority: $crate::interrupt::Priority,
                 ) -> Channel<'a, [<I2s $num DmaChannel>], $crate::Async> {
                     let mut tx_impl = [<I2s $num DmaChannelTxImpl>] {};
-                    tx_impl.init(burst_mode, priority);
+                    tx_impl.init(burst, priority);
 
                     let mut rx_impl = [<I2s $num DmaChannelRxImpl>] {};
-                    rx_impl.init(burst_mode, priority);
+                    rx_impl.init(burst, priority);
 
-                    <[<I2s $num DmaChannel>] as ChannelTypes>::Binder::set_isr(super::asynch::interrupt::[< interrupt_handler_i2s $num >]);
+                    [<Channel $num InterruptBinder>]::set_isr($crate::interrupt::InterruptHandler::new(
+                        super::asynch::interrupt::[< interrupt_handler_i2s $num >].handler(),
+                        interrupt_priority,
+                    ));
 
                     Channel {
-                        tx: ChannelTx::new(tx_impl, burst_mode),
-                        rx: ChannelRx::new(rx_impl, burst_mode),
+                        tx: ChannelTx::new(tx_impl, burst),
+                        rx: ChannelRx::new(rx_impl, burst),
                         phantom: PhantomData,
                     }
                 }
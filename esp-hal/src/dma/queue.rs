@@ -0,0 +1,448 @@
+//! # DMA transaction queue
+//!
+//! ## Overview
+//! A [DmaTxQueue] holds up to `N` buffers which are sent back to back. Each
+//! buffer gets its own part of the descriptors, the last descriptor of a
+//! queued buffer is linked to the first descriptor of the next one, so the
+//! DMA moves on to the next buffer without the CPU restarting the channel.
+//!
+//! Buffers can be queued before the transfer is started, and while it's
+//! running with [DmaTransferTxQueue::enqueue]. If the DMA already sent all
+//! queued buffers it's resumed with the new one.
+//!
+//! Peripherals which keep consuming data until they are stopped, like I2S,
+//! just keep running. Peripherals which need to know the length of a
+//! transaction when it's started, like SPI, are told how many bytes were
+//! queued. The SPI sends all bytes queued when a transaction starts in that
+//! transaction, bytes queued while it's running are sent in the next one.
+//! The DMA keeps filling the FIFO in between, so there's only a gap on the
+//! bus if a buffer is queued while the SPI is busy.
+
+use core::ptr::addr_of_mut;
+
+use super::{
+    dma_private::DmaSupportTx,
+    DescriptorChain,
+    DmaDescriptor,
+    DmaError,
+    DmaPeripheral,
+    TxPrivate,
+};
+
+/// A queue of up to `N` buffers sent back to back by the DMA
+pub struct DmaTxQueue<const N: usize> {
+    chains: [DescriptorChain; N],
+    /// The last descriptor of the buffer queued in each slot
+    last_descriptors: [*mut DmaDescriptor; N],
+    /// The length of the buffer queued in each slot
+    lengths: [usize; N],
+    /// The slot of the oldest buffer which wasn't sent yet
+    head: usize,
+    /// Number of queued buffers which weren't sent yet
+    pending: usize,
+    /// Number of buffers sent since the completions were last taken
+    completed: usize,
+}
+
+impl<const N: usize> DmaTxQueue<N> {
+    /// Creates a queue which splits `descriptors` evenly into `N` slots.
+    ///
+    /// Each slot can hold a buffer of up to `descriptors.len() / N` times
+    /// [CHUNK_SIZE](super::CHUNK_SIZE) bytes.
+    ///
+    /// Returns [DmaError::OutOfDescriptors] if there are fewer descriptors
+    /// than slots.
+    pub fn new(descriptors: &'static mut [DmaDescriptor]) -> Result<Self, DmaError> {
+        let slot_len = descriptors.len() / N;
+        if slot_len == 0 {
+            return Err(DmaError::OutOfDescriptors { required: N });
+        }
+
+        let mut rest = descriptors;
+        let chains = core::array::from_fn(|_| {
+            let (slot, tail) = core::mem::take(&mut rest).split_at_mut(slot_len);
+            rest = tail;
+            DescriptorChain::new(slot)
+        });
+
+        Ok(Self {
+            chains,
+            last_descriptors: [core::ptr::null_mut(); N],
+            lengths: [0; N],
+            head: 0,
+            pending: 0,
+            completed: 0,
+        })
+    }
+
+    /// Number of queued buffers which weren't sent yet.
+    pub fn len(&self) -> usize {
+        self.pending
+    }
+
+    /// Returns whether all queued buffers were sent.
+    pub fn is_empty(&self) -> bool {
+        self.pending == 0
+    }
+
+    /// Returns whether no more buffers can be queued.
+    pub fn is_full(&self) -> bool {
+        self.pending == N
+    }
+
+    /// Queues `buffer` to be sent after the buffers queued before it.
+    ///
+    /// Returns [DmaError::QueueFull] if `N` buffers are waiting to be sent
+    /// and [DmaError::BufferTooSmall] if `buffer` is empty. Use
+    /// [DmaTransferTxQueue::enqueue] while a transfer is running.
+    pub fn enqueue(&mut self, buffer: &'static [u8]) -> Result<(), DmaError> {
+        if self.is_full() {
+            return Err(DmaError::QueueFull);
+        }
+        if buffer.is_empty() {
+            return Err(DmaError::BufferTooSmall);
+        }
+
+        let slot = (self.head + self.pending) % N;
+        let chain = &mut self.chains[slot];
+
+        // Always fill the descriptors again, the last one might still be linked
+        // to the buffer which was queued after it the last time.
+        chain.prepared = None;
+        chain.fill_for_tx(false, buffer.as_ptr(), buffer.len())?;
+        #[cfg(esp32s3)]
+        chain.writeback_psram_buffers();
+
+        let last = buffer.len().div_ceil(chain.chunk_size) - 1;
+        self.last_descriptors[slot] = addr_of_mut!(chain.descriptors[last]);
+        self.lengths[slot] = buffer.len();
+        let first = chain.first_mut();
+
+        // The descriptors of the new buffer have to be written before the DMA
+        // can follow the link to them.
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+
+        // Link the new buffer to the last one which wasn't sent yet. If all
+        // buffers were sent the DMA has to be started again instead.
+        if self.pending > 0 {
+            let tail = (slot + N - 1) % N;
+            unsafe {
+                addr_of_mut!((*self.last_descriptors[tail]).next).write_volatile(first);
+            }
+        }
+
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+
+        self.pending += 1;
+
+        Ok(())
+    }
+
+    /// The descriptors of the oldest buffer which wasn't sent yet, the DMA
+    /// starts with them.
+    pub(crate) fn first_pending(&self) -> Option<&DescriptorChain> {
+        (!self.is_empty()).then(|| &self.chains[self.head])
+    }
+
+    /// Number of bytes in the buffers which weren't sent yet.
+    fn pending_bytes(&self) -> usize {
+        (0..self.pending)
+            .map(|offset| self.lengths[(self.head + offset) % N])
+            .sum()
+    }
+
+    /// Maps the address of the descriptor which caused a descriptor error to
+    /// the matching [DmaError], the index is counted from the start of the
+    /// descriptors passed to [DmaTxQueue::new].
+    fn descriptor_error(&self, address: Option<usize>) -> DmaError {
+        let slot_len = self.chains[0].descriptors.len();
+
+        self.chains
+            .iter()
+            .enumerate()
+            .find_map(|(slot, chain)| match chain.descriptor_error(address) {
+                DmaError::DescriptorErrorAt { index, address } => {
+                    Some(DmaError::DescriptorErrorAt {
+                        index: slot * slot_len + index,
+                        address,
+                    })
+                }
+                _ => None,
+            })
+            .unwrap_or(DmaError::DescriptorError)
+    }
+
+    /// Marks the buffers as sent which the DMA finished since the last update.
+    fn update<T>(&mut self, channel: &T)
+    where
+        T: TxPrivate,
+    {
+        if !channel.descriptors_handled() {
+            return;
+        }
+
+        channel.reset_descriptors_handled();
+        let address = channel.last_out_dscr_address();
+
+        // Every buffer ends with an EOF descriptor, the DMA reports the most
+        // recent one. All buffers up to it are sent.
+        for offset in 0..self.pending {
+            let slot = (self.head + offset) % N;
+            if self.last_descriptors[slot] as usize == address {
+                self.head = (slot + 1) % N;
+                self.pending -= offset + 1;
+                self.completed += offset + 1;
+                break;
+            }
+        }
+    }
+
+    fn take_completed(&mut self) -> usize {
+        core::mem::take(&mut self.completed)
+    }
+
+    /// Discards the buffers which weren't sent yet.
+    fn clear(&mut self) {
+        self.head = 0;
+        self.pending = 0;
+        self.completed = 0;
+    }
+}
+
+/// DMA transaction sending the buffers of a [DmaTxQueue]
+#[non_exhaustive]
+#[must_use]
+pub struct DmaTransferTxQueue<'a, I, const N: usize>
+where
+    I: DmaSupportTx,
+{
+    instance: &'a mut I,
+    queue: &'a mut DmaTxQueue<N>,
+    peripheral: DmaPeripheral,
+}
+
+impl<'a, I, const N: usize> DmaTransferTxQueue<'a, I, N>
+where
+    I: DmaSupportTx,
+{
+    /// Starts sending the queued buffers. Peripherals which keep consuming
+    /// data have to be started already, others start their transactions in
+    /// [DmaSupport::peripheral_queued](super::dma_private::DmaSupport::peripheral_queued).
+    pub(crate) fn start(
+        instance: &'a mut I,
+        queue: &'a mut DmaTxQueue<N>,
+        peripheral: DmaPeripheral,
+    ) -> Result<Self, DmaError> {
+        let mut this = Self {
+            instance,
+            queue,
+            peripheral,
+        };
+
+        this.start_pending()?;
+
+        let len = this.queue.pending_bytes();
+        if len > 0 {
+            this.instance.peripheral_queued(len);
+        }
+
+        Ok(this)
+    }
+
+    /// Marks the buffers as sent which the DMA finished and lets the
+    /// peripheral start a transaction for the queued bytes, returns whether
+    /// the peripheral has to be polled again for that.
+    fn update(&mut self) -> bool {
+        let continuing = self.instance.peripheral_continue_dma();
+        self.queue.update(self.instance.tx());
+        continuing
+    }
+
+    /// The error of the DMA or the peripheral, if any.
+    fn error(&mut self) -> Option<DmaError> {
+        if let Some(error) = self.instance.peripheral_error() {
+            return Some(error);
+        }
+
+        let tx = self.instance.tx();
+        tx.has_error()
+            .then(|| self.queue.descriptor_error(tx.error_descriptor_address()))
+    }
+
+    fn start_pending(&mut self) -> Result<(), DmaError> {
+        let Some(chain) = self.queue.first_pending() else {
+            return Ok(());
+        };
+
+        let tx = self.instance.tx();
+        unsafe {
+            tx.prepare_transfer_without_start(self.peripheral, chain)?;
+        }
+        tx.start_transfer()
+    }
+
+    /// Queues `buffer` to be sent after the buffers queued before it.
+    ///
+    /// If the DMA already sent all queued buffers it's started again with this
+    /// one. Returns [DmaError::QueueFull] if `N` buffers are waiting to be
+    /// sent.
+    pub fn enqueue(&mut self, buffer: &'static [u8]) -> Result<(), DmaError> {
+        self.update();
+
+        let idle = self.queue.is_empty();
+        self.queue.enqueue(buffer)?;
+
+        if idle {
+            self.start_pending()?;
+        } else {
+            // The DMA might have read the link of the previous buffer before it
+            // was set. This makes it read the link again, it has no effect if the
+            // DMA moved on to the new buffer already.
+            self.instance.tx().restart_transfer();
+        }
+
+        self.instance.peripheral_queued(buffer.len());
+
+        Ok(())
+    }
+
+    /// Number of queued buffers which weren't sent yet.
+    pub fn pending(&mut self) -> usize {
+        self.update();
+        self.queue.len()
+    }
+
+    /// Returns the number of buffers sent since the last call.
+    pub fn completed(&mut self) -> usize {
+        self.update();
+        self.queue.take_completed()
+    }
+
+    /// Waits until at least one buffer was sent since the last call and
+    /// returns the number of buffers sent.
+    ///
+    /// Returns `Ok(0)` right away if there are no buffers left to send.
+    pub fn wait_completed(&mut self) -> Result<usize, DmaError> {
+        loop {
+            self.update();
+
+            let completed = self.queue.take_completed();
+            if completed > 0 || self.queue.is_empty() {
+                return Ok(completed);
+            }
+
+            if let Some(error) = self.error() {
+                return Err(error);
+            }
+        }
+    }
+
+    /// Waits until at least one buffer was sent since the last call and
+    /// returns the number of buffers sent, see
+    /// [DmaTransferTxQueue::wait_completed].
+    ///
+    /// The DMA channel needs to be configured for async operation.
+    #[cfg(feature = "async")]
+    pub async fn wait_completed_async(&mut self) -> Result<usize, DmaError> {
+        loop {
+            let continuing = self.update();
+
+            let completed = self.queue.take_completed();
+            if completed > 0 || self.queue.is_empty() {
+                return Ok(completed);
+            }
+
+            self.wait_for_progress(continuing).await?;
+        }
+    }
+
+    /// Waits until all queued buffers were sent and the peripheral is done
+    /// with them, then stops the transfer.
+    ///
+    /// Only for peripherals which stop on their own once they sent the queued
+    /// data, like SPI. I2S keeps running, use [DmaTransferTxQueue::stop]
+    /// instead.
+    pub fn wait(mut self) -> Result<(), DmaError> {
+        loop {
+            let continuing = self.update();
+
+            if let Some(error) = self.error() {
+                return Err(error);
+            }
+
+            if self.queue.is_empty() && !continuing {
+                break;
+            }
+        }
+
+        self.instance.peripheral_wait_dma(true, false);
+        self.stop()
+    }
+
+    /// Waits until all queued buffers were sent and the peripheral is done
+    /// with them, then stops the transfer, see [DmaTransferTxQueue::wait].
+    ///
+    /// The DMA channel needs to be configured for async operation.
+    #[cfg(feature = "async")]
+    pub async fn wait_async(mut self) -> Result<(), DmaError> {
+        loop {
+            let continuing = self.update();
+
+            if let Some(error) = self.error() {
+                return Err(error);
+            }
+
+            if self.queue.is_empty() && !continuing {
+                break;
+            }
+
+            self.wait_for_progress(continuing).await?;
+        }
+
+        self.instance.peripheral_wait_dma(true, false);
+        self.stop()
+    }
+
+    /// Waits for the DMA to finish a buffer. The end of a transaction of the
+    /// peripheral doesn't wake the task, so it's polled while the peripheral
+    /// has to start another one.
+    #[cfg(feature = "async")]
+    async fn wait_for_progress(&mut self, continuing: bool) -> Result<(), DmaError> {
+        if continuing {
+            embassy_futures::yield_now().await;
+            return Ok(());
+        }
+
+        let result = super::asynch::DmaTxDoneChFuture::new(self.instance.tx()).await;
+        result.map_err(|_| {
+            // The future already cleared the error flag, the address of the
+            // descriptor is still readable.
+            self.instance.peripheral_error().unwrap_or_else(|| {
+                let address = self.instance.tx().error_descriptor_address();
+                self.queue.descriptor_error(address)
+            })
+        })
+    }
+
+    /// Stop the DMA transfer, buffers which weren't sent yet are discarded.
+    pub fn stop(mut self) -> Result<(), DmaError> {
+        let error = self.error();
+        self.instance.peripheral_dma_stop();
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a, I, const N: usize> Drop for DmaTransferTxQueue<'a, I, N>
+where
+    I: DmaSupportTx,
+{
+    fn drop(&mut self) {
+        self.instance.peripheral_dma_stop();
+        self.instance.tx().stop_transfer();
+        self.queue.clear();
+    }
+}
@@ -0,0 +1,90 @@
+//! # DMA statistics
+//!
+//! ## Overview
+//! With the `dma-stats` feature enabled every DMA channel counts the
+//! transfers it started, the bytes it transferred, the descriptor errors it
+//! ran into and the EOF interrupts it handled, separately for TX and RX.
+//!
+//! The counters are read with [Channel::stats](super::Channel::stats) and
+//! cleared with [Channel::reset_stats](super::Channel::reset_stats). Without
+//! the feature nothing is counted.
+//!
+//! The bytes are counted when waiting for a transfer with `wait`, transfers
+//! which are awaited asynchronously or stopped early only count as started.
+
+use portable_atomic::{AtomicU32, Ordering};
+
+/// Counters of one direction of a DMA channel
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DmaDirectionStats {
+    /// Number of transfers started
+    pub transfers: u32,
+    /// Number of bytes transferred by completed transfers
+    pub bytes: u32,
+    /// Number of descriptor errors
+    pub descriptor_errors: u32,
+    /// Number of EOF interrupts handled
+    pub eof_interrupts: u32,
+}
+
+/// Counters of a DMA channel
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DmaChannelStats {
+    /// Counters of the TX half of the channel
+    pub tx: DmaDirectionStats,
+    /// Counters of the RX half of the channel
+    pub rx: DmaDirectionStats,
+}
+
+#[doc(hidden)]
+pub struct DmaStatsCounters {
+    transfers: AtomicU32,
+    bytes: AtomicU32,
+    descriptor_errors: AtomicU32,
+    eof_interrupts: AtomicU32,
+}
+
+impl DmaStatsCounters {
+    pub(crate) const fn new() -> Self {
+        Self {
+            transfers: AtomicU32::new(0),
+            bytes: AtomicU32::new(0),
+            descriptor_errors: AtomicU32::new(0),
+            eof_interrupts: AtomicU32::new(0),
+        }
+    }
+
+    pub(crate) fn count_transfer(&self) {
+        self.transfers.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn count_bytes(&self, bytes: usize) {
+        self.bytes.fetch_add(bytes as u32, Ordering::Relaxed);
+    }
+
+    pub(crate) fn count_descriptor_error(&self) {
+        self.descriptor_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn count_eof_interrupt(&self) {
+        self.eof_interrupts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> DmaDirectionStats {
+        DmaDirectionStats {
+            transfers: self.transfers.load(Ordering::Relaxed),
+            bytes: self.bytes.load(Ordering::Relaxed),
+            descriptor_errors: self.descriptor_errors.load(Ordering::Relaxed),
+            eof_interrupts: self.eof_interrupts.load(Ordering::Relaxed),
+        }
+    }
+
+    pub(crate) fn reset(&self) {
+        self.transfers.store(0, Ordering::Relaxed);
+        self.bytes.store(0, Ordering::Relaxed);
+        self.descriptor_errors.store(0, Ordering::Relaxed);
+        self.eof_interrupts.store(0, Ordering::Relaxed);
+    }
+}
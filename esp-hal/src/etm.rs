@@ -61,6 +61,8 @@
 //! # }
 //! ```
 
+use core::marker::PhantomData;
+
 use crate::{
     peripheral::{Peripheral, PeripheralRef},
     system::PeripheralClockControl,
@@ -79,19 +81,7 @@ impl<const C: u8> EtmChannel<C> {
         E: EtmEvent,
         T: EtmTask,
     {
-        let etm = unsafe { crate::peripherals::SOC_ETM::steal() };
-
-        etm.ch(C as usize)
-            .evt_id()
-            .modify(|_, w| unsafe { w.evt_id().bits(event.id()) });
-        etm.ch(C as usize)
-            .task_id()
-            .modify(|_, w| unsafe { w.task_id().bits(task.id()) });
-        if C < 32 {
-            etm.ch_ena_ad0_set().write(|w| w.ch_set(C).set_bit());
-        } else {
-            etm.ch_ena_ad1_set().write(|w| w.ch_set(C - 32).set_bit());
-        }
+        enable_channel(C, event.id(), task.id());
 
         EtmConfiguredChannel {
             _event: event,
@@ -100,6 +90,155 @@ impl<const C: u8> EtmChannel<C> {
     }
 }
 
+impl<const C: u8> EtmChannel<C> {
+    /// Setup the channel together with a second channel
+    ///
+    /// Both channels trigger `task`, one of them on each of the combined
+    /// events.
+    pub fn setup_combined<'a, E1, E2, T, const C2: u8>(
+        self,
+        second: EtmChannel<C2>,
+        events: &'a EtmEventCombiner<'a, E1, E2>,
+        task: &'a T,
+    ) -> (
+        EtmConfiguredChannel<'a, E1, T, C>,
+        EtmConfiguredChannel<'a, E2, T, C2>,
+    )
+    where
+        E1: EtmEvent,
+        E2: EtmEvent,
+        T: EtmTask,
+    {
+        match events.mode {
+            CombineMode::Or => (
+                self.setup(events.first, task),
+                second.setup(events.second, task),
+            ),
+        }
+    }
+
+    /// Setup the channel together with two more channels for a logical AND
+    ///
+    /// The second and third channel set and clear the latch of `events`, this
+    /// channel triggers `task` when the latch is cleared while it was set.
+    pub fn setup_and<'a, E1, E2, L, T, const C2: u8, const C3: u8>(
+        self,
+        second: EtmChannel<C2>,
+        third: EtmChannel<C3>,
+        events: &'a EtmAndCombiner<'a, E1, E2, L>,
+        task: &'a T,
+    ) -> EtmConfiguredAndChannels<'a, C, C2, C3>
+    where
+        E1: EtmEvent,
+        E2: EtmEvent,
+        L: EtmLatch,
+        T: EtmTask,
+    {
+        let _ = (second, third);
+
+        enable_channel(C, events.latch.cleared_event_id(), task.id());
+        enable_channel(C2, events.first.id(), events.latch.set_task_id());
+        enable_channel(C3, events.second.id(), events.latch.clear_task_id());
+
+        EtmConfiguredAndChannels {
+            _events: PhantomData,
+        }
+    }
+}
+
+/// How the events of an [EtmEventCombiner] are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum CombineMode {
+    /// The task is triggered by either event.
+    Or,
+}
+
+/// Two events combined into one
+///
+/// The ETM has no native event combiners, so a combined event is set up on two
+/// channels with [EtmChannel::setup_combined]. Events are combined with a
+/// logical AND by an [EtmAndCombiner].
+pub struct EtmEventCombiner<'a, E1, E2>
+where
+    E1: EtmEvent,
+    E2: EtmEvent,
+{
+    first: &'a E1,
+    second: &'a E2,
+    mode: CombineMode,
+}
+
+impl<'a, E1, E2> EtmEventCombiner<'a, E1, E2>
+where
+    E1: EtmEvent,
+    E2: EtmEvent,
+{
+    /// Combine two events
+    pub fn new(first: &'a E1, second: &'a E2, mode: CombineMode) -> Self {
+        Self {
+            first,
+            second,
+            mode,
+        }
+    }
+}
+
+/// Two events combined with a logical AND
+///
+/// The ETM can't keep state between events, so the combination goes through a
+/// latch like [GpioEtmLatch](crate::gpio::etm::GpioEtmLatch). The first event
+/// sets the latch and the second event clears it. The task is triggered when a
+/// set latch is cleared, i.e. by the second event if the first one happened
+/// since the task was last triggered.
+///
+/// The combined event is set up on three channels with
+/// [EtmChannel::setup_and].
+pub struct EtmAndCombiner<'a, E1, E2, L>
+where
+    E1: EtmEvent,
+    E2: EtmEvent,
+    L: EtmLatch,
+{
+    first: &'a E1,
+    second: &'a E2,
+    latch: &'a L,
+}
+
+impl<'a, E1, E2, L> EtmAndCombiner<'a, E1, E2, L>
+where
+    E1: EtmEvent,
+    E2: EtmEvent,
+    L: EtmLatch,
+{
+    /// Combine two events through `latch`
+    pub fn new(first: &'a E1, second: &'a E2, latch: &'a L) -> Self {
+        Self {
+            first,
+            second,
+            latch,
+        }
+    }
+}
+
+fn enable_channel(channel: u8, event: u8, task: u8) {
+    let etm = unsafe { crate::peripherals::SOC_ETM::steal() };
+
+    etm.ch(channel as usize)
+        .evt_id()
+        .modify(|_, w| unsafe { w.evt_id().bits(event) });
+    etm.ch(channel as usize)
+        .task_id()
+        .modify(|_, w| unsafe { w.task_id().bits(task) });
+    if channel < 32 {
+        etm.ch_ena_ad0_set().write(|w| w.ch_set(channel).set_bit());
+    } else {
+        etm.ch_ena_ad1_set()
+            .write(|w| w.ch_set(channel - 32).set_bit());
+    }
+}
+
 fn disable_channel(channel: u8) {
     let etm = unsafe { crate::peripherals::SOC_ETM::steal() };
     if channel < 32 {
@@ -134,6 +273,23 @@ where
     }
 }
 
+/// The readily configured channels of an [EtmAndCombiner]
+///
+/// The channels are disabled when this is dropped.
+#[non_exhaustive]
+pub struct EtmConfiguredAndChannels<'a, const C: u8, const C2: u8, const C3: u8> {
+    _events: PhantomData<&'a ()>,
+}
+
+impl<'a, const C: u8, const C2: u8, const C3: u8> Drop for EtmConfiguredAndChannels<'a, C, C2, C3> {
+    fn drop(&mut self) {
+        debug!("drop {} {} {}", C, C2, C3);
+        disable_channel(C2);
+        disable_channel(C3);
+        disable_channel(C);
+    }
+}
+
 macro_rules! create_etm {
     ($($num:literal),+) => {
         paste::paste! {
@@ -175,3 +331,12 @@ pub trait EtmEvent: crate::private::Sealed {
 pub trait EtmTask: crate::private::Sealed {
     fn id(&self) -> u8;
 }
+
+#[doc(hidden)]
+pub trait EtmLatch: crate::private::Sealed {
+    fn set_task_id(&self) -> u8;
+
+    fn clear_task_id(&self) -> u8;
+
+    fn cleared_event_id(&self) -> u8;
+}
@@ -361,6 +361,62 @@ impl<const C: u8> GpioEtmTaskChannel<C> {
         enable_task_channel(C, pin.number(private::Internal));
         GpioEtmTaskToggle { _pin: pin }
     }
+
+    /// Latch on a pin for [EtmAndCombiner](crate::etm::EtmAndCombiner)
+    ///
+    /// The set and clear tasks of this channel drive `pin`, and
+    /// `event_channel` reports its falling edges. The pin starts low.
+    pub fn latch<'d, PIN, const E: u8>(
+        self,
+        event_channel: GpioEtmEventChannel<E>,
+        pin: impl Peripheral<P = PIN> + 'd,
+    ) -> GpioEtmLatch<'d, PIN, C, E>
+    where
+        PIN: super::InputPin + super::OutputPin,
+    {
+        let _ = event_channel;
+        crate::into_ref!(pin);
+
+        pin.set_output_high(false, private::Internal);
+        pin.set_to_push_pull_output(private::Internal);
+        // the event channel watches the level the pin is driven to
+        pin.enable_input(true, private::Internal);
+
+        enable_task_channel(C, pin.number(private::Internal));
+        enable_event_channel(E, pin.number(private::Internal));
+        GpioEtmLatch { _pin: pin }
+    }
+}
+
+/// A pin based latch, see [GpioEtmTaskChannel::latch]
+#[non_exhaustive]
+pub struct GpioEtmLatch<'d, PIN, const C: u8, const E: u8>
+where
+    PIN: super::Pin,
+{
+    _pin: PeripheralRef<'d, PIN>,
+}
+
+impl<'d, PIN, const C: u8, const E: u8> private::Sealed for GpioEtmLatch<'d, PIN, C, E> where
+    PIN: super::Pin
+{
+}
+
+impl<'d, PIN, const C: u8, const E: u8> crate::etm::EtmLatch for GpioEtmLatch<'d, PIN, C, E>
+where
+    PIN: super::Pin,
+{
+    fn set_task_id(&self) -> u8 {
+        1 + C
+    }
+
+    fn clear_task_id(&self) -> u8 {
+        9 + C
+    }
+
+    fn cleared_event_id(&self) -> u8 {
+        9 + E
+    }
 }
 
 /// Task for set operation
@@ -10,6 +10,10 @@
 //! toggling). It provides an interface to interact with GPIO pins on ESP chips,
 //! allowing developers to control and read the state of the pins.
 //!
+//! Toggling an output inverts the level in its output register, not the level
+//! sensed on the pin. This matters for open drain outputs, where the sensed
+//! level can differ from the driven one.
+//!
 //! ## Usage
 //! This module also implements a number of traits from [embedded-hal] to
 //! provide a common interface for GPIO pins.
@@ -148,6 +152,51 @@ impl From<Level> for bool {
     }
 }
 
+/// Configuration of an open-drain output, see
+/// [OutputOpenDrain::new_with_config]
+///
+/// The internal pull-up resistor is roughly 45 kΩ. This is too weak for I2C
+/// faster than 100 kHz, use an external pull-up resistor for that.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OutputConfig {
+    /// The initial output level
+    pub initial_output: Level,
+    /// Whether the internal pull-up resistor is enabled
+    pub pullup: bool,
+}
+
+impl Default for OutputConfig {
+    fn default() -> Self {
+        Self {
+            initial_output: Level::High,
+            pullup: false,
+        }
+    }
+}
+
+impl OutputConfig {
+    /// Set the initial output level, [Level::High] by default.
+    pub fn initial_output(mut self, level: Level) -> Self {
+        self.initial_output = level;
+        self
+    }
+
+    /// Enable or disable the internal pull-up resistor, disabled by default.
+    pub fn pullup(mut self, enable: bool) -> Self {
+        self.pullup = enable;
+        self
+    }
+
+    fn pull(&self) -> Pull {
+        if self.pullup {
+            Pull::Up
+        } else {
+            Pull::None
+        }
+    }
+}
+
 /// Pull setting for an input.
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -1753,6 +1802,8 @@ where
 {
     /// Create GPIO open-drain output driver for a [Pin] with the provided
     /// initial output-level and [Pull] configuration.
+    ///
+    /// See [OutputConfig] for the strength of the internal pull-up resistor.
     #[inline]
     pub fn new(
         pin: impl crate::peripheral::Peripheral<P = P> + 'd,
@@ -1768,6 +1819,16 @@ where
         Self { pin }
     }
 
+    /// Create GPIO open-drain output driver for a [Pin] with the provided
+    /// [OutputConfig].
+    #[inline]
+    pub fn new_with_config(
+        pin: impl crate::peripheral::Peripheral<P = P> + 'd,
+        config: OutputConfig,
+    ) -> Self {
+        Self::new(pin, config.initial_output, config.pull())
+    }
+
     /// Get whether the pin input level is high.
     #[inline]
     pub fn is_high(&self) -> bool {
@@ -1810,6 +1871,14 @@ where
         self.pin.set_output_high(false, private::Internal);
     }
 
+    /// Enable or disable the internal pull-up resistor.
+    ///
+    /// See [OutputConfig] for the strength of the internal pull-up resistor.
+    #[inline]
+    pub fn set_pullup(&mut self, enable: bool) {
+        self.pin.internal_pull_up(enable, private::Internal);
+    }
+
     /// Set the output level.
     #[inline]
     pub fn set_level(&mut self, level: Level) {
@@ -2098,6 +2167,8 @@ pub struct AnyOutputOpenDrain<'d> {
 impl<'d> AnyOutputOpenDrain<'d> {
     /// Create GPIO open-drain output driver for a [Pin] with the provided
     /// initial output-level and [Pull] configuration.
+    ///
+    /// See [OutputConfig] for the strength of the internal pull-up resistor.
     #[inline]
     pub fn new<P: OutputPin + InputPin + CreateErasedPin>(
         pin: impl crate::peripheral::Peripheral<P = P> + 'd,
@@ -2118,6 +2189,16 @@ impl<'d> AnyOutputOpenDrain<'d> {
         }
     }
 
+    /// Create GPIO open-drain output driver for a [Pin] with the provided
+    /// [OutputConfig].
+    #[inline]
+    pub fn new_with_config<P: OutputPin + InputPin + CreateErasedPin>(
+        pin: impl crate::peripheral::Peripheral<P = P> + 'd,
+        config: OutputConfig,
+    ) -> Self {
+        Self::new(pin, config.initial_output, config.pull())
+    }
+
     /// Get whether the pin input level is high.
     #[inline]
     pub fn is_high(&self) -> bool {
@@ -2160,6 +2241,14 @@ impl<'d> AnyOutputOpenDrain<'d> {
         self.pin.set_output_high(false, private::Internal);
     }
 
+    /// Enable or disable the internal pull-up resistor.
+    ///
+    /// See [OutputConfig] for the strength of the internal pull-up resistor.
+    #[inline]
+    pub fn set_pullup(&mut self, enable: bool) {
+        self.pin.internal_pull_up(enable, private::Internal);
+    }
+
     /// Set the output level.
     #[inline]
     pub fn set_level(&mut self, level: Level) {
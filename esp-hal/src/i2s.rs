@@ -66,7 +66,7 @@
 //! let mut transfer = i2s_rx.read_dma_circular(&mut rx_buffer).unwrap();
 //!
 //! loop {
-//!     let avail = transfer.available();
+//!     let avail = transfer.available().unwrap();
 //!
 //!     if avail > 0 {
 //!         let mut rcv = [0u8; 5000];
@@ -95,6 +95,7 @@ use crate::{
         Channel,
         ChannelRx,
         ChannelTx,
+        CircularSnapshot,
         DescriptorChain,
         DmaChannel,
         DmaDescriptor,
@@ -103,9 +104,13 @@ use crate::{
         DmaTransferRxCircular,
         DmaTransferTx,
         DmaTransferTxCircular,
+        DmaTransferTxQueue,
+        DmaTransferTxRxCircular,
+        DmaTxQueue,
         I2s0Peripheral,
         I2sPeripheral,
         RxPrivate,
+        TxCircularState,
         TxPrivate,
     },
     gpio::OutputPin,
@@ -135,6 +140,18 @@ const I2S_LL_MCLK_DIVIDER_BIT_WIDTH: usize = 9;
 
 const I2S_LL_MCLK_DIVIDER_MAX: usize = (1 << I2S_LL_MCLK_DIVIDER_BIT_WIDTH) - 1;
 
+/// The largest TDM half frame in bits, limited by the 6 bit HALF_SAMPLE_BITS
+/// field (9 bit WS width and 8 bit HALF_SAMPLE_BITS on the ESP32-H2)
+#[cfg(any(esp32c3, esp32c6, esp32s3))]
+const TDM_MAX_HALF_FRAME_BITS: u32 = 64;
+
+#[cfg(esp32h2)]
+const TDM_MAX_HALF_FRAME_BITS: u32 = 256;
+
+/// The BCLK divider used in PDM TX mode, the minimum supported by the hardware
+#[cfg(not(esp32s2))]
+const PDM_TX_BCLK_DIVIDER: u32 = 8;
+
 trait AcceptedWord {}
 impl AcceptedWord for u8 {}
 impl AcceptedWord for u16 {}
@@ -167,6 +184,40 @@ pub enum Standard {
     // Pdm,
 }
 
+/// Upsampling of the PCM samples in PDM TX mode
+///
+/// The hardware filter upsamples the PCM data by `fp / fs` before converting
+/// it to a 1-bit PDM stream.
+#[cfg(not(esp32s2))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PdmUpsample {
+    /// The PDM clock is fixed at 128 × 48kHz, independent of the sample rate
+    /// (`fp` = 960, `fs` = sample rate / 100). The sample rate can't exceed
+    /// 48kHz.
+    Fixed,
+    /// The PDM clock is 128 × the sample rate (`fp` = 960, `fs` = 480)
+    SampleRate,
+}
+
+#[cfg(not(esp32s2))]
+impl PdmUpsample {
+    /// Returns the `fp` and `fs` upsampling parameters for the given sample
+    /// rate
+    fn fp_fs(&self, sample_rate: u32) -> Result<(u32, u32), Error> {
+        match self {
+            PdmUpsample::Fixed => {
+                let fs = sample_rate / 100;
+                if fs == 0 || fs > 480 {
+                    return Err(Error::IllegalArgument);
+                }
+                Ok((960, fs))
+            }
+            PdmUpsample::SampleRate => Ok((960, 480)),
+        }
+    }
+}
+
 /// Supported data formats
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -187,6 +238,9 @@ pub enum DataFormat {
 #[cfg(any(esp32, esp32s2))]
 pub enum DataFormat {
     Data32Channel32,
+    /// 24-bit samples, each one stored in the lower three bytes of a 32-bit
+    /// word
+    Data32Channel24,
     Data16Channel16,
 }
 
@@ -222,6 +276,7 @@ impl DataFormat {
     pub fn data_bits(&self) -> u8 {
         match self {
             DataFormat::Data32Channel32 => 32,
+            DataFormat::Data32Channel24 => 32,
             DataFormat::Data16Channel16 => 16,
         }
     }
@@ -229,11 +284,108 @@ impl DataFormat {
     pub fn channel_bits(&self) -> u8 {
         match self {
             DataFormat::Data32Channel32 => 32,
+            DataFormat::Data32Channel24 => 24,
             DataFormat::Data16Channel16 => 16,
         }
     }
 }
 
+/// Bits per sample
+///
+/// Converts into the [DataFormat] which stores each sample in the smallest
+/// word it fits in, e.g. 24-bit samples in 32-bit words.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BitsPerSample {
+    #[cfg(not(any(esp32, esp32s2)))]
+    Bits8,
+    Bits16,
+    Bits24,
+    Bits32,
+}
+
+impl From<BitsPerSample> for DataFormat {
+    fn from(bits: BitsPerSample) -> Self {
+        match bits {
+            #[cfg(not(any(esp32, esp32s2)))]
+            BitsPerSample::Bits8 => DataFormat::Data8Channel8,
+            BitsPerSample::Bits16 => DataFormat::Data16Channel16,
+            BitsPerSample::Bits24 => DataFormat::Data32Channel24,
+            BitsPerSample::Bits32 => DataFormat::Data32Channel32,
+        }
+    }
+}
+
+/// Configuration for [I2s::new_with_config]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct I2sConfig {
+    standard: Standard,
+    bits_per_sample: BitsPerSample,
+    sample_rate: fugit::HertzU32,
+}
+
+impl I2sConfig {
+    /// Creates a configuration for the Philips standard with 16 bits per
+    /// sample
+    pub fn new(sample_rate: impl Into<fugit::HertzU32>) -> Self {
+        Self {
+            standard: Standard::Philips,
+            bits_per_sample: BitsPerSample::Bits16,
+            sample_rate: sample_rate.into(),
+        }
+    }
+
+    /// Sets the standard
+    pub fn standard(mut self, standard: Standard) -> Self {
+        self.standard = standard;
+        self
+    }
+
+    /// Sets the bits per sample, which selects the [DataFormat]
+    pub fn bits_per_sample(mut self, bits_per_sample: BitsPerSample) -> Self {
+        self.bits_per_sample = bits_per_sample;
+        self
+    }
+}
+
+/// Marker for the sample width of an [I2sWriter], see [SampleBits]
+pub struct Bits<const BITS: u8>;
+
+/// The sample type written by an [I2sWriter] of a given width
+pub trait SampleBits: crate::private::Sealed {
+    /// The sample type
+    type Sample: Copy;
+    /// The bits per sample the peripheral has to be configured for
+    const BITS_PER_SAMPLE: BitsPerSample;
+}
+
+#[cfg(not(any(esp32, esp32s2)))]
+impl crate::private::Sealed for Bits<8> {}
+#[cfg(not(any(esp32, esp32s2)))]
+impl SampleBits for Bits<8> {
+    type Sample = i8;
+    const BITS_PER_SAMPLE: BitsPerSample = BitsPerSample::Bits8;
+}
+
+impl crate::private::Sealed for Bits<16> {}
+impl SampleBits for Bits<16> {
+    type Sample = i16;
+    const BITS_PER_SAMPLE: BitsPerSample = BitsPerSample::Bits16;
+}
+
+impl crate::private::Sealed for Bits<24> {}
+/// 24-bit samples are stored in the lower three bytes of an `i32`
+impl SampleBits for Bits<24> {
+    type Sample = i32;
+    const BITS_PER_SAMPLE: BitsPerSample = BitsPerSample::Bits24;
+}
+
+impl crate::private::Sealed for Bits<32> {}
+impl SampleBits for Bits<32> {
+    type Sample = i32;
+    const BITS_PER_SAMPLE: BitsPerSample = BitsPerSample::Bits32;
+}
+
 /// Blocking I2s Write
 pub trait I2sWrite<W> {
     fn write(&mut self, words: &[W]) -> Result<(), Error>;
@@ -313,22 +465,16 @@ where
     CH: DmaChannel,
     DmaMode: Mode,
 {
-    #[allow(clippy::too_many_arguments)]
-    fn new_internal(
-        _i2s: impl Peripheral<P = I> + 'd,
+    fn configure_master(
         standard: Standard,
         data_format: DataFormat,
         sample_rate: impl Into<fugit::HertzU32>,
-        mut channel: Channel<'d, CH, DmaMode>,
-        tx_descriptors: &'static mut [DmaDescriptor],
-        rx_descriptors: &'static mut [DmaDescriptor],
         clocks: &Clocks,
-    ) -> Self {
+    ) {
         // on ESP32-C3 / ESP32-S3 and later RX and TX are independent and
         // could be configured totally independently but for now handle all
         // the targets the same and force same configuration for both, TX and RX
 
-        channel.tx.init_channel();
         PeripheralClockControl::enable(I::get_peripheral());
         I::set_clock(calculate_clock(
             sample_rate,
@@ -339,12 +485,69 @@ where
         I::configure(&standard, &data_format);
         I::set_master();
         I::update();
+    }
+
+    fn new_tx_internal(
+        _i2s: impl Peripheral<P = I> + 'd,
+        standard: Standard,
+        data_format: DataFormat,
+        sample_rate: impl Into<fugit::HertzU32>,
+        mut channel: ChannelTx<'d, CH>,
+        tx_descriptors: &'static mut [DmaDescriptor],
+        clocks: &Clocks,
+    ) -> TxCreator<'d, I, CH, DmaMode> {
+        channel.init_channel();
+        Self::configure_master(standard, data_format, sample_rate, clocks);
+
+        TxCreator {
+            register_access: PhantomData,
+            tx_channel: channel,
+            descriptors: tx_descriptors,
+            data_format,
+            phantom: PhantomData,
+        }
+    }
+
+    fn new_rx_internal(
+        _i2s: impl Peripheral<P = I> + 'd,
+        standard: Standard,
+        data_format: DataFormat,
+        sample_rate: impl Into<fugit::HertzU32>,
+        mut channel: ChannelRx<'d, CH>,
+        rx_descriptors: &'static mut [DmaDescriptor],
+        clocks: &Clocks,
+    ) -> RxCreator<'d, I, CH, DmaMode> {
+        channel.init_channel();
+        Self::configure_master(standard, data_format, sample_rate, clocks);
+
+        RxCreator {
+            register_access: PhantomData,
+            rx_channel: channel,
+            descriptors: rx_descriptors,
+            phantom: PhantomData,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn new_internal(
+        _i2s: impl Peripheral<P = I> + 'd,
+        standard: Standard,
+        data_format: DataFormat,
+        sample_rate: impl Into<fugit::HertzU32>,
+        mut channel: Channel<'d, CH, DmaMode>,
+        tx_descriptors: &'static mut [DmaDescriptor],
+        rx_descriptors: &'static mut [DmaDescriptor],
+        clocks: &Clocks,
+    ) -> Self {
+        channel.tx.init_channel();
+        Self::configure_master(standard, data_format, sample_rate, clocks);
 
         Self {
             i2s_tx: TxCreator {
                 register_access: PhantomData,
                 tx_channel: channel.tx,
                 descriptors: tx_descriptors,
+                data_format,
                 phantom: PhantomData,
             },
             i2s_rx: RxCreator {
@@ -423,7 +626,7 @@ where
     pub fn new(
         i2s: impl Peripheral<P = I> + 'd,
         standard: Standard,
-        data_format: DataFormat,
+        data_format: impl Into<DataFormat>,
         sample_rate: impl Into<fugit::HertzU32>,
         channel: Channel<'d, CH, DmaMode>,
         tx_descriptors: &'static mut [DmaDescriptor],
@@ -438,7 +641,7 @@ where
         Self::new_internal(
             i2s,
             standard,
-            data_format,
+            data_format.into(),
             sample_rate,
             channel,
             tx_descriptors,
@@ -454,7 +657,7 @@ where
     pub fn new_i2s1(
         i2s: impl Peripheral<P = I> + 'd,
         standard: Standard,
-        data_format: DataFormat,
+        data_format: impl Into<DataFormat>,
         sample_rate: impl Into<fugit::HertzU32>,
         channel: Channel<'d, CH, DmaMode>,
         tx_descriptors: &'static mut [DmaDescriptor],
@@ -468,7 +671,7 @@ where
         Self::new_internal(
             i2s,
             standard,
-            data_format,
+            data_format.into(),
             sample_rate,
             channel,
             tx_descriptors,
@@ -477,12 +680,256 @@ where
         )
     }
 
+    /// Construct a new I2S peripheral driver instance for the first I2S
+    /// peripheral from an [I2sConfig]
+    pub fn new_with_config(
+        i2s: impl Peripheral<P = I> + 'd,
+        config: I2sConfig,
+        channel: Channel<'d, CH, DmaMode>,
+        tx_descriptors: &'static mut [DmaDescriptor],
+        rx_descriptors: &'static mut [DmaDescriptor],
+        clocks: &Clocks,
+    ) -> Self
+    where
+        I: I2s0Instance,
+        CH::P: I2sPeripheral + I2s0Peripheral,
+    {
+        Self::new_internal(
+            i2s,
+            config.standard,
+            config.bits_per_sample.into(),
+            config.sample_rate,
+            channel,
+            tx_descriptors,
+            rx_descriptors,
+            clocks,
+        )
+    }
+
+    /// Construct a new I2S peripheral driver instance for the second I2S
+    /// peripheral from an [I2sConfig]
+    #[cfg(any(esp32s3, esp32))]
+    pub fn new_i2s1_with_config(
+        i2s: impl Peripheral<P = I> + 'd,
+        config: I2sConfig,
+        channel: Channel<'d, CH, DmaMode>,
+        tx_descriptors: &'static mut [DmaDescriptor],
+        rx_descriptors: &'static mut [DmaDescriptor],
+        clocks: &Clocks,
+    ) -> Self
+    where
+        I: I2s1Instance,
+        CH::P: I2sPeripheral + I2s1Peripheral,
+    {
+        Self::new_internal(
+            i2s,
+            config.standard,
+            config.bits_per_sample.into(),
+            config.sample_rate,
+            channel,
+            tx_descriptors,
+            rx_descriptors,
+            clocks,
+        )
+    }
+
+    /// Construct an I2S driver for the first I2S peripheral which only sends,
+    /// using the TX half of a DMA channel split with [Channel::split]
+    ///
+    /// The RX half stays available to other drivers. A channel half doesn't
+    /// tell whether the channel was configured for async use, so the mode is
+    /// named on the type, e.g. `I2s::<_, _, Async>::new_tx(...)`.
+    pub fn new_tx(
+        i2s: impl Peripheral<P = I> + 'd,
+        standard: Standard,
+        data_format: impl Into<DataFormat>,
+        sample_rate: impl Into<fugit::HertzU32>,
+        channel: ChannelTx<'d, CH>,
+        tx_descriptors: &'static mut [DmaDescriptor],
+        clocks: &Clocks,
+    ) -> TxCreator<'d, I, CH, DmaMode>
+    where
+        I: I2s0Instance,
+        CH::P: I2sPeripheral + I2s0Peripheral,
+    {
+        Self::new_tx_internal(
+            i2s,
+            standard,
+            data_format.into(),
+            sample_rate,
+            channel,
+            tx_descriptors,
+            clocks,
+        )
+    }
+
+    /// Construct an I2S driver for the first I2S peripheral which only
+    /// receives, using the RX half of a DMA channel split with
+    /// [Channel::split]
+    ///
+    /// See [I2s::new_tx].
+    pub fn new_rx(
+        i2s: impl Peripheral<P = I> + 'd,
+        standard: Standard,
+        data_format: impl Into<DataFormat>,
+        sample_rate: impl Into<fugit::HertzU32>,
+        channel: ChannelRx<'d, CH>,
+        rx_descriptors: &'static mut [DmaDescriptor],
+        clocks: &Clocks,
+    ) -> RxCreator<'d, I, CH, DmaMode>
+    where
+        I: I2s0Instance,
+        CH::P: I2sPeripheral + I2s0Peripheral,
+    {
+        Self::new_rx_internal(
+            i2s,
+            standard,
+            data_format.into(),
+            sample_rate,
+            channel,
+            rx_descriptors,
+            clocks,
+        )
+    }
+
+    /// Construct an I2S driver for the second I2S peripheral which only sends,
+    /// see [I2s::new_tx]
+    #[cfg(any(esp32s3, esp32))]
+    pub fn new_tx_i2s1(
+        i2s: impl Peripheral<P = I> + 'd,
+        standard: Standard,
+        data_format: impl Into<DataFormat>,
+        sample_rate: impl Into<fugit::HertzU32>,
+        channel: ChannelTx<'d, CH>,
+        tx_descriptors: &'static mut [DmaDescriptor],
+        clocks: &Clocks,
+    ) -> TxCreator<'d, I, CH, DmaMode>
+    where
+        I: I2s1Instance,
+        CH::P: I2sPeripheral + I2s1Peripheral,
+    {
+        Self::new_tx_internal(
+            i2s,
+            standard,
+            data_format.into(),
+            sample_rate,
+            channel,
+            tx_descriptors,
+            clocks,
+        )
+    }
+
+    /// Construct an I2S driver for the second I2S peripheral which only
+    /// receives, see [I2s::new_rx]
+    #[cfg(any(esp32s3, esp32))]
+    pub fn new_rx_i2s1(
+        i2s: impl Peripheral<P = I> + 'd,
+        standard: Standard,
+        data_format: impl Into<DataFormat>,
+        sample_rate: impl Into<fugit::HertzU32>,
+        channel: ChannelRx<'d, CH>,
+        rx_descriptors: &'static mut [DmaDescriptor],
+        clocks: &Clocks,
+    ) -> RxCreator<'d, I, CH, DmaMode>
+    where
+        I: I2s1Instance,
+        CH::P: I2sPeripheral + I2s1Peripheral,
+    {
+        Self::new_rx_internal(
+            i2s,
+            standard,
+            data_format.into(),
+            sample_rate,
+            channel,
+            rx_descriptors,
+            clocks,
+        )
+    }
+
     pub fn with_mclk<P: OutputPin>(self, pin: impl Peripheral<P = P> + 'd) -> Self {
         into_ref!(pin);
         pin.set_to_push_pull_output(crate::private::Internal);
         pin.connect_peripheral_to_output(I::mclk_signal(), crate::private::Internal);
         self
     }
+
+    /// Reconfigures the peripheral for TDM (time division multiplexing) with
+    /// `slot_count` 32-bit slots per frame.
+    ///
+    /// Only the slots set in `slot_mask` are active, the data for inactive
+    /// slots is not taken from the buffer and they are sent as zero. Both TX
+    /// and RX use the same TDM configuration.
+    ///
+    /// Returns [Error::IllegalArgument] if `slot_count` is not in `1..=16` or
+    /// `slot_mask` doesn't select a slot below `slot_count`. Half of the frame
+    /// has to fit the half sample bits register field, which limits the
+    /// ESP32-C3, ESP32-C6 and ESP32-S3 to 4 slots.
+    #[cfg(not(any(esp32, esp32s2)))]
+    pub fn into_tdm(
+        mut self,
+        slot_count: u8,
+        slot_mask: u16,
+        sample_rate: impl Into<fugit::HertzU32>,
+        clocks: &Clocks,
+    ) -> Result<Self, Error> {
+        if !(1..=16).contains(&slot_count) || slot_count as u32 * 32 / 2 > TDM_MAX_HALF_FRAME_BITS {
+            return Err(Error::IllegalArgument);
+        }
+
+        let slot_mask = slot_mask & (u16::MAX >> (16 - slot_count));
+        if slot_mask == 0 {
+            return Err(Error::IllegalArgument);
+        }
+
+        I::set_clock(calculate_clock(sample_rate, slot_count, 32, clocks));
+        I::configure_tdm(slot_count, slot_mask);
+        I::update();
+        self.i2s_tx.data_format = DataFormat::Data32Channel32;
+
+        Ok(self)
+    }
+
+    /// Reconfigures the first I2S peripheral to output PDM (pulse density
+    /// modulation), e.g. to drive a PDM speaker or amplifier directly.
+    ///
+    /// The TX channel takes 16-bit stereo PCM samples (`i16`), which the
+    /// hardware filter converts to PDM. The PDM clock is output on the WS pin
+    /// and the PDM data on the DOUT pin. The RX channel is not usable in PDM
+    /// TX mode.
+    ///
+    /// Returns [Error::IllegalArgument] if the sample rate is not supported by
+    /// the `upsample` setting.
+    #[cfg(not(esp32s2))]
+    pub fn into_pdm_tx(
+        mut self,
+        sample_rate: impl Into<fugit::HertzU32>,
+        upsample: PdmUpsample,
+    ) -> Result<TxCreator<'d, I, CH, DmaMode>, Error>
+    where
+        I: I2s0Instance,
+    {
+        let sample_rate = sample_rate.into().raw();
+        let (fp, fs) = upsample.fp_fs(sample_rate)?;
+
+        I::configure(&Standard::Philips, &DataFormat::Data16Channel16);
+        I::set_clock(calculate_pdm_tx_clock(sample_rate, fp, fs));
+        configure_pdm_tx(fp, fs);
+        I::update();
+        self.i2s_tx.data_format = DataFormat::Data16Channel16;
+
+        Ok(self.i2s_tx)
+    }
+
+    /// Builds both channels for transfers running in both directions at the
+    /// same time, see [I2sTxRx::write_read_dma_circular].
+    ///
+    /// The pins need to be configured on `i2s_tx` and `i2s_rx` before.
+    pub fn build_tx_rx(self) -> I2sTxRx<'d, I, CH, DmaMode> {
+        I2sTxRx {
+            tx: self.i2s_tx.build(),
+            rx: self.i2s_rx.build(),
+        }
+    }
 }
 
 /// I2S TX channel
@@ -586,10 +1033,32 @@ where
         &'t mut self,
         words: &'t TXBUF,
         circular: bool,
+        offset: usize,
     ) -> Result<(), Error>
     where
         TXBUF: ReadBuffer<Word = u8>,
         DmaMode: Mode,
+    {
+        self.prepare_tx_transfer(words, circular, offset)?;
+
+        // start: set I2S_TX_START
+        T::tx_start();
+
+        Ok(())
+    }
+
+    /// Sets up the TX unit and the DMA, the peripheral isn't started yet.
+    ///
+    /// A circular transfer starts at `offset` and wraps around to the start
+    /// of `words`.
+    fn prepare_tx_transfer<TXBUF>(
+        &mut self,
+        words: &TXBUF,
+        circular: bool,
+        offset: usize,
+    ) -> Result<(), Error>
+    where
+        TXBUF: ReadBuffer<Word = u8>,
     {
         let (ptr, len) = unsafe { words.read_buffer() };
 
@@ -600,7 +1069,8 @@ where
 
         // configure DMA outlink
         unsafe {
-            self.tx_chain.fill_for_tx(circular, ptr, len)?;
+            self.tx_chain
+                .fill_for_tx_range(circular, ptr, len, offset, len)?;
             self.tx_channel
                 .prepare_transfer_without_start(T::get_dma_peripheral(), &self.tx_chain)
                 .and_then(|_| self.tx_channel.start_transfer())?;
@@ -608,9 +1078,6 @@ where
 
         // set I2S_TX_STOP_EN if needed
 
-        // start: set I2S_TX_START
-        T::tx_start();
-
         Ok(())
     }
 
@@ -622,6 +1089,27 @@ where
     }
 }
 
+#[cfg(not(any(esp32, esp32s2)))]
+impl<'d, T, CH, DmaMode> I2sTx<'d, T, CH, DmaMode>
+where
+    T: RegisterAccess,
+    CH: DmaChannel,
+    DmaMode: Mode,
+{
+    /// Writes TDM frames after configuring the peripheral with
+    /// [I2s::into_tdm].
+    ///
+    /// `data` contains one sample per active slot, interleaved in slot order,
+    /// so its length has to be a multiple of the number of active slots.
+    pub fn write_tdm(&mut self, data: &[i32]) -> Result<(), Error> {
+        if data.len() % T::tdm_active_slots() != 0 {
+            return Err(Error::IllegalArgument);
+        }
+
+        self.write(data)
+    }
+}
+
 impl<'d, T, W, CH, DmaMode> I2sWrite<W> for I2sTx<'d, T, CH, DmaMode>
 where
     T: RegisterAccess,
@@ -639,28 +1127,152 @@ where
     }
 }
 
-impl<'d, T, CH, TXBUF, DmaMode> I2sWriteDma<'d, T, CH, TXBUF, DmaMode> for I2sTx<'d, T, CH, DmaMode>
+/// I2S TX channel which only takes samples of `BITS` bits
+///
+/// Created by [TxCreator::build_writer], which checks that the peripheral is
+/// configured for the sample width.
+pub struct I2sWriter<'d, T, CH, DmaMode, const BITS: u8>
+where
+    T: RegisterAccess,
+    CH: DmaChannel,
+{
+    tx: I2sTx<'d, T, CH, DmaMode>,
+}
+
+impl<'d, T, CH, DmaMode, const BITS: u8> I2sWriter<'d, T, CH, DmaMode, BITS>
+where
+    T: RegisterAccess,
+    CH: DmaChannel,
+    DmaMode: Mode,
+    Bits<BITS>: SampleBits,
+{
+    /// Writes the samples, blocking until they are sent
+    pub fn write(&mut self, samples: &[<Bits<BITS> as SampleBits>::Sample]) -> Result<(), Error> {
+        self.tx.write_bytes(unsafe {
+            core::slice::from_raw_parts(
+                samples as *const _ as *const u8,
+                core::mem::size_of_val(samples),
+            )
+        })
+    }
+
+    /// Returns the untyped TX channel
+    pub fn into_inner(self) -> I2sTx<'d, T, CH, DmaMode> {
+        self.tx
+    }
+}
+
+impl<'d, T, CH, TXBUF, DmaMode> I2sWriteDma<'d, T, CH, TXBUF, DmaMode> for I2sTx<'d, T, CH, DmaMode>
+where
+    T: RegisterAccess,
+    CH: DmaChannel,
+    DmaMode: Mode,
+{
+    fn write_dma<'t>(&'t mut self, words: &'t TXBUF) -> Result<DmaTransferTx<Self>, Error>
+    where
+        TXBUF: ReadBuffer<Word = u8>,
+    {
+        self.start_tx_transfer(words, false, 0)?;
+        Ok(DmaTransferTx::new(self))
+    }
+
+    fn write_dma_circular<'t>(
+        &'t mut self,
+        words: &'t TXBUF,
+    ) -> Result<DmaTransferTxCircular<Self>, Error>
+    where
+        TXBUF: ReadBuffer<Word = u8>,
+    {
+        self.start_tx_transfer(words, true, 0)?;
+        Ok(DmaTransferTxCircular::new(self))
+    }
+}
+
+impl<'d, T, CH, DmaMode> I2sTx<'d, T, CH, DmaMode>
 where
     T: RegisterAccess,
     CH: DmaChannel,
     DmaMode: Mode,
 {
-    fn write_dma<'t>(&'t mut self, words: &'t TXBUF) -> Result<DmaTransferTx<Self>, Error>
+    /// Write the buffers of `queue` back to back. Returns
+    /// [DmaTransferTxQueue] which represents the in-progress DMA transfer,
+    /// more buffers can be queued with it while the transfer is running.
+    ///
+    /// If the queue runs empty, the peripheral keeps running and the DMA is
+    /// started again when the next buffer is queued.
+    pub fn write_dma_queue<'t, const N: usize>(
+        &'t mut self,
+        queue: &'t mut DmaTxQueue<N>,
+    ) -> Result<DmaTransferTxQueue<'t, Self, N>, Error> {
+        // Reset TX unit and TX FIFO
+        T::reset_tx();
+
+        let transfer = DmaTransferTxQueue::start(self, queue, T::get_dma_peripheral())?;
+
+        // start: set I2S_TX_START
+        T::tx_start();
+
+        Ok(transfer)
+    }
+
+    /// Continuously write to I2S, resuming a transfer stopped with
+    /// [DmaTransferTxCircular::pause].
+    ///
+    /// `words` has to be the buffer of the paused transfer, the data which
+    /// wasn't sent yet is sent first and pushing continues where it stopped.
+    /// Fails with [DmaError::InvalidSnapshot] if `snapshot` doesn't belong to
+    /// `words`.
+    ///
+    /// The buffer is split at the offset the transfer resumes at, this can
+    /// take one more descriptor than starting at the start of the buffer.
+    pub fn write_dma_circular_resume<'t, TXBUF>(
+        &'t mut self,
+        words: &'t TXBUF,
+        snapshot: &CircularSnapshot,
+    ) -> Result<DmaTransferTxCircular<'t, Self>, Error>
     where
         TXBUF: ReadBuffer<Word = u8>,
     {
-        self.start_tx_transfer(words, false)?;
-        Ok(DmaTransferTx::new(self))
+        let (ptr, len) = unsafe { words.read_buffer() };
+
+        if ptr as usize != snapshot.buffer_address || len != snapshot.buffer_len {
+            return Err(Error::DmaError(DmaError::InvalidSnapshot));
+        }
+
+        // Reset TX unit and TX FIFO
+        T::reset_tx();
+
+        self.tx_chain
+            .fill_for_tx_range(true, ptr, len, snapshot.read_offset(), len)?;
+        let state = TxCircularState::restore(&mut self.tx_chain, snapshot)?;
+
+        unsafe {
+            self.tx_channel
+                .prepare_transfer_without_start(T::get_dma_peripheral(), &self.tx_chain)
+                .and_then(|_| self.tx_channel.start_transfer())?;
+        }
+
+        // start: set I2S_TX_START
+        T::tx_start();
+
+        Ok(DmaTransferTxCircular::resume(self, state))
     }
 
-    fn write_dma_circular<'t>(
+    /// Continuously write to I2S, starting at `offset` into `words`.
+    ///
+    /// The transfer wraps around to the start of `words` when it reaches its
+    /// end, which allows to start playback of a circular buffer in the middle
+    /// of it. Fails with [DmaError::BufferTooSmall] if `offset` is outside of
+    /// `words`.
+    pub fn write_dma_circular_from<'t, TXBUF>(
         &'t mut self,
         words: &'t TXBUF,
-    ) -> Result<DmaTransferTxCircular<Self>, Error>
+        offset: usize,
+    ) -> Result<DmaTransferTxCircular<'t, Self>, Error>
     where
         TXBUF: ReadBuffer<Word = u8>,
     {
-        self.start_tx_transfer(words, true)?;
+        self.start_tx_transfer(words, true, offset)?;
         Ok(DmaTransferTxCircular::new(self))
     }
 }
@@ -767,13 +1379,35 @@ where
         &'t mut self,
         words: &'t mut RXBUF,
         circular: bool,
+        offset: usize,
     ) -> Result<(), Error>
+    where
+        RXBUF: WriteBuffer<Word = u8>,
+    {
+        let len = self.prepare_rx_transfer(words, circular, offset)?;
+
+        // start: set I2S_RX_START
+        T::rx_start(len);
+        Ok(())
+    }
+
+    /// Sets up the RX unit and the DMA, the peripheral isn't started yet.
+    ///
+    /// A circular transfer starts at `offset` and wraps around to the start
+    /// of `words`. Returns the length of the buffer, which is needed to start
+    /// the peripheral.
+    fn prepare_rx_transfer<RXBUF>(
+        &mut self,
+        words: &mut RXBUF,
+        circular: bool,
+        offset: usize,
+    ) -> Result<usize, Error>
     where
         RXBUF: WriteBuffer<Word = u8>,
     {
         let (ptr, len) = unsafe { words.write_buffer() };
 
-        if len % 4 != 0 {
+        if len % 4 != 0 || offset % 4 != 0 {
             return Err(Error::IllegalArgument);
         }
 
@@ -784,7 +1418,8 @@ where
 
         // configure DMA outlink
         unsafe {
-            self.rx_chain.fill_for_rx(circular, ptr, len)?;
+            self.rx_chain
+                .fill_for_rx_range(circular, ptr, len, offset, len)?;
             self.rx_channel
                 .prepare_transfer_without_start(T::get_dma_peripheral(), &self.rx_chain)
                 .and_then(|_| self.rx_channel.start_transfer())?;
@@ -792,9 +1427,72 @@ where
 
         // set I2S_TX_STOP_EN if needed
 
-        // start: set I2S_RX_START
-        T::rx_start(len);
-        Ok(())
+        Ok(len)
+    }
+}
+
+impl<'d, T, CH, DmaMode> I2sRx<'d, T, CH, DmaMode>
+where
+    T: RegisterAccess,
+    CH: DmaChannel,
+    DmaMode: Mode,
+{
+    /// Reads 16-bit samples, for data formats with 16-bit words.
+    ///
+    /// Returns the number of samples read.
+    pub fn read_samples(&mut self, samples: &mut [i16]) -> Result<usize, Error> {
+        self.read(samples)?;
+
+        for sample in samples.iter_mut() {
+            *sample = i16::from_le(*sample);
+        }
+
+        Ok(samples.len())
+    }
+
+    /// Continuously read from I2S, starting at `offset` into `words`.
+    ///
+    /// The transfer wraps around to the start of `words` when it reaches its
+    /// end. `offset` has to be a multiple of 4, fails with
+    /// [DmaError::BufferTooSmall] if it is outside of `words`.
+    pub fn read_dma_circular_from<'t, RXBUF>(
+        &'t mut self,
+        words: &'t mut RXBUF,
+        offset: usize,
+    ) -> Result<DmaTransferRxCircular<'t, Self>, Error>
+    where
+        RXBUF: WriteBuffer<Word = u8>,
+    {
+        self.start_rx_transfer(words, true, offset)?;
+        Ok(DmaTransferRxCircular::new(self))
+    }
+
+    /// Reads 32-bit samples, for data formats with 32-bit words.
+    ///
+    /// Returns the number of samples read.
+    pub fn read_samples_32(&mut self, samples: &mut [i32]) -> Result<usize, Error> {
+        self.read(samples)?;
+
+        for sample in samples.iter_mut() {
+            *sample = i32::from_le(*sample);
+        }
+
+        Ok(samples.len())
+    }
+
+    /// Reads 24-bit samples stored in 32-bit words, i.e. for
+    /// [DataFormat::Data32Channel24].
+    ///
+    /// The samples are sign-extended to 32 bits. Returns the number of samples
+    /// read.
+    pub fn read_samples_24(&mut self, samples: &mut [i32]) -> Result<usize, Error> {
+        self.read(samples)?;
+
+        for sample in samples.iter_mut() {
+            *sample = (i32::from_le(*sample) << 8) >> 8;
+        }
+
+        Ok(samples.len())
     }
 }
 
@@ -830,7 +1528,7 @@ where
     where
         RXBUF: WriteBuffer<Word = u8>,
     {
-        self.start_rx_transfer(words, false)?;
+        self.start_rx_transfer(words, false, 0)?;
         Ok(DmaTransferRx::new(self))
     }
 
@@ -841,25 +1539,148 @@ where
     where
         RXBUF: WriteBuffer<Word = u8>,
     {
-        self.start_rx_transfer(words, true)?;
+        self.start_rx_transfer(words, true, 0)?;
         Ok(DmaTransferRxCircular::new(self))
     }
 }
 
+/// I2S TX and RX channels for full-duplex transfers, see
+/// [I2s::build_tx_rx]
+pub struct I2sTxRx<'d, T, CH, DmaMode>
+where
+    T: RegisterAccess,
+    CH: DmaChannel,
+    DmaMode: Mode,
+{
+    tx: I2sTx<'d, T, CH, DmaMode>,
+    rx: I2sRx<'d, T, CH, DmaMode>,
+}
+
+impl<'d, T, CH, DmaMode> core::fmt::Debug for I2sTxRx<'d, T, CH, DmaMode>
+where
+    T: RegisterAccess,
+    CH: DmaChannel,
+    DmaMode: Mode,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("I2sTxRx").finish()
+    }
+}
+
+impl<'d, T, CH, DmaMode> DmaSupport for I2sTxRx<'d, T, CH, DmaMode>
+where
+    T: RegisterAccess,
+    CH: DmaChannel,
+    DmaMode: Mode,
+{
+    fn peripheral_wait_dma(&mut self, is_tx: bool, is_rx: bool) {
+        if is_tx {
+            self.tx.peripheral_wait_dma(true, false);
+        }
+        if is_rx {
+            self.rx.peripheral_wait_dma(false, true);
+        }
+    }
+
+    fn peripheral_dma_stop(&mut self) {
+        self.tx.peripheral_dma_stop();
+        self.rx.peripheral_dma_stop();
+    }
+}
+
+impl<'d, T, CH, DmaMode> DmaSupportTx for I2sTxRx<'d, T, CH, DmaMode>
+where
+    T: RegisterAccess,
+    CH: DmaChannel,
+    DmaMode: Mode,
+{
+    type TX = ChannelTx<'d, CH>;
+
+    fn tx(&mut self) -> &mut Self::TX {
+        &mut self.tx.tx_channel
+    }
+
+    fn chain(&mut self) -> &mut DescriptorChain {
+        &mut self.tx.tx_chain
+    }
+}
+
+impl<'d, T, CH, DmaMode> DmaSupportRx for I2sTxRx<'d, T, CH, DmaMode>
+where
+    T: RegisterAccess,
+    CH: DmaChannel,
+    DmaMode: Mode,
+{
+    type RX = ChannelRx<'d, CH>;
+
+    fn rx(&mut self) -> &mut Self::RX {
+        &mut self.rx.rx_channel
+    }
+
+    fn chain(&mut self) -> &mut DescriptorChain {
+        &mut self.rx.rx_chain
+    }
+}
+
+impl<'d, T, CH, DmaMode> I2sTxRx<'d, T, CH, DmaMode>
+where
+    T: RegisterAccess,
+    CH: DmaChannel,
+    DmaMode: Mode,
+{
+    /// Continuously write and read at the same time. Returns
+    /// [DmaTransferTxRxCircular] which represents the in-progress DMA
+    /// transfers in both directions.
+    ///
+    /// Sending starts right away with the current content of `tx_buffer`,
+    /// which should be initialized with silence. RX is started right before
+    /// TX, so the first sample received is the first sample sent if the RX
+    /// data is clocked by the TX unit, e.g. when looping back or when RX is in
+    /// slave mode. Otherwise they are at most one frame apart. The latency
+    /// stays the same as long as data is pushed and popped fast enough.
+    pub fn write_read_dma_circular<'t, TXBUF, RXBUF>(
+        &'t mut self,
+        tx_buffer: &'t TXBUF,
+        rx_buffer: &'t mut RXBUF,
+    ) -> Result<DmaTransferTxRxCircular<'t, Self>, Error>
+    where
+        TXBUF: ReadBuffer<Word = u8>,
+        RXBUF: WriteBuffer<Word = u8>,
+    {
+        let rx_len = self.rx.prepare_rx_transfer(rx_buffer, true, 0)?;
+        self.tx.prepare_tx_transfer(tx_buffer, true, 0)?;
+
+        // start both units as close together as possible, RX first so it
+        // doesn't miss the first frame sent
+        critical_section::with(|_| {
+            T::rx_start(rx_len);
+            T::tx_start();
+        });
+
+        Ok(DmaTransferTxRxCircular::new(self))
+    }
+}
+
 pub trait RegisterAccess: RegisterAccessPrivate {}
 
-mod private {
+pub(crate) mod private {
     use core::marker::PhantomData;
 
     use enumset::EnumSet;
     use fugit::HertzU32;
 
+    #[cfg(not(esp32s2))]
+    use super::PDM_TX_BCLK_DIVIDER;
     use super::{
+        Bits,
         DataFormat,
+        Error,
         I2sInterrupt,
         I2sRx,
         I2sTx,
+        I2sWriter,
         RegisterAccess,
+        SampleBits,
         Standard,
         I2S_LL_MCLK_DIVIDER_MAX,
     };
@@ -890,6 +1711,7 @@ mod private {
         pub register_access: PhantomData<T>,
         pub tx_channel: ChannelTx<'d, CH>,
         pub descriptors: &'static mut [DmaDescriptor],
+        pub(crate) data_format: DataFormat,
         pub(crate) phantom: PhantomData<DmaMode>,
     }
 
@@ -903,6 +1725,24 @@ mod private {
             I2sTx::new(self.tx_channel, self.descriptors)
         }
 
+        /// Builds a writer which takes samples of `BITS` bits, see
+        /// [I2sWriter].
+        ///
+        /// Returns [Error::IllegalArgument] if the peripheral isn't configured
+        /// for the [BitsPerSample] of the writer.
+        pub fn build_writer<const BITS: u8>(
+            self,
+        ) -> Result<I2sWriter<'d, T, CH, DmaMode, BITS>, Error>
+        where
+            Bits<BITS>: SampleBits,
+        {
+            if self.data_format != Bits::<BITS>::BITS_PER_SAMPLE.into() {
+                return Err(Error::IllegalArgument);
+            }
+
+            Ok(I2sWriter { tx: self.build() })
+        }
+
         pub fn with_bclk<P>(self, pin: impl crate::peripheral::Peripheral<P = P> + 'd) -> Self
         where
             P: OutputPin,
@@ -1112,7 +1952,7 @@ mod private {
             let i2s = Self::register_block();
 
             let fifo_mod = match data_format {
-                DataFormat::Data32Channel32 => 2,
+                DataFormat::Data32Channel32 | DataFormat::Data32Channel24 => 2,
                 DataFormat::Data16Channel16 => 0,
             };
 
@@ -1793,6 +2633,48 @@ mod private {
 
             i2s.int_clr().write(|w| w.rx_done().clear_bit_by_one());
         }
+
+        fn configure_tdm(slot_count: u8, slot_mask: u16) {
+            let i2s = Self::register_block();
+
+            // 32 bit slots, WS is high for the second half of the frame
+            let half_frame_bits = slot_count as u32 * 32 / 2;
+
+            #[allow(clippy::useless_conversion)]
+            i2s.tx_conf1().modify(|_, w| unsafe {
+                w.tx_tdm_ws_width()
+                    .bits(((half_frame_bits - 1) as u8).into())
+                    .tx_bits_mod()
+                    .bits(31)
+                    .tx_tdm_chan_bits()
+                    .bits(31)
+                    .tx_half_sample_bits()
+                    .bits((half_frame_bits - 1) as u8)
+            });
+            #[allow(clippy::useless_conversion)]
+            i2s.rx_conf1().modify(|_, w| unsafe {
+                w.rx_tdm_ws_width()
+                    .bits(((half_frame_bits - 1) as u8).into())
+                    .rx_bits_mod()
+                    .bits(31)
+                    .rx_tdm_chan_bits()
+                    .bits(31)
+                    .rx_half_sample_bits()
+                    .bits((half_frame_bits - 1) as u8)
+            });
+
+            // SAFETY:
+            // bits 0..16 are the CHANn_EN bits, bits 16..20 TDM_TOT_CHAN_NUM,
+            // TX_TDM_SKIP_MSK_EN (bit 20) is cleared to send all slots
+            let ctrl = slot_mask as u32 | ((slot_count as u32 - 1) << 16);
+            i2s.tx_tdm_ctrl().write(|w| unsafe { w.bits(ctrl) });
+            i2s.rx_tdm_ctrl().write(|w| unsafe { w.bits(ctrl) });
+        }
+
+        fn tdm_active_slots() -> usize {
+            let i2s = Self::register_block();
+            (i2s.tx_tdm_ctrl().read().bits() & 0xffff).count_ones() as usize
+        }
     }
 
     #[cfg(any(esp32c3, esp32c6, esp32h2))]
@@ -2072,6 +2954,94 @@ mod private {
     #[cfg(any(esp32s3, esp32))]
     impl I2s1Instance for I2S1 {}
 
+    /// Enables the PCM to PDM conversion of I2S0, which is the only I2S
+    /// peripheral supporting PDM TX
+    #[cfg(esp32)]
+    pub fn configure_pdm_tx(fp: u32, fs: u32) {
+        let i2s = unsafe { &*I2S0::PTR };
+
+        i2s.conf().modify(|_, w| w.tx_msb_shift().clear_bit());
+
+        i2s.pdm_freq_conf()
+            .modify(|_, w| unsafe { w.tx_pdm_fp().bits(fp as u16).tx_pdm_fs().bits(fs as u16) });
+
+        i2s.pdm_conf().modify(|_, w| unsafe {
+            w.tx_pdm_en()
+                .set_bit()
+                .rx_pdm_en()
+                .clear_bit()
+                .pcm2pdm_conv_en()
+                .set_bit()
+                .tx_pdm_sinc_osr2()
+                .bits((fp / fs) as u8)
+                .tx_pdm_prescale()
+                .bits(0)
+                .tx_pdm_hp_in_shift()
+                .bits(1)
+                .tx_pdm_lp_in_shift()
+                .bits(1)
+                .tx_pdm_sinc_in_shift()
+                .bits(1)
+                .tx_pdm_sigmadelta_in_shift()
+                .bits(1)
+                .tx_pdm_hp_bypass()
+                .clear_bit()
+        });
+    }
+
+    /// Enables the PCM to PDM conversion of I2S0, which is the only I2S
+    /// peripheral supporting PDM TX
+    #[cfg(any(esp32c3, esp32c6, esp32h2, esp32s3))]
+    pub fn configure_pdm_tx(fp: u32, fs: u32) {
+        let i2s = unsafe { &*I2S0::PTR };
+
+        #[cfg(not(esp32h2))]
+        i2s.tx_conf1().modify(|_, w| w.tx_msb_shift().clear_bit());
+        #[cfg(esp32h2)]
+        i2s.tx_conf().modify(|_, w| w.tx_msb_shift().clear_bit());
+
+        i2s.tx_conf()
+            .modify(|_, w| w.tx_tdm_en().clear_bit().tx_pdm_en().set_bit());
+
+        i2s.tx_pcm2pdm_conf1().modify(|_, w| unsafe {
+            w.tx_pdm_fp()
+                .bits(fp as u16)
+                .tx_pdm_fs()
+                .bits(fs as u16)
+                .tx_iir_hp_mult12_5()
+                .bits(7)
+                .tx_iir_hp_mult12_0()
+                .bits(7)
+        });
+
+        i2s.tx_pcm2pdm_conf().modify(|_, w| unsafe {
+            w.pcm2pdm_conv_en()
+                .set_bit()
+                .tx_pdm_sinc_osr2()
+                .bits((fp / fs) as u8)
+                .tx_pdm_prescale()
+                .bits(0)
+                .tx_pdm_hp_in_shift()
+                .bits(1)
+                .tx_pdm_lp_in_shift()
+                .bits(1)
+                .tx_pdm_sinc_in_shift()
+                .bits(1)
+                .tx_pdm_sigmadelta_in_shift()
+                .bits(1)
+                .tx_pdm_sigmadelta_dither()
+                .clear_bit()
+                .tx_pdm_sigmadelta_dither2()
+                .set_bit()
+                .tx_pdm_dac_mode_en()
+                .clear_bit()
+                .tx_pdm_dac_2out_en()
+                .clear_bit()
+                .tx_pdm_hp_bypass()
+                .clear_bit()
+        });
+    }
+
     pub struct I2sClockDividers {
         mclk_divider: u32,
         bclk_divider: u32,
@@ -2093,7 +3063,6 @@ mod private {
         // If data_bits is a power of two, use 256 as the mclk_multiple
         // If data_bits is 24, use 192 (24 * 8) as the mclk_multiple
         let mclk_multiple = if data_bits == 24 { 192 } else { 256 };
-        let sclk = crate::soc::constants::I2S_SCLK; // for now it's fixed 160MHz and 96MHz (just H2)
 
         let rate_hz: HertzU32 = sample_rate.into();
         let rate = rate_hz.raw();
@@ -2101,6 +3070,24 @@ mod private {
         let bclk = rate * channels as u32 * data_bits as u32;
         let mclk = rate * mclk_multiple;
         let bclk_divider = mclk / bclk;
+
+        clock_dividers(mclk, bclk_divider)
+    }
+
+    /// Calculates the clock dividers for PDM TX, this corresponds to
+    /// `i2s_pdm_tx_calculate_clock` in esp-idf
+    #[cfg(not(esp32s2))]
+    pub fn calculate_pdm_tx_clock(sample_rate: u32, fp: u32, fs: u32) -> I2sClockDividers {
+        // the PDM clock is 64 times the upsampled sample rate
+        let bclk = sample_rate * 64 * fp / fs;
+        let bclk_divider = PDM_TX_BCLK_DIVIDER;
+        let mclk = bclk * bclk_divider;
+
+        clock_dividers(mclk, bclk_divider)
+    }
+
+    fn clock_dividers(mclk: u32, bclk_divider: u32) -> I2sClockDividers {
+        let sclk = crate::soc::constants::I2S_SCLK; // for now it's fixed 160MHz and 96MHz (just H2)
         let mut mclk_divider = sclk / mclk;
 
         let mut ma: u32;
@@ -2153,10 +3140,11 @@ mod private {
 pub mod asynch {
     use embedded_dma::{ReadBuffer, WriteBuffer};
 
-    use super::{Error, I2sRx, I2sTx, RegisterAccess};
+    use super::{Error, I2s, I2sRx, I2sTx, RegisterAccess};
     use crate::{
         dma::{
-            asynch::{DmaRxDoneChFuture, DmaRxFuture, DmaTxDoneChFuture, DmaTxFuture},
+            asynch::{DmaRxDoneChFuture, DmaRxFuture, DmaTxDoneChFuture, DmaTxFuture, OnDrop},
+            CompletedDescriptor,
             DmaChannel,
             RxCircularState,
             RxPrivate,
@@ -2194,7 +3182,7 @@ pub mod asynch {
 
             T::reset_tx();
 
-            let future = DmaTxFuture::new(&mut self.tx_channel);
+            let future = DmaTxFuture::new(&mut self.tx_channel).with_chain(self.tx_chain.span());
 
             unsafe {
                 self.tx_chain.fill_for_tx(false, ptr, len)?;
@@ -2205,7 +3193,11 @@ pub mod asynch {
 
             future.tx.start_transfer()?;
             T::tx_start();
-            future.await?;
+
+            let guard = OnDrop::new(|| T::tx_stop());
+            let res = future.await;
+            guard.defuse();
+            res?;
 
             Ok(())
         }
@@ -2275,7 +3267,9 @@ pub mod asynch {
                     break Ok(res);
                 }
 
-                DmaTxDoneChFuture::new(&mut self.i2s_tx.tx_channel).await?
+                DmaTxDoneChFuture::new(&mut self.i2s_tx.tx_channel)
+                    .with_chain(self.i2s_tx.tx_chain.span())
+                    .await?
             }
         }
 
@@ -2298,6 +3292,24 @@ pub mod asynch {
             let _avail = self.available().await;
             Ok(self.state.push_with(f)?)
         }
+
+        /// Waits until the DMA finished sending a descriptor.
+        ///
+        /// Descriptors which were completed since the last call are reported
+        /// as [CompletedDescriptor::skipped].
+        pub async fn wait_for_descriptor(&mut self) -> Result<CompletedDescriptor, Error> {
+            loop {
+                self.state.update(&self.i2s_tx.tx_channel);
+
+                if let Some(completed) = self.state.take_completed() {
+                    return Ok(completed);
+                }
+
+                DmaTxDoneChFuture::new(&mut self.i2s_tx.tx_channel)
+                    .with_chain(self.i2s_tx.tx_chain.span())
+                    .await?
+            }
+        }
     }
 
     /// Initiate an async DMA rx transfer
@@ -2333,16 +3345,16 @@ pub mod asynch {
             // Reset TX unit and TX FIFO
             T::reset_rx();
 
-            let mut future = DmaRxFuture::new(&mut self.rx_channel);
+            let future = DmaRxFuture::new(&mut self.rx_channel).with_chain(self.rx_chain.span());
 
             // configure DMA outlink
             unsafe {
                 self.rx_chain.fill_for_rx(false, ptr, len)?;
                 future
-                    .rx()
+                    .rx
                     .prepare_transfer_without_start(T::get_dma_peripheral(), &self.rx_chain)?;
             }
-            future.rx().start_transfer()?;
+            future.rx.start_transfer()?;
 
             // set I2S_TX_STOP_EN if needed
 
@@ -2353,7 +3365,10 @@ pub mod asynch {
             #[cfg(esp32)]
             T::rx_start(len);
 
-            future.await?;
+            let guard = OnDrop::new(|| T::reset_rx());
+            let res = future.await;
+            guard.defuse();
+            res?;
 
             Ok(())
         }
@@ -2424,7 +3439,7 @@ pub mod asynch {
         /// Will wait for more than 0 bytes available.
         pub async fn available(&mut self) -> Result<usize, Error> {
             loop {
-                self.state.update();
+                self.state.update()?;
 
                 let res = self.state.available;
 
@@ -2432,7 +3447,9 @@ pub mod asynch {
                     break Ok(res);
                 }
 
-                DmaRxDoneChFuture::new(&mut self.i2s_rx.rx_channel).await?;
+                DmaRxDoneChFuture::new(&mut self.i2s_rx.rx_channel)
+                    .with_chain(self.i2s_rx.rx_chain.span())
+                    .await?;
             }
         }
 
@@ -2443,4 +3460,106 @@ pub mod asynch {
             Ok(self.state.pop(&mut data[..to_rcv])?)
         }
     }
+
+    impl<'d, I, CH> I2s<'d, I, CH, Async>
+    where
+        I: RegisterAccess,
+        CH: DmaChannel,
+    {
+        /// Starts continuous circular DMA transfers in both directions for
+        /// streaming audio. Returns [I2sCircular].
+        ///
+        /// The pins need to be configured on `i2s_tx` and `i2s_rx` before.
+        /// Sending starts right away with the current content of `tx_buffer`,
+        /// which should be initialized with silence.
+        pub fn into_circular_dma<TXBUF, RXBUF>(
+            self,
+            tx_buffer: TXBUF,
+            rx_buffer: RXBUF,
+        ) -> Result<I2sCircular<'d, I, CH, TXBUF, RXBUF>, Error>
+        where
+            TXBUF: ReadBuffer<Word = u8>,
+            RXBUF: WriteBuffer<Word = u8>,
+        {
+            let rx = self.i2s_rx.build().read_dma_circular_async(rx_buffer)?;
+            let tx = self.i2s_tx.build().write_dma_circular_async(tx_buffer)?;
+
+            Ok(I2sCircular { tx, rx })
+        }
+    }
+
+    /// Continuous async circular DMA transfers in both directions, see
+    /// [I2s::into_circular_dma].
+    ///
+    /// The DMA keeps running while the buffers are refilled or emptied, so
+    /// there are no gaps in the audio as long as the samples are written and
+    /// read fast enough.
+    #[non_exhaustive]
+    pub struct I2sCircular<'d, T, CH, TXBUF, RXBUF>
+    where
+        T: RegisterAccess,
+        CH: DmaChannel,
+    {
+        tx: I2sWriteDmaTransferAsync<'d, T, CH, TXBUF>,
+        rx: I2sReadDmaTransferAsync<'d, T, CH, RXBUF>,
+    }
+
+    impl<'d, T, CH, TXBUF, RXBUF> I2sCircular<'d, T, CH, TXBUF, RXBUF>
+    where
+        T: RegisterAccess,
+        CH: DmaChannel,
+    {
+        /// Writes all samples into the TX buffer, waiting for the DMA to free
+        /// up space as needed.
+        pub async fn write_audio(&mut self, samples: &[i16]) -> Result<(), Error> {
+            let mut data = unsafe {
+                core::slice::from_raw_parts(
+                    samples as *const _ as *const u8,
+                    core::mem::size_of_val(samples),
+                )
+            };
+
+            while !data.is_empty() {
+                let written = self.tx.push(data).await?;
+                data = &data[written..];
+            }
+
+            Ok(())
+        }
+
+        /// Fills `samples` from the RX buffer, waiting for the DMA to receive
+        /// more data as needed.
+        ///
+        /// Fails with
+        /// [DmaError::BufferOverrun](crate::dma::DmaError::BufferOverrun)
+        /// if received data got lost because it wasn't read fast enough.
+        pub async fn read_audio(&mut self, samples: &mut [i16]) -> Result<(), Error> {
+            let data = unsafe {
+                core::slice::from_raw_parts_mut(
+                    samples as *mut _ as *mut u8,
+                    core::mem::size_of_val(samples),
+                )
+            };
+
+            let mut received = 0;
+            while received < data.len() {
+                self.rx.available().await?;
+
+                while received < data.len() && self.rx.state.available > 0 {
+                    let offset = received;
+                    received += self.rx.state.pop_with(|buffer| {
+                        let len = usize::min(buffer.len(), data.len() - offset);
+                        data[offset..][..len].copy_from_slice(&buffer[..len]);
+                        len
+                    })?;
+                }
+            }
+
+            for sample in samples.iter_mut() {
+                *sample = i16::from_le(*sample);
+            }
+
+            Ok(())
+        }
+    }
 }
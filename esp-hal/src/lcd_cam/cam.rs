@@ -63,8 +63,26 @@
 //! .with_ctrl_pins(vsync_pin, href_pin);
 //! # }
 //! ```
-
-use core::mem::size_of;
+//! ## Double Buffered Capture
+//! [Camera::start_double_buffered] captures frames into two buffers in turn,
+//! so a frame can be processed while the next one is received. The end of a
+//! frame is signalled by VSYNC.
+//!
+//! The descriptors of both buffers are linked in a ring, the DMA moves on to
+//! the other buffer at the end of a frame which fills its buffer exactly. A
+//! buffer which is in use is owned by the CPU, the DMA stops at it instead of
+//! overwriting it and is restarted when the buffer is handed back.
+//!
+//! Receiving into a buffer has to start at the beginning of a frame. After a
+//! restart, or a frame shorter than its buffer, the DMA starts with the next
+//! frame if the stream noticed the end of the frame right away, otherwise the
+//! partial frame is dropped. Dropped frames are counted by
+//! [CameraStream::dropped_frames].
+
+use core::{
+    mem::size_of,
+    sync::atomic::{compiler_fence, Ordering},
+};
 
 use embedded_dma::WriteBuffer;
 use fugit::HertzU32;
@@ -82,6 +100,7 @@ use crate::{
         DmaTransferRx,
         DmaTransferRxCircular,
         LcdCamPeripheral,
+        Owner,
         RxPrivate,
     },
     gpio::{InputPin, InputSignal, OutputPin, OutputSignal},
@@ -144,7 +163,7 @@ where
             frequency.to_Hz() as _,
             &[
                 clocks.xtal_clock.to_Hz() as _,
-                clocks.cpu_clock.to_Hz() as _,
+                clocks.cpu_clock().to_Hz() as _,
                 clocks.crypto_pwm_clock.to_Hz() as _,
             ],
         );
@@ -229,7 +248,9 @@ impl<'d, CH: DmaChannel> DmaSupport for Camera<'d, CH> {
     }
 
     fn peripheral_dma_stop(&mut self) {
-        // TODO: Stop DMA?? self.instance.rx_channel.stop_transfer();
+        self.lcd_cam
+            .cam_ctrl1()
+            .modify(|_, w| w.cam_start().clear_bit());
     }
 }
 
@@ -414,6 +435,412 @@ impl<'d, CH: DmaChannel> Camera<'d, CH> {
 
         Ok(DmaTransferRxCircular::new(self))
     }
+
+    /// Captures frames into `buffers` in turn.
+    ///
+    /// The descriptors of the camera are linked into a ring over both buffers,
+    /// which need to be able to hold a whole frame. Frames which fill their
+    /// buffer exactly are received back to back by the DMA. A shorter frame
+    /// ends in the middle of its buffer, the DMA is then restarted with the
+    /// other buffer when the stream is polled.
+    ///
+    /// The first frame is dropped, the capture might have started in the
+    /// middle of it.
+    pub fn start_double_buffered<'t>(
+        &'t mut self,
+        buffers: [&'t mut [u8]; 2],
+    ) -> Result<CameraStream<'t, 'd, CH>, DmaError> {
+        if buffers.iter().any(|buffer| buffer.is_empty()) {
+            return Err(DmaError::BufferTooSmall);
+        }
+
+        self.rx_channel.set_check_owner(true);
+
+        let mut stream = CameraStream {
+            camera: self,
+            buffers,
+            descriptors: [0..0, 0..0],
+            armed: None,
+            ready: None,
+            held: None,
+            resync: false,
+            dropped: 0,
+            stalled: false,
+            error: None,
+        };
+
+        stream.arm(0, false)?;
+
+        Ok(stream)
+    }
+}
+
+/// The state of the frame received into a buffer
+enum FrameState {
+    /// The frame didn't end yet
+    Receiving,
+    /// The frame of `len` bytes ended, `at_end` tells whether it filled the
+    /// whole buffer
+    Frame { len: usize, at_end: bool },
+    /// The buffer is full but the frame didn't end
+    Overflow,
+}
+
+/// Frames captured by [Camera::start_double_buffered]
+///
+/// While a frame is borrowed with a [FrameGuard] the next one is received
+/// into the other buffer.
+pub struct CameraStream<'t, 'd, CH: DmaChannel> {
+    camera: &'t mut Camera<'d, CH>,
+    buffers: [&'t mut [u8]; 2],
+    /// The descriptors of each buffer in the camera's chain
+    descriptors: [core::ops::Range<usize>; 2],
+    /// The buffer the DMA receives into, `None` if the DMA stops at the next
+    /// frame because no buffer is free
+    armed: Option<usize>,
+    /// A captured frame which wasn't handed out yet, the buffer and the
+    /// length
+    ready: Option<(usize, usize)>,
+    /// The buffer handed out in a [FrameGuard]
+    held: Option<usize>,
+    /// The DMA was started in the middle of a frame, the first frame received
+    /// into the armed buffer is incomplete
+    resync: bool,
+    dropped: usize,
+    /// The DMA stopped, or ran into a buffer which wasn't handed back yet
+    stalled: bool,
+    /// An error which occurred when a [FrameGuard] was dropped
+    error: Option<DmaError>,
+}
+
+impl<'t, 'd, CH: DmaChannel> CameraStream<'t, 'd, CH>
+where
+    CH::P: LcdCamPeripheral,
+{
+    /// Waits until a frame was captured and returns it.
+    ///
+    /// Receiving the next frame into the other buffer is started right away
+    /// if the frame ended while waiting, otherwise from the next frame on.
+    pub fn wait_for_frame(&mut self) -> Result<FrameGuard<'_, 't, 'd, CH>, DmaError> {
+        let mut immediate = false;
+        loop {
+            if let Some((index, len)) = self.ready.take() {
+                return Ok(self.frame(index, len));
+            }
+
+            self.update(immediate)?;
+            immediate = true;
+        }
+    }
+
+    /// Waits until a frame was captured and returns it, see
+    /// [CameraStream::wait_for_frame].
+    ///
+    /// The DMA channel needs to be configured for async operation.
+    #[cfg(feature = "async")]
+    pub async fn wait_for_frame_async(&mut self) -> Result<FrameGuard<'_, 't, 'd, CH>, DmaError> {
+        let mut immediate = false;
+        loop {
+            if let Some((index, len)) = self.ready.take() {
+                return Ok(self.frame(index, len));
+            }
+
+            self.update(immediate)?;
+            if self.ready.is_none() {
+                self.wait_for_dma().await;
+            }
+            immediate = true;
+        }
+    }
+
+    /// Number of frames which were dropped since the capture was started.
+    ///
+    /// Frames are dropped when both buffers are in use, when a frame is larger
+    /// than its buffer and when the capture of a buffer started in the middle
+    /// of a frame. Several frames missed in a row while both buffers were in
+    /// use might be counted as one, so this is a lower bound.
+    pub fn dropped_frames(&self) -> usize {
+        self.dropped
+    }
+
+    fn frame(&mut self, index: usize, len: usize) -> FrameGuard<'_, 't, 'd, CH> {
+        self.held = Some(index);
+
+        FrameGuard {
+            stream: self,
+            index,
+            len,
+        }
+    }
+
+    /// Whether the DMA may receive into the buffer.
+    fn is_free(&self, index: usize) -> bool {
+        self.held != Some(index) && self.ready.map(|(ready, _)| ready) != Some(index)
+    }
+
+    /// Handles the frames which ended since the last call.
+    ///
+    /// `immediate` tells whether a frame ended after the last call, so the
+    /// next frame didn't start yet.
+    fn update(&mut self, immediate: bool) -> Result<(), DmaError> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+
+        if let Some(error) = self.camera.rx_error() {
+            if !self.ran_into_used_buffer() {
+                return Err(error);
+            }
+
+            // The frames received until the DMA is restarted are lost
+            if !core::mem::replace(&mut self.stalled, true) {
+                self.dropped += 1;
+            }
+        }
+        // The descriptors are checked below, an EOF after this is seen by the
+        // next call.
+        self.camera.rx_channel.clear_interrupts();
+
+        while let Some(index) = self.armed {
+            let (len, at_end) = match self.frame_state(index) {
+                FrameState::Receiving => break,
+                FrameState::Frame { len, at_end } => (len, at_end),
+                FrameState::Overflow => {
+                    // The rest of the frame is received into the other buffer,
+                    // if it's free, or it's lost.
+                    self.dropped += 1;
+                    return self.arm(index, false);
+                }
+            };
+
+            if core::mem::take(&mut self.resync) {
+                self.dropped += 1;
+                self.release(index);
+            } else if let Some((older, _)) = self.ready.replace((index, len)) {
+                // The consumer is too slow, the most recent frame is kept
+                self.dropped += 1;
+                self.release(older);
+            }
+
+            let next = 1 - index;
+            if !at_end || self.stalled {
+                // The DMA continues with the next frame in the same buffer, or
+                // it stopped
+                return if self.is_free(next) {
+                    self.arm(next, immediate)
+                } else if self.is_free(index) {
+                    self.arm(index, immediate)
+                } else {
+                    self.stop();
+                    Ok(())
+                };
+            }
+
+            self.armed = self.is_free(next).then_some(next);
+        }
+
+        Ok(())
+    }
+
+    /// Whether the descriptor error of the DMA is caused by a buffer which
+    /// wasn't handed back yet.
+    fn ran_into_used_buffer(&self) -> bool {
+        let index = self
+            .camera
+            .rx_channel
+            .error_descriptor_address()
+            .and_then(|address| self.camera.rx_chain.index_of(address));
+
+        (0..2).any(|buffer| {
+            !self.is_free(buffer)
+                && index.map_or(true, |index| self.descriptors[buffer].contains(&index))
+        })
+    }
+
+    /// Reads the state of the frame received into a buffer from the
+    /// descriptors written back by the DMA.
+    fn frame_state(&self, index: usize) -> FrameState {
+        let descriptors = self.camera.rx_chain.descriptors.as_ptr();
+        let range = self.descriptors[index].clone();
+        let last = range.end - 1;
+
+        let mut len = 0;
+        for i in range {
+            let descriptor = unsafe { descriptors.add(i).read_volatile() };
+            if descriptor.owner() == Owner::Dma {
+                return FrameState::Receiving;
+            }
+
+            len += descriptor.len();
+            if descriptor.has_suc_eof() {
+                return FrameState::Frame {
+                    len,
+                    at_end: i == last,
+                };
+            }
+        }
+
+        FrameState::Overflow
+    }
+
+    /// Hands the descriptors of a buffer back to the DMA.
+    ///
+    /// If the DMA is about to move on to the buffer, the first descriptor is
+    /// written last, so the DMA either stops at it or sees all descriptors
+    /// handed back.
+    fn release(&mut self, index: usize) {
+        let descriptors = self.camera.rx_chain.descriptors.as_mut_ptr();
+        for i in self.descriptors[index].clone().rev() {
+            unsafe {
+                let mut descriptor = descriptors.add(i).read_volatile();
+                descriptor.set_owner(Owner::Dma);
+                descriptor.set_suc_eof(false);
+                descriptor.set_length_unchecked(0);
+                descriptors.add(i).write_volatile(descriptor);
+            }
+        }
+        compiler_fence(Ordering::SeqCst);
+
+        if self.armed.is_some() || !self.is_free(index) {
+            return;
+        }
+
+        if self.stalled || self.camera.rx_channel.has_error() {
+            if let Err(error) = self.arm(index, false) {
+                self.error = Some(error);
+            }
+        } else {
+            // The DMA didn't reach the buffer yet, it continues with it
+            self.armed = Some(index);
+        }
+    }
+
+    /// Restarts the DMA with the given buffer.
+    ///
+    /// The descriptors are linked in a ring starting with this buffer, the
+    /// other buffer keeps its descriptors owned by the CPU if it's in use.
+    fn arm(&mut self, index: usize, immediate: bool) -> Result<(), DmaError> {
+        self.stop();
+
+        let next = 1 - index;
+        let regions = [index, next].map(|i| (self.buffers[i].as_mut_ptr(), self.buffers[i].len()));
+        self.camera
+            .rx_chain
+            .fill_for_regions(true, false, regions.into_iter())?;
+
+        let claim = !self.is_free(next);
+        let descriptors = &mut self.camera.rx_chain.descriptors;
+        let split = descriptors
+            .iter()
+            .position(|descriptor| descriptor.buffer == regions[1].0)
+            .unwrap_or(0);
+        let end = descriptors
+            .iter()
+            .position(|descriptor| descriptor.buffer.is_null())
+            .unwrap_or(descriptors.len());
+        self.descriptors[index] = 0..split;
+        self.descriptors[next] = split..end;
+
+        if claim {
+            for descriptor in &mut descriptors[split..end] {
+                descriptor.set_owner(Owner::Cpu);
+            }
+        }
+
+        self.camera.reset_unit_and_fifo();
+        unsafe {
+            self.camera
+                .rx_channel
+                .prepare_transfer_without_start(DmaPeripheral::LcdCam, &self.camera.rx_chain)?;
+        }
+        self.camera.rx_channel.start_transfer()?;
+        self.camera.start_unit();
+
+        self.armed = Some(index);
+        self.stalled = false;
+        self.resync = !immediate;
+
+        Ok(())
+    }
+
+    /// Stops receiving frames until the DMA is armed again.
+    fn stop(&mut self) {
+        self.camera
+            .lcd_cam
+            .cam_ctrl1()
+            .modify(|_, w| w.cam_start().clear_bit());
+        self.camera.rx_channel.stop_transfer();
+        self.armed = None;
+        self.stalled = true;
+    }
+
+    #[cfg(feature = "async")]
+    async fn wait_for_dma(&mut self) {
+        let rx = &mut self.camera.rx_channel;
+        core::future::poll_fn(|cx| {
+            rx.waker().register(cx.waker());
+
+            rx.listen_eof();
+            rx.listen_in_descriptor_error();
+
+            if rx.is_done() || rx.has_error() {
+                core::task::Poll::Ready(())
+            } else {
+                core::task::Poll::Pending
+            }
+        })
+        .await;
+
+        rx.unlisten_eof();
+        rx.unlisten_in_descriptor_error();
+    }
+}
+
+impl<'t, 'd, CH: DmaChannel> Drop for CameraStream<'t, 'd, CH> {
+    fn drop(&mut self) {
+        self.camera
+            .lcd_cam
+            .cam_ctrl1()
+            .modify(|_, w| w.cam_start().clear_bit());
+        self.camera.rx_channel.stop_transfer();
+        self.camera.rx_channel.set_check_owner(false);
+    }
+}
+
+/// A frame captured by a [CameraStream]
+///
+/// The buffer is handed back to the stream when this is dropped.
+pub struct FrameGuard<'s, 't, 'd, CH: DmaChannel>
+where
+    CH::P: LcdCamPeripheral,
+{
+    stream: &'s mut CameraStream<'t, 'd, CH>,
+    index: usize,
+    len: usize,
+}
+
+impl<'s, 't, 'd, CH: DmaChannel> core::ops::Deref for FrameGuard<'s, 't, 'd, CH>
+where
+    CH::P: LcdCamPeripheral,
+{
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.stream.buffers[self.index][..self.len]
+    }
+}
+
+impl<'s, 't, 'd, CH: DmaChannel> Drop for FrameGuard<'s, 't, 'd, CH>
+where
+    CH::P: LcdCamPeripheral,
+{
+    fn drop(&mut self) {
+        // The DMA can receive into the buffer again. If it stopped because
+        // both buffers were in use it's restarted, errors are reported by the
+        // next call to `wait_for_frame`.
+        self.stream.held = None;
+        self.stream.release(self.index);
+    }
 }
 
 pub struct RxEightBits {
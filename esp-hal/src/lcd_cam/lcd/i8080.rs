@@ -121,7 +121,7 @@ where
             (frequency.to_Hz() * 2) as _,
             &[
                 clocks.xtal_clock.to_Hz() as _,
-                clocks.cpu_clock.to_Hz() as _,
+                clocks.cpu_clock().to_Hz() as _,
                 clocks.crypto_pwm_clock.to_Hz() as _,
             ],
         );
@@ -262,7 +262,7 @@ impl<'d, CH: DmaChannel, P: TxPins> DmaSupport for I8080<'d, CH, P> {
     }
 
     fn peripheral_dma_stop(&mut self) {
-        unreachable!("unsupported")
+        self.tear_down_send();
     }
 }
 
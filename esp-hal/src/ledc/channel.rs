@@ -9,7 +9,7 @@
 //! `Pulse-Width Modulation (PWM)` applications by offering configurable duty
 //! cycles and frequencies.
 
-use super::timer::{TimerIFace, TimerSpeed};
+use super::timer::{Number as TimerNumber, TimerIFace, TimerSpeed};
 use crate::{
     gpio::{OutputPin, OutputSignal},
     peripheral::{Peripheral, PeripheralRef},
@@ -42,6 +42,8 @@ pub enum Error {
     Channel,
     /// Fade parameters invalid
     Fade(FadeError),
+    /// Channel is driven by a different timer
+    TimerMismatch,
 }
 
 /// Channel number
@@ -60,6 +62,9 @@ pub enum Number {
     Channel7 = 7,
 }
 
+/// Largest value of the fade step count and cycle count registers
+const MAX_FADE_FIELD: u16 = 1023;
+
 /// Channel configuration
 pub mod config {
     use crate::ledc::timer::{TimerIFace, TimerSpeed};
@@ -127,6 +132,30 @@ pub trait ChannelHW<O: OutputPin> {
     fn is_duty_fade_running_hw(&self) -> bool;
 }
 
+/// A channel whose duty can be updated together with other channels, see
+/// [Timer::synchronized_update](super::timer::Timer::synchronized_update).
+///
+/// This erases the output pin from the type of a [Channel], so that channels
+/// connected to different pins can be updated at once.
+pub trait SyncedChannel<S: TimerSpeed> {
+    /// Check that the channel is driven by `timer` and that `duty` is in range.
+    #[doc(hidden)]
+    fn check_synced_duty(
+        &self,
+        timer: TimerNumber,
+        duty: u32,
+        _: crate::private::Internal,
+    ) -> Result<(), Error>;
+
+    /// Write `duty` to the duty register without starting it.
+    #[doc(hidden)]
+    fn stage_synced_duty(&self, duty: u32, _: crate::private::Internal);
+
+    /// Start the staged duty.
+    #[doc(hidden)]
+    fn start_synced_duty(&self, _: crate::private::Internal);
+}
+
 /// Channel struct
 pub struct Channel<'a, S: TimerSpeed, O: OutputPin> {
     ledc: &'a RegisterBlock,
@@ -147,24 +176,8 @@ impl<'a, S: TimerSpeed, O: OutputPin> Channel<'a, S, O> {
             output_pin,
         }
     }
-}
-
-impl<'a, S: TimerSpeed, O: OutputPin> ChannelIFace<'a, S, O> for Channel<'a, S, O>
-where
-    Channel<'a, S, O>: ChannelHW<O>,
-{
-    /// Configure channel
-    fn configure(&mut self, config: config::Config<'a, S>) -> Result<(), Error> {
-        self.timer = Some(config.timer);
-
-        self.set_duty(config.duty_pct)?;
-        self.configure_hw_with_pin_config(config.pin_config)?;
-
-        Ok(())
-    }
 
-    /// Set duty % of channel
-    fn set_duty(&self, duty_pct: u8) -> Result<(), Error> {
+    fn duty_range(&self) -> Result<u32, Error> {
         let duty_exp;
         if let Some(timer) = self.timer {
             if let Some(timer_duty) = timer.get_duty() {
@@ -176,7 +189,11 @@ where
             return Err(Error::Channel);
         }
 
-        let duty_range = 2u32.pow(duty_exp);
+        Ok(2u32.pow(duty_exp))
+    }
+
+    fn duty_value(&self, duty_pct: u8) -> Result<u32, Error> {
+        let duty_range = self.duty_range()?;
         let duty_value = (duty_range * duty_pct as u32) / 100;
 
         if duty_pct > 100u8 {
@@ -184,6 +201,165 @@ where
             return Err(Error::Duty);
         }
 
+        Ok(duty_value)
+    }
+
+    /// Set duty % of channel without applying it
+    ///
+    /// The duty takes effect once the channel's
+    /// [`LedcEtmTask::update_duty`](super::etm::LedcEtmTask::update_duty) task
+    /// is triggered, which allows updating several channels at the same time.
+    #[cfg(soc_etm)]
+    pub fn stage_duty(&self, duty_pct: u8) -> Result<(), Error> {
+        let duty_value = self.duty_value(duty_pct)?;
+
+        self.write_duty(duty_value);
+        self.configure_duty_without_fading();
+
+        Ok(())
+    }
+}
+
+impl<'a, S: TimerSpeed, O: OutputPin> Channel<'a, S, O>
+where
+    Channel<'a, S, O>: ChannelHW<O>,
+{
+    /// Set the duty cycle of the channel to `numerator / denominator`.
+    ///
+    /// The raw duty value is calculated from the duty resolution of the timer
+    /// using integer arithmetic only. Returns [Error::Duty] if the fraction is
+    /// greater than one or `denominator` is zero.
+    pub fn set_duty_cycle_fraction(
+        &mut self,
+        numerator: u16,
+        denominator: u16,
+    ) -> Result<(), Error> {
+        if denominator == 0 || numerator > denominator {
+            return Err(Error::Duty);
+        }
+
+        let duty_range = self.duty_range()? as u64;
+        let duty_value = duty_range * numerator as u64 / denominator as u64;
+        self.set_duty_hw(duty_value as u32);
+
+        Ok(())
+    }
+
+    /// Set the duty cycle of the channel to `percent` %.
+    ///
+    /// Returns [Error::Duty] if `percent` is greater than 100.
+    pub fn set_duty_cycle_percent(&mut self, percent: u8) -> Result<(), Error> {
+        self.set_duty_cycle_fraction(percent as u16, 100)
+    }
+
+    /// Fade the duty from its current value to the raw `target_duty` and wait
+    /// for the fade to finish.
+    ///
+    /// The hardware changes the duty by `step_scale` every `cycle_num` PWM
+    /// cycles. Once the fade is done the duty is set to `target_duty`, in case
+    /// the change isn't a multiple of `step_scale`.
+    pub fn fade_blocking(
+        &mut self,
+        target_duty: u32,
+        step_scale: u8,
+        cycle_num: u16,
+    ) -> Result<(), Error> {
+        if self.start_fade_hw(target_duty, step_scale, cycle_num)? {
+            while self.is_duty_fade_running_hw() {}
+        }
+        self.set_duty_hw(target_duty);
+
+        Ok(())
+    }
+
+    /// Fade the duty from its current value to the raw `target_duty`, see
+    /// [Self::fade_blocking].
+    ///
+    /// The returned future completes on the fade end interrupt, which requires
+    /// the LEDC driver to be created with
+    /// [Ledc::new_async](super::Ledc::new_async).
+    #[cfg(feature = "async")]
+    pub async fn start_fade(
+        &mut self,
+        target_duty: u32,
+        step_scale: u8,
+        cycle_num: u16,
+    ) -> Result<(), Error> {
+        if self.start_fade_hw(target_duty, step_scale, cycle_num)? {
+            self.listen_fade_end();
+
+            core::future::poll_fn(|cx| {
+                asynch::WAKERS[self.waker_index()].register(cx.waker());
+
+                if self.is_duty_fade_running_hw() {
+                    core::task::Poll::Pending
+                } else {
+                    core::task::Poll::Ready(())
+                }
+            })
+            .await;
+        }
+        self.set_duty_hw(target_duty);
+
+        Ok(())
+    }
+
+    /// Starts a fade to `target_duty`, returns `false` if the duty is already
+    /// within one step of it.
+    fn start_fade_hw(
+        &mut self,
+        target_duty: u32,
+        step_scale: u8,
+        cycle_num: u16,
+    ) -> Result<bool, Error> {
+        if target_duty > self.duty_range()? {
+            return Err(Error::Duty);
+        }
+        if step_scale == 0 {
+            return Err(Error::Fade(FadeError::DutyRange));
+        }
+        if !(1..=MAX_FADE_FIELD).contains(&cycle_num) {
+            return Err(Error::Fade(FadeError::Duration));
+        }
+
+        let start_duty = self.current_duty();
+        let steps = target_duty.abs_diff(start_duty) / step_scale as u32;
+        if steps > MAX_FADE_FIELD as u32 {
+            return Err(Error::Fade(FadeError::DutyRange));
+        }
+        if steps == 0 {
+            return Ok(false);
+        }
+
+        self.start_duty_fade_hw(
+            start_duty,
+            target_duty > start_duty,
+            steps as u16,
+            cycle_num,
+            step_scale as u16,
+        );
+
+        Ok(true)
+    }
+}
+
+impl<'a, S: TimerSpeed, O: OutputPin> ChannelIFace<'a, S, O> for Channel<'a, S, O>
+where
+    Channel<'a, S, O>: ChannelHW<O>,
+{
+    /// Configure channel
+    fn configure(&mut self, config: config::Config<'a, S>) -> Result<(), Error> {
+        self.timer = Some(config.timer);
+
+        self.set_duty(config.duty_pct)?;
+        self.configure_hw_with_pin_config(config.pin_config)?;
+
+        Ok(())
+    }
+
+    /// Set duty % of channel
+    fn set_duty(&self, duty_pct: u8) -> Result<(), Error> {
+        let duty_value = self.duty_value(duty_pct)?;
         self.set_duty_hw(duty_value);
 
         Ok(())
@@ -278,6 +454,38 @@ where
     }
 }
 
+impl<'a, S: TimerSpeed, O: OutputPin> SyncedChannel<S> for Channel<'a, S, O>
+where
+    Channel<'a, S, O>: ChannelHW<O>,
+{
+    fn check_synced_duty(
+        &self,
+        timer: TimerNumber,
+        duty: u32,
+        _: crate::private::Internal,
+    ) -> Result<(), Error> {
+        let duty_range = self.duty_range()?;
+
+        if self.timer.map(|t| t.get_number()) != Some(timer) {
+            return Err(Error::TimerMismatch);
+        }
+        if duty > duty_range {
+            return Err(Error::Duty);
+        }
+
+        Ok(())
+    }
+
+    fn stage_synced_duty(&self, duty: u32, _: crate::private::Internal) {
+        self.write_duty(duty);
+    }
+
+    fn start_synced_duty(&self, _: crate::private::Internal) {
+        self.start_duty_without_fading();
+        self.update_channel();
+    }
+}
+
 #[cfg(feature = "embedded-hal")]
 mod ehal1 {
     use embedded_hal::pwm::{self, ErrorKind, ErrorType, SetDutyCycle};
@@ -323,6 +531,99 @@ mod ehal1 {
 }
 
 impl<'a, O: OutputPin, S: crate::ledc::timer::TimerSpeed> Channel<'a, S, O> {
+    /// The current duty of the channel, including a running fade.
+    #[cfg(esp32)]
+    fn current_duty(&self) -> u32 {
+        let duty = if S::IS_HS {
+            self.ledc
+                .hsch(self.number as usize)
+                .duty_r()
+                .read()
+                .duty_r()
+                .bits()
+        } else {
+            self.ledc
+                .lsch(self.number as usize)
+                .duty_r()
+                .read()
+                .duty_r()
+                .bits()
+        };
+
+        // the lowest 4 bits are the fractional part
+        duty >> 4
+    }
+
+    /// The current duty of the channel, including a running fade.
+    #[cfg(not(esp32))]
+    fn current_duty(&self) -> u32 {
+        let duty = self
+            .ledc
+            .ch(self.number as usize)
+            .duty_r()
+            .read()
+            .duty_r()
+            .bits();
+
+        // the lowest 4 bits are the fractional part
+        duty >> 4
+    }
+
+    #[cfg(all(esp32, feature = "async"))]
+    fn listen_fade_end(&self) {
+        if S::IS_HS {
+            self.ledc
+                .int_ena()
+                .modify(|_, w| w.duty_chng_end_hsch(self.number as u8).set_bit());
+        } else {
+            self.ledc
+                .int_ena()
+                .modify(|_, w| w.duty_chng_end_lsch(self.number as u8).set_bit());
+        }
+    }
+
+    #[cfg(all(not(esp32), feature = "async"))]
+    fn listen_fade_end(&self) {
+        self.ledc
+            .int_ena()
+            .modify(|_, w| w.duty_chng_end_ch(self.number as u8).set_bit());
+    }
+
+    /// Write the duty register, which takes effect once the duty is started.
+    #[cfg(esp32)]
+    fn write_duty(&self, duty: u32) {
+        if S::IS_HS {
+            self.ledc
+                .hsch(self.number as usize)
+                .duty()
+                .write(|w| unsafe { w.duty().bits(duty << 4) });
+        } else {
+            self.ledc
+                .lsch(self.number as usize)
+                .duty()
+                .write(|w| unsafe { w.duty().bits(duty << 4) });
+        }
+    }
+
+    /// Write the duty register, which takes effect once the duty is started.
+    #[cfg(not(esp32))]
+    fn write_duty(&self, duty: u32) {
+        self.ledc
+            .ch(self.number as usize)
+            .duty()
+            .write(|w| unsafe { w.duty().bits(duty << 4) });
+    }
+
+    #[cfg(feature = "async")]
+    fn waker_index(&self) -> usize {
+        #[cfg(esp32)]
+        if !S::IS_HS {
+            return asynch::LS_WAKER_OFFSET + self.number as usize;
+        }
+
+        self.number as usize
+    }
+
     #[cfg(esp32)]
     fn set_channel(&mut self, timer_number: u8) {
         if S::IS_HS {
@@ -379,12 +680,15 @@ impl<'a, O: OutputPin, S: crate::ledc::timer::TimerSpeed> Channel<'a, S, O> {
     }
     #[cfg(any(esp32c6, esp32h2))]
     fn start_duty_without_fading(&self) {
-        let cnum = self.number as usize;
         self.ledc
-            .ch(cnum)
+            .ch(self.number as usize)
             .conf1()
             .write(|w| w.duty_start().set_bit());
-        self.ledc.ch_gamma_wr(cnum).write(|w| {
+        self.configure_duty_without_fading();
+    }
+    #[cfg(any(esp32c6, esp32h2))]
+    fn configure_duty_without_fading(&self) {
+        self.ledc.ch_gamma_wr(self.number as usize).write(|w| {
             w.ch_gamma_duty_inc().set_bit();
             unsafe {
                 w.ch_gamma_duty_num().bits(0x1);
@@ -599,30 +903,8 @@ where
     }
 
     /// Set duty in channel HW
-    #[cfg(esp32)]
-    fn set_duty_hw(&self, duty: u32) {
-        if S::IS_HS {
-            self.ledc
-                .hsch(self.number as usize)
-                .duty()
-                .write(|w| unsafe { w.duty().bits(duty << 4) });
-        } else {
-            self.ledc
-                .lsch(self.number as usize)
-                .duty()
-                .write(|w| unsafe { w.duty().bits(duty << 4) });
-        }
-        self.start_duty_without_fading();
-        self.update_channel();
-    }
-
-    /// Set duty in channel HW
-    #[cfg(not(esp32))]
     fn set_duty_hw(&self, duty: u32) {
-        self.ledc
-            .ch(self.number as usize)
-            .duty()
-            .write(|w| unsafe { w.duty().bits(duty << 4) });
+        self.write_duty(duty);
         self.start_duty_without_fading();
         self.update_channel();
     }
@@ -698,3 +980,58 @@ where
             .bit_is_clear()
     }
 }
+
+#[cfg(feature = "async")]
+pub(crate) mod asynch {
+    use embassy_sync::waitqueue::AtomicWaker;
+    use procmacros::handler;
+
+    #[cfg(esp32)]
+    pub(super) const LS_WAKER_OFFSET: usize = 8;
+
+    #[cfg(esp32)]
+    const NUM_WAKERS: usize = 16;
+    #[cfg(any(esp32s2, esp32s3))]
+    const NUM_WAKERS: usize = 8;
+    #[cfg(not(any(esp32, esp32s2, esp32s3)))]
+    const NUM_WAKERS: usize = 6;
+
+    #[allow(clippy::declare_interior_mutable_const)]
+    const INIT: AtomicWaker = AtomicWaker::new();
+    pub(super) static WAKERS: [AtomicWaker; NUM_WAKERS] = [INIT; NUM_WAKERS];
+
+    #[cfg(esp32)]
+    #[handler]
+    pub(crate) fn fade_interrupt_handler() {
+        let ledc = unsafe { &*crate::peripherals::LEDC::ptr() };
+        let status = ledc.int_st().read();
+
+        for ch in 0..LS_WAKER_OFFSET as u8 {
+            if status.duty_chng_end_hsch(ch).bit_is_set() {
+                ledc.int_ena()
+                    .modify(|_, w| w.duty_chng_end_hsch(ch).clear_bit());
+                WAKERS[ch as usize].wake();
+            }
+            if status.duty_chng_end_lsch(ch).bit_is_set() {
+                ledc.int_ena()
+                    .modify(|_, w| w.duty_chng_end_lsch(ch).clear_bit());
+                WAKERS[LS_WAKER_OFFSET + ch as usize].wake();
+            }
+        }
+    }
+
+    #[cfg(not(esp32))]
+    #[handler]
+    pub(crate) fn fade_interrupt_handler() {
+        let ledc = unsafe { &*crate::peripherals::LEDC::ptr() };
+        let status = ledc.int_st().read();
+
+        for ch in 0..NUM_WAKERS as u8 {
+            if status.duty_chng_end_ch(ch).bit_is_set() {
+                ledc.int_ena()
+                    .modify(|_, w| w.duty_chng_end_ch(ch).clear_bit());
+                WAKERS[ch as usize].wake();
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! # Event Task Matrix Function
+//!
+//! ## Overview
+//!
+//! The LEDC supports the Event Task Matrix (ETM) function, which allows LEDC
+//! events to trigger any peripheral's ETM tasks and LEDC tasks to be triggered
+//! by any peripheral's ETM events.
+//!
+//! The LEDC can generate the following ETM events:
+//! - DUTY_CHNG_END_CHx: Indicates that the duty change of channel x is done
+//!
+//! The LEDC can receive the following ETM tasks:
+//! - DUTY_SCALE_UPDATE_CHx: Applies the duty staged with
+//!   [Channel::stage_duty](super::channel::Channel::stage_duty) to channel x
+//!
+//! Triggering the update task of several channels from the same event updates
+//! their duty at the same time, without involving the CPU.
+//!
+//! The events and tasks can only be created after the LEDC was initialized with
+//! [Ledc::new](super::Ledc::new).
+//!
+//! ## Example
+//! ```rust, no_run
+#![doc = crate::before_snippet!()]
+//! # use esp_hal::etm::Etm;
+//! # use esp_hal::ledc::{channel, etm::{LedcEtmEvent, LedcEtmTask}};
+//! let duty_done = LedcEtmEvent::duty_change_done(channel::Number::Channel0);
+//! let update_duty = LedcEtmTask::update_duty(channel::Number::Channel1);
+//!
+//! let etm = Etm::new(peripherals.SOC_ETM);
+//!
+//! // make sure the configured channel doesn't get dropped - dropping it will
+//! // disable the channel
+//! let _configured_channel = etm.channel0.setup(&duty_done, &update_duty);
+//! # }
+//! ```
+
+use super::channel::Number;
+use crate::{
+    etm::{EtmEvent, EtmTask},
+    private::Sealed,
+};
+
+// IDs of the channel 0 event and task in the ETM event and task tables, the
+// other channels follow in order.
+const DUTY_CHNG_END_CH0_EVENT: u8 = 28;
+const DUTY_SCALE_UPDATE_CH0_TASK: u8 = 29;
+
+/// An ETM controlled LEDC event
+pub struct LedcEtmEvent {
+    id: u8,
+}
+
+impl LedcEtmEvent {
+    /// Event triggered once the duty change of `channel` is done
+    pub fn duty_change_done(channel: Number) -> Self {
+        let ledc = unsafe { &*crate::peripherals::LEDC::ptr() };
+        ledc.evt_task_en0().modify(|_, w| match channel {
+            Number::Channel0 => w.evt_duty_chng_end_ch0_en().set_bit(),
+            Number::Channel1 => w.evt_duty_chng_end_ch1_en().set_bit(),
+            Number::Channel2 => w.evt_duty_chng_end_ch2_en().set_bit(),
+            Number::Channel3 => w.evt_duty_chng_end_ch3_en().set_bit(),
+            Number::Channel4 => w.evt_duty_chng_end_ch4_en().set_bit(),
+            Number::Channel5 => w.evt_duty_chng_end_ch5_en().set_bit(),
+        });
+
+        Self {
+            id: DUTY_CHNG_END_CH0_EVENT + channel as u8,
+        }
+    }
+}
+
+impl Sealed for LedcEtmEvent {}
+
+impl EtmEvent for LedcEtmEvent {
+    fn id(&self) -> u8 {
+        self.id
+    }
+}
+
+/// An ETM controlled LEDC task
+pub struct LedcEtmTask {
+    id: u8,
+}
+
+impl LedcEtmTask {
+    /// Task applying the duty staged for `channel`
+    pub fn update_duty(channel: Number) -> Self {
+        let ledc = unsafe { &*crate::peripherals::LEDC::ptr() };
+        ledc.evt_task_en0().modify(|_, w| match channel {
+            Number::Channel0 => w.task_duty_scale_update_ch0_en().set_bit(),
+            Number::Channel1 => w.task_duty_scale_update_ch1_en().set_bit(),
+            Number::Channel2 => w.task_duty_scale_update_ch2_en().set_bit(),
+            Number::Channel3 => w.task_duty_scale_update_ch3_en().set_bit(),
+            Number::Channel4 => w.task_duty_scale_update_ch4_en().set_bit(),
+            Number::Channel5 => w.task_duty_scale_update_ch5_en().set_bit(),
+        });
+
+        Self {
+            id: DUTY_SCALE_UPDATE_CH0_TASK + channel as u8,
+        }
+    }
+}
+
+impl Sealed for LedcEtmTask {}
+
+impl EtmTask for LedcEtmTask {
+    fn id(&self) -> u8 {
+        self.id
+    }
+}
@@ -72,6 +72,8 @@ use crate::{
 };
 
 pub mod channel;
+#[cfg(soc_etm)]
+pub mod etm;
 pub mod timer;
 
 /// Global slow clock source
@@ -124,6 +126,28 @@ impl<'d> Ledc<'d> {
         }
     }
 
+    /// Return a new LEDC which can wait for fades asynchronously, see
+    /// [Channel::start_fade](channel::Channel::start_fade).
+    #[cfg(feature = "async")]
+    pub fn new_async(
+        _instance: impl Peripheral<P = crate::peripherals::LEDC> + 'd,
+        clock_control_config: &'d Clocks,
+    ) -> Self {
+        let this = Self::new(_instance, clock_control_config);
+
+        let handler = channel::asynch::fade_interrupt_handler;
+        unsafe {
+            crate::interrupt::bind_interrupt(
+                crate::peripherals::Interrupt::LEDC,
+                handler.handler(),
+            );
+            crate::interrupt::enable(crate::peripherals::Interrupt::LEDC, handler.priority())
+                .unwrap();
+        }
+
+        this
+    }
+
     /// Set global slow clock source
     #[cfg(esp32)]
     pub fn set_global_slow_clock(&mut self, _clock_source: LSGlobalClkSource) {
@@ -15,7 +15,11 @@ use fugit::HertzU32;
 
 #[cfg(esp32)]
 use super::HighSpeed;
-use super::{LowSpeed, Speed};
+use super::{
+    channel::{self, SyncedChannel},
+    LowSpeed,
+    Speed,
+};
 use crate::{clock::Clocks, peripherals::ledc};
 
 const LEDC_TIMER_DIV_NUM_MAX: u64 = 0x3FFFF;
@@ -241,6 +245,45 @@ impl<'a, S: TimerSpeed> Timer<'a, S> {
             clock_source: None,
         }
     }
+
+    /// Set the raw duty of several channels driven by this timer, so that all
+    /// of them change on the same PWM cycle.
+    ///
+    /// `channels[i]` is set to `duties[i]`. Nothing is changed if any of the
+    /// channels isn't driven by this timer or any duty is out of range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channels` and `duties` have different lengths.
+    pub fn synchronized_update(
+        &self,
+        channels: &mut [&mut dyn SyncedChannel<S>],
+        duties: &[u32],
+    ) -> Result<(), channel::Error> {
+        assert_eq!(channels.len(), duties.len());
+
+        if !self.configured {
+            return Err(channel::Error::Timer);
+        }
+
+        for (channel, duty) in channels.iter().zip(duties) {
+            channel.check_synced_duty(self.number, *duty, crate::private::Internal)?;
+        }
+
+        for (channel, duty) in channels.iter().zip(duties) {
+            channel.stage_synced_duty(*duty, crate::private::Internal);
+        }
+
+        // The new duties are latched at the next overflow of the timer, start
+        // all of them back to back so they don't end up on different cycles.
+        critical_section::with(|_| {
+            for channel in channels.iter() {
+                channel.start_synced_duty(crate::private::Internal);
+            }
+        });
+
+        Ok(())
+    }
 }
 
 /// Timer HW implementation for LowSpeed timers
@@ -224,6 +224,8 @@ pub mod trace;
 pub mod twai;
 #[cfg(any(uart0, uart1, uart2))]
 pub mod uart;
+#[cfg(all(uhci0, gdma))]
+pub mod uhci;
 #[cfg(usb_device)]
 pub mod usb_serial_jtag;
 
@@ -568,6 +570,10 @@ mod critical_section_impl {
 /// needs to transmit data from flash (ROM) via the embedded-hal traits. This is
 /// often a `const` variable.
 ///
+/// Buffers the DMA can access are passed straight through to the wrapped
+/// peripheral. Other buffers are copied into an internal buffer of `SIZE`
+/// bytes and sent in chunks of at most `SIZE` bytes.
+///
 /// Example usage using [`spi::master::dma::SpiDma`]
 /// ```rust, ignore
 /// const ARRAY_IN_FLASH = [0xAA; 128];
@@ -580,7 +586,7 @@ mod critical_section_impl {
 ///
 /// spi.write(&ARRAY_IN_FLASH[..]).unwrap(); // success
 /// ```
-pub struct FlashSafeDma<T, const SIZE: usize> {
+pub struct FlashSafeDma<T, const SIZE: usize = 256> {
     inner: T,
     #[allow(unused)]
     buffer: [u8; SIZE],
@@ -605,6 +611,20 @@ impl<T, const SIZE: usize> FlashSafeDma<T, SIZE> {
     pub fn free(self) -> T {
         self.inner
     }
+
+    /// Returns whether `words` has to be copied into RAM before the DMA can
+    /// transmit it, see [dma::is_dma_capable].
+    #[allow(unused)]
+    fn needs_copy(words: &[u8]) -> bool {
+        !words.is_empty() && !dma::is_dma_capable(words.as_ptr(), words.len())
+    }
+
+    /// The part of a buffer of `len` bytes which is sent in the chunk starting
+    /// at `offset`.
+    #[allow(unused)]
+    fn chunk(offset: usize, len: usize) -> core::ops::Range<usize> {
+        offset.min(len)..(offset + SIZE).min(len)
+    }
 }
 
 /// Default (unhandled) interrupt handler
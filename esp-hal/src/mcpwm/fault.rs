@@ -0,0 +1,227 @@
+//! # MCPWM Fault Detection Module
+//!
+//! ## Overview
+//! The fault detection module monitors the fault input pins of an MCPWM
+//! peripheral, e.g. connected to an overcurrent or overvoltage comparator. The
+//! [`Operator`](super::operator::Operator)s can be configured to force their
+//! outputs low while a fault is active, see
+//! [`Operator::set_brake_mode`](super::operator::Operator::set_brake_mode).
+
+use core::marker::PhantomData;
+
+use crate::{
+    gpio::InputPin,
+    mcpwm::PwmPeripheral,
+    peripheral::{Peripheral, PeripheralRef},
+    private,
+};
+
+/// The level of a fault input pin which signals a fault
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Polarity {
+    /// A high level signals a fault
+    ActiveHigh,
+    /// A low level signals a fault
+    ActiveLow,
+}
+
+/// How an operator reacts to a fault
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BrakeMode {
+    /// The outputs are forced low until the fault is cleared and the brake is
+    /// released with [`FaultInput::release_brake`]
+    OneShot,
+    /// The outputs are forced low while the fault is active, they are
+    /// re-enabled when the timer reaches zero after the fault is cleared
+    Cycle,
+}
+
+/// Events of a fault detector
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FaultEvent {
+    /// The fault became active
+    Fault,
+    /// The fault was cleared
+    FaultCleared,
+}
+
+/// One of the three fault detectors of an MCPWM peripheral
+///
+/// Use [`Fault::with_pin`] to connect it to an input pin.
+pub struct Fault<const F: u8, PWM> {
+    phantom: PhantomData<PWM>,
+}
+
+impl<const F: u8, PWM: PwmPeripheral> Fault<F, PWM> {
+    pub(super) fn new() -> Self {
+        Fault {
+            phantom: PhantomData,
+        }
+    }
+
+    /// Monitor the given pin for faults
+    pub fn with_pin<'d, Pin: InputPin>(
+        self,
+        pin: impl Peripheral<P = Pin> + 'd,
+        polarity: Polarity,
+    ) -> FaultInput<'d, Pin, PWM, F> {
+        FaultInput::new(pin, polarity)
+    }
+}
+
+/// A fault detector connected to an input pin
+pub struct FaultInput<'d, Pin, PWM: PwmPeripheral, const F: u8> {
+    _pin: PeripheralRef<'d, Pin>,
+    phantom: PhantomData<PWM>,
+}
+
+impl<'d, Pin, PWM: PwmPeripheral, const F: u8> FaultInput<'d, Pin, PWM, F> {
+    /// Fn_EN
+    const EN: u32 = 1 << F;
+    /// Fn_POLE
+    const POLE: u32 = 1 << (F + 3);
+    /// EVENT_Fn
+    const EVENT: u32 = 1 << (F + 6);
+
+    /// The FAULTn or FAULTn_CLR bit in the INT_* registers
+    const fn event_bit(event: FaultEvent) -> u32 {
+        match event {
+            FaultEvent::Fault => 1 << (F + 9),
+            FaultEvent::FaultCleared => 1 << (F + 12),
+        }
+    }
+}
+
+impl<'d, Pin: InputPin, PWM: PwmPeripheral, const F: u8> FaultInput<'d, Pin, PWM, F> {
+    fn new(pin: impl Peripheral<P = Pin> + 'd, polarity: Polarity) -> Self {
+        crate::into_ref!(pin);
+        pin.set_to_input(private::Internal);
+        pin.connect_input_to_peripheral(PWM::fault_signal::<F>(), private::Internal);
+
+        // SAFETY:
+        // We only modify our Fn_EN and Fn_POLE bits
+        let block = unsafe { &*PWM::block() };
+        block.fault_detect().modify(|r, w| {
+            let mut bits = r.bits() | Self::EN;
+            match polarity {
+                Polarity::ActiveHigh => bits |= Self::POLE,
+                Polarity::ActiveLow => bits &= !Self::POLE,
+            }
+            // SAFETY:
+            // `bits` is a valid bit pattern
+            unsafe { w.bits(bits) }
+        });
+
+        FaultInput {
+            _pin: pin,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns whether the fault is currently active
+    pub fn is_active(&self) -> bool {
+        // SAFETY:
+        // We only read from the FAULT_DETECT register
+        let block = unsafe { &*PWM::block() };
+        block.fault_detect().read().bits() & Self::EVENT != 0
+    }
+
+    /// Re-enable the outputs of all operators which were stopped by this fault
+    /// in [`BrakeMode::OneShot`]
+    ///
+    /// Returns `false` and leaves the outputs disabled if the fault is still
+    /// active.
+    pub fn release_brake(&self) -> bool {
+        if self.is_active() {
+            return false;
+        }
+
+        // SAFETY:
+        // We only write to the FHx_CFG1 registers of operators which use this
+        // fault for their one shot brake
+        let block = unsafe { &*PWM::block() };
+        for op in 0..3 {
+            let ch = block.ch(op);
+            if ch.fh_cfg0().read().bits() & fault_ost_bit(F) != 0 {
+                // the one shot brake is cleared on a rising edge of CLR_OST
+                ch.fh_cfg1()
+                    .modify(|r, w| unsafe { w.bits(r.bits() & !FH_CLR_OST) });
+                ch.fh_cfg1()
+                    .modify(|r, w| unsafe { w.bits(r.bits() | FH_CLR_OST) });
+            }
+        }
+
+        true
+    }
+
+    /// Returns whether `event` occurred since it was last cleared
+    pub fn is_event_set(&self, event: FaultEvent) -> bool {
+        // SAFETY:
+        // We only read from the INT_RAW register
+        let block = unsafe { &*PWM::block() };
+        block.int_raw().read().bits() & Self::event_bit(event) != 0
+    }
+
+    /// Clears `event`
+    pub fn clear_event(&self, event: FaultEvent) {
+        // SAFETY:
+        // Writing a one only clears our event, the other bits are ignored
+        let block = unsafe { &*PWM::block() };
+        block
+            .int_clr()
+            .write(|w| unsafe { w.bits(Self::event_bit(event)) });
+    }
+
+    /// Re-enables the outputs stopped in [`BrakeMode::OneShot`] once the
+    /// [`FaultEvent::FaultCleared`] event occurred
+    ///
+    /// Returns whether the event occurred, it is cleared in that case. Call
+    /// this regularly while a fault may be active, outputs in
+    /// [`BrakeMode::Cycle`] are re-enabled by the hardware.
+    pub fn handle_fault_cleared(&self) -> bool {
+        if !self.is_event_set(FaultEvent::FaultCleared) {
+            return false;
+        }
+
+        self.clear_event(FaultEvent::FaultCleared);
+        // The fault might have become active again in the meantime, the outputs
+        // stay disabled then
+        self.release_brake();
+
+        true
+    }
+}
+
+impl<'d, Pin, PWM: PwmPeripheral, const F: u8> Drop for FaultInput<'d, Pin, PWM, F> {
+    fn drop(&mut self) {
+        // SAFETY:
+        // We only modify our Fn_EN bit
+        let block = unsafe { &*PWM::block() };
+        block
+            .fault_detect()
+            .modify(|r, w| unsafe { w.bits(r.bits() & !Self::EN) });
+    }
+}
+
+/// FHx_CFG0 A_CBC_D, A_CBC_U, B_CBC_D and B_CBC_U set to "force low"
+pub(super) const FH_CBC_FORCE_LOW: u32 = 0b0101_0000_0101 << 8;
+/// FHx_CFG0 A_OST_D, A_OST_U, B_OST_D and B_OST_U set to "force low"
+pub(super) const FH_OST_FORCE_LOW: u32 = 0b0101_0000_0101 << 12;
+
+/// FHx_CFG1 CLR_OST
+pub(super) const FH_CLR_OST: u32 = 1 << 0;
+/// FHx_CFG1 CBCPULSE, refresh the cycle-by-cycle state when the timer is zero
+pub(super) const FH_CBCPULSE_TEZ: u32 = 1 << 1;
+
+/// FHx_CFG0 Fn_CBC
+pub(super) const fn fault_cbc_bit(fault: u8) -> u32 {
+    1 << (3 - fault)
+}
+
+/// FHx_CFG0 Fn_OST
+pub(super) const fn fault_ost_bit(fault: u8) -> u32 {
+    1 << (7 - fault)
+}
@@ -24,14 +24,14 @@
 //!       independently, in symmetric and asymmetric configuration.
 //!     * Software, asynchronously override control of PWM signals.
 //!     * Configurable dead-time on rising and falling edges; each set up
-//!       independently. (Not yet implemented)
+//!       independently.
 //!     * All events can trigger CPU interrupts. (Not yet implemented)
 //!     * Modulating of PWM output by high-frequency carrier signals, useful
 //!       when gate drivers are insulated with a transformer. (Not yet
 //!       implemented)
 //!     * Period, time stamps and important control registers have shadow
 //!       registers with flexible updating methods.
-//! * Fault Detection Module (Not yet implemented)
+//! * Fault Detection Module
 //! * Capture Module (Not yet implemented)
 #![doc = ""]
 #![cfg_attr(esp32, doc = "Clock source is PWM_CLOCK")]
@@ -87,17 +87,20 @@
 
 use core::{marker::PhantomData, ops::Deref};
 
+use fault::Fault;
 use fugit::HertzU32;
 use operator::Operator;
 use timer::Timer;
 
 use crate::{
     clock::Clocks,
-    gpio::OutputSignal,
+    gpio::{InputSignal, OutputSignal},
     peripheral::{Peripheral, PeripheralRef},
     system::{Peripheral as PeripheralEnable, PeripheralClockControl},
 };
 
+/// MCPWM fault detection
+pub mod fault;
 /// MCPWM operators
 pub mod operator;
 /// MCPWM timers
@@ -121,6 +124,12 @@ pub struct McPwm<'d, PWM> {
     pub operator1: Operator<1, PWM>,
     /// Operator2
     pub operator2: Operator<2, PWM>,
+    /// Fault detector 0
+    pub fault0: Fault<0, PWM>,
+    /// Fault detector 1
+    pub fault1: Fault<1, PWM>,
+    /// Fault detector 2
+    pub fault2: Fault<2, PWM>,
 }
 
 impl<'d, PWM: PwmPeripheral> McPwm<'d, PWM> {
@@ -178,9 +187,12 @@ impl<'d, PWM: PwmPeripheral> McPwm<'d, PWM> {
             timer0: Timer::new(),
             timer1: Timer::new(),
             timer2: Timer::new(),
-            operator0: Operator::new(),
-            operator1: Operator::new(),
-            operator2: Operator::new(),
+            operator0: Operator::new(peripheral_clock.frequency),
+            operator1: Operator::new(peripheral_clock.frequency),
+            operator2: Operator::new(peripheral_clock.frequency),
+            fault0: Fault::new(),
+            fault1: Fault::new(),
+            fault2: Fault::new(),
         }
     }
 }
@@ -316,6 +328,8 @@ pub trait PwmPeripheral: Deref<Target = RegisterBlock> + crate::private::Sealed
     fn block() -> *const RegisterBlock;
     /// Get operator GPIO mux output signal
     fn output_signal<const OP: u8, const IS_A: bool>() -> OutputSignal;
+    /// Get fault detector GPIO mux input signal
+    fn fault_signal<const F: u8>() -> InputSignal;
 }
 
 #[cfg(mcpwm0)]
@@ -339,6 +353,15 @@ impl PwmPeripheral for crate::peripherals::MCPWM0 {
             _ => unreachable!(),
         }
     }
+
+    fn fault_signal<const F: u8>() -> InputSignal {
+        match F {
+            0 => InputSignal::PWM0_F0,
+            1 => InputSignal::PWM0_F1,
+            2 => InputSignal::PWM0_F2,
+            _ => unreachable!(),
+        }
+    }
 }
 
 #[cfg(mcpwm1)]
@@ -362,4 +385,13 @@ impl PwmPeripheral for crate::peripherals::MCPWM1 {
             _ => unreachable!(),
         }
     }
+
+    fn fault_signal<const F: u8>() -> InputSignal {
+        match F {
+            0 => InputSignal::PWM1_F0,
+            1 => InputSignal::PWM1_F1,
+            2 => InputSignal::PWM1_F2,
+            _ => unreachable!(),
+        }
+    }
 }
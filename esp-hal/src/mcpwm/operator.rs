@@ -11,9 +11,15 @@
 
 use core::marker::PhantomData;
 
+use fugit::HertzU32;
+
 use crate::{
     gpio::OutputPin,
-    mcpwm::{timer::Timer, PwmPeripheral},
+    mcpwm::{
+        fault::{self, BrakeMode, FaultInput},
+        timer::{PwmWorkingMode, Timer},
+        PwmPeripheral,
+    },
     peripheral::{Peripheral, PeripheralRef},
     private,
 };
@@ -84,7 +90,23 @@ impl DeadTimeCfg {
     pub const fn new_ahc() -> DeadTimeCfg {
         DeadTimeCfg { cfg_reg: Self::S3 }
     }
-    // TODO: Add some common configurations ~AHC~,ALC,AH,AC
+
+    /// Active Low Complementary (ALC) from Technical Reference manual
+    ///
+    /// Like [`DeadTimeCfg::new_ahc`] but with both outputs inverted, such that
+    /// they are both on during a transition.
+    pub const fn new_alc() -> DeadTimeCfg {
+        DeadTimeCfg { cfg_reg: Self::S2 }
+    }
+
+    /// Active High (AH) from Technical Reference manual
+    ///
+    /// Will generate a PWM from input PWMA, such that output PWMA is delayed
+    /// on its rising edge and output PWMB is delayed on its falling edge,
+    /// without inverting either of them.
+    pub const fn new_ah() -> DeadTimeCfg {
+        DeadTimeCfg { cfg_reg: 0 }
+    }
 
     #[must_use]
     const fn set_flag(mut self, flag: u32, val: bool) -> Self {
@@ -157,22 +179,56 @@ impl DeadTimeCfg {
     }
 }
 
+/// Common dead time generator configurations, see [`Operator::set_dead_time`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DeadTimeMode {
+    /// No dead time is applied, see [`DeadTimeCfg::new_bypass`]
+    Bypass,
+    /// Complementary outputs which are both low during a transition, see
+    /// [`DeadTimeCfg::new_ahc`]
+    ActiveHighComplement,
+    /// Complementary outputs which are both high during a transition, see
+    /// [`DeadTimeCfg::new_alc`]
+    ActiveLowComplement,
+    /// Non-inverted outputs with delayed edges, see [`DeadTimeCfg::new_ah`]
+    ActiveHigh,
+}
+
+impl DeadTimeMode {
+    const fn cfg(self) -> DeadTimeCfg {
+        match self {
+            DeadTimeMode::Bypass => DeadTimeCfg::new_bypass(),
+            DeadTimeMode::ActiveHighComplement => DeadTimeCfg::new_ahc(),
+            DeadTimeMode::ActiveLowComplement => DeadTimeCfg::new_alc(),
+            DeadTimeMode::ActiveHigh => DeadTimeCfg::new_ah(),
+        }
+    }
+}
+
+/// The requested dead time is longer than the PWM period or doesn't fit into
+/// the dead time registers.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DeadTimeError;
+
 /// A MCPWM operator
 ///
 /// The PWM Operator submodule has the following functions:
 /// * Generates a PWM signal pair, based on timing references obtained from the
 ///   corresponding PWM timer.
 /// * Each signal out of the PWM signal pair includes a specific pattern of dead
-///   time. (Not yet implemented)
+///   time.
 /// * Superimposes a carrier on the PWM signal, if configured to do so. (Not yet
 ///   implemented)
-/// * Handles response under fault conditions. (Not yet implemented)
+/// * Handles response under fault conditions.
 pub struct Operator<const OP: u8, PWM> {
+    pwm_clock: HertzU32,
     phantom: PhantomData<PWM>,
 }
 
 impl<const OP: u8, PWM: PwmPeripheral> Operator<OP, PWM> {
-    pub(super) fn new() -> Self {
+    pub(super) fn new(pwm_clock: HertzU32) -> Self {
         // Side note:
         // It would have been nice to deselect any timer reference on peripheral
         // initialization.
@@ -180,10 +236,56 @@ impl<const OP: u8, PWM: PwmPeripheral> Operator<OP, PWM> {
         // will not disable the timer reference but instead act as though `2` was
         // written.
         Operator {
+            pwm_clock,
             phantom: PhantomData,
         }
     }
 
+    /// Configure the dead time generator of this operator
+    ///
+    /// `rising_ns` and `falling_ns` are rounded up to the next cycle of the
+    /// peripheral clock. Returns [`DeadTimeError`] if their sum exceeds the
+    /// period of the timer selected with [`Operator::set_timer`], so the timer
+    /// should be started before calling this.
+    pub fn set_dead_time(
+        &mut self,
+        rising_ns: u32,
+        falling_ns: u32,
+        mode: DeadTimeMode,
+    ) -> Result<(), DeadTimeError> {
+        set_dead_time::<PWM, OP>(self.pwm_clock, rising_ns, falling_ns, mode)
+    }
+
+    /// Force both outputs of this operator low when `fault` is active
+    ///
+    /// A fault can be used by multiple operators, and an operator can react to
+    /// multiple faults.
+    pub fn set_brake_mode<Pin, const F: u8>(
+        &mut self,
+        _fault: &FaultInput<'_, Pin, PWM, F>,
+        mode: BrakeMode,
+    ) {
+        // SAFETY:
+        // We only write to our FHx_CFG0 and FHx_CFG1 registers
+        let ch = unsafe { &*PWM::block() }.ch(OP as usize);
+
+        ch.fh_cfg0().modify(|r, w| {
+            let mut bits = r.bits() & !(fault::fault_cbc_bit(F) | fault::fault_ost_bit(F));
+            bits |= match mode {
+                BrakeMode::OneShot => fault::fault_ost_bit(F) | fault::FH_OST_FORCE_LOW,
+                BrakeMode::Cycle => fault::fault_cbc_bit(F) | fault::FH_CBC_FORCE_LOW,
+            };
+            // SAFETY:
+            // `bits` is a valid bit pattern
+            unsafe { w.bits(bits) }
+        });
+
+        if mode == BrakeMode::Cycle {
+            ch.fh_cfg1()
+                .modify(|r, w| unsafe { w.bits(r.bits() | fault::FH_CBCPULSE_TEZ) });
+        }
+    }
+
     /// Select a [`Timer`] to be the timing reference for this operator
     ///
     /// ### Note:
@@ -247,7 +349,7 @@ impl<const OP: u8, PWM: PwmPeripheral> Operator<OP, PWM> {
         config_b: PwmPinConfig<false>,
         config_dt: DeadTimeCfg,
     ) -> LinkedPins<'d, PinA, PinB, PWM, OP> {
-        LinkedPins::new(pin_a, config_a, pin_b, config_b, config_dt)
+        LinkedPins::new(pin_a, config_a, pin_b, config_b, config_dt, self.pwm_clock)
     }
 }
 
@@ -522,6 +624,7 @@ impl<'d, Pin: OutputPin, PWM: PwmPeripheral, const OP: u8, const IS_A: bool>
 pub struct LinkedPins<'d, PinA, PinB, PWM, const OP: u8> {
     pin_a: PwmPin<'d, PinA, PWM, OP, true>,
     pin_b: PwmPin<'d, PinB, PWM, OP, false>,
+    pwm_clock: HertzU32,
 }
 
 impl<'d, PinA: OutputPin, PinB: OutputPin, PWM: PwmPeripheral, const OP: u8>
@@ -533,6 +636,7 @@ impl<'d, PinA: OutputPin, PinB: OutputPin, PWM: PwmPeripheral, const OP: u8>
         pin_b: impl Peripheral<P = PinB> + 'd,
         config_b: PwmPinConfig<false>,
         config_dt: DeadTimeCfg,
+        pwm_clock: HertzU32,
     ) -> Self {
         // setup deadtime config before enabling the pins
         #[cfg(esp32s3)]
@@ -544,7 +648,11 @@ impl<'d, PinA: OutputPin, PinB: OutputPin, PWM: PwmPeripheral, const OP: u8>
         let pin_a = PwmPin::new(pin_a, config_a);
         let pin_b = PwmPin::new(pin_b, config_b);
 
-        LinkedPins { pin_a, pin_b }
+        LinkedPins {
+            pin_a,
+            pin_b,
+            pwm_clock,
+        }
     }
 
     /// Configure what actions should be taken on timing events
@@ -604,12 +712,87 @@ impl<'d, PinA: OutputPin, PinB: OutputPin, PWM: PwmPeripheral, const OP: u8>
         dt_fed.write(|w| unsafe { w.fed().bits(dead_time) });
     }
 
+    /// Configure the deadtime generator from a duration in nanoseconds, see
+    /// [`Operator::set_dead_time`]
+    pub fn set_dead_time(
+        &mut self,
+        rising_ns: u32,
+        falling_ns: u32,
+        mode: DeadTimeMode,
+    ) -> Result<(), DeadTimeError> {
+        set_dead_time::<PWM, OP>(self.pwm_clock, rising_ns, falling_ns, mode)
+    }
+
     unsafe fn ch() -> &'static crate::peripherals::mcpwm0::CH {
         let block = unsafe { &*PWM::block() };
         block.ch(OP as usize)
     }
 }
 
+fn set_dead_time<PWM: PwmPeripheral, const OP: u8>(
+    pwm_clock: HertzU32,
+    rising_ns: u32,
+    falling_ns: u32,
+    mode: DeadTimeMode,
+) -> Result<(), DeadTimeError> {
+    let to_cycles = |ns: u32| (ns as u64 * pwm_clock.raw() as u64).div_ceil(1_000_000_000);
+
+    let red = to_cycles(rising_ns);
+    let fed = to_cycles(falling_ns);
+    if red > u16::MAX as u64 || fed > u16::MAX as u64 {
+        return Err(DeadTimeError);
+    }
+    if red + fed > pwm_period_cycles::<PWM, OP>() as u64 {
+        return Err(DeadTimeError);
+    }
+
+    // SAFETY:
+    // We only write to our DTx_CFG, DTx_RED_CFG and DTx_FED_CFG registers
+    let ch = unsafe { &*PWM::block() }.ch(OP as usize);
+
+    #[cfg(esp32s3)]
+    let (dt_cfg, dt_red, dt_fed) = (ch.db_cfg(), ch.db_red_cfg(), ch.db_fed_cfg());
+    #[cfg(not(esp32s3))]
+    let (dt_cfg, dt_red, dt_fed) = (ch.dt_cfg(), ch.dt_red_cfg(), ch.dt_fed_cfg());
+
+    dt_red.write(|w| unsafe { w.red().bits(red as u16) });
+    dt_fed.write(|w| unsafe { w.fed().bits(fed as u16) });
+    dt_cfg.write(|w| unsafe { w.bits(mode.cfg().cfg_reg) });
+
+    Ok(())
+}
+
+/// The PWM period of the timer selected by operator `OP` in cycles of the
+/// peripheral clock
+fn pwm_period_cycles<PWM: PwmPeripheral, const OP: u8>() -> u32 {
+    // SAFETY:
+    // We only read from the registers of the selected timer
+    let block = unsafe { &*PWM::block() };
+
+    let tim_select = block.operator_timersel().read();
+    let tim = match OP {
+        0 => tim_select.operator0_timersel().bits(),
+        1 => tim_select.operator1_timersel().bits(),
+        2 => tim_select.operator2_timersel().bits(),
+        _ => {
+            unreachable!()
+        }
+    };
+
+    let tmr = block.timer(tim as usize);
+    let cfg0 = tmr.cfg0().read();
+    let period = cfg0.period().bits() as u32;
+    let prescaler = cfg0.prescale().bits() as u32;
+
+    let cycle_period = if tmr.cfg1().read().mod_().bits() == PwmWorkingMode::UpDown as u8 {
+        period * 2
+    } else {
+        period + 1
+    };
+
+    cycle_period * (prescaler + 1)
+}
+
 /// An action the operator applies to an output
 #[non_exhaustive]
 #[repr(u32)]
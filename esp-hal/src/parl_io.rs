@@ -87,6 +87,8 @@ pub enum Error {
     MaxDmaTransferSizeExceeded,
     /// Trying to use an impossible clock rate
     UnreachableClockRate,
+    /// The operation requires [EofMode::EnableSignal]
+    EnableSignalRequired,
 }
 
 impl From<DmaError> for Error {
@@ -1487,7 +1489,7 @@ where
     }
 
     fn peripheral_dma_stop(&mut self) {
-        unreachable!("unsupported")
+        Instance::set_tx_start(false);
     }
 }
 
@@ -1521,7 +1523,9 @@ where
     /// The maximum amount of data is 32736 bytes when using [EofMode::ByteLen].
     ///
     /// It's only limited by the size of the DMA buffer when using
-    /// [EofMode::EnableSignal].
+    /// [EofMode::EnableSignal]. The transfer is done when the enable signal
+    /// becomes inactive, [DmaTransferRx::received_bytes] is the length of the
+    /// received frame then.
     pub fn read_dma<'t, RXBUF>(
         &'t mut self,
         words: &'t mut RXBUF,
@@ -1540,6 +1544,39 @@ where
         Ok(DmaTransferRx::new(self))
     }
 
+    /// Receives the frames ended by the enable signal into `buffers` in turn.
+    ///
+    /// The descriptors of the driver are split evenly between the two buffers.
+    /// Receiving into one buffer starts right after a frame was received into
+    /// the other one, see [ParlIoRxFrames::wait_frame].
+    ///
+    /// Returns [Error::EnableSignalRequired] if the RX isn't configured with
+    /// [EofMode::EnableSignal].
+    pub fn read_frames_dma<'t>(
+        &'t mut self,
+        buffers: [&'t mut [u8]; 2],
+    ) -> Result<ParlIoRxFrames<'t, 'd, CH, DM>, Error> {
+        if !Instance::is_suc_eof_generated_externally() {
+            return Err(Error::EnableSignalRequired);
+        }
+
+        let descriptors = self.rx_chain.take_descriptors();
+        let descriptors_len = descriptors.len();
+        let (first, second) = descriptors.split_at_mut(descriptors_len / 2);
+
+        let mut frames = ParlIoRxFrames {
+            rx: self,
+            chains: [DescriptorChain::new(first), DescriptorChain::new(second)],
+            descriptors_len,
+            buffers,
+            current: 0,
+        };
+
+        frames.start(0)?;
+
+        Ok(frames)
+    }
+
     fn start_receive_bytes_dma(
         rx_channel: &mut ChannelRx<'d, CH>,
         rx_chain: &mut DescriptorChain,
@@ -1553,7 +1590,9 @@ where
             .modify(|_, w| w.parl_rx_rst_en().clear_bit());
 
         Instance::clear_rx_interrupts();
-        Instance::set_rx_bytes(len as u16);
+        // Not used when the EOF is generated by the enable signal, a frame can be
+        // as long as the buffer then.
+        Instance::set_rx_bytes(len.min(u16::MAX as usize) as u16);
 
         unsafe {
             rx_chain.fill_for_rx(false, ptr, len)?;
@@ -1589,7 +1628,7 @@ where
     }
 
     fn peripheral_dma_stop(&mut self) {
-        unreachable!("unsupported")
+        Instance::set_rx_start(false);
     }
 }
 
@@ -1610,6 +1649,90 @@ where
     }
 }
 
+/// Frames received by [ParlIoRx::read_frames_dma] into two buffers in turn
+///
+/// The enable signal has to stay inactive until the next buffer is armed,
+/// which happens when [ParlIoRxFrames::wait_frame] sees the end of a frame.
+/// Data arriving before that is lost.
+pub struct ParlIoRxFrames<'t, 'd, CH, DM>
+where
+    CH: DmaChannel,
+    CH::P: ParlIoPeripheral,
+    DM: Mode,
+{
+    rx: &'t mut ParlIoRx<'d, CH, DM>,
+    chains: [DescriptorChain; 2],
+    descriptors_len: usize,
+    buffers: [&'t mut [u8]; 2],
+    current: usize,
+}
+
+impl<'t, 'd, CH, DM> ParlIoRxFrames<'t, 'd, CH, DM>
+where
+    CH: DmaChannel,
+    CH::P: ParlIoPeripheral,
+    DM: Mode,
+{
+    fn start(&mut self, index: usize) -> Result<(), Error> {
+        let buffer = &mut self.buffers[index];
+        ParlIoRx::<'d, CH, DM>::start_receive_bytes_dma(
+            &mut self.rx.rx_channel,
+            &mut self.chains[index],
+            buffer.as_mut_ptr(),
+            buffer.len(),
+        )
+    }
+
+    /// Waits until a frame was received and returns it.
+    ///
+    /// Receiving the next frame into the other buffer is started before the
+    /// frame is returned, the descriptors are reused for it. A frame which is
+    /// longer than its buffer is cut off.
+    pub fn wait_frame(&mut self) -> Result<&[u8], Error> {
+        loop {
+            let rx = &self.rx.rx_channel;
+            if rx.has_error() {
+                let address = rx.error_descriptor_address();
+                let error = self.chains[self.current].descriptor_error(address);
+                return Err(Error::DmaError(error));
+            }
+            if rx.has_eof_error() || rx.has_dscr_empty_error() {
+                return Err(Error::DmaError(DmaError::DescriptorError));
+            }
+            if rx.is_done() {
+                break;
+            }
+        }
+
+        let received = self.current;
+        let len = self.chains[received].received_bytes();
+
+        self.current = 1 - received;
+        self.start(self.current)?;
+
+        Ok(&self.buffers[received][..len])
+    }
+}
+
+impl<'t, 'd, CH, DM> Drop for ParlIoRxFrames<'t, 'd, CH, DM>
+where
+    CH: DmaChannel,
+    CH::P: ParlIoPeripheral,
+    DM: Mode,
+{
+    fn drop(&mut self) {
+        Instance::set_rx_start(false);
+        self.rx.rx_channel.stop_transfer();
+
+        // Hand the descriptors back to the driver, both halves were split off
+        // the same slice.
+        let first = self.chains[0].take_descriptors();
+        let descriptors =
+            unsafe { core::slice::from_raw_parts_mut(first.as_mut_ptr(), self.descriptors_len) };
+        self.rx.rx_chain = DescriptorChain::new(descriptors);
+    }
+}
+
 /// Creates a TX channel
 pub struct TxCreator<'d, CH, DM>
 where
@@ -1664,7 +1787,12 @@ pub mod asynch {
 
     use super::{private::Instance, Error, ParlIoRx, ParlIoTx, MAX_DMA_SIZE};
     use crate::{
-        dma::{asynch::DmaRxFuture, DmaChannel, ParlIoPeripheral},
+        dma::{
+            asynch::{DmaRxFuture, OnDrop},
+            DmaChannel,
+            ParlIoPeripheral,
+            TxPrivate,
+        },
         peripherals::Interrupt,
     };
 
@@ -1735,7 +1863,13 @@ pub mod asynch {
 
             let future = TxDoneFuture::new();
             self.start_write_bytes_dma(ptr, len)?;
+
+            let guard = OnDrop::new(|| {
+                Instance::set_tx_start(false);
+                self.tx_channel.stop_transfer();
+            });
             future.await;
+            guard.defuse();
 
             Ok(())
         }
@@ -1756,9 +1890,13 @@ pub mod asynch {
                 return Err(Error::MaxDmaTransferSizeExceeded);
             }
 
-            let future = DmaRxFuture::new(&mut self.rx_channel);
+            let future = DmaRxFuture::new(&mut self.rx_channel).with_chain(self.rx_chain.span());
             Self::start_receive_bytes_dma(future.rx, &mut self.rx_chain, ptr, len)?;
-            future.await?;
+
+            let guard = OnDrop::new(|| Instance::set_rx_start(false));
+            let res = future.await;
+            guard.defuse();
+            res?;
 
             Ok(())
         }
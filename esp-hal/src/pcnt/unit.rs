@@ -12,8 +12,9 @@
 use core::marker::PhantomData;
 
 use critical_section::CriticalSection;
+use fugit::{HertzU32, MicrosDurationU64};
 
-use crate::pcnt::channel::Channel;
+use crate::pcnt::channel::{Channel, CtrlMode, EdgeMode, PcntSource};
 
 /// Invalid filter threshold value
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -290,6 +291,107 @@ impl<'d, const NUM: usize> Unit<'d, NUM> {
     pub fn get_value(&self) -> i16 {
         self.counter.get()
     }
+
+    /// Configures the unit to count the edges of `source`, which allows
+    /// measuring the frequency of the signal.
+    ///
+    /// Only channel 0 is used, the counter is paused until a measurement is
+    /// started.
+    pub fn into_frequency_counter(
+        self,
+        source: PcntSource,
+        edges: CountEdges,
+    ) -> FrequencyCounter<'d, NUM> {
+        self.pause();
+
+        // The counter wraps around, which is accounted for while counting
+        unwrap!(self.set_low_limit(None));
+        unwrap!(self.set_high_limit(None));
+
+        self.channel0.set_edge_signal(source);
+        self.channel0.set_ctrl_signal(PcntSource::always_high());
+        self.channel0.set_ctrl_mode(CtrlMode::Keep, CtrlMode::Keep);
+        match edges {
+            CountEdges::Rising => self
+                .channel0
+                .set_input_mode(EdgeMode::Hold, EdgeMode::Increment),
+            CountEdges::Both => self
+                .channel0
+                .set_input_mode(EdgeMode::Increment, EdgeMode::Increment),
+        }
+        self.channel1.set_input_mode(EdgeMode::Hold, EdgeMode::Hold);
+
+        self.clear();
+
+        FrequencyCounter { unit: self, edges }
+    }
+}
+
+/// The edges of a signal counted by a [FrequencyCounter]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CountEdges {
+    /// Count rising edges only
+    Rising,
+    /// Count rising and falling edges
+    Both,
+}
+
+/// Measures the frequency of a signal by counting its edges during a gate
+/// window.
+pub struct FrequencyCounter<'d, const NUM: usize> {
+    unit: Unit<'d, NUM>,
+    edges: CountEdges,
+}
+
+impl<'d, const NUM: usize> FrequencyCounter<'d, NUM> {
+    /// Counts the edges of the signal for `gate_time_ms` milliseconds.
+    ///
+    /// This blocks for the duration of the gate window.
+    pub fn count_pulses(&mut self, gate_time_ms: u32) -> u32 {
+        let gate_time = MicrosDurationU64::millis(gate_time_ms as u64);
+
+        self.unit.clear();
+        self.unit.resume();
+        let start = crate::time::current_time();
+
+        let mut pulses = 0u32;
+        let mut last = 0u16;
+        loop {
+            let done = crate::time::current_time() - start >= gate_time;
+            if done {
+                self.unit.pause();
+            }
+
+            // The 16 bit counter wraps around, it is read often enough to not
+            // miss a wrap-around
+            let value = self.unit.get_value() as u16;
+            pulses += value.wrapping_sub(last) as u32;
+            last = value;
+
+            if done {
+                break;
+            }
+        }
+
+        pulses
+    }
+
+    /// Measures the frequency of the signal during a gate window of
+    /// `gate_time_ms` milliseconds.
+    pub fn frequency(&mut self, gate_time_ms: u32) -> HertzU32 {
+        let mut pulses = self.count_pulses(gate_time_ms) as u64;
+        if self.edges == CountEdges::Both {
+            pulses /= 2;
+        }
+
+        HertzU32::from_raw((pulses * 1000 / gate_time_ms.max(1) as u64) as u32)
+    }
+
+    /// Returns the unit, the configuration of its channels is left as is.
+    pub fn release(self) -> Unit<'d, NUM> {
+        self.unit
+    }
 }
 
 impl<'d, const NUM: usize> Drop for Unit<'d, NUM> {
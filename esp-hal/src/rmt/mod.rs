@@ -166,6 +166,16 @@ impl From<PulseCode> for u32 {
     }
 }
 
+/// Output level on which the carrier is modulated
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CarrierLevel {
+    /// The carrier is modulated while the output is low
+    Low,
+    /// The carrier is modulated while the output is high
+    High,
+}
+
 /// Channel configuration for TX channels
 #[derive(Debug, Copy, Clone, Default)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -208,6 +218,8 @@ pub struct RxChannelConfig {
 
 pub use impl_for_chip::{ChannelCreator, Rmt};
 
+pub mod protocols;
+
 #[cfg(feature = "async")]
 use self::asynch::{RxChannelAsync, TxChannelAsync};
 
@@ -1039,6 +1051,32 @@ pub trait TxChannel: private::TxChannelInternal<crate::Blocking> {
         let _index = Self::send_raw(data, true, loopcount);
         Ok(ContinuousTxTransaction { channel: self })
     }
+
+    /// Modulate the output with a carrier of the given frequency and duty
+    /// cycle (in percent).
+    ///
+    /// The carrier is applied while the output is at `level` and stays enabled
+    /// for all following transmissions until [`Self::disable_carrier`] is
+    /// called.
+    fn enable_carrier(
+        &mut self,
+        frequency: HertzU32,
+        duty_percent: u8,
+        level: CarrierLevel,
+    ) -> Result<(), Error>
+    where
+        Self: Sized,
+    {
+        Self::configure_carrier(frequency, duty_percent, level)
+    }
+
+    /// Stop modulating the output with a carrier
+    fn disable_carrier(&mut self)
+    where
+        Self: Sized,
+    {
+        Self::set_carrier(false, 0, 0, false);
+    }
 }
 
 /// RX transaction instance
@@ -1145,6 +1183,22 @@ pub mod asynch {
         }
     }
 
+    impl<T> Drop for RmtTxFuture<T>
+    where
+        T: TxChannelAsync,
+    {
+        fn drop(&mut self) {
+            // Stop the channel if the future is dropped before it completed
+            if !T::is_done() && !T::is_error() {
+                T::unlisten_interrupt(Event::End);
+                T::unlisten_interrupt(Event::Error);
+                T::stop();
+                T::clear_interrupts();
+                T::update();
+            }
+        }
+    }
+
     impl<T> core::future::Future for RmtTxFuture<T>
     where
         T: TxChannelAsync,
@@ -1188,6 +1242,32 @@ pub mod asynch {
                 Ok(())
             }
         }
+
+        /// Modulate the output with a carrier of the given frequency and duty
+        /// cycle (in percent).
+        ///
+        /// The carrier is applied while the output is at `level` and stays
+        /// enabled for all following transmissions until
+        /// [`Self::disable_carrier`] is called.
+        fn enable_carrier(
+            &mut self,
+            frequency: HertzU32,
+            duty_percent: u8,
+            level: CarrierLevel,
+        ) -> Result<(), Error>
+        where
+            Self: Sized,
+        {
+            Self::configure_carrier(frequency, duty_percent, level)
+        }
+
+        /// Stop modulating the output with a carrier
+        fn disable_carrier(&mut self)
+        where
+            Self: Sized,
+        {
+            Self::set_carrier(false, 0, 0, false);
+        }
     }
 
     pub(crate) struct RmtRxFuture<T>
@@ -1208,6 +1288,22 @@ pub mod asynch {
         }
     }
 
+    impl<T> Drop for RmtRxFuture<T>
+    where
+        T: RxChannelAsync,
+    {
+        fn drop(&mut self) {
+            // Stop the channel if the future is dropped before it completed
+            if !T::is_done() && !T::is_error() {
+                T::unlisten_interrupt(Event::End);
+                T::unlisten_interrupt(Event::Error);
+                T::stop();
+                T::clear_interrupts();
+                T::update();
+            }
+        }
+    }
+
     impl<T> core::future::Future for RmtRxFuture<T>
     where
         T: RxChannelAsync,
@@ -1229,7 +1325,13 @@ pub mod asynch {
         /// Start receiving a pulse code sequence.
         /// The length of sequence cannot exceed the size of the allocated RMT
         /// RAM.
-        async fn receive<'a, T: From<u32> + Copy>(&mut self, data: &'a mut [T]) -> Result<(), Error>
+        ///
+        /// Returns the number of received pulse codes, including the one
+        /// containing the end marker.
+        async fn receive<'a, T: From<u32> + Copy>(
+            &mut self,
+            data: &'a mut [T],
+        ) -> Result<usize, Error>
         where
             Self: Sized,
         {
@@ -1254,12 +1356,19 @@ pub mod asynch {
                 let ptr = (constants::RMT_RAM_START
                     + Self::CHANNEL as usize * constants::RMT_CHANNEL_RAM_SIZE * 4)
                     as *mut u32;
-                let len = data.len();
-                for (idx, entry) in data.iter_mut().take(len).enumerate() {
-                    *entry = unsafe { ptr.add(idx).read_volatile().into() };
+                let mut received = data.len();
+                for (idx, entry) in data.iter_mut().enumerate() {
+                    let raw = unsafe { ptr.add(idx).read_volatile() };
+                    *entry = raw.into();
+
+                    // a zero length marks the end of the received sequence
+                    if raw & 0x7fff == 0 || (raw >> 16) & 0x7fff == 0 {
+                        received = idx + 1;
+                        break;
+                    }
                 }
 
-                Ok(())
+                Ok(received)
             }
         }
     }
@@ -1447,6 +1556,9 @@ pub mod asynch {
 }
 
 mod private {
+    use fugit::HertzU32;
+
+    use super::{CarrierLevel, Error};
     use crate::{peripheral::Peripheral, soc::constants};
 
     pub enum Event {
@@ -1483,6 +1595,34 @@ mod private {
 
         fn set_carrier(carrier: bool, high: u16, low: u16, level: bool);
 
+        fn configure_carrier(
+            frequency: HertzU32,
+            duty_percent: u8,
+            level: CarrierLevel,
+        ) -> Result<(), Error> {
+            if duty_percent == 0 || duty_percent >= 100 {
+                return Err(Error::InvalidArgument);
+            }
+
+            if frequency.raw() == 0 {
+                return Err(Error::UnreachableTargetFrequency);
+            }
+
+            // the carrier is generated from the RMT source clock, not the
+            // divided channel clock
+            let period = super::chip_specific::source_frequency().raw() / frequency.raw();
+            let high = period * duty_percent as u32 / 100;
+            let low = period - high;
+
+            if high == 0 || low == 0 || high > u16::MAX as u32 || low > u16::MAX as u32 {
+                return Err(Error::UnreachableTargetFrequency);
+            }
+
+            Self::set_carrier(true, high as u16, low as u16, level == CarrierLevel::High);
+
+            Ok(())
+        }
+
         fn set_idle_output(enable: bool, level: bool);
 
         fn set_memsize(memsize: u8);
@@ -1634,6 +1774,22 @@ mod chip_specific {
         }
     }
 
+    pub fn source_frequency() -> fugit::HertzU32 {
+        #[cfg(not(pcr))]
+        let div = {
+            let rmt = unsafe { &*crate::peripherals::RMT::PTR };
+            rmt.sys_conf().read().sclk_div_num().bits()
+        };
+
+        #[cfg(pcr)]
+        let div = {
+            let pcr = unsafe { &*crate::peripherals::PCR::PTR };
+            pcr.rmt_sclk_conf().read().sclk_div_num().bits()
+        };
+
+        crate::soc::constants::RMT_CLOCK_SRC_FREQ / (div as u32 + 1)
+    }
+
     #[allow(unused)]
     #[cfg(not(esp32s3))]
     pub fn pending_interrupt_for_channel() -> Option<usize> {
@@ -2043,6 +2199,11 @@ mod chip_specific {
         rmt.apb_conf().modify(|_, w| w.clk_en().set_bit());
     }
 
+    pub fn source_frequency() -> fugit::HertzU32 {
+        // the channels are clocked by APB, see `REF_ALWAYS_ON` above
+        fugit::HertzU32::MHz(80)
+    }
+
     #[allow(unused)]
     #[cfg(esp32)]
     pub fn pending_interrupt_for_channel() -> Option<usize> {
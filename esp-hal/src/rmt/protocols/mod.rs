@@ -0,0 +1,9 @@
+//! # RMT protocol encoders
+//!
+//! ## Overview
+//! Drivers for common protocols built on top of the RMT TX channels.
+//!
+//! Currently the following protocols are supported:
+//! - [WS2812B / NeoPixel LEDs](ws2812)
+
+pub mod ws2812;
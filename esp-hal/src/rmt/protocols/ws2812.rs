@@ -0,0 +1,230 @@
+//! # WS2812B / NeoPixel LED driver
+//!
+//! ## Overview
+//! WS2812B LEDs are daisy-chained and driven by a single data line. Every LED
+//! takes 24 bits (green, red and blue, most significant bit first) encoded as
+//! 800 kHz NRZ pulses, and passes the remaining bits on to the next LED. A low
+//! level of at least 280 µs latches the colors (50 µs on older revisions).
+//!
+//! [Ws2812] encodes the colors into [PulseCode]s, one per bit plus a final
+//! reset code which holds the line low before it ends the transmission, and
+//! transmits them with an RMT TX channel. The buffer for the
+//! pulse codes is provided by the user and needs to hold
+//! [buffer_size] `(pixel_count)` entries.
+//!
+//! ## Examples
+//! ```rust, no_run
+#![doc = crate::before_snippet!()]
+//! # use esp_hal::gpio::Io;
+//! # use esp_hal::rmt::{PulseCode, Rmt, TxChannelConfig, TxChannelCreator};
+//! # use esp_hal::rmt::protocols::ws2812::{buffer_size, Ws2812};
+//! # use crate::esp_hal::prelude::_fugit_RateExtU32;
+//! # let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+#![cfg_attr(esp32h2, doc = "let freq = 32.MHz();")]
+#![cfg_attr(not(esp32h2), doc = "let freq = 80.MHz();")]
+//! let rmt = Rmt::new(peripherals.RMT, freq, &clocks).unwrap();
+//! let channel = rmt
+//!     .channel0
+//!     .configure(
+//!         io.pins.gpio8,
+//!         TxChannelConfig {
+//!             clk_divider: 4,
+//!             ..TxChannelConfig::default()
+//!         },
+//!     )
+//!     .unwrap();
+//!
+//! static mut BUFFER: [PulseCode; buffer_size(3)] = [PulseCode {
+//!     level1: false,
+//!     length1: 0,
+//!     level2: false,
+//!     length2: 0,
+//! }; buffer_size(3)];
+//!
+//! let buffer = unsafe { &mut BUFFER };
+//! let mut leds = Ws2812::new(channel, freq / 4, buffer).unwrap();
+//! leds.write_pixels(&[(255, 0, 0), (0, 255, 0), (0, 0, 255)])
+//!     .unwrap();
+//! # }
+//! ```
+
+use fugit::HertzU32;
+
+#[cfg(feature = "async")]
+use crate::rmt::asynch::TxChannelAsync;
+use crate::rmt::{Error, PulseCode, TxChannel};
+
+/// High time of a `0` bit in nanoseconds
+const T0H_NS: u32 = 400;
+/// Low time of a `0` bit in nanoseconds
+const T0L_NS: u32 = 850;
+/// High time of a `1` bit in nanoseconds
+const T1H_NS: u32 = 800;
+/// Low time of a `1` bit in nanoseconds
+const T1L_NS: u32 = 450;
+/// Low time which latches the colors in nanoseconds
+const RES_NS: u32 = 300_000;
+
+/// Number of pulse codes needed to transmit `pixel_count` pixels
+pub const fn buffer_size(pixel_count: usize) -> usize {
+    24 * pixel_count + 1
+}
+
+/// WS2812B LED strip driven by an RMT TX channel
+pub struct Ws2812<'d, C> {
+    channel: Option<C>,
+    buffer: &'d mut [PulseCode],
+    zero: PulseCode,
+    one: PulseCode,
+    reset: PulseCode,
+}
+
+impl<'d, C> Ws2812<'d, C> {
+    fn new_internal(
+        channel: C,
+        tick_frequency: HertzU32,
+        buffer: &'d mut [PulseCode],
+    ) -> Result<Self, Error> {
+        let ticks = |ns: u32| {
+            let ticks = (tick_frequency.raw() as u64 * ns as u64 + 500_000_000) / 1_000_000_000;
+            // lengths are 15 bits wide
+            if ticks == 0 || ticks > 0x7fff {
+                Err(Error::UnreachableTargetFrequency)
+            } else {
+                Ok(ticks as u16)
+            }
+        };
+
+        let zero = PulseCode {
+            level1: true,
+            length1: ticks(T0H_NS)?,
+            level2: false,
+            length2: ticks(T0L_NS)?,
+        };
+        let one = PulseCode {
+            level1: true,
+            length1: ticks(T1H_NS)?,
+            level2: false,
+            length2: ticks(T1L_NS)?,
+        };
+        // the zero length of the second half ends the transmission after the
+        // reset time
+        let reset = PulseCode {
+            level1: false,
+            length1: ticks(RES_NS)?,
+            level2: false,
+            length2: 0,
+        };
+
+        Ok(Self {
+            channel: Some(channel),
+            buffer,
+            zero,
+            one,
+            reset,
+        })
+    }
+
+    /// Encodes the pixels into the buffer, returns the number of pulse codes
+    /// to transmit.
+    fn encode(&mut self, pixels: &[(u8, u8, u8)]) -> Result<usize, Error> {
+        let len = buffer_size(pixels.len());
+        if len > self.buffer.len() {
+            return Err(Error::Overflow);
+        }
+
+        let mut codes = self.buffer.iter_mut();
+        for &(r, g, b) in pixels {
+            let bits = (g as u32) << 16 | (r as u32) << 8 | b as u32;
+            for bit in (0..24).rev() {
+                *codes.next().unwrap() = if bits & (1 << bit) != 0 {
+                    self.one
+                } else {
+                    self.zero
+                };
+            }
+        }
+        *codes.next().unwrap() = self.reset;
+
+        Ok(len)
+    }
+
+    /// Releases the RMT channel
+    pub fn free(mut self) -> C {
+        self.channel.take().unwrap()
+    }
+}
+
+impl<'d, C> Ws2812<'d, C>
+where
+    C: TxChannel,
+{
+    /// Creates a new driver for the LEDs connected to `channel`
+    ///
+    /// `tick_frequency` is the frequency the channel is clocked with, i.e. the
+    /// frequency of the [Rmt](crate::rmt::Rmt) divided by the channel's
+    /// `clk_divider`. It has to be high enough to produce the pulses, a
+    /// frequency of at least 10 MHz is recommended, and at most about 100 MHz
+    /// for the reset time to fit into a pulse code.
+    ///
+    /// The output is configured to idle low, so the LEDs latch the colors
+    /// after every transmission.
+    pub fn new(
+        channel: C,
+        tick_frequency: HertzU32,
+        buffer: &'d mut [PulseCode],
+    ) -> Result<Self, Error> {
+        C::set_idle_output(true, false);
+        Self::new_internal(channel, tick_frequency, buffer)
+    }
+
+    /// Sets the colors of the LEDs, given as `(r, g, b)` triples
+    ///
+    /// Returns [Error::Overflow] if the buffer can't hold the pulse codes for
+    /// all pixels.
+    pub fn write_pixels(&mut self, pixels: &[(u8, u8, u8)]) -> Result<(), Error> {
+        let len = self.encode(pixels)?;
+
+        let channel = self.channel.take().unwrap();
+        let (result, channel) = match channel.transmit(&self.buffer[..len]).wait() {
+            Ok(channel) => (Ok(()), channel),
+            Err((error, channel)) => (Err(error), channel),
+        };
+        self.channel = Some(channel);
+
+        result
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'d, C> Ws2812<'d, C>
+where
+    C: TxChannelAsync,
+{
+    /// Creates a new driver for the LEDs connected to the async `channel`
+    ///
+    /// See [Ws2812::new] for the meaning of `tick_frequency`.
+    pub fn new_async(
+        channel: C,
+        tick_frequency: HertzU32,
+        buffer: &'d mut [PulseCode],
+    ) -> Result<Self, Error> {
+        C::set_idle_output(true, false);
+        Self::new_internal(channel, tick_frequency, buffer)
+    }
+
+    /// Sets the colors of the LEDs, given as `(r, g, b)` triples
+    ///
+    /// The async transmission is limited to the RMT RAM of the channel, so
+    /// only a few pixels can be written at once. Returns
+    /// [Error::InvalidArgument] if the pulse codes don't fit into the RAM and
+    /// [Error::Overflow] if they don't fit into the buffer.
+    pub async fn write_pixels_async(&mut self, pixels: &[(u8, u8, u8)]) -> Result<(), Error> {
+        let len = self.encode(pixels)?;
+
+        // `transmit` only borrows the channel, it's never taken out in async
+        // mode
+        let channel = self.channel.as_mut().unwrap();
+        channel.transmit(&self.buffer[..len]).await
+    }
+}
@@ -37,6 +37,11 @@
 //! by calling the `read` method. The driver also implements the traits from the
 //! [`rand_core`] crate.
 //!
+//! Only [Trng] implements [`rand_core::CryptoRng`], as it makes sure an entropy
+//! source is enabled. [Rng] can't tell whether one of the conditions above is
+//! met, so crates which require a `CryptoRng`, e.g. for key generation, need a
+//! [Trng] unless Wi-Fi or Bluetooth is running.
+//!
 //! [`rand_core`]: https://crates.io/crates/rand_core
 //!
 //! ## Examples
@@ -52,6 +57,10 @@ use crate::{
 };
 
 /// Random number generator driver
+///
+/// Implements [`rand_core::RngCore`], but not [`rand_core::CryptoRng`]: the
+/// numbers are only truly random while an entropy source is enabled, see the
+/// [module documentation](self). Use [Trng] where a `CryptoRng` is required.
 #[derive(Clone, Copy)]
 pub struct Rng {
     _phantom: PhantomData<RNG>,
@@ -273,10 +273,14 @@ impl<'d> Rtc<'d> {
     }
 
     /// Enter light sleep and wake with the provided `wake_sources`.
+    ///
+    /// Returns the source which woke the chip up.
     #[cfg(any(esp32, esp32s3, esp32c3, esp32c6))]
-    pub fn sleep_light(&mut self, wake_sources: &[&dyn WakeSource]) {
+    pub fn sleep_light(&mut self, wake_sources: &[&dyn WakeSource]) -> SleepSource {
         let config = RtcSleepConfig::default();
         self.sleep(&config, wake_sources);
+
+        wakeup_cause()
     }
 
     /// Enter sleep with the provided `config` and wake with the provided
@@ -954,11 +958,19 @@ pub fn get_reset_reason(cpu: Cpu) -> Option<SocResetReason> {
 }
 
 /// Return wakeup reason.
+///
+/// This is the reason for waking up from deep sleep, the reason for waking up
+/// from light sleep is returned by [Rtc::sleep_light].
 pub fn get_wakeup_cause() -> SleepSource {
     if get_reset_reason(Cpu::ProCpu) != Some(SocResetReason::CoreDeepSleep) {
         return SleepSource::Undefined;
     }
 
+    wakeup_cause()
+}
+
+/// Returns the source which ended the last sleep.
+fn wakeup_cause() -> SleepSource {
     #[cfg(any(esp32c6, esp32h2))]
     let wakeup_cause = WakeupReason::from_bits_retain(unsafe {
         (*crate::peripherals::PMU::PTR)
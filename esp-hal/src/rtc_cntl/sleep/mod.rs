@@ -22,7 +22,11 @@ use core::time::Duration;
 use crate::gpio::RtcPin as RtcIoWakeupPinType;
 #[cfg(any(esp32c3, esp32c6))]
 use crate::gpio::RtcPinWithResistors as RtcIoWakeupPinType;
-use crate::rtc_cntl::Rtc;
+use crate::{
+    rtc_cntl::{get_reset_reason, Rtc, SocResetReason},
+    Cpu,
+    Persistable,
+};
 
 #[cfg_attr(esp32, path = "esp32.rs")]
 #[cfg_attr(esp32s3, path = "esp32s3.rs")]
@@ -304,3 +308,111 @@ bitfield::bitfield! {
 pub trait WakeSource {
     fn apply(&self, rtc: &Rtc, triggers: &mut WakeTriggers, sleep_config: &mut RtcSleepConfig);
 }
+
+/// Size of the data [DeepSleep::save_to_rtc] can keep in RTC fast memory
+pub const RTC_DATA_SIZE: usize = 256;
+
+/// Marks [RtcData] as written by [DeepSleep::save_to_rtc]
+const RTC_DATA_MAGIC: u32 = 0x5254_4344;
+
+#[repr(C, align(4))]
+struct RtcData {
+    magic: u32,
+    len: u32,
+    data: [u8; RTC_DATA_SIZE],
+}
+
+// Not initialized on boot, the content is only valid after waking up from deep
+// sleep.
+#[link_section = ".rtc_fast.persistent"]
+static mut RTC_DATA: RtcData = RtcData {
+    magic: 0,
+    len: 0,
+    data: [0; RTC_DATA_SIZE],
+};
+
+/// Deep sleep with a set of wakeup sources
+///
+/// The chip is reset when it wakes up from deep sleep. Data which should
+/// survive the sleep can be kept in RTC fast memory with
+/// [DeepSleep::save_to_rtc] and read back after the reset with
+/// [DeepSleep::load_from_rtc].
+pub struct DeepSleep<'a> {
+    wake_sources: &'a [&'a dyn WakeSource],
+    keep_rtc_data: core::cell::Cell<bool>,
+}
+
+impl<'a> DeepSleep<'a> {
+    /// Create a deep sleep which wakes up with the provided `wake_sources`,
+    /// like [Rtc::sleep_light].
+    pub fn new(wake_sources: &'a [&'a dyn WakeSource]) -> Self {
+        Self {
+            wake_sources,
+            keep_rtc_data: core::cell::Cell::new(false),
+        }
+    }
+
+    /// Writes `data` to RTC fast memory, which is kept powered during this
+    /// deep sleep.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is larger than [RTC_DATA_SIZE] bytes.
+    pub fn save_to_rtc<T: Copy + Sized>(&self, data: &T) {
+        let len = core::mem::size_of::<T>();
+        assert!(len <= RTC_DATA_SIZE, "data doesn't fit into RTC memory");
+
+        unsafe {
+            let rtc_data = core::ptr::addr_of_mut!(RTC_DATA);
+            core::ptr::write_volatile(core::ptr::addr_of_mut!((*rtc_data).magic), 0);
+            core::ptr::write_unaligned(
+                core::ptr::addr_of_mut!((*rtc_data).data).cast::<T>(),
+                *data,
+            );
+            core::ptr::write_volatile(core::ptr::addr_of_mut!((*rtc_data).len), len as u32);
+            core::ptr::write_volatile(core::ptr::addr_of_mut!((*rtc_data).magic), RTC_DATA_MAGIC);
+        }
+
+        self.keep_rtc_data.set(true);
+    }
+
+    /// Reads the data written by [DeepSleep::save_to_rtc] before the last deep
+    /// sleep.
+    ///
+    /// Returns `None` if the chip wasn't woken up from deep sleep, e.g. after
+    /// a power-on reset, or if no `T` was saved.
+    pub fn load_from_rtc<T: Copy + Persistable>() -> Option<T> {
+        if get_reset_reason(Cpu::ProCpu) != Some(SocResetReason::CoreDeepSleep) {
+            return None;
+        }
+
+        unsafe {
+            let rtc_data = core::ptr::addr_of!(RTC_DATA);
+            if core::ptr::read_volatile(core::ptr::addr_of!((*rtc_data).magic)) != RTC_DATA_MAGIC
+                || core::ptr::read_volatile(core::ptr::addr_of!((*rtc_data).len)) as usize
+                    != core::mem::size_of::<T>()
+            {
+                return None;
+            }
+
+            Some(core::ptr::read_unaligned(
+                core::ptr::addr_of!((*rtc_data).data).cast::<T>(),
+            ))
+        }
+    }
+
+    /// Enter deep sleep. The chip is reset when it wakes up.
+    pub fn start(self, rtc: &mut Rtc) -> ! {
+        #[allow(unused_mut)]
+        let mut config = RtcSleepConfig::deep();
+
+        // RTC fast memory is kept powered in deep sleep on chips with a PMU
+        #[cfg(not(pmu))]
+        if self.keep_rtc_data.get() {
+            config.set_rtc_fastmem_pd_en(false);
+        }
+
+        rtc.sleep(&config, self.wake_sources);
+        unreachable!();
+    }
+}
@@ -54,8 +54,25 @@
 //!
 //! # }
 //! ```
+//! On chips with GDMA the data can be fed to the SHA peripheral by DMA, see
+//! [dma::ShaDma]. DMA and CPU updates can be mixed while calculating one hash.
+//!
+//! With the `digest` feature [Sha] implements `digest::Update`, so it can be
+//! fed by code which is generic over the `digest` traits. [Sha] selects the
+//! algorithm at runtime with [ShaMode], so the output size isn't known at
+//! compile time. [ShaDigest] selects the algorithm with a type instead, e.g.
+//! `ShaDigest<Sha256>`, and also implements `digest::FixedOutput`:
+//! ```rust, ignore
+//! use digest::{FixedOutput, Update};
+//! use esp_hal::sha::{Sha256, ShaDigest};
+//!
+//! let mut hasher = ShaDigest::<Sha256>::new(peripherals.SHA);
+//! hasher.update(b"HELLO, ESPRESSIF!");
+//! let output = hasher.finalize_fixed();
+//! ```
+//! 
 //! ## Implementation State
-//! - DMA-SHA Mode is not supported.
+//! - DMA-SHA Mode is only supported on chips with GDMA.
 
 use core::{convert::Infallible, marker::PhantomData};
 
@@ -77,7 +94,7 @@ use crate::{
 // – SHA-512/t (not implemented yet)
 // Two working modes
 // – Typical SHA
-// – DMA-SHA (not implemented yet)
+// – DMA-SHA (GDMA chips only)
 
 /// The SHA Accelerator driver instance
 pub struct Sha<'d, DM: crate::Mode> {
@@ -435,4 +452,375 @@ impl<'d, DM: crate::Mode> Sha<'d, DM> {
 
         Ok(())
     }
+
+    /// Discards the data of the current hash, the next update starts a new one
+    #[cfg(feature = "digest")]
+    fn reset(&mut self) {
+        self.cursor = 0;
+        self.first_run = true;
+        self.finished = false;
+        self.alignment_helper.reset();
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'d, DM: crate::Mode> digest::Update for Sha<'d, DM> {
+    fn update(&mut self, data: &[u8]) {
+        let mut remaining = data;
+        while !remaining.is_empty() {
+            remaining = nb::block!(Sha::update(self, remaining)).unwrap();
+        }
+    }
+}
+
+/// A hash algorithm of the SHA peripheral, selecting the algorithm of a
+/// [ShaDigest] at compile time
+#[cfg(feature = "digest")]
+pub trait ShaAlgorithm: crate::private::Sealed {
+    /// The mode the SHA peripheral is configured with
+    const MODE: ShaMode;
+
+    /// The length of the digest in bytes
+    type DigestOutputSize: digest::generic_array::ArrayLength<u8> + 'static;
+}
+
+macro_rules! sha_algorithm {
+    ($(#[$attr:meta])* $name:ident, $mode:ident, $output_size:ident) => {
+        $(#[$attr])*
+        #[cfg(feature = "digest")]
+        #[non_exhaustive]
+        pub struct $name;
+
+        $(#[$attr])*
+        #[cfg(feature = "digest")]
+        impl crate::private::Sealed for $name {}
+
+        $(#[$attr])*
+        #[cfg(feature = "digest")]
+        impl ShaAlgorithm for $name {
+            const MODE: ShaMode = ShaMode::$mode;
+
+            type DigestOutputSize = digest::consts::$output_size;
+        }
+    };
+}
+
+sha_algorithm!(
+    /// SHA-1
+    Sha1, SHA1, U20
+);
+sha_algorithm!(
+    /// SHA-224
+    #[cfg(not(esp32))]
+    Sha224, SHA224, U28
+);
+sha_algorithm!(
+    /// SHA-256
+    Sha256, SHA256, U32
+);
+sha_algorithm!(
+    /// SHA-384
+    #[cfg(any(esp32, esp32s2, esp32s3))]
+    Sha384, SHA384, U48
+);
+sha_algorithm!(
+    /// SHA-512
+    #[cfg(any(esp32, esp32s2, esp32s3))]
+    Sha512, SHA512, U64
+);
+sha_algorithm!(
+    /// SHA-512/224
+    #[cfg(any(esp32s2, esp32s3))]
+    #[allow(non_camel_case_types)]
+    Sha512_224, SHA512_224, U28
+);
+sha_algorithm!(
+    /// SHA-512/256
+    #[cfg(any(esp32s2, esp32s3))]
+    #[allow(non_camel_case_types)]
+    Sha512_256, SHA512_256, U32
+);
+
+/// The SHA accelerator computing the hash algorithm `A`, implementing the
+/// `digest` traits including `digest::FixedOutput`
+#[cfg(feature = "digest")]
+pub struct ShaDigest<'d, A> {
+    sha: Sha<'d, crate::Blocking>,
+    _algorithm: PhantomData<A>,
+}
+
+#[cfg(feature = "digest")]
+impl<'d, A: ShaAlgorithm> ShaDigest<'d, A> {
+    /// Create a new instance computing the hash algorithm `A`
+    pub fn new(sha: impl Peripheral<P = SHA> + 'd) -> Self {
+        Self {
+            sha: Sha::new(sha, A::MODE),
+            _algorithm: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'d, A: ShaAlgorithm> digest::HashMarker for ShaDigest<'d, A> {}
+
+#[cfg(feature = "digest")]
+impl<'d, A: ShaAlgorithm> digest::OutputSizeUser for ShaDigest<'d, A> {
+    type OutputSize = A::DigestOutputSize;
+}
+
+#[cfg(feature = "digest")]
+impl<'d, A: ShaAlgorithm> digest::Update for ShaDigest<'d, A> {
+    fn update(&mut self, data: &[u8]) {
+        digest::Update::update(&mut self.sha, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'d, A: ShaAlgorithm> digest::FixedOutput for ShaDigest<'d, A> {
+    fn finalize_into(mut self, out: &mut digest::Output<Self>) {
+        nb::block!(self.sha.finish(out)).unwrap();
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'d, A: ShaAlgorithm> digest::Reset for ShaDigest<'d, A> {
+    fn reset(&mut self) {
+        self.sha.reset();
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'d, A: ShaAlgorithm> digest::FixedOutputReset for ShaDigest<'d, A> {
+    fn finalize_into_reset(&mut self, out: &mut digest::Output<Self>) {
+        // Finishing a hash resets the state for the next one
+        nb::block!(self.sha.finish(out)).unwrap();
+    }
+}
+
+/// Provides DMA (Direct Memory Access) support for SHA operations.
+///
+/// The DMA feeds whole blocks of data to the SHA peripheral while the CPU is
+/// free to do other work, which speeds up hashing large amounts of data like
+/// firmware images. Partial blocks and the final padding are handled by the
+/// CPU, so DMA updates can be mixed with [Sha::update] calls on one hash.
+#[cfg(gdma)]
+pub mod dma {
+    use crate::dma::{
+        dma_private::{DmaSupport, DmaSupportTx},
+        Channel,
+        ChannelTx,
+        DescriptorChain,
+        DmaChannel,
+        DmaDescriptor,
+        DmaError,
+        DmaPeripheral,
+        DmaTransferTx,
+        ShaPeripheral,
+        TxPrivate,
+    };
+
+    /// A DMA capable SHA instance.
+    pub struct ShaDma<'d, C, DM>
+    where
+        C: DmaChannel,
+        C::P: ShaPeripheral,
+        DM: crate::Mode,
+    {
+        pub sha: super::Sha<'d, crate::Blocking>,
+
+        pub(crate) channel: Channel<'d, C, DM>,
+        tx_chain: DescriptorChain,
+    }
+
+    pub trait WithDmaSha<'d, C, DM>
+    where
+        C: DmaChannel,
+        C::P: ShaPeripheral,
+        DM: crate::Mode,
+    {
+        fn with_dma(
+            self,
+            channel: Channel<'d, C, DM>,
+            tx_descriptors: &'static mut [DmaDescriptor],
+        ) -> ShaDma<'d, C, DM>;
+    }
+
+    impl<'d, C, DM> WithDmaSha<'d, C, DM> for super::Sha<'d, crate::Blocking>
+    where
+        C: DmaChannel,
+        C::P: ShaPeripheral,
+        DM: crate::Mode,
+    {
+        fn with_dma(
+            self,
+            mut channel: Channel<'d, C, DM>,
+            tx_descriptors: &'static mut [DmaDescriptor],
+        ) -> ShaDma<'d, C, DM> {
+            channel.tx.init_channel();
+
+            ShaDma {
+                sha: self,
+                channel,
+                tx_chain: DescriptorChain::new(tx_descriptors),
+            }
+        }
+    }
+
+    impl<'d, C, DM> core::fmt::Debug for ShaDma<'d, C, DM>
+    where
+        C: DmaChannel,
+        C::P: ShaPeripheral,
+        DM: crate::Mode,
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("ShaDma").finish()
+        }
+    }
+
+    impl<'d, C, DM> DmaSupport for ShaDma<'d, C, DM>
+    where
+        C: DmaChannel,
+        C::P: ShaPeripheral,
+        DM: crate::Mode,
+    {
+        fn peripheral_wait_dma(&mut self, _is_tx: bool, _is_rx: bool) {
+            // The SHA peripheral stays busy until it processed all blocks
+            while self.sha.is_busy() {}
+        }
+
+        fn peripheral_dma_stop(&mut self) {
+            // The blocks which weren't sent are missing from the hash, start
+            // a new one with the next update
+            self.sha.first_run = true;
+            self.sha.cursor = 0;
+            self.sha.alignment_helper.reset();
+        }
+    }
+
+    impl<'d, C, DM> DmaSupportTx for ShaDma<'d, C, DM>
+    where
+        C: DmaChannel,
+        C::P: ShaPeripheral,
+        DM: crate::Mode,
+    {
+        type TX = ChannelTx<'d, C>;
+
+        fn tx(&mut self) -> &mut Self::TX {
+            &mut self.channel.tx
+        }
+
+        fn chain(&mut self) -> &mut DescriptorChain {
+            &mut self.tx_chain
+        }
+    }
+
+    impl<'d, C, DM> ShaDma<'d, C, DM>
+    where
+        C: DmaChannel,
+        C::P: ShaPeripheral,
+        DM: crate::Mode,
+    {
+        /// Add `data` to the hash, sending its whole blocks by DMA.
+        ///
+        /// Returns a [DmaTransferTx] to wait for, and the end of `data` which
+        /// doesn't fill a whole block. It has to be passed to [Sha::update]
+        /// (or the next DMA update) after the transfer is done.
+        ///
+        /// `data` has to be in RAM the DMA can access and fit into the
+        /// descriptors.
+        ///
+        /// [Sha::update]: super::Sha::update
+        pub fn update_dma<'t, 'b>(
+            &'t mut self,
+            data: &'b [u8],
+        ) -> Result<(DmaTransferTx<'t, Self>, &'b [u8]), DmaError>
+        where
+            'b: 't,
+        {
+            let remaining = self.start_update_dma(data)?;
+
+            Ok((DmaTransferTx::new(self), remaining))
+        }
+
+        fn start_update_dma<'t>(&mut self, data: &'t [u8]) -> Result<&'t [u8], DmaError> {
+            let chunk_len = self.sha.chunk_length();
+
+            // Complete the block started by previous updates with the CPU
+            let head_len = usize::min(
+                data.len(),
+                (chunk_len - self.sha.cursor % chunk_len) % chunk_len,
+            );
+            let (mut head, data) = data.split_at(head_len);
+            while !head.is_empty() {
+                head = nb::block!(self.sha.update(head)).unwrap();
+            }
+            while self.sha.is_busy() {}
+
+            let blocks = data.len() / chunk_len;
+            let (blocks_data, remaining) = data.split_at(blocks * chunk_len);
+            if blocks == 0 {
+                return Ok(remaining);
+            }
+
+            self.tx_chain
+                .fill_for_tx(false, blocks_data.as_ptr(), blocks_data.len())?;
+            unsafe {
+                self.channel
+                    .tx
+                    .prepare_transfer_without_start(DmaPeripheral::Sha, &self.tx_chain)?;
+            }
+            self.channel.tx.start_transfer()?;
+
+            self.sha
+                .sha
+                .dma_block_num()
+                .write(|w| unsafe { w.bits(blocks as u32) });
+            if self.sha.first_run {
+                self.sha.sha.dma_start().write(|w| unsafe { w.bits(1) });
+                self.sha.first_run = false;
+            } else {
+                self.sha.sha.dma_continue().write(|w| unsafe { w.bits(1) });
+            }
+
+            self.sha.cursor = self.sha.cursor.wrapping_add(blocks_data.len());
+            self.sha.finished = false;
+
+            Ok(remaining)
+        }
+    }
+
+    impl<'d, C> ShaDma<'d, C, crate::Async>
+    where
+        C: DmaChannel,
+        C::P: ShaPeripheral,
+    {
+        /// Add `data` to the hash, sending its whole blocks by DMA, see
+        /// [ShaDma::update_dma].
+        ///
+        /// Returns the end of `data` which doesn't fill a whole block.
+        #[cfg(feature = "async")]
+        pub async fn update_dma_async<'t>(&mut self, data: &'t [u8]) -> Result<&'t [u8], DmaError> {
+            let remaining = self.start_update_dma(data)?;
+
+            if self.sha.is_busy() {
+                let future = crate::dma::asynch::DmaTxFuture::new(&mut self.channel.tx)
+                    .with_chain(self.tx_chain.span());
+                let sha = &mut self.sha;
+                let guard = crate::dma::asynch::OnDrop::new(|| {
+                    // See `peripheral_dma_stop`
+                    sha.first_run = true;
+                    sha.cursor = 0;
+                    sha.alignment_helper.reset();
+                });
+                let res = future.await;
+                guard.defuse();
+                res?;
+
+                // The last block is still being processed after the DMA is done
+                while self.sha.is_busy() {}
+            }
+
+            Ok(remaining)
+        }
+    }
 }
@@ -118,6 +118,51 @@ impl Efuse {
     pub fn get_flash_encryption() -> bool {
         (Self::read_field_le::<u8>(FLASH_CRYPT_CNT).count_ones() % 2) != 0
     }
+
+    /// Get the raw ADC1 readings at 150 mV and 850 mV measured during
+    /// manufacturing, if the two point calibration values have been burned.
+    ///
+    /// see <https://github.com/espressif/esp-idf/blob/903af13e8/components/esp_adc/esp32/adc_cali_line_fitting.c#L71>
+    pub fn get_adc1_two_point_cal() -> Option<(u16, u16)> {
+        if !Self::read_field_le::<bool>(BLK3_PART_RESERVE) {
+            return None;
+        }
+
+        let low = Self::read_field_le::<u16>(ADC1_TP_LOW);
+        let high = Self::read_field_le::<u16>(ADC1_TP_HIGH);
+
+        let low = 278 + decode_twos_complement(low, 7) * 4;
+        let high = 3265 + decode_twos_complement(high, 9) * 4;
+
+        Some((low as u16, high as u16))
+    }
+
+    /// Get the ADC reference voltage in millivolts, if it has been burned.
+    ///
+    /// see <https://github.com/espressif/esp-idf/blob/903af13e8/components/esp_adc/esp32/adc_cali_line_fitting.c#L94>
+    pub fn get_adc_vref() -> Option<u16> {
+        let bits = Self::read_field_le::<u8>(ADC_VREF);
+
+        if bits == 0 {
+            return None;
+        }
+
+        // The value is stored in sign-magnitude representation
+        let magnitude = (bits & 0x0f) as i32;
+        let offset = if bits & 0x10 != 0 {
+            -magnitude
+        } else {
+            magnitude
+        };
+
+        Some((1100 + offset * 7) as u16)
+    }
+}
+
+/// Decodes a `width` bit wide two's complement value.
+fn decode_twos_complement(bits: u16, width: u32) -> i32 {
+    let shift = 16 - width;
+    ((bits << shift) as i16 >> shift) as i32
 }
 
 #[allow(unused)]
@@ -33,6 +33,11 @@ pub(crate) mod constants {
     pub const SOC_DRAM_LOW: u32 = 0x3FCA_0000;
     pub const SOC_DRAM_HIGH: u32 = 0x3FCE_0000;
 
+    pub const SOC_IROM_LOW: u32 = 0x4200_0000;
+    pub const SOC_IROM_HIGH: u32 = 0x4240_0000;
+    pub const SOC_DROM_LOW: u32 = 0x3C00_0000;
+    pub const SOC_DROM_HIGH: u32 = 0x3C40_0000;
+
     pub const RC_FAST_CLK: fugit::HertzU32 = fugit::HertzU32::kHz(17500);
 }
 
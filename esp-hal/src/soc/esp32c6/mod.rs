@@ -51,6 +51,11 @@ pub(crate) mod constants {
     pub const SOC_DRAM_LOW: u32 = 0x4080_0000;
     pub const SOC_DRAM_HIGH: u32 = 0x4088_0000;
 
+    pub const SOC_IROM_LOW: u32 = 0x4200_0000;
+    pub const SOC_IROM_HIGH: u32 = 0x4280_0000;
+    pub const SOC_DROM_LOW: u32 = 0x4280_0000;
+    pub const SOC_DROM_HIGH: u32 = 0x4300_0000;
+
     pub const RC_FAST_CLK: fugit::HertzU32 = fugit::HertzU32::kHz(17500);
 }
 
@@ -47,6 +47,11 @@ pub(crate) mod constants {
     pub const SOC_DRAM_LOW: u32 = 0x3FFB_0000;
     pub const SOC_DRAM_HIGH: u32 = 0x4000_0000;
 
+    pub const SOC_IROM_LOW: u32 = 0x4008_0000;
+    pub const SOC_IROM_HIGH: u32 = 0x4080_0000;
+    pub const SOC_DROM_LOW: u32 = 0x3F00_0000;
+    pub const SOC_DROM_HIGH: u32 = 0x3F40_0000;
+
     pub const REF_TICK: fugit::HertzU32 = fugit::HertzU32::MHz(1);
 }
 
@@ -88,6 +88,29 @@ impl Efuse {
         }
     }
 
+    /// Get the temperature sensor calibration value in degrees Celsius
+    ///
+    /// see <https://github.com/espressif/esp-idf/blob/903af13e8/components/efuse/esp32s3/esp_efuse_rtc_calib.c#L93>
+    pub fn get_rtc_calib_tsens_delta() -> Option<f32> {
+        let version = Self::get_rtc_calib_version();
+
+        if version != 1 {
+            return None;
+        }
+
+        let cal_temp = Self::read_field_le::<u16>(TEMP_CALIB);
+
+        // BIT(8) stands for the sign, the value is stored in 0.1°C steps
+        let magnitude = (cal_temp & 0xff) as f32;
+        let delta = if cal_temp & 0x100 != 0 {
+            -magnitude
+        } else {
+            magnitude
+        };
+
+        Some(delta / 10.0)
+    }
+
     /// Get ADC initial code for specified attenuation from efuse
     ///
     /// see <https://github.com/espressif/esp-idf/blob/903af13e8/components/efuse/esp32s3/esp_efuse_rtc_calib.c#L28>
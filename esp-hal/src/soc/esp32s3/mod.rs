@@ -50,9 +50,34 @@ pub(crate) mod constants {
     pub const SOC_DRAM_LOW: u32 = 0x3FC8_8000;
     pub const SOC_DRAM_HIGH: u32 = 0x3FD0_0000;
 
+    pub const SOC_IROM_LOW: u32 = 0x4200_0000;
+    pub const SOC_IROM_HIGH: u32 = 0x4400_0000;
+    pub const SOC_DROM_LOW: u32 = 0x3C00_0000;
+    pub const SOC_DROM_HIGH: u32 = 0x3E00_0000;
+
     pub const RC_FAST_CLK: fugit::HertzU32 = fugit::HertzU32::kHz(17500);
 }
 
+/// Writes the dirty data cache lines of the given address range back to
+/// external memory.
+pub(crate) unsafe fn cache_writeback_addr(addr: u32, size: u32) {
+    extern "C" {
+        fn Cache_WriteBack_Addr(addr: u32, size: u32);
+    }
+
+    Cache_WriteBack_Addr(addr, size);
+}
+
+/// Drops the data cache lines of the given address range, the next access
+/// reads from external memory.
+pub(crate) unsafe fn cache_invalidate_addr(addr: u32, size: u32) {
+    extern "C" {
+        fn Cache_Invalidate_Addr(addr: u32, size: u32);
+    }
+
+    Cache_Invalidate_Addr(addr, size);
+}
+
 #[doc(hidden)]
 #[link_section = ".rwtext"]
 pub unsafe fn configure_cpu_caches() {
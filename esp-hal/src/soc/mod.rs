@@ -67,7 +67,26 @@ impl self::efuse::Efuse {
     }
 }
 
-#[allow(unused)]
 pub(crate) fn is_valid_ram_address(address: u32) -> bool {
-    (self::constants::SOC_DRAM_LOW..=self::constants::SOC_DRAM_HIGH).contains(&address)
+    (self::constants::SOC_DRAM_LOW..self::constants::SOC_DRAM_HIGH).contains(&address)
+}
+
+/// Returns whether `address` is in the part of the external RAM which is
+/// mapped by the PSRAM driver.
+pub(crate) fn is_valid_psram_address(address: u32) -> bool {
+    #[cfg(psram)]
+    {
+        let start = crate::psram::psram_vaddr_start();
+        (start..start + crate::psram::PSRAM_BYTES).contains(&(address as usize))
+    }
+    #[cfg(not(psram))]
+    {
+        let _ = address;
+        false
+    }
+}
+
+pub(crate) fn is_valid_flash_address(address: u32) -> bool {
+    (self::constants::SOC_IROM_LOW..self::constants::SOC_IROM_HIGH).contains(&address)
+        || (self::constants::SOC_DROM_LOW..self::constants::SOC_DROM_HIGH).contains(&address)
 }
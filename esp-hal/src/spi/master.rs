@@ -79,7 +79,7 @@ use super::{
 };
 use crate::{
     clock::Clocks,
-    dma::{DescriptorChain, DmaPeripheral, Rx, Tx},
+    dma::{DescriptorChain, DmaError, DmaPeripheral, Rx, Tx},
     gpio::{InputPin, InputSignal, OutputPin, OutputSignal},
     interrupt::InterruptHandler,
     peripheral::{Peripheral, PeripheralRef},
@@ -857,8 +857,11 @@ pub mod dma {
             DmaTransferRxOwned,
             DmaTransferTx,
             DmaTransferTxOwned,
+            DmaTransferTxQueue,
             DmaTransferTxRx,
             DmaTransferTxRxOwned,
+            DmaTxQueue,
+            RxPrivate,
             Spi2Peripheral,
             SpiPeripheral,
             TxPrivate,
@@ -918,6 +921,9 @@ pub mod dma {
                 channel,
                 tx_chain: DescriptorChain::new(tx_descriptors),
                 rx_chain: DescriptorChain::new(rx_descriptors),
+                pending: None,
+                segment_error: None,
+                queued: 0,
                 _mode: PhantomData,
             }
         }
@@ -944,6 +950,9 @@ pub mod dma {
                 channel,
                 tx_chain: DescriptorChain::new(tx_descriptors),
                 rx_chain: DescriptorChain::new(rx_descriptors),
+                pending: None,
+                segment_error: None,
+                queued: 0,
                 _mode: PhantomData,
             }
         }
@@ -961,9 +970,75 @@ pub mod dma {
         pub(crate) channel: Channel<'d, C, DmaMode>,
         tx_chain: DescriptorChain,
         rx_chain: DescriptorChain,
+        pending: Option<PendingSegments>,
+        segment_error: Option<DmaError>,
+        /// Bytes of a [DmaTxQueue] which no transaction was started for yet
+        queued: usize,
         _mode: PhantomData<M>,
     }
 
+    /// The part of a DMA read, write or transfer which didn't fit into the
+    /// transactions started so far
+    #[derive(Clone, Copy)]
+    struct PendingSegments {
+        write_addr: usize,
+        write_len: usize,
+        read_addr: usize,
+        read_len: usize,
+        /// Whether data is received, if there's nothing left to send the
+        /// transactions only receive
+        transfer: bool,
+    }
+
+    impl PendingSegments {
+        /// Whether the transactions send data.
+        fn sends(&self) -> bool {
+            !self.transfer || self.write_len > 0
+        }
+
+        /// Whether the transactions receive data.
+        fn receives(&self) -> bool {
+            self.transfer
+        }
+
+        /// Takes the next transaction of up to [MAX_DMA_SIZE] bytes from
+        /// `pending`, and whether it's the last one.
+        fn take_next(pending: &mut Option<Self>) -> Option<(Self, bool)> {
+            let this = pending.take()?;
+
+            let segment = Self {
+                write_len: usize::min(this.write_len, MAX_DMA_SIZE),
+                read_len: usize::min(this.read_len, MAX_DMA_SIZE),
+                ..this
+            };
+            let rest = Self {
+                write_addr: this.write_addr + segment.write_len,
+                write_len: this.write_len - segment.write_len,
+                read_addr: this.read_addr + segment.read_len,
+                read_len: this.read_len - segment.read_len,
+                transfer: this.transfer,
+            };
+
+            let last = rest.write_len == 0 && rest.read_len == 0;
+            if !last {
+                *pending = Some(rest);
+            }
+
+            Some((segment, last))
+        }
+
+        /// Converts the error of a transaction which was started to continue
+        /// a transfer into the error returned when waiting for the transfer.
+        fn transfer_error(error: super::Error) -> DmaError {
+            match error {
+                super::Error::DmaError(error) => error,
+                // Starting a transaction of a validated transfer only fails
+                // when the DMA rejects it
+                _ => DmaError::DescriptorError,
+            }
+        }
+    }
+
     impl<'d, T, C, M, DmaMode> core::fmt::Debug for SpiDma<'d, T, C, M, DmaMode>
     where
         C: DmaChannel,
@@ -976,6 +1051,52 @@ pub mod dma {
         }
     }
 
+    #[cfg(feature = "async")]
+    impl<'d, T, C, M> SpiDma<'d, T, C, M, crate::Blocking>
+    where
+        C: DmaChannel,
+        C::P: SpiPeripheral,
+        M: DuplexMode,
+    {
+        /// Converts the driver for use with async APIs, see
+        /// [Channel::into_async]
+        pub fn into_async(self) -> SpiDma<'d, T, C, M, crate::Async> {
+            SpiDma {
+                spi: self.spi,
+                channel: self.channel.into_async(),
+                tx_chain: self.tx_chain,
+                rx_chain: self.rx_chain,
+                pending: self.pending,
+                segment_error: self.segment_error,
+                queued: self.queued,
+                _mode: PhantomData,
+            }
+        }
+    }
+
+    #[cfg(feature = "async")]
+    impl<'d, T, C, M> SpiDma<'d, T, C, M, crate::Async>
+    where
+        C: DmaChannel,
+        C::P: SpiPeripheral,
+        M: DuplexMode,
+    {
+        /// Converts the driver for use with blocking APIs, see
+        /// [Channel::into_blocking]
+        pub fn into_blocking(self) -> SpiDma<'d, T, C, M, crate::Blocking> {
+            SpiDma {
+                spi: self.spi,
+                channel: self.channel.into_blocking(),
+                tx_chain: self.tx_chain,
+                rx_chain: self.rx_chain,
+                pending: self.pending,
+                segment_error: self.segment_error,
+                queued: self.queued,
+                _mode: PhantomData,
+            }
+        }
+    }
+
     impl<'d, T, C, M, DmaMode> SpiDma<'d, T, C, M, DmaMode>
     where
         T: InstanceDma<ChannelTx<'d, C>, ChannelRx<'d, C>>,
@@ -1050,6 +1171,138 @@ pub mod dma {
         pub fn change_bus_frequency(&mut self, frequency: HertzU32, clocks: &Clocks) {
             self.spi.ch_bus_freq(frequency, clocks);
         }
+
+        /// Starts the next transaction of a DMA write or transfer, each one
+        /// sends or receives up to [MAX_DMA_SIZE] bytes. CS is kept asserted
+        /// until the last one is done.
+        ///
+        /// Returns `false` if there is nothing left to transfer.
+        fn start_next_segment(&mut self) -> Result<bool, super::Error> {
+            let Some((segment, last)) = PendingSegments::take_next(&mut self.pending) else {
+                return Ok(false);
+            };
+
+            // The register is synced when the transaction is started
+            self.spi.set_cs_keep_active(!last);
+
+            let result = unsafe {
+                if !segment.sends() {
+                    self.spi.start_read_bytes_dma(
+                        &mut self.rx_chain,
+                        segment.read_addr as *mut u8,
+                        segment.read_len,
+                        &mut self.channel.rx,
+                    )
+                } else if segment.receives() {
+                    self.spi.start_transfer_dma(
+                        &mut self.tx_chain,
+                        &mut self.rx_chain,
+                        segment.write_addr as *const u8,
+                        segment.write_len,
+                        segment.read_addr as *mut u8,
+                        segment.read_len,
+                        &mut self.channel.tx,
+                        &mut self.channel.rx,
+                    )
+                } else {
+                    self.spi.start_write_bytes_dma(
+                        &mut self.tx_chain,
+                        segment.write_addr as *const u8,
+                        segment.write_len,
+                        &mut self.channel.tx,
+                    )
+                }
+            };
+
+            if result.is_err() {
+                self.pending = None;
+                self.spi.set_cs_keep_active(false);
+                self.spi.update();
+            }
+
+            result.map(|_| true)
+        }
+
+        /// Starts the next transaction once the current one is done.
+        ///
+        /// Returns `false` if the transfer is done, an error starting the next
+        /// transaction is returned when waiting for the transfer.
+        fn continue_segments(&mut self) -> bool {
+            match self.start_next_segment() {
+                Ok(started) => started,
+                Err(error) => {
+                    self.segment_error = Some(PendingSegments::transfer_error(error));
+                    false
+                }
+            }
+        }
+
+        /// Starts a transaction for the queued bytes once the current one is
+        /// done, see [InstanceDma::start_queued_write].
+        ///
+        /// Returns `false` if a transaction was started for all queued bytes.
+        fn continue_queue(&mut self) -> bool {
+            if self.queued == 0 {
+                return false;
+            }
+
+            if !self.spi.busy() {
+                let len = usize::min(self.queued, MAX_DMA_SIZE);
+                self.queued -= len;
+                self.spi.start_queued_write(len);
+            }
+
+            true
+        }
+    }
+
+    impl<'d, T, C, M> SpiDma<'d, T, C, M, crate::Blocking>
+    where
+        T: InstanceDma<ChannelTx<'d, C>, ChannelRx<'d, C>>,
+        C: DmaChannel,
+        C::P: SpiPeripheral,
+        M: DuplexMode,
+    {
+        /// Runs `f` with the given descriptors instead of the ones passed to
+        /// `with_dma`, e.g. to use descriptors on the stack for a few blocking
+        /// transfers. Buffers which don't live for `'static` can be
+        /// transferred with the `SpiBus` methods.
+        ///
+        /// The descriptors need to be in internal RAM, otherwise transfers
+        /// fail with [DmaError::UnsupportedMemoryRegion].
+        ///
+        /// A transfer which was started in `f` and leaked with
+        /// [core::mem::forget] would still use the descriptors after they are
+        /// released, so this waits for the SPI to finish before returning.
+        pub fn with_descriptors<R>(
+            &mut self,
+            tx_descriptors: &mut [DmaDescriptor],
+            rx_descriptors: &mut [DmaDescriptor],
+            f: impl FnOnce(&mut Self) -> R,
+        ) -> R {
+            // SAFETY: The descriptors are only used until they are swapped back
+            // below, after the last transfer using them finished.
+            let (tx_descriptors, rx_descriptors) = unsafe {
+                (
+                    core::mem::transmute::<&mut [DmaDescriptor], &'static mut [DmaDescriptor]>(
+                        tx_descriptors,
+                    ),
+                    core::mem::transmute::<&mut [DmaDescriptor], &'static mut [DmaDescriptor]>(
+                        rx_descriptors,
+                    ),
+                )
+            };
+            let tx_descriptors = self.tx_chain.replace_descriptors(tx_descriptors);
+            let rx_descriptors = self.rx_chain.replace_descriptors(rx_descriptors);
+
+            let result = f(self);
+
+            self.peripheral_wait_dma(true, true);
+            self.tx_chain.replace_descriptors(tx_descriptors);
+            self.rx_chain.replace_descriptors(rx_descriptors);
+
+            result
+        }
     }
 
     impl<'d, T, C, M, DmaMode> DmaSupport for SpiDma<'d, T, C, M, DmaMode>
@@ -1061,11 +1314,47 @@ pub mod dma {
         DmaMode: Mode,
     {
         fn peripheral_wait_dma(&mut self, _is_tx: bool, _is_rx: bool) {
-            self.spi.flush().ok();
+            loop {
+                self.spi.flush().ok();
+                if !self.continue_segments() && !self.continue_queue() {
+                    break;
+                }
+            }
         }
 
         fn peripheral_dma_stop(&mut self) {
-            unreachable!("unsupported")
+            self.spi.abort_dma();
+            self.pending = None;
+            self.segment_error = None;
+            self.queued = 0;
+            self.spi.set_cs_keep_active(false);
+            self.spi.update();
+        }
+
+        fn peripheral_continue_dma(&mut self) -> bool {
+            if self.queued > 0 {
+                return self.continue_queue();
+            }
+
+            if self.pending.is_none() {
+                return false;
+            }
+
+            // The DMA might be done while the SPI still shifts out the FIFO
+            if self.spi.busy() {
+                return true;
+            }
+
+            self.continue_segments()
+        }
+
+        fn peripheral_error(&mut self) -> Option<DmaError> {
+            self.segment_error.take()
+        }
+
+        fn peripheral_queued(&mut self, len: usize) {
+            self.queued += len;
+            self.continue_queue();
         }
     }
 
@@ -1117,8 +1406,9 @@ pub mod dma {
     {
         /// Perform a DMA write.
         ///
-        /// This will return a [DmaTransferTx]. The maximum amount of data to be
-        /// sent is 32736 bytes.
+        /// This will return a [DmaTransferTx]. Buffers larger than 32736 bytes
+        /// are sent in multiple SPI transactions, CS stays asserted in between.
+        /// The transfer is done after the last one.
         #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
         pub fn dma_write<'t, TXBUF>(
             &'t mut self,
@@ -1134,8 +1424,8 @@ pub mod dma {
         /// Perform a DMA write.
         ///
         /// This will return a [DmaTransferTxOwned] owning the buffer and the
-        /// SPI instance. The maximum amount of data to be sent is 32736
-        /// bytes.
+        /// SPI instance. Buffers larger than 32736 bytes are sent in multiple
+        /// SPI transactions, see [SpiDma::dma_write].
         #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
         pub fn dma_write_owned<TXBUF>(
             mut self,
@@ -1148,28 +1438,103 @@ pub mod dma {
             Ok(DmaTransferTxOwned::new(self, words))
         }
 
+        /// Send the buffers of `queue` back to back. Returns
+        /// [DmaTransferTxQueue] which represents the in-progress DMA transfer,
+        /// more buffers can be queued with it while the transfer is running.
+        ///
+        /// The DMA moves on to the next buffer on its own. A SPI transaction
+        /// sends all bytes queued when it starts, up to 32736 bytes, bytes
+        /// queued while it's running are sent in the next one. CS stays
+        /// asserted until the transfer is stopped, use
+        /// [DmaTransferTxQueue::wait] to stop it after the last buffer.
         #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
-        fn dma_write_start<'t, TXBUF>(&'t mut self, words: &'t TXBUF) -> Result<(), super::Error>
+        pub fn dma_write_queue<'t, const N: usize>(
+            &'t mut self,
+            queue: &'t mut DmaTxQueue<N>,
+        ) -> Result<DmaTransferTxQueue<'t, Self, N>, super::Error> {
+            self.spi.start_queue_dma()?;
+
+            let peripheral = self.spi.dma_peripheral();
+            Ok(DmaTransferTxQueue::start(self, queue, peripheral)?)
+        }
+
+        /// Perform a DMA write of multiple buffers, sent back to back.
+        ///
+        /// This avoids copying e.g. a header and a payload into one contiguous
+        /// buffer. This will return a [DmaTransferTx]. The maximum amount of
+        /// data to be sent is 32736 bytes in total.
+        #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
+        pub fn dma_write_scattered<'t, TXBUF, const N: usize>(
+            &'t mut self,
+            parts: &'t [TXBUF; N],
+        ) -> Result<DmaTransferTx<'t, Self>, super::Error>
         where
             TXBUF: ReadBuffer<Word = u8>,
         {
-            let (ptr, len) = unsafe { words.read_buffer() };
+            self.dma_write_scattered_start(parts)?;
+            Ok(DmaTransferTx::new(self))
+        }
 
-            if len > MAX_DMA_SIZE {
+        /// Perform a DMA write of multiple buffers, sent back to back.
+        ///
+        /// This will return a [DmaTransferTxOwned] owning the buffers and the
+        /// SPI instance. The maximum amount of data to be sent is 32736
+        /// bytes in total.
+        #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
+        pub fn dma_write_scattered_owned<TXBUF, const N: usize>(
+            mut self,
+            parts: [TXBUF; N],
+        ) -> Result<DmaTransferTxOwned<Self, [TXBUF; N]>, super::Error>
+        where
+            TXBUF: ReadBuffer<Word = u8>,
+        {
+            self.dma_write_scattered_start(&parts)?;
+            Ok(DmaTransferTxOwned::new(self, parts))
+        }
+
+        #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
+        fn dma_write_scattered_start<'t, TXBUF, const N: usize>(
+            &'t mut self,
+            parts: &'t [TXBUF; N],
+        ) -> Result<(), super::Error>
+        where
+            TXBUF: ReadBuffer<Word = u8>,
+        {
+            let parts: [&[u8]; N] = core::array::from_fn(|i| unsafe {
+                let (ptr, len) = parts[i].read_buffer();
+                core::slice::from_raw_parts(ptr, len)
+            });
+
+            if parts.iter().map(|part| part.len()).sum::<usize>() > MAX_DMA_SIZE {
                 return Err(super::Error::MaxDmaTransferSizeExceeded);
             }
 
             unsafe {
-                self.spi.start_write_bytes_dma(
-                    &mut self.tx_chain,
-                    ptr,
-                    len,
-                    &mut self.channel.tx,
-                )?;
+                self.spi
+                    .start_write_parts_dma(&mut self.tx_chain, &parts, &mut self.channel.tx)?;
             }
             Ok(())
         }
 
+        #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
+        fn dma_write_start<'t, TXBUF>(&'t mut self, words: &'t TXBUF) -> Result<(), super::Error>
+        where
+            TXBUF: ReadBuffer<Word = u8>,
+        {
+            let (ptr, len) = unsafe { words.read_buffer() };
+
+            self.pending = Some(PendingSegments {
+                write_addr: ptr as usize,
+                write_len: len,
+                read_addr: 0,
+                read_len: 0,
+                transfer: false,
+            });
+            self.start_next_segment()?;
+
+            Ok(())
+        }
+
         /// Perform a DMA read.
         ///
         /// This will return a [DmaTransferRx]. The maximum amount of data to be
@@ -1228,8 +1593,9 @@ pub mod dma {
 
         /// Perform a DMA transfer.
         ///
-        /// This will return a [DmaTransferTxRx].
-        /// The maximum amount of data to be sent/received is 32736 bytes.
+        /// This will return a [DmaTransferTxRx]. Buffers larger than 32736
+        /// bytes are sent/received in multiple SPI transactions, CS stays
+        /// asserted in between. The transfer is done after the last one.
         pub fn dma_transfer<'t, TXBUF, RXBUF>(
             &'t mut self,
             words: &'t TXBUF,
@@ -1246,8 +1612,8 @@ pub mod dma {
         /// Perform a DMA transfer
         ///
         /// This will return a [DmaTransferTxRxOwned] owning the buffers and
-        /// the SPI instance. The maximum amount of data to be
-        /// sent/received is 32736 bytes.
+        /// the SPI instance. Buffers larger than 32736 bytes are sent/received
+        /// in multiple SPI transactions, see [SpiDma::dma_transfer].
         pub fn dma_transfer_owned<TXBUF, RXBUF>(
             mut self,
             words: TXBUF,
@@ -1261,28 +1627,36 @@ pub mod dma {
             Ok(DmaTransferTxRxOwned::new(self, words, read_buffer))
         }
 
-        fn dma_transfer_start<'t, TXBUF, RXBUF>(
+        /// Perform a DMA transfer, sending multiple buffers back to back.
+        ///
+        /// This will return a [DmaTransferTxRx].
+        /// The maximum amount of data to be sent/received is 32736 bytes.
+        pub fn dma_transfer_scattered<'t, TXBUF, RXBUF, const N: usize>(
             &'t mut self,
-            words: &'t TXBUF,
+            parts: &'t [TXBUF; N],
             read_buffer: &'t mut RXBUF,
-        ) -> Result<(), super::Error>
+        ) -> Result<DmaTransferTxRx<'t, Self>, super::Error>
         where
             TXBUF: ReadBuffer<Word = u8>,
             RXBUF: WriteBuffer<Word = u8>,
         {
-            let (write_ptr, write_len) = unsafe { words.read_buffer() };
+            let parts: [&[u8]; N] = core::array::from_fn(|i| unsafe {
+                let (ptr, len) = parts[i].read_buffer();
+                core::slice::from_raw_parts(ptr, len)
+            });
             let (read_ptr, read_len) = unsafe { read_buffer.write_buffer() };
 
-            if write_len > MAX_DMA_SIZE || read_len > MAX_DMA_SIZE {
+            if parts.iter().map(|part| part.len()).sum::<usize>() > MAX_DMA_SIZE
+                || read_len > MAX_DMA_SIZE
+            {
                 return Err(super::Error::MaxDmaTransferSizeExceeded);
             }
 
             unsafe {
-                self.spi.start_transfer_dma(
+                self.spi.start_transfer_parts_dma(
                     &mut self.tx_chain,
                     &mut self.rx_chain,
-                    write_ptr,
-                    write_len,
+                    &parts,
                     read_ptr,
                     read_len,
                     &mut self.channel.tx,
@@ -1290,10 +1664,393 @@ pub mod dma {
                 )?;
             }
 
+            Ok(DmaTransferTxRx::new(self))
+        }
+
+        fn dma_transfer_start<'t, TXBUF, RXBUF>(
+            &'t mut self,
+            words: &'t TXBUF,
+            read_buffer: &'t mut RXBUF,
+        ) -> Result<(), super::Error>
+        where
+            TXBUF: ReadBuffer<Word = u8>,
+            RXBUF: WriteBuffer<Word = u8>,
+        {
+            let (write_ptr, write_len) = unsafe { words.read_buffer() };
+            let (read_ptr, read_len) = unsafe { read_buffer.write_buffer() };
+
+            self.pending = Some(PendingSegments {
+                write_addr: write_ptr as usize,
+                write_len,
+                read_addr: read_ptr as usize,
+                read_len,
+                transfer: true,
+            });
+            self.start_next_segment()?;
+
             Ok(())
         }
     }
 
+    macro_rules! spi_dma_half_constructors {
+        ($peripheral:ident, $dma_peripheral:ident) => {
+            impl<'d, M> Spi<'d, crate::peripherals::$peripheral, M>
+            where
+                M: IsFullDuplex,
+            {
+                /// Configures the SPI to only send via DMA, using the TX half
+                /// of a DMA channel split with [Channel::split].
+                ///
+                /// No RX descriptors are needed, the returned [SpiDmaTx] only
+                /// provides [SpiDmaTx::dma_write].
+                pub fn with_dma_tx<C>(
+                    self,
+                    mut channel: ChannelTx<'d, C>,
+                    tx_descriptors: &'static mut [DmaDescriptor],
+                ) -> SpiDmaTx<'d, crate::peripherals::$peripheral, C, M>
+                where
+                    C: DmaChannel,
+                    C::P: SpiPeripheral + $dma_peripheral,
+                {
+                    channel.init_channel();
+
+                    SpiDmaTx {
+                        spi: self.spi,
+                        channel,
+                        chain: DescriptorChain::new(tx_descriptors),
+                        pending: None,
+                        segment_error: None,
+                        queued: 0,
+                        _mode: PhantomData,
+                    }
+                }
+
+                /// Configures the SPI to only receive via DMA, using the RX
+                /// half of a DMA channel split with [Channel::split].
+                ///
+                /// No TX descriptors are needed, the returned [SpiDmaRx] only
+                /// provides [SpiDmaRx::dma_read].
+                pub fn with_dma_rx<C>(
+                    self,
+                    mut channel: ChannelRx<'d, C>,
+                    rx_descriptors: &'static mut [DmaDescriptor],
+                ) -> SpiDmaRx<'d, crate::peripherals::$peripheral, C, M>
+                where
+                    C: DmaChannel,
+                    C::P: SpiPeripheral + $dma_peripheral,
+                {
+                    channel.init_channel();
+
+                    SpiDmaRx {
+                        spi: self.spi,
+                        channel,
+                        chain: DescriptorChain::new(rx_descriptors),
+                        _mode: PhantomData,
+                    }
+                }
+            }
+        };
+    }
+
+    spi_dma_half_constructors!(SPI2, Spi2Peripheral);
+    #[cfg(spi3)]
+    spi_dma_half_constructors!(SPI3, Spi3Peripheral);
+
+    /// A SPI instance which only sends via DMA
+    pub struct SpiDmaTx<'d, T, C, M>
+    where
+        C: DmaChannel,
+        C::P: SpiPeripheral,
+        M: DuplexMode,
+    {
+        spi: PeripheralRef<'d, T>,
+        channel: ChannelTx<'d, C>,
+        chain: DescriptorChain,
+        pending: Option<PendingSegments>,
+        segment_error: Option<DmaError>,
+        /// Bytes of a [DmaTxQueue] which no transaction was started for yet
+        queued: usize,
+        _mode: PhantomData<M>,
+    }
+
+    impl<'d, T, C, M> core::fmt::Debug for SpiDmaTx<'d, T, C, M>
+    where
+        C: DmaChannel,
+        C::P: SpiPeripheral,
+        M: DuplexMode,
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("SpiDmaTx").finish()
+        }
+    }
+
+    impl<'d, T, C, M> SpiDmaTx<'d, T, C, M>
+    where
+        T: InstanceDma<ChannelTx<'d, C>, ChannelRx<'d, C>>,
+        C: DmaChannel,
+        C::P: SpiPeripheral,
+        M: IsFullDuplex,
+    {
+        /// Perform a DMA write.
+        ///
+        /// This will return a [DmaTransferTx]. Buffers larger than 32736 bytes
+        /// are sent in multiple SPI transactions like [SpiDma::dma_write].
+        #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
+        pub fn dma_write<'t, TXBUF>(
+            &'t mut self,
+            words: &'t TXBUF,
+        ) -> Result<DmaTransferTx<'t, Self>, super::Error>
+        where
+            TXBUF: ReadBuffer<Word = u8>,
+        {
+            let (ptr, len) = unsafe { words.read_buffer() };
+
+            self.pending = Some(PendingSegments {
+                write_addr: ptr as usize,
+                write_len: len,
+                read_addr: 0,
+                read_len: 0,
+                transfer: false,
+            });
+            self.start_next_segment()?;
+
+            Ok(DmaTransferTx::new(self))
+        }
+
+        /// Send the buffers of `queue` back to back, like
+        /// [SpiDma::dma_write_queue].
+        #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
+        pub fn dma_write_queue<'t, const N: usize>(
+            &'t mut self,
+            queue: &'t mut DmaTxQueue<N>,
+        ) -> Result<DmaTransferTxQueue<'t, Self, N>, super::Error> {
+            self.spi.start_queue_dma()?;
+
+            let peripheral = self.spi.dma_peripheral();
+            Ok(DmaTransferTxQueue::start(self, queue, peripheral)?)
+        }
+
+        fn start_next_segment(&mut self) -> Result<bool, super::Error> {
+            let Some((segment, last)) = PendingSegments::take_next(&mut self.pending) else {
+                return Ok(false);
+            };
+
+            // The register is synced when the transaction is started
+            self.spi.set_cs_keep_active(!last);
+
+            let result = unsafe {
+                self.spi.start_write_bytes_dma(
+                    &mut self.chain,
+                    segment.write_addr as *const u8,
+                    segment.write_len,
+                    &mut self.channel,
+                )
+            };
+
+            if result.is_err() {
+                self.pending = None;
+                self.spi.set_cs_keep_active(false);
+                self.spi.update();
+            }
+
+            result.map(|_| true)
+        }
+
+        /// Starts the next transaction once the current one is done.
+        ///
+        /// Returns `false` if the transfer is done, an error starting the next
+        /// transaction is returned when waiting for the transfer.
+        fn continue_segments(&mut self) -> bool {
+            match self.start_next_segment() {
+                Ok(started) => started,
+                Err(error) => {
+                    self.segment_error = Some(PendingSegments::transfer_error(error));
+                    false
+                }
+            }
+        }
+
+        /// Starts a transaction for the queued bytes once the current one is
+        /// done, see [InstanceDma::start_queued_write].
+        ///
+        /// Returns `false` if a transaction was started for all queued bytes.
+        fn continue_queue(&mut self) -> bool {
+            if self.queued == 0 {
+                return false;
+            }
+
+            if !self.spi.busy() {
+                let len = usize::min(self.queued, MAX_DMA_SIZE);
+                self.queued -= len;
+                self.spi.start_queued_write(len);
+            }
+
+            true
+        }
+    }
+
+    impl<'d, T, C, M> DmaSupport for SpiDmaTx<'d, T, C, M>
+    where
+        T: InstanceDma<ChannelTx<'d, C>, ChannelRx<'d, C>>,
+        C: DmaChannel,
+        C::P: SpiPeripheral,
+        M: IsFullDuplex,
+    {
+        fn peripheral_wait_dma(&mut self, _is_tx: bool, _is_rx: bool) {
+            loop {
+                self.spi.flush().ok();
+                if !self.continue_segments() && !self.continue_queue() {
+                    break;
+                }
+            }
+        }
+
+        fn peripheral_dma_stop(&mut self) {
+            self.spi.abort_dma();
+            self.pending = None;
+            self.segment_error = None;
+            self.queued = 0;
+            self.spi.set_cs_keep_active(false);
+            self.spi.update();
+        }
+
+        fn peripheral_continue_dma(&mut self) -> bool {
+            if self.queued > 0 {
+                return self.continue_queue();
+            }
+
+            if self.pending.is_none() {
+                return false;
+            }
+
+            // The DMA might be done while the SPI still shifts out the FIFO
+            if self.spi.busy() {
+                return true;
+            }
+
+            self.continue_segments()
+        }
+
+        fn peripheral_error(&mut self) -> Option<DmaError> {
+            self.segment_error.take()
+        }
+
+        fn peripheral_queued(&mut self, len: usize) {
+            self.queued += len;
+            self.continue_queue();
+        }
+    }
+
+    impl<'d, T, C, M> DmaSupportTx for SpiDmaTx<'d, T, C, M>
+    where
+        T: InstanceDma<ChannelTx<'d, C>, ChannelRx<'d, C>>,
+        C: DmaChannel,
+        C::P: SpiPeripheral,
+        M: IsFullDuplex,
+    {
+        type TX = ChannelTx<'d, C>;
+
+        fn tx(&mut self) -> &mut Self::TX {
+            &mut self.channel
+        }
+
+        fn chain(&mut self) -> &mut DescriptorChain {
+            &mut self.chain
+        }
+    }
+
+    /// A SPI instance which only receives via DMA
+    pub struct SpiDmaRx<'d, T, C, M>
+    where
+        C: DmaChannel,
+        C::P: SpiPeripheral,
+        M: DuplexMode,
+    {
+        spi: PeripheralRef<'d, T>,
+        channel: ChannelRx<'d, C>,
+        chain: DescriptorChain,
+        _mode: PhantomData<M>,
+    }
+
+    impl<'d, T, C, M> core::fmt::Debug for SpiDmaRx<'d, T, C, M>
+    where
+        C: DmaChannel,
+        C::P: SpiPeripheral,
+        M: DuplexMode,
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("SpiDmaRx").finish()
+        }
+    }
+
+    impl<'d, T, C, M> SpiDmaRx<'d, T, C, M>
+    where
+        T: InstanceDma<ChannelTx<'d, C>, ChannelRx<'d, C>>,
+        C: DmaChannel,
+        C::P: SpiPeripheral,
+        M: IsFullDuplex,
+    {
+        /// Perform a DMA read.
+        ///
+        /// This will return a [DmaTransferRx]. The maximum amount of data to be
+        /// received is 32736 bytes.
+        #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
+        pub fn dma_read<'t, RXBUF>(
+            &'t mut self,
+            words: &'t mut RXBUF,
+        ) -> Result<DmaTransferRx<'t, Self>, super::Error>
+        where
+            RXBUF: WriteBuffer<Word = u8>,
+        {
+            let (ptr, len) = unsafe { words.write_buffer() };
+
+            if len > MAX_DMA_SIZE {
+                return Err(super::Error::MaxDmaTransferSizeExceeded);
+            }
+
+            unsafe {
+                self.spi
+                    .start_read_bytes_dma(&mut self.chain, ptr, len, &mut self.channel)?;
+            }
+
+            Ok(DmaTransferRx::new(self))
+        }
+    }
+
+    impl<'d, T, C, M> DmaSupport for SpiDmaRx<'d, T, C, M>
+    where
+        T: InstanceDma<ChannelTx<'d, C>, ChannelRx<'d, C>>,
+        C: DmaChannel,
+        C::P: SpiPeripheral,
+        M: IsFullDuplex,
+    {
+        fn peripheral_wait_dma(&mut self, _is_tx: bool, _is_rx: bool) {
+            self.spi.flush().ok();
+        }
+
+        fn peripheral_dma_stop(&mut self) {
+            self.spi.abort_dma();
+        }
+    }
+
+    impl<'d, T, C, M> DmaSupportRx for SpiDmaRx<'d, T, C, M>
+    where
+        T: InstanceDma<ChannelTx<'d, C>, ChannelRx<'d, C>>,
+        C: DmaChannel,
+        C::P: SpiPeripheral,
+        M: IsFullDuplex,
+    {
+        type RX = ChannelRx<'d, C>;
+
+        fn rx(&mut self) -> &mut Self::RX {
+            &mut self.channel
+        }
+
+        fn chain(&mut self) -> &mut DescriptorChain {
+            &mut self.chain
+        }
+    }
+
     impl<'d, T, C, M, DmaMode> SpiDma<'d, T, C, M, DmaMode>
     where
         T: InstanceDma<ChannelTx<'d, C>, ChannelRx<'d, C>>,
@@ -1522,7 +2279,7 @@ pub mod dma {
         type Error = T::Error;
 
         fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
-            if !crate::soc::is_valid_ram_address(&words[0] as *const _ as u32) {
+            if Self::needs_copy(words) {
                 for chunk in words.chunks(SIZE) {
                     self.buffer[..chunk.len()].copy_from_slice(chunk);
                     self.inner.write(&self.buffer[..chunk.len()])?;
@@ -1561,6 +2318,65 @@ pub mod dma {
     #[cfg(feature = "async")]
     mod asynch {
         use super::*;
+        use crate::dma::asynch::{DmaRxFuture, DmaTxFuture, OnDrop};
+
+        impl<'d, T, C, M> SpiDma<'d, T, C, M, crate::Async>
+        where
+            T: InstanceDma<ChannelTx<'d, C>, ChannelRx<'d, C>>,
+            C: DmaChannel,
+            C::P: SpiPeripheral,
+            M: IsFullDuplex,
+        {
+            /// Runs the pending transactions one after the other, like the
+            /// blocking transfers CS stays asserted until the last one is
+            /// done.
+            async fn transfer_segments(&mut self) -> Result<(), super::Error> {
+                loop {
+                    let Some(next) = self.pending else {
+                        return Ok(());
+                    };
+                    self.start_next_segment()?;
+
+                    // Stops the transaction and releases CS if the future is
+                    // dropped before the transfer is done
+                    let guard = OnDrop::new(|| {
+                        self.spi.abort_dma();
+                        self.pending = None;
+                        self.spi.set_cs_keep_active(false);
+                        self.spi.update();
+                    });
+                    let result = match (next.sends(), next.receives()) {
+                        (true, true) => {
+                            let tx_future = DmaTxFuture::new(&mut self.channel.tx)
+                                .with_chain(self.tx_chain.span());
+                            let rx_future = DmaRxFuture::new(&mut self.channel.rx)
+                                .with_chain(self.rx_chain.span());
+                            let (tx_result, rx_result) =
+                                embassy_futures::join::join(tx_future, rx_future).await;
+                            tx_result.and(rx_result)
+                        }
+                        (true, false) => {
+                            DmaTxFuture::new(&mut self.channel.tx)
+                                .with_chain(self.tx_chain.span())
+                                .await
+                        }
+                        (false, _) => {
+                            DmaRxFuture::new(&mut self.channel.rx)
+                                .with_chain(self.rx_chain.span())
+                                .await
+                        }
+                    };
+                    guard.defuse();
+
+                    if let Err(err) = result {
+                        self.peripheral_dma_stop();
+                        return Err(err.into());
+                    }
+
+                    self.spi.flush()?;
+                }
+            }
+        }
 
         impl<'d, T, C, M> embedded_hal_async::spi::SpiBus for SpiDma<'d, T, C, M, crate::Async>
         where
@@ -1570,104 +2386,63 @@ pub mod dma {
             M: IsFullDuplex,
         {
             async fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
-                let mut future = crate::dma::asynch::DmaRxFuture::new(&mut self.channel.rx);
-                unsafe {
-                    self.spi.start_read_bytes_dma(
-                        &mut self.rx_chain,
-                        words.as_mut_ptr(),
-                        words.len(),
-                        future.rx(),
-                    )?;
+                if words.is_empty() {
+                    return Ok(());
                 }
-                future.await?;
 
-                Ok(())
+                self.pending = Some(PendingSegments {
+                    write_addr: 0,
+                    write_len: 0,
+                    read_addr: words.as_mut_ptr() as usize,
+                    read_len: words.len(),
+                    transfer: true,
+                });
+                self.transfer_segments().await
             }
 
             async fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
-                for chunk in words.chunks(MAX_DMA_SIZE) {
-                    let mut future = crate::dma::asynch::DmaTxFuture::new(&mut self.channel.tx);
-                    unsafe {
-                        self.spi.start_write_bytes_dma(
-                            &mut self.tx_chain,
-                            chunk.as_ptr(),
-                            chunk.len(),
-                            future.tx(),
-                        )?;
-                    }
-                    future.await?;
-
-                    self.spi.flush()?;
+                if words.is_empty() {
+                    return Ok(());
                 }
 
-                Ok(())
+                self.pending = Some(PendingSegments {
+                    write_addr: words.as_ptr() as usize,
+                    write_len: words.len(),
+                    read_addr: 0,
+                    read_len: 0,
+                    transfer: false,
+                });
+                self.transfer_segments().await
             }
 
             async fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
-                let mut idx = 0;
-                loop {
-                    let write_idx = isize::min(idx, write.len() as isize);
-                    let write_len = usize::min(write.len() - idx as usize, MAX_DMA_SIZE);
-
-                    let read_idx = isize::min(idx, read.len() as isize);
-                    let read_len = usize::min(read.len() - idx as usize, MAX_DMA_SIZE);
-
-                    let mut tx_future = crate::dma::asynch::DmaTxFuture::new(&mut self.channel.tx);
-                    let mut rx_future = crate::dma::asynch::DmaRxFuture::new(&mut self.channel.rx);
-
-                    unsafe {
-                        self.spi.start_transfer_dma(
-                            &mut self.tx_chain,
-                            &mut self.rx_chain,
-                            write.as_ptr().offset(write_idx),
-                            write_len,
-                            read.as_mut_ptr().offset(read_idx),
-                            read_len,
-                            tx_future.tx(),
-                            rx_future.rx(),
-                        )?;
-                    }
-                    let (tx_res, rx_res) = embassy_futures::join::join(tx_future, rx_future).await;
-                    tx_res?;
-                    rx_res?;
-
-                    self.spi.flush()?;
-
-                    idx += MAX_DMA_SIZE as isize;
-                    if idx >= write.len() as isize && idx >= read.len() as isize {
-                        break;
-                    }
+                if read.is_empty() && write.is_empty() {
+                    return Ok(());
                 }
 
-                Ok(())
+                self.pending = Some(PendingSegments {
+                    write_addr: write.as_ptr() as usize,
+                    write_len: write.len(),
+                    read_addr: read.as_mut_ptr() as usize,
+                    read_len: read.len(),
+                    transfer: true,
+                });
+                self.transfer_segments().await
             }
 
             async fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
-                for chunk in words.chunks_mut(MAX_DMA_SIZE) {
-                    let mut tx_future = crate::dma::asynch::DmaTxFuture::new(&mut self.channel.tx);
-                    let mut rx_future = crate::dma::asynch::DmaRxFuture::new(&mut self.channel.rx);
-
-                    unsafe {
-                        self.spi.start_transfer_dma(
-                            &mut self.tx_chain,
-                            &mut self.rx_chain,
-                            chunk.as_ptr(),
-                            chunk.len(),
-                            chunk.as_mut_ptr(),
-                            chunk.len(),
-                            tx_future.tx(),
-                            rx_future.rx(),
-                        )?;
-                    }
-
-                    let (tx_res, rx_res) = embassy_futures::join::join(tx_future, rx_future).await;
-                    tx_res?;
-                    rx_res?;
-
-                    self.spi.flush()?;
+                if words.is_empty() {
+                    return Ok(());
                 }
 
-                Ok(())
+                self.pending = Some(PendingSegments {
+                    write_addr: words.as_ptr() as usize,
+                    write_len: words.len(),
+                    read_addr: words.as_mut_ptr() as usize,
+                    read_len: words.len(),
+                    transfer: true,
+                });
+                self.transfer_segments().await
             }
 
             async fn flush(&mut self) -> Result<(), Self::Error> {
@@ -1683,7 +2458,7 @@ pub mod dma {
             }
 
             async fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
-                if !crate::soc::is_valid_ram_address(&words[0] as *const _ as u32) {
+                if Self::needs_copy(words) {
                     for chunk in words.chunks(SIZE) {
                         self.buffer[..chunk.len()].copy_from_slice(chunk);
                         self.inner.write(&self.buffer[..chunk.len()]).await?;
@@ -1703,8 +2478,11 @@ pub mod dma {
             }
 
             async fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
-                if !crate::soc::is_valid_ram_address(&write[0] as *const _ as u32) {
-                    for (read, write) in read.chunks_mut(SIZE).zip(write.chunks(SIZE)) {
+                if Self::needs_copy(write) {
+                    let read_len = read.len();
+                    for offset in (0..read_len.max(write.len())).step_by(SIZE) {
+                        let read = &mut read[Self::chunk(offset, read_len)];
+                        let write = &write[Self::chunk(offset, write.len())];
                         self.buffer[..write.len()].copy_from_slice(write);
                         self.inner
                             .transfer(read, &self.buffer[..write.len()])
@@ -1804,7 +2582,7 @@ pub mod dma {
             }
 
             fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
-                if !crate::soc::is_valid_ram_address(&words[0] as *const _ as u32) {
+                if Self::needs_copy(words) {
                     for chunk in words.chunks(SIZE) {
                         self.buffer[..chunk.len()].copy_from_slice(chunk);
                         self.inner.write(&self.buffer[..chunk.len()])?;
@@ -1816,8 +2594,11 @@ pub mod dma {
             }
 
             fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
-                if !crate::soc::is_valid_ram_address(&write[0] as *const _ as u32) {
-                    for (read, write) in read.chunks_mut(SIZE).zip(write.chunks(SIZE)) {
+                if Self::needs_copy(write) {
+                    let read_len = read.len();
+                    for offset in (0..read_len.max(write.len())).step_by(SIZE) {
+                        let read = &mut read[Self::chunk(offset, read_len)];
+                        let write = &write[Self::chunk(offset, write.len())];
                         self.buffer[..write.len()].copy_from_slice(write);
                         self.inner.transfer(read, &self.buffer[..write.len()])?;
                     }
@@ -2026,6 +2807,56 @@ where
         tx: &mut TX,
         rx: &mut RX,
     ) -> Result<(), Error> {
+        self.start_transfer_with_dma(
+            tx_chain,
+            rx_chain,
+            write_buffer_len,
+            |chain| chain.fill_for_tx(false, write_buffer_ptr, write_buffer_len),
+            read_buffer_ptr,
+            read_buffer_len,
+            tx,
+            rx,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn start_transfer_parts_dma(
+        &mut self,
+        tx_chain: &mut DescriptorChain,
+        rx_chain: &mut DescriptorChain,
+        parts: &[&[u8]],
+        read_buffer_ptr: *mut u8,
+        read_buffer_len: usize,
+        tx: &mut TX,
+        rx: &mut RX,
+    ) -> Result<(), Error> {
+        self.start_transfer_with_dma(
+            tx_chain,
+            rx_chain,
+            parts.iter().map(|part| part.len()).sum(),
+            |chain| chain.fill_for_tx_scattered(false, parts),
+            read_buffer_ptr,
+            read_buffer_len,
+            tx,
+            rx,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn start_transfer_with_dma(
+        &mut self,
+        tx_chain: &mut DescriptorChain,
+        rx_chain: &mut DescriptorChain,
+        write_buffer_len: usize,
+        fill_tx: impl FnOnce(&mut DescriptorChain) -> Result<(), DmaError>,
+        read_buffer_ptr: *mut u8,
+        read_buffer_len: usize,
+        tx: &mut TX,
+        rx: &mut RX,
+    ) -> Result<(), Error> {
+        // An aborted transaction might still be running
+        self.flush()?;
+
         let reg_block = self.register_block();
         self.configure_datalen(usize::max(read_buffer_len, write_buffer_len) as u32 * 8);
 
@@ -2036,7 +2867,7 @@ where
         self.update();
 
         reset_dma_before_load_dma_dscr(reg_block);
-        tx_chain.fill_for_tx(false, write_buffer_ptr, write_buffer_len)?;
+        fill_tx(tx_chain)?;
         tx.prepare_transfer_without_start(self.dma_peripheral(), tx_chain)
             .and_then(|_| tx.start_transfer())?;
         rx_chain.fill_for_rx(false, read_buffer_ptr, read_buffer_len)?;
@@ -2077,6 +2908,33 @@ where
         len: usize,
         tx: &mut TX,
     ) -> Result<(), Error> {
+        self.start_write_dma(chain, len, tx, |chain| chain.fill_for_tx(false, ptr, len))
+    }
+
+    #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
+    unsafe fn start_write_parts_dma(
+        &mut self,
+        chain: &mut DescriptorChain,
+        parts: &[&[u8]],
+        tx: &mut TX,
+    ) -> Result<(), Error> {
+        let len = parts.iter().map(|part| part.len()).sum();
+        self.start_write_dma(chain, len, tx, |chain| {
+            chain.fill_for_tx_scattered(false, parts)
+        })
+    }
+
+    #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
+    unsafe fn start_write_dma(
+        &mut self,
+        chain: &mut DescriptorChain,
+        len: usize,
+        tx: &mut TX,
+        fill: impl FnOnce(&mut DescriptorChain) -> Result<(), DmaError>,
+    ) -> Result<(), Error> {
+        // An aborted transaction might still be running
+        self.flush()?;
+
         let reg_block = self.register_block();
         self.configure_datalen(len as u32 * 8);
 
@@ -2086,7 +2944,7 @@ where
         self.update();
 
         reset_dma_before_load_dma_dscr(reg_block);
-        chain.fill_for_tx(false, ptr, len)?;
+        fill(chain)?;
         unsafe {
             tx.prepare_transfer_without_start(self.dma_peripheral(), chain)
                 .and_then(|_| tx.start_transfer())?;
@@ -2100,6 +2958,43 @@ where
         Ok(())
     }
 
+    /// Prepares the SPI for sending the buffers of a
+    /// [DmaTxQueue](crate::dma::DmaTxQueue), the DMA has to be started
+    /// afterwards.
+    #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
+    fn start_queue_dma(&mut self) -> Result<(), Error> {
+        // An aborted transaction might still be running
+        self.flush()?;
+
+        let reg_block = self.register_block();
+        self.enable_dma();
+        self.update();
+
+        reset_dma_before_load_dma_dscr(reg_block);
+        reset_dma_before_usr_cmd(reg_block);
+
+        Ok(())
+    }
+
+    /// Starts a transaction sending the next `len` bytes of a
+    /// [DmaTxQueue](crate::dma::DmaTxQueue).
+    ///
+    /// The DMA keeps pushing the queued buffers into the FIFO, so unlike
+    /// [InstanceDma::start_write_bytes_dma] neither the DMA nor the FIFO are
+    /// touched. CS is kept asserted after the transaction, the next one
+    /// continues it.
+    #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
+    fn start_queued_write(&mut self, len: usize) {
+        let reg_block = self.register_block();
+
+        self.set_cs_keep_active(true);
+        self.configure_datalen(len as u32 * 8);
+        self.update();
+
+        self.clear_dma_interrupts();
+        reg_block.cmd().modify(|_, w| w.usr().set_bit());
+    }
+
     #[cfg_attr(feature = "place-spi-driver-in-ram", ram)]
     unsafe fn start_read_bytes_dma(
         &mut self,
@@ -2108,6 +3003,9 @@ where
         len: usize,
         rx: &mut RX,
     ) -> Result<(), Error> {
+        // An aborted transaction might still be running
+        self.flush()?;
+
         let reg_block = self.register_block();
         self.configure_datalen(len as u32 * 8);
 
@@ -2129,6 +3027,16 @@ where
         Ok(())
     }
 
+    /// Discards the data in the DMA FIFOs without waiting for the current
+    /// transaction.
+    ///
+    /// A master transaction can't be interrupted, it ends on its own once the
+    /// configured number of bits was clocked out. The next transaction waits
+    /// for that before it's configured.
+    fn abort_dma(&self) {
+        reset_dma_before_usr_cmd(self.register_block());
+    }
+
     fn dma_peripheral(&self) -> DmaPeripheral {
         match self.spi_num() {
             2 => DmaPeripheral::Spi2,
@@ -2986,6 +3894,9 @@ pub trait Instance: private::Sealed {
         dummy_state: bool,
         no_mosi_miso: bool,
     ) {
+        // An aborted transaction might still be running
+        self.flush().ok();
+
         let reg_block = self.register_block();
         reg_block.user().modify(|_, w| {
             w.usr_miso_highpart()
@@ -3200,6 +4111,18 @@ pub trait Instance: private::Sealed {
                 .write(|w| unsafe { w.usr_miso_dbitlen().bits(len) });
         }
     }
+
+    /// Keeps CS asserted after the current transaction, so the next one
+    /// continues it on the bus.
+    fn set_cs_keep_active(&self, keep: bool) {
+        let reg_block = self.register_block();
+
+        #[cfg(esp32)]
+        reg_block.pin().modify(|_, w| w.cs_keep_active().bit(keep));
+
+        #[cfg(not(esp32))]
+        reg_block.misc().modify(|_, w| w.cs_keep_active().bit(keep));
+    }
 }
 
 #[cfg(any(esp32c2, esp32c3, esp32c6, esp32h2))]
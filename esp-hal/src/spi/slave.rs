@@ -91,7 +91,6 @@ const MAX_DMA_SIZE: usize = 32768 - 32;
 /// SPI peripheral driver
 pub struct Spi<'d, T, M> {
     spi: PeripheralRef<'d, T>,
-    #[allow(dead_code)]
     data_mode: SpiMode,
     _mode: PhantomData<M>,
 }
@@ -218,6 +217,8 @@ pub mod dma {
                 channel,
                 tx_chain: DescriptorChain::new(tx_descriptors),
                 rx_chain: DescriptorChain::new(rx_descriptors),
+                data_mode: self.data_mode,
+                needs_reset: false,
             }
         }
     }
@@ -243,6 +244,8 @@ pub mod dma {
                 channel,
                 tx_chain: DescriptorChain::new(tx_descriptors),
                 rx_chain: DescriptorChain::new(rx_descriptors),
+                data_mode: self.data_mode,
+                needs_reset: false,
             }
         }
     }
@@ -258,6 +261,10 @@ pub mod dma {
         pub(crate) channel: Channel<'d, C, DmaMode>,
         tx_chain: DescriptorChain,
         rx_chain: DescriptorChain,
+        data_mode: SpiMode,
+        /// A dropped transfer was aborted while the peripheral might still have
+        /// been in the middle of a transaction
+        needs_reset: bool,
     }
 
     impl<'d, T, C, DmaMode> core::fmt::Debug for SpiDma<'d, T, C, DmaMode>
@@ -288,7 +295,11 @@ pub mod dma {
         }
 
         fn peripheral_dma_stop(&mut self) {
-            unreachable!("unsupported")
+            self.spi.abort_dma();
+        }
+
+        fn peripheral_mark_needs_reset(&mut self) {
+            self.needs_reset = true;
         }
     }
 
@@ -335,6 +346,17 @@ pub mod dma {
         C::P: SpiPeripheral,
         DmaMode: Mode,
     {
+        /// Resets and initializes the peripheral again if a transfer was
+        /// aborted while the master might have been in the middle of a
+        /// transaction.
+        fn reset_if_needed(&mut self) {
+            if core::mem::take(&mut self.needs_reset) {
+                self.spi.reset_peripheral();
+                self.spi.init();
+                self.spi.set_data_mode(self.data_mode);
+            }
+        }
+
         /// Register a buffer for a DMA write.
         ///
         /// This will return a [DmaTransferTx]. The maximum amount of data to be
@@ -354,6 +376,8 @@ pub mod dma {
                 return Err(Error::MaxDmaTransferSizeExceeded);
             }
 
+            self.reset_if_needed();
+
             unsafe {
                 self.spi
                     .start_write_bytes_dma(&mut self.tx_chain, ptr, len, &mut self.channel.tx)
@@ -380,6 +404,8 @@ pub mod dma {
                 return Err(Error::MaxDmaTransferSizeExceeded);
             }
 
+            self.reset_if_needed();
+
             unsafe {
                 self.spi
                     .start_read_bytes_dma(&mut self.rx_chain, ptr, len, &mut self.channel.rx)
@@ -410,6 +436,8 @@ pub mod dma {
                 return Err(Error::MaxDmaTransferSizeExceeded);
             }
 
+            self.reset_if_needed();
+
             unsafe {
                 self.spi
                     .start_transfer_dma(
@@ -542,6 +570,14 @@ where
         Ok(())
     }
 
+    // Abort the current transaction and discard the data in the DMA FIFOs
+    fn abort_dma(&self) {
+        let reg_block = self.register_block();
+
+        reg_block.cmd().modify(|_, w| w.usr().clear_bit());
+        reset_dma_before_usr_cmd(reg_block);
+    }
+
     fn dma_peripheral(&self) -> DmaPeripheral {
         match self.spi_num() {
             2 => DmaPeripheral::Spi2,
@@ -697,6 +733,9 @@ pub trait Instance: private::Sealed {
 
     fn enable_peripheral(&self);
 
+    /// Resets all registers of the peripheral
+    fn reset_peripheral(&self);
+
     fn spi_num(&self) -> u8;
 
     /// Initialize for full-duplex 1 bit mode
@@ -867,6 +906,11 @@ impl Instance for crate::peripherals::SPI2 {
         PeripheralClockControl::enable(crate::system::Peripheral::Spi2);
     }
 
+    #[inline(always)]
+    fn reset_peripheral(&self) {
+        PeripheralClockControl::reset(crate::system::Peripheral::Spi2);
+    }
+
     #[inline(always)]
     fn spi_num(&self) -> u8 {
         2
@@ -905,6 +949,11 @@ impl Instance for crate::peripherals::SPI2 {
         PeripheralClockControl::enable(crate::system::Peripheral::Spi2)
     }
 
+    #[inline(always)]
+    fn reset_peripheral(&self) {
+        PeripheralClockControl::reset(crate::system::Peripheral::Spi2);
+    }
+
     #[inline(always)]
     fn spi_num(&self) -> u8 {
         2
@@ -943,6 +992,11 @@ impl Instance for crate::peripherals::SPI3 {
         PeripheralClockControl::enable(crate::system::Peripheral::Spi3)
     }
 
+    #[inline(always)]
+    fn reset_peripheral(&self) {
+        PeripheralClockControl::reset(crate::system::Peripheral::Spi3);
+    }
+
     #[inline(always)]
     fn spi_num(&self) -> u8 {
         3
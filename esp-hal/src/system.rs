@@ -82,6 +82,8 @@ pub enum Peripheral {
     Wdt,
     #[cfg(sha)]
     Sha,
+    #[cfg(all(uhci0, gdma))]
+    Uhci0,
     #[cfg(usb_device)]
     UsbDevice,
     #[cfg(uart0)]
@@ -433,6 +435,11 @@ impl PeripheralClockControl {
                 #[cfg(not(esp32))]
                 perip_rst_en1.modify(|_, w| w.crypto_sha_rst().clear_bit());
             }
+            #[cfg(all(uhci0, gdma))]
+            Peripheral::Uhci0 => {
+                perip_clk_en0.modify(|_, w| w.uhci0_clk_en().set_bit());
+                perip_rst_en0.modify(|_, w| w.uhci0_rst().clear_bit());
+            }
             #[cfg(esp32c3)]
             Peripheral::UsbDevice => {
                 perip_clk_en0.modify(|_, w| w.usb_device_clk_en().set_bit());
@@ -640,6 +647,11 @@ impl PeripheralClockControl {
                 #[cfg(not(esp32))]
                 perip_rst_en1.modify(|_, w| w.crypto_sha_rst().clear_bit());
             }
+            #[cfg(all(uhci0, gdma))]
+            Peripheral::Uhci0 => {
+                perip_rst_en0.modify(|_, w| w.uhci0_rst().set_bit());
+                perip_rst_en0.modify(|_, w| w.uhci0_rst().clear_bit());
+            }
             #[cfg(esp32c3)]
             Peripheral::UsbDevice => {
                 perip_rst_en0.modify(|_, w| w.usb_device_rst().set_bit());
@@ -840,6 +852,11 @@ impl PeripheralClockControl {
                 system.sha_conf().modify(|_, w| w.sha_clk_en().set_bit());
                 system.sha_conf().modify(|_, w| w.sha_rst_en().clear_bit());
             }
+            #[cfg(all(uhci0, gdma))]
+            Peripheral::Uhci0 => {
+                system.uhci_conf().modify(|_, w| w.uhci_clk_en().set_bit());
+                system.uhci_conf().modify(|_, w| w.uhci_rst_en().clear_bit());
+            }
             #[cfg(usb_device)]
             Peripheral::UsbDevice => {
                 system
@@ -1038,6 +1055,11 @@ impl PeripheralClockControl {
                 system.sha_conf().modify(|_, w| w.sha_rst_en().set_bit());
                 system.sha_conf().modify(|_, w| w.sha_rst_en().clear_bit());
             }
+            #[cfg(all(uhci0, gdma))]
+            Peripheral::Uhci0 => {
+                system.uhci_conf().modify(|_, w| w.uhci_rst_en().set_bit());
+                system.uhci_conf().modify(|_, w| w.uhci_rst_en().clear_bit());
+            }
             #[cfg(usb_device)]
             Peripheral::UsbDevice => {
                 system
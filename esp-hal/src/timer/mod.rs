@@ -61,6 +61,8 @@ pub enum Error {
     AlarmInactive,
     /// The provided timeout is too large.
     InvalidTimeout,
+    /// The provided prescaler is out of range.
+    InvalidPrescaler,
 }
 
 /// Functionality provided by any timer peripheral.
@@ -605,12 +605,12 @@ mod asynch {
     const INIT: AtomicWaker = AtomicWaker::new();
     static WAKERS: [AtomicWaker; NUM_ALARMS] = [INIT; NUM_ALARMS];
 
-    pub(crate) struct AlarmFuture<'a, const N: u8> {
-        phantom: PhantomData<&'a Alarm<Periodic, crate::Async, N>>,
+    pub(crate) struct AlarmFuture<'a, T, const N: u8> {
+        alarm: &'a Alarm<T, crate::Async, N>,
     }
 
-    impl<'a, const N: u8> AlarmFuture<'a, N> {
-        pub(crate) fn new(alarm: &'a Alarm<Periodic, crate::Async, N>) -> Self {
+    impl<'a, T, const N: u8> AlarmFuture<'a, T, N> {
+        pub(crate) fn new(alarm: &'a Alarm<T, crate::Async, N>) -> Self {
             alarm.clear_interrupt();
 
             let (interrupt, handler) = match N {
@@ -626,9 +626,7 @@ mod asynch {
 
             alarm.enable_interrupt(true);
 
-            Self {
-                phantom: PhantomData,
-            }
+            Self { alarm }
         }
 
         fn event_bit_is_clear(&self) -> bool {
@@ -640,7 +638,7 @@ mod asynch {
         }
     }
 
-    impl<'a, const N: u8> core::future::Future for AlarmFuture<'a, N> {
+    impl<'a, T, const N: u8> core::future::Future for AlarmFuture<'a, T, N> {
         type Output = ();
 
         fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
@@ -654,6 +652,26 @@ mod asynch {
         }
     }
 
+    impl<'a, T, const N: u8> Drop for AlarmFuture<'a, T, N> {
+        fn drop(&mut self) {
+            // Disarm the alarm, otherwise a cancelled future could still cause a wakeup
+            self.alarm.enable_interrupt(false);
+            self.alarm.stop();
+            self.alarm.clear_interrupt();
+        }
+    }
+
+    impl<const CHANNEL: u8> Alarm<Target, crate::Async, CHANNEL> {
+        /// Wait asynchronously for *at least* the given duration.
+        pub async fn delay_async(&mut self, duration: MicrosDurationU64) {
+            self.enable_auto_reload(false);
+            unwrap!(self.load_value(duration));
+            self.start();
+
+            AlarmFuture::new(self).await;
+        }
+    }
+
     impl<const CHANNEL: u8> embedded_hal_async::delay::DelayNs
         for Alarm<Periodic, crate::Async, CHANNEL>
     {
@@ -60,11 +60,14 @@
 //! ```
 
 use core::{
+    cell::RefCell,
     marker::PhantomData,
     ops::{Deref, DerefMut},
 };
 
+use critical_section::{CriticalSection, Mutex};
 use fugit::{HertzU32, Instant, MicrosDurationU64};
+use procmacros::handler;
 
 use super::Error;
 #[cfg(timg1)]
@@ -259,6 +262,26 @@ where
             wdt: Wdt::new(),
         }
     }
+
+    /// Construct a new instance of [`TimerGroup`] in blocking mode, with the
+    /// timers' clock divided by `prescaler`.
+    ///
+    /// A larger prescaler lowers the resolution of the timers and extends the
+    /// time until their counters overflow. Returns
+    /// [`Error::InvalidPrescaler`] if `prescaler` is not in the range
+    /// `2..=65535`.
+    pub fn new_with_prescaler(
+        timer_group: impl Peripheral<P = T> + 'd,
+        clocks: &Clocks,
+        prescaler: u16,
+    ) -> Result<Self, Error> {
+        validate_prescaler(prescaler)?;
+
+        let this = Self::new(timer_group, clocks);
+        this.set_prescaler(prescaler);
+
+        Ok(this)
+    }
 }
 
 impl<'d, T> TimerGroup<'d, T, Async>
@@ -298,6 +321,43 @@ where
             wdt: Wdt::new(),
         }
     }
+
+    /// Construct a new instance of [`TimerGroup`] in asynchronous mode, with
+    /// the timers' clock divided by `prescaler`.
+    ///
+    /// See [`TimerGroup::new_with_prescaler`] for details.
+    pub fn new_async_with_prescaler(
+        timer_group: impl Peripheral<P = T> + 'd,
+        clocks: &Clocks,
+        prescaler: u16,
+    ) -> Result<Self, Error> {
+        validate_prescaler(prescaler)?;
+
+        let this = Self::new_async(timer_group, clocks);
+        this.set_prescaler(prescaler);
+
+        Ok(this)
+    }
+}
+
+impl<'d, T, DM> TimerGroup<'d, T, DM>
+where
+    T: TimerGroupInstance,
+    DM: Mode,
+{
+    fn set_prescaler(&self, prescaler: u16) {
+        self.timer0.set_prescaler(prescaler);
+        #[cfg(timg_timer1)]
+        self.timer1.set_prescaler(prescaler);
+    }
+}
+
+fn validate_prescaler(prescaler: u16) -> Result<(), Error> {
+    if prescaler < 2 {
+        return Err(Error::InvalidPrescaler);
+    }
+
+    Ok(())
 }
 
 /// General-purpose timer.
@@ -347,6 +407,19 @@ where
     pub fn wait(&mut self) {
         while !self.has_elapsed() {}
     }
+
+    /// The frequency at which the timer's counter is incremented.
+    pub fn tick_hz(&self) -> u32 {
+        self.apb_clk_freq.to_Hz() / self.timg.divider()
+    }
+
+    fn set_prescaler(&self, prescaler: u16) {
+        // The divider must only be changed while the counter is stopped.
+        let active = self.timg.is_counter_active();
+        self.timg.set_counter_active(false);
+        self.timg.set_divider(prescaler);
+        self.timg.set_counter_active(active);
+    }
 }
 
 impl<T, DM> Deref for Timer<T, DM>
@@ -522,6 +595,238 @@ where
     }
 }
 
+/// The number of alarms which can be created on a single timer, see
+/// [`Timer::create_alarm`].
+pub const MAX_ALARMS_PER_TIMER: usize = 4;
+
+const NUM_TIMERS: usize = 4;
+
+#[derive(Clone, Copy)]
+struct AlarmSlot {
+    allocated: bool,
+    /// The counter value at which the alarm fires, in timer ticks.
+    target: Option<u64>,
+    fired: bool,
+}
+
+impl AlarmSlot {
+    const fn new() -> Self {
+        Self {
+            allocated: false,
+            target: None,
+            fired: false,
+        }
+    }
+}
+
+static ALARMS: Mutex<RefCell<[[AlarmSlot; MAX_ALARMS_PER_TIMER]; NUM_TIMERS]>> = Mutex::new(
+    RefCell::new([[AlarmSlot::new(); MAX_ALARMS_PER_TIMER]; NUM_TIMERS]),
+);
+
+fn timer_index(timer_group: u8, timer_number: u8) -> usize {
+    (timer_group * 2 + timer_number) as usize
+}
+
+impl<T, DM> Timer<T, DM>
+where
+    T: Instance,
+    DM: Mode,
+{
+    /// Create a new alarm on this timer.
+    ///
+    /// Each timer only has a single comparator, which is shared by all of its
+    /// alarms: it is always set to the earliest pending target, and the
+    /// timer's interrupt handler marks the alarms which are due as fired.
+    /// The counter has to keep running while alarms exist, so the timer must
+    /// not be started, reset or used for delays in the meantime.
+    ///
+    /// Returns `None` if [`MAX_ALARMS_PER_TIMER`] alarms already exist.
+    pub fn create_alarm(&self) -> Option<AlarmHandle<'_, T, DM>> {
+        let (index, first) = critical_section::with(|cs| {
+            let mut alarms = ALARMS.borrow_ref_mut(cs);
+            let slots = &mut alarms[self.alarm_index()];
+
+            let first = slots.iter().all(|slot| !slot.allocated);
+            let index = slots.iter().position(|slot| !slot.allocated)?;
+            slots[index] = AlarmSlot {
+                allocated: true,
+                ..AlarmSlot::new()
+            };
+
+            Some((index, first))
+        })?;
+
+        if first {
+            let handler = match (self.timer_group(), self.timer_number()) {
+                (0, 0) => tg0_t0_alarm_handler,
+                #[cfg(timg_timer1)]
+                (0, 1) => tg0_t1_alarm_handler,
+                #[cfg(timg1)]
+                (1, 0) => tg1_t0_alarm_handler,
+                #[cfg(all(timg_timer1, timg1))]
+                (1, 1) => tg1_t1_alarm_handler,
+                _ => unreachable!(),
+            };
+
+            <Self as super::Timer>::set_interrupt_handler(self, handler);
+
+            self.timg.set_auto_reload(false);
+            self.timg.set_counter_decrementing(false);
+            self.timg.set_counter_active(true);
+        }
+
+        Some(AlarmHandle { timer: self, index })
+    }
+
+    fn alarm_index(&self) -> usize {
+        timer_index(self.timg.timer_group(), self.timg.timer_number())
+    }
+}
+
+/// An alarm of a [`Timer`], created by [`Timer::create_alarm`].
+///
+/// The alarms of a timer are independent of each other, setting the target of
+/// one alarm doesn't affect the others.
+pub struct AlarmHandle<'a, T, DM>
+where
+    T: Instance,
+    DM: Mode,
+{
+    timer: &'a Timer<T, DM>,
+    index: usize,
+}
+
+impl<'a, T, DM> AlarmHandle<'a, T, DM>
+where
+    T: Instance,
+    DM: Mode,
+{
+    /// The index of this alarm among the alarms of its timer.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Arm the alarm to fire once the timer reaches `target`.
+    ///
+    /// A target which has already passed fires right away.
+    pub fn set_target(&mut self, target: Instant<u64, 1, 1_000_000>) {
+        let ticks = timeout_to_ticks(
+            MicrosDurationU64::from_ticks(target.ticks()),
+            self.timer.apb_clk_freq,
+            self.timer.timg.divider(),
+        );
+
+        self.update(|slot| {
+            slot.target = Some(ticks);
+            slot.fired = false;
+        });
+    }
+
+    /// Disarm the alarm.
+    pub fn cancel(&mut self) {
+        self.update(|slot| {
+            slot.target = None;
+            slot.fired = false;
+        });
+    }
+
+    /// Check if the alarm has fired since its target was last set.
+    pub fn has_fired(&self) -> bool {
+        critical_section::with(|cs| {
+            ALARMS.borrow_ref(cs)[self.timer.alarm_index()][self.index].fired
+        })
+    }
+
+    /// Block until the alarm has fired.
+    pub fn wait(&self) {
+        while !self.has_fired() {}
+    }
+
+    fn update(&mut self, f: impl FnOnce(&mut AlarmSlot)) {
+        critical_section::with(|cs| {
+            f(&mut ALARMS.borrow_ref_mut(cs)[self.timer.alarm_index()][self.index]);
+            update_alarms(cs, &self.timer.timg);
+        });
+    }
+}
+
+impl<'a, T, DM> Drop for AlarmHandle<'a, T, DM>
+where
+    T: Instance,
+    DM: Mode,
+{
+    fn drop(&mut self) {
+        self.update(|slot| *slot = AlarmSlot::new());
+    }
+}
+
+/// Marks the alarms of `timer` which are due as fired, and sets the comparator
+/// to the earliest target of the remaining ones.
+fn update_alarms(cs: CriticalSection<'_>, timer: &impl Instance) {
+    let mut alarms = ALARMS.borrow_ref_mut(cs);
+    let slots = &mut alarms[timer_index(timer.timer_group(), timer.timer_number())];
+
+    loop {
+        let now = timer.now();
+        let mut next: Option<u64> = None;
+
+        for slot in slots.iter_mut() {
+            match slot.target {
+                Some(target) if target <= now => {
+                    slot.target = None;
+                    slot.fired = true;
+                }
+                Some(target) => next = Some(next.map_or(target, |next| next.min(target))),
+                None => {}
+            }
+        }
+
+        let Some(next) = next else {
+            timer.unlisten();
+            timer.set_alarm_active(false);
+            return;
+        };
+
+        timer.load_alarm_value(next);
+        timer.set_alarm_active(true);
+        timer.listen();
+
+        // If the counter passed the target while the comparator was being set
+        // up, the alarm would never fire.
+        if timer.now() < next {
+            return;
+        }
+    }
+}
+
+fn handle_alarms(timer: &impl Instance) {
+    timer.clear_interrupt();
+    critical_section::with(|cs| update_alarms(cs, timer));
+}
+
+#[handler]
+fn tg0_t0_alarm_handler() {
+    handle_alarms(&unsafe { Timer0::<TIMG0>::steal() });
+}
+
+#[cfg(timg_timer1)]
+#[handler]
+fn tg0_t1_alarm_handler() {
+    handle_alarms(&unsafe { Timer1::<TIMG0>::steal() });
+}
+
+#[cfg(timg1)]
+#[handler]
+fn tg1_t0_alarm_handler() {
+    handle_alarms(&unsafe { Timer0::<TIMG1>::steal() });
+}
+
+#[cfg(all(timg_timer1, timg1))]
+#[handler]
+fn tg1_t1_alarm_handler() {
+    handle_alarms(&unsafe { Timer1::<TIMG1>::steal() });
+}
+
 #[doc(hidden)]
 pub trait Instance: Sealed + Enable {
     fn register_block(&self) -> &RegisterBlock;
@@ -1039,6 +1344,159 @@ where
     }
 }
 
+// Async functionality of the timer groups.
+#[cfg(feature = "async")]
+mod asynch {
+    use core::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    use embassy_sync::waitqueue::AtomicWaker;
+
+    use super::*;
+    use crate::timer::Timer as _;
+
+    const NUM_WAKERS: usize = 4;
+
+    #[allow(clippy::declare_interior_mutable_const)]
+    const INIT: AtomicWaker = AtomicWaker::new();
+    static WAKERS: [AtomicWaker; NUM_WAKERS] = [INIT; NUM_WAKERS];
+
+    pub(crate) struct TimerFuture<'a, T>
+    where
+        T: Instance,
+    {
+        timer: &'a Timer<T, crate::Async>,
+    }
+
+    impl<'a, T> TimerFuture<'a, T>
+    where
+        T: Instance,
+    {
+        pub(crate) fn new(timer: &'a Timer<T, crate::Async>) -> Self {
+            let handler = match (timer.timer_group(), timer.timer_number()) {
+                (0, 0) => tg0_t0_handler,
+                #[cfg(timg_timer1)]
+                (0, 1) => tg0_t1_handler,
+                #[cfg(timg1)]
+                (1, 0) => tg1_t0_handler,
+                #[cfg(all(timg_timer1, timg1))]
+                (1, 1) => tg1_t1_handler,
+                _ => unreachable!(),
+            };
+
+            timer.set_interrupt_handler(handler);
+            timer.enable_interrupt(true);
+
+            Self { timer }
+        }
+
+        fn event_bit_is_clear(&self) -> bool {
+            self.timer
+                .register_block()
+                .int_ena_timers()
+                .read()
+                .t(self.timer.timer_number())
+                .bit_is_clear()
+        }
+    }
+
+    impl<'a, T> core::future::Future for TimerFuture<'a, T>
+    where
+        T: Instance,
+    {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+            let index = timer_index(self.timer.timer_group(), self.timer.timer_number());
+            WAKERS[index].register(ctx.waker());
+
+            if self.event_bit_is_clear() {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        }
+    }
+
+    impl<'a, T> Drop for TimerFuture<'a, T>
+    where
+        T: Instance,
+    {
+        fn drop(&mut self) {
+            // Disarm the alarm, otherwise a cancelled future could still cause a wakeup
+            self.timer.enable_interrupt(false);
+            self.timer.stop();
+            self.timer.set_alarm_active(false);
+            crate::timer::Timer::clear_interrupt(self.timer);
+        }
+    }
+
+    impl<T> Timer<T, crate::Async>
+    where
+        T: Instance,
+    {
+        /// Wait asynchronously for *at least* the given duration.
+        pub async fn delay_async(&mut self, duration: MicrosDurationU64) {
+            if self.is_running() {
+                self.stop();
+            }
+
+            crate::timer::Timer::clear_interrupt(self);
+            self.reset();
+
+            self.enable_auto_reload(false);
+            self.load_value(duration).unwrap();
+            self.start();
+
+            TimerFuture::new(self).await;
+        }
+    }
+
+    impl<T> embedded_hal_async::delay::DelayNs for Timer<T, crate::Async>
+    where
+        T: Instance,
+    {
+        async fn delay_ns(&mut self, ns: u32) {
+            self.delay_async(MicrosDurationU64::from_ticks(ns.div_ceil(1000) as u64))
+                .await;
+        }
+    }
+
+    fn handle_interrupt(timg: &RegisterBlock, timer_group: u8, timer_number: u8) {
+        timg.int_ena_timers()
+            .modify(|_, w| w.t(timer_number).clear_bit());
+        timg.int_clr_timers()
+            .write(|w| w.t(timer_number).clear_bit_by_one());
+
+        WAKERS[timer_index(timer_group, timer_number)].wake();
+    }
+
+    #[handler]
+    fn tg0_t0_handler() {
+        handle_interrupt(unsafe { &*TIMG0::PTR }, 0, 0);
+    }
+
+    #[cfg(timg_timer1)]
+    #[handler]
+    fn tg0_t1_handler() {
+        handle_interrupt(unsafe { &*TIMG0::PTR }, 0, 1);
+    }
+
+    #[cfg(timg1)]
+    #[handler]
+    fn tg1_t0_handler() {
+        handle_interrupt(unsafe { &*TIMG1::PTR }, 1, 0);
+    }
+
+    #[cfg(all(timg_timer1, timg1))]
+    #[handler]
+    fn tg1_t1_handler() {
+        handle_interrupt(unsafe { &*TIMG1::PTR }, 1, 1);
+    }
+}
+
 /// Event Task Matrix
 #[cfg(soc_etm)]
 pub mod etm {
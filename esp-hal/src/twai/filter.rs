@@ -13,7 +13,7 @@
 
 use super::{ExtendedId, StandardId};
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FilterType {
     Single,
     Dual,
@@ -201,6 +201,14 @@ impl SingleStandardFilter {
     }
 }
 
+impl SingleStandardFilter {
+    /// Create a filter that only accepts frames with the given standard id,
+    /// with any RTR value and payload.
+    pub fn match_id(id: StandardId) -> Self {
+        Self::new_from_code_mask(id, StandardId::MAX, false, false, [0x00; 2], [0x00; 2])
+    }
+}
+
 impl Filter for SingleStandardFilter {
     const FILTER_TYPE: FilterType = FilterType::Single;
     fn to_registers(&self) -> [u8; 8] {
@@ -284,6 +292,14 @@ impl SingleExtendedFilter {
     }
 }
 
+impl SingleExtendedFilter {
+    /// Create a filter that only accepts frames with the given extended id,
+    /// with any RTR value.
+    pub fn match_id(id: ExtendedId) -> Self {
+        Self::new_from_code_mask(id, ExtendedId::MAX, false, false)
+    }
+}
+
 impl Filter for SingleExtendedFilter {
     const FILTER_TYPE: FilterType = FilterType::Single;
     fn to_registers(&self) -> [u8; 8] {
@@ -530,3 +546,40 @@ impl Filter for DualExtendedFilter {
         self.raw
     }
 }
+
+/// A filter given by the raw values of the acceptance code and mask registers.
+///
+/// The mask follows the usual CAN convention, a reset bit (0) means the
+/// corresponding bit of the code must match, a set bit (1) means the bit could
+/// be any value. The layout of the bits depends on the filter type and the
+/// frame format, see the
+/// [ESP32C3 Reference Manual](https://www.espressif.com/sites/default/files/documentation/esp32-c3_technical_reference_manual_en.pdf#subsubsection.29.4.6).
+pub struct RawFilter {
+    raw: [u8; 8],
+    filter_type: FilterType,
+}
+
+impl RawFilter {
+    /// Create a filter from the raw acceptance code and mask.
+    pub const fn new(code: u32, mask: u32, filter_type: FilterType) -> Self {
+        // The registers use the same convention as the mask, so it's inverted
+        // once more to undo the inversion in `code_mask_to_register_array`.
+        Self {
+            raw: code_mask_to_register_array(code, !mask),
+            filter_type,
+        }
+    }
+}
+
+impl Filter for RawFilter {
+    // Not used, the filter type is chosen at runtime.
+    const FILTER_TYPE: FilterType = FilterType::Single;
+
+    fn filter_type(&self) -> FilterType {
+        self.filter_type
+    }
+
+    fn to_registers(&self) -> [u8; 8] {
+        self.raw
+    }
+}
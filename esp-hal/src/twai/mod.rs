@@ -74,7 +74,7 @@
 
 use core::marker::PhantomData;
 
-use self::filter::{Filter, FilterType};
+use self::filter::{Filter, FilterType, RawFilter};
 use crate::{
     clock::Clocks,
     gpio::{InputPin, InputSignal, OutputPin, OutputSignal},
@@ -736,6 +736,15 @@ where
         }
     }
 
+    /// Set the acceptance filter from the raw acceptance code and mask, see
+    /// [RawFilter].
+    ///
+    /// A reset bit (0) in `mask` means the corresponding bit of `code` must
+    /// match, a set bit (1) means the bit could be any value.
+    pub fn set_acceptance_filter(&mut self, code: u32, mask: u32, filter_type: FilterType) {
+        self.set_filter(RawFilter::new(code, mask, filter_type));
+    }
+
     /// Set the error warning threshold.
     ///
     /// In the case when any of an error counter value exceeds the threshold, or
@@ -751,6 +760,11 @@ where
     /// Put the peripheral into Operation Mode, allowing the transmission and
     /// reception of packets using the new object.
     pub fn start(self) -> Twai<'d, T, DM> {
+        // Leave listen only mode, this has to be done while in reset mode.
+        T::register_block()
+            .mode()
+            .modify(|_, w| w.listen_only_mode().clear_bit());
+
         // Put the peripheral into operation mode by clearing the reset mode bit.
         T::register_block()
             .mode()
@@ -882,6 +896,43 @@ where
         }
     }
 
+    /// Put the peripheral into Listen Only Mode, to monitor the bus without
+    /// affecting it.
+    ///
+    /// In this mode the TWAI controller receives all frames, but never
+    /// transmits anything on the bus, not even the ACK bit of a received frame
+    /// or error frames. [Twai::transmit] returns
+    /// [EspTwaiError::NotInTransmitMode].
+    ///
+    /// Use [Twai::stop] and [TwaiConfiguration::start] to return to Normal
+    /// Mode.
+    pub fn into_monitor_mode(self) -> Self {
+        let register_block = T::register_block();
+
+        // The mode can only be changed in reset mode.
+        register_block
+            .mode()
+            .modify(|_, w| w.reset_mode().set_bit());
+        register_block
+            .mode()
+            .modify(|_, w| w.listen_only_mode().set_bit());
+        register_block
+            .mode()
+            .modify(|_, w| w.reset_mode().clear_bit());
+
+        self
+    }
+
+    /// Check if the controller is in Listen Only Mode, see
+    /// [Twai::into_monitor_mode].
+    pub fn is_monitor_mode(&self) -> bool {
+        T::register_block()
+            .mode()
+            .read()
+            .listen_only_mode()
+            .bit_is_set()
+    }
+
     pub fn receive_error_count(&self) -> u8 {
         T::register_block().rx_err_cnt().read().rx_err_cnt().bits()
     }
@@ -899,6 +950,24 @@ where
             .bit_is_set()
     }
 
+    /// Start the recovery from the bus off state.
+    ///
+    /// After entering the bus off state the controller stays in reset mode,
+    /// this leaves it to let the controller monitor the bus. It becomes bus
+    /// on again after it has seen 128 occurrences of 11 consecutive recessive
+    /// bits, see [Twai::is_bus_off].
+    ///
+    /// Does nothing if the controller is not bus off.
+    pub fn initiate_bus_off_recovery(&mut self) {
+        if !self.is_bus_off() {
+            return;
+        }
+
+        T::register_block()
+            .mode()
+            .modify(|_, w| w.reset_mode().clear_bit());
+    }
+
     /// Get the number of messages that the peripheral has available in the
     /// receive FIFO.
     ///
@@ -965,6 +1034,12 @@ where
     /// of the reference manual.
     pub fn transmit(&mut self, frame: &EspTwaiFrame) -> nb::Result<(), EspTwaiError> {
         let register_block = T::register_block();
+
+        // Check that the peripheral is allowed to transmit.
+        if register_block.mode().read().listen_only_mode().bit_is_set() {
+            return nb::Result::Err(nb::Error::Other(EspTwaiError::NotInTransmitMode));
+        }
+
         let status = register_block.status().read();
 
         // Check that the peripheral is not in a bus off state.
@@ -1027,6 +1102,8 @@ where
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum EspTwaiError {
     BusOff,
+    /// The peripheral is in Listen Only Mode and can't transmit
+    NotInTransmitMode,
     EmbeddedHAL(ErrorKind),
 }
 
@@ -1034,7 +1111,7 @@ pub enum EspTwaiError {
 impl embedded_hal_02::can::Error for EspTwaiError {
     fn kind(&self) -> embedded_hal_02::can::ErrorKind {
         match self {
-            Self::BusOff => embedded_hal_02::can::ErrorKind::Other,
+            Self::BusOff | Self::NotInTransmitMode => embedded_hal_02::can::ErrorKind::Other,
             Self::EmbeddedHAL(kind) => (*kind).into(),
         }
     }
@@ -1044,7 +1121,7 @@ impl embedded_hal_02::can::Error for EspTwaiError {
 impl embedded_can::Error for EspTwaiError {
     fn kind(&self) -> embedded_can::ErrorKind {
         match self {
-            Self::BusOff => embedded_can::ErrorKind::Other,
+            Self::BusOff | Self::NotInTransmitMode => embedded_can::ErrorKind::Other,
             Self::EmbeddedHAL(kind) => (*kind).into(),
         }
     }
@@ -1148,6 +1225,12 @@ pub trait Instance: crate::private::Sealed {
     fn enable_peripheral();
 
     fn enable_interrupts();
+
+    fn disable_tx_interrupt();
+
+    fn disable_rx_interrupt();
+
+    fn enable_err_warn_interrupt();
 }
 
 pub trait OperationInstance: Instance {
@@ -1346,6 +1429,27 @@ impl Instance for crate::peripherals::TWAI0 {
                 .set_bit()
         });
     }
+
+    fn disable_tx_interrupt() {
+        let register_block = Self::register_block();
+        register_block
+            .int_ena()
+            .modify(|_, w| w.tx_int_ena().clear_bit());
+    }
+
+    fn disable_rx_interrupt() {
+        let register_block = Self::register_block();
+        register_block
+            .int_ena()
+            .modify(|_, w| w.rx_int_ena().clear_bit());
+    }
+
+    fn enable_err_warn_interrupt() {
+        let register_block = Self::register_block();
+        register_block
+            .int_ena()
+            .modify(|_, w| w.err_warn_int_ena().set_bit());
+    }
 }
 
 #[cfg(any(esp32, esp32c3, esp32s2, esp32s3))]
@@ -1390,6 +1494,27 @@ impl Instance for crate::peripherals::TWAI0 {
                 .set_bit()
         });
     }
+
+    fn disable_tx_interrupt() {
+        let register_block = Self::register_block();
+        register_block
+            .interrupt_enable()
+            .modify(|_, w| w.ext_transmit_int_ena().clear_bit());
+    }
+
+    fn disable_rx_interrupt() {
+        let register_block = Self::register_block();
+        register_block
+            .interrupt_enable()
+            .modify(|_, w| w.ext_receive_int_ena().clear_bit());
+    }
+
+    fn enable_err_warn_interrupt() {
+        let register_block = Self::register_block();
+        register_block
+            .interrupt_enable()
+            .modify(|_, w| w.ext_err_warning_int_ena().set_bit());
+    }
 }
 
 #[cfg(esp32c6)]
@@ -1434,6 +1559,27 @@ impl Instance for crate::peripherals::TWAI1 {
                 .set_bit()
         });
     }
+
+    fn disable_tx_interrupt() {
+        let register_block = Self::register_block();
+        register_block
+            .interrupt_enable()
+            .modify(|_, w| w.ext_transmit_int_ena().clear_bit());
+    }
+
+    fn disable_rx_interrupt() {
+        let register_block = Self::register_block();
+        register_block
+            .interrupt_enable()
+            .modify(|_, w| w.ext_receive_int_ena().clear_bit());
+    }
+
+    fn enable_err_warn_interrupt() {
+        let register_block = Self::register_block();
+        register_block
+            .interrupt_enable()
+            .modify(|_, w| w.ext_err_warning_int_ena().set_bit());
+    }
 }
 
 #[cfg(esp32c6)]
@@ -1441,7 +1587,11 @@ impl OperationInstance for crate::peripherals::TWAI1 {}
 
 #[cfg(feature = "async")]
 mod asynch {
-    use core::{future::poll_fn, task::Poll};
+    use core::{
+        cell::Cell,
+        pin::Pin,
+        task::{Context, Poll},
+    };
 
     use embassy_sync::{
         blocking_mutex::raw::CriticalSectionRawMutex,
@@ -1458,6 +1608,7 @@ mod asynch {
     pub struct TwaiAsyncState {
         pub tx_waker: AtomicWaker,
         pub err_waker: AtomicWaker,
+        pub bus_waker: AtomicWaker,
         pub rx_queue: Channel<CriticalSectionRawMutex, Result<EspTwaiFrame, EspTwaiError>, 32>,
     }
 
@@ -1472,6 +1623,7 @@ mod asynch {
             Self {
                 tx_waker: AtomicWaker::new(),
                 err_waker: AtomicWaker::new(),
+                bus_waker: AtomicWaker::new(),
                 rx_queue: Channel::new(),
             }
         }
@@ -1486,73 +1638,238 @@ mod asynch {
     where
         T: OperationInstance,
     {
+        /// Transmits a frame, waiting until the transmit buffer is free.
+        ///
+        /// Dropping the future before the frame was written disarms the
+        /// transmit interrupt.
         pub async fn transmit_async(&mut self, frame: &EspTwaiFrame) -> Result<(), EspTwaiError> {
             self.tx.transmit_async(frame).await
         }
 
+        /// Waits for the next received frame.
+        ///
+        /// Dropping the future before a frame was received disarms the
+        /// receive interrupt, frames received in the meantime stay in the
+        /// receive FIFO.
         pub async fn receive_async(&mut self) -> Result<EspTwaiFrame, EspTwaiError> {
             self.rx.receive_async().await
         }
+
+        /// Waits until the controller is no longer in the bus off state.
+        ///
+        /// Returns [EspTwaiError::BusOff] if the controller is bus off and the
+        /// recovery wasn't started with [Twai::initiate_bus_off_recovery].
+        pub async fn wait_bus_on(&mut self) -> Result<(), EspTwaiError> {
+            TwaiBusOnFuture::<T>::new().await
+        }
     }
 
-    impl<'d, T> TwaiTx<'d, T, crate::Async>
+    pub(crate) struct TwaiBusOnFuture<T>
     where
         T: OperationInstance,
     {
-        pub async fn transmit_async(&mut self, frame: &EspTwaiFrame) -> Result<(), EspTwaiError> {
+        _phantom: PhantomData<T>,
+    }
+
+    impl<T> TwaiBusOnFuture<T>
+    where
+        T: OperationInstance,
+    {
+        pub fn new() -> Self {
+            // The error warning interrupt is raised when the controller
+            // becomes bus on again.
             T::enable_interrupts();
-            poll_fn(|cx| {
-                T::async_state().tx_waker.register(cx.waker());
+            T::enable_err_warn_interrupt();
 
-                let register_block = T::register_block();
-                let status = register_block.status().read();
+            Self {
+                _phantom: PhantomData,
+            }
+        }
+    }
 
-                // Check that the peripheral is not in a bus off state.
-                if status.bus_off_st().bit_is_set() {
-                    return Poll::Ready(Err(EspTwaiError::BusOff));
-                }
-                // Check that the peripheral is not already transmitting a packet.
-                if !status.tx_buf_st().bit_is_set() {
-                    return Poll::Pending;
-                }
+    impl<T> core::future::Future for TwaiBusOnFuture<T>
+    where
+        T: OperationInstance,
+    {
+        type Output = Result<(), EspTwaiError>;
 
-                T::write_frame(frame);
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            T::async_state().bus_waker.register(cx.waker());
 
-                Poll::Ready(Ok(()))
-            })
-            .await
+            let register_block = T::register_block();
+
+            if register_block.status().read().bus_off_st().bit_is_clear() {
+                return Poll::Ready(Ok(()));
+            }
+
+            // The controller stays in reset mode until the recovery is started.
+            if register_block.mode().read().reset_mode().bit_is_set() {
+                return Poll::Ready(Err(EspTwaiError::BusOff));
+            }
+
+            // The interrupt handler disables the interrupts which fired.
+            T::enable_err_warn_interrupt();
+
+            Poll::Pending
         }
     }
 
-    impl<'d, T> TwaiRx<'d, T, crate::Async>
+    pub(crate) struct TwaiTxFuture<'a, T>
     where
         T: OperationInstance,
     {
-        pub async fn receive_async(&mut self) -> Result<EspTwaiFrame, EspTwaiError> {
+        frame: &'a EspTwaiFrame,
+        done: Cell<bool>,
+        _phantom: PhantomData<T>,
+    }
+
+    impl<'a, T> TwaiTxFuture<'a, T>
+    where
+        T: OperationInstance,
+    {
+        pub fn new(frame: &'a EspTwaiFrame) -> Self {
             T::enable_interrupts();
-            poll_fn(|cx| {
-                T::async_state().err_waker.register(cx.waker());
-
-                if let Poll::Ready(result) = T::async_state().rx_queue.poll_receive(cx) {
-                    return Poll::Ready(result);
-                } else {
-                    let register_block = T::register_block();
-                    let status = register_block.status().read();
-
-                    // Check that the peripheral is not in a bus off state.
-                    if status.bus_off_st().bit_is_set() {
-                        return Poll::Ready(Err(EspTwaiError::BusOff));
-                    }
-
-                    // Check if the packet in the receive buffer is valid or overrun.
-                    if status.miss_st().bit_is_set() {
-                        return Poll::Ready(Err(EspTwaiError::EmbeddedHAL(ErrorKind::Overrun)));
-                    }
-                }
 
-                Poll::Pending
-            })
-            .await
+            Self {
+                frame,
+                done: Cell::new(false),
+                _phantom: PhantomData,
+            }
+        }
+    }
+
+    impl<'a, T> Drop for TwaiTxFuture<'a, T>
+    where
+        T: OperationInstance,
+    {
+        fn drop(&mut self) {
+            // Disarm the interrupt if the future is dropped before it completed
+            if !self.done.get() {
+                T::disable_tx_interrupt();
+            }
+        }
+    }
+
+    impl<'a, T> core::future::Future for TwaiTxFuture<'a, T>
+    where
+        T: OperationInstance,
+    {
+        type Output = Result<(), EspTwaiError>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            T::async_state().tx_waker.register(cx.waker());
+
+            let register_block = T::register_block();
+
+            // Check that the peripheral is allowed to transmit.
+            if register_block.mode().read().listen_only_mode().bit_is_set() {
+                self.done.set(true);
+                return Poll::Ready(Err(EspTwaiError::NotInTransmitMode));
+            }
+
+            let status = register_block.status().read();
+
+            // Check that the peripheral is not in a bus off state.
+            if status.bus_off_st().bit_is_set() {
+                self.done.set(true);
+                return Poll::Ready(Err(EspTwaiError::BusOff));
+            }
+            // Check that the peripheral is not already transmitting a packet.
+            if !status.tx_buf_st().bit_is_set() {
+                return Poll::Pending;
+            }
+
+            T::write_frame(self.frame);
+
+            self.done.set(true);
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    pub(crate) struct TwaiRxFuture<T>
+    where
+        T: OperationInstance,
+    {
+        done: Cell<bool>,
+        _phantom: PhantomData<T>,
+    }
+
+    impl<T> TwaiRxFuture<T>
+    where
+        T: OperationInstance,
+    {
+        pub fn new() -> Self {
+            T::enable_interrupts();
+
+            Self {
+                done: Cell::new(false),
+                _phantom: PhantomData,
+            }
+        }
+    }
+
+    impl<T> Drop for TwaiRxFuture<T>
+    where
+        T: OperationInstance,
+    {
+        fn drop(&mut self) {
+            // Disarm the interrupt if the future is dropped before it completed
+            if !self.done.get() {
+                T::disable_rx_interrupt();
+            }
+        }
+    }
+
+    impl<T> core::future::Future for TwaiRxFuture<T>
+    where
+        T: OperationInstance,
+    {
+        type Output = Result<EspTwaiFrame, EspTwaiError>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            T::async_state().err_waker.register(cx.waker());
+
+            if let Poll::Ready(result) = T::async_state().rx_queue.poll_receive(cx) {
+                self.done.set(true);
+                return Poll::Ready(result);
+            }
+
+            let register_block = T::register_block();
+            let status = register_block.status().read();
+
+            // Check that the peripheral is not in a bus off state.
+            if status.bus_off_st().bit_is_set() {
+                self.done.set(true);
+                return Poll::Ready(Err(EspTwaiError::BusOff));
+            }
+
+            // Check if the packet in the receive buffer is valid or overrun.
+            if status.miss_st().bit_is_set() {
+                self.done.set(true);
+                return Poll::Ready(Err(EspTwaiError::EmbeddedHAL(ErrorKind::Overrun)));
+            }
+
+            Poll::Pending
+        }
+    }
+
+    impl<'d, T> TwaiTx<'d, T, crate::Async>
+    where
+        T: OperationInstance,
+    {
+        /// Transmits a frame, see [Twai::transmit_async].
+        pub async fn transmit_async(&mut self, frame: &EspTwaiFrame) -> Result<(), EspTwaiError> {
+            TwaiTxFuture::<T>::new(frame).await
+        }
+    }
+
+    impl<'d, T> TwaiRx<'d, T, crate::Async>
+    where
+        T: OperationInstance,
+    {
+        /// Waits for the next received frame, see [Twai::receive_async].
+        pub async fn receive_async(&mut self) -> Result<EspTwaiFrame, EspTwaiError> {
+            TwaiRxFuture::<T>::new().await
         }
     }
 
@@ -1592,6 +1909,7 @@ mod asynch {
 
         if intr_status.bits() & 0b11111100 > 0 {
             async_state.err_waker.wake();
+            async_state.bus_waker.wake();
         }
 
         unsafe {
@@ -1637,6 +1955,7 @@ mod asynch {
 
         if intr_status.bits() & 0b11111100 > 0 {
             async_state.err_waker.wake();
+            async_state.bus_waker.wake();
         }
 
         unsafe {
@@ -1682,6 +2001,7 @@ mod asynch {
 
         if intr_status.bits() & 0b11111100 > 0 {
             async_state.err_waker.wake();
+            async_state.bus_waker.wake();
         }
 
         unsafe {
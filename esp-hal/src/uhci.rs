@@ -0,0 +1,368 @@
+//! # UART with DMA (UHCI)
+//!
+//! ## Overview
+//! The Universal Host Controller Interface (UHCI) connects a UART to a DMA
+//! channel. The received data is written to memory and the data to send is
+//! read from memory by the DMA, without the CPU emptying or filling the UART
+//! FIFOs. This allows receiving at high baud rates without losing data.
+//!
+//! The UHCI is used in transparent mode: the data is sent and received as is,
+//! without the SLIP framing, packet headers or checksums the peripheral
+//! supports.
+//!
+//! A receive transfer ends when the RX line stays idle for the UART's idle
+//! threshold (256 bit times by default) or when the buffer is full, so
+//! [DmaTransferRx::received_bytes] is the size of the received frame. In
+//! circular mode a descriptor is handed back early when the line becomes idle.
+//!
+//! ## Examples
+//! ```rust, no_run
+#![doc = crate::before_snippet!()]
+//! # use esp_hal::dma::{Dma, DmaPriority};
+//! # use esp_hal::dma_buffers;
+//! # use esp_hal::gpio::Io;
+//! # use esp_hal::uart::{config::Config, Uart};
+//! # use esp_hal::uhci::UhciUart;
+//! let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+//! let uart = Uart::new_with_config(
+//!     peripherals.UART1,
+//!     Config::default().baudrate(2_000_000),
+//!     &clocks,
+//!     io.pins.gpio1,
+//!     io.pins.gpio2,
+//! )
+//! .unwrap();
+//!
+//! let dma = Dma::new(peripherals.DMA);
+//! let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) =
+//!     dma_buffers!(4096);
+//!
+//! let mut uhci = UhciUart::new(
+//!     peripherals.UHCI0,
+//!     uart,
+//!     dma.channel0.configure(false, DmaPriority::Priority0),
+//!     tx_descriptors,
+//!     rx_descriptors,
+//! );
+//!
+//! uhci.tx.write_dma(&tx_buffer).unwrap().wait().unwrap();
+//!
+//! let mut transfer = uhci.rx.read_dma(&mut rx_buffer).unwrap();
+//! while !transfer.is_done() {}
+//! let received = transfer.received_bytes();
+//! transfer.wait().unwrap();
+//! # }
+//! ```
+
+use core::marker::PhantomData;
+
+use embedded_dma::{ReadBuffer, WriteBuffer};
+
+use crate::{
+    dma::{
+        dma_private::{DmaSupport, DmaSupportRx, DmaSupportTx},
+        Channel,
+        ChannelRx,
+        ChannelTx,
+        DescriptorChain,
+        DmaChannel,
+        DmaDescriptor,
+        DmaError,
+        DmaPeripheral,
+        DmaTransferRx,
+        DmaTransferRxCircular,
+        DmaTransferTx,
+        RxPrivate,
+        TxPrivate,
+        UhciPeripheral,
+    },
+    peripheral::{Peripheral, PeripheralRef},
+    peripherals::UHCI0,
+    system::PeripheralClockControl,
+    uart::{Instance, Uart},
+    Blocking,
+    Mode,
+};
+
+fn register_block() -> &'static crate::peripherals::uhci0::RegisterBlock {
+    unsafe { &*UHCI0::PTR }
+}
+
+fn reset_tx() {
+    let uhci = register_block();
+    uhci.conf0().modify(|_, w| w.tx_rst().set_bit());
+    uhci.conf0().modify(|_, w| w.tx_rst().clear_bit());
+}
+
+fn reset_rx() {
+    let uhci = register_block();
+    uhci.conf0().modify(|_, w| w.rx_rst().set_bit());
+    uhci.conf0().modify(|_, w| w.rx_rst().clear_bit());
+}
+
+/// A UART which sends and receives via DMA
+pub struct UhciUart<'d, T, C>
+where
+    C: DmaChannel,
+    C::P: UhciPeripheral,
+{
+    _uhci: PeripheralRef<'d, UHCI0>,
+    _uart: Uart<'d, T, Blocking>,
+    /// The transmitting half
+    pub tx: UhciUartTx<'d, T, C>,
+    /// The receiving half
+    pub rx: UhciUartRx<'d, T, C>,
+}
+
+impl<'d, T, C> UhciUart<'d, T, C>
+where
+    T: Instance,
+    C: DmaChannel,
+    C::P: UhciPeripheral,
+{
+    /// Connects the configured `uart` to `channel` via the UHCI.
+    ///
+    /// The baud rate and the other UART settings are kept.
+    pub fn new<DM: Mode>(
+        uhci: impl Peripheral<P = UHCI0> + 'd,
+        uart: Uart<'d, T, Blocking>,
+        channel: Channel<'d, C, DM>,
+        tx_descriptors: &'static mut [DmaDescriptor],
+        rx_descriptors: &'static mut [DmaDescriptor],
+    ) -> Self {
+        crate::into_ref!(uhci);
+
+        PeripheralClockControl::reset(crate::system::Peripheral::Uhci0);
+        PeripheralClockControl::enable(crate::system::Peripheral::Uhci0);
+
+        let (mut tx_channel, rx_channel) = channel.split();
+        // no need to call this for both, TX and RX
+        tx_channel.init_channel();
+
+        // Transparent mode: no SLIP escapes, packet headers or checksums
+        let uhci_regs = register_block();
+        uhci_regs.conf0().write(|w| {
+            // end the received frame when the RX line is idle
+            w.clk_en().set_bit().uart_idle_eof_en().set_bit();
+            match T::uart_number() {
+                0 => w.uart0_ce().set_bit(),
+                1 => w.uart1_ce().set_bit(),
+                #[cfg(esp32s3)]
+                2 => w.uart2_ce().set_bit(),
+                _ => unreachable!(),
+            }
+        });
+        uhci_regs.conf1().write(|w| unsafe { w.bits(0) });
+        uhci_regs.escape_conf().write(|w| unsafe { w.bits(0) });
+
+        reset_tx();
+        reset_rx();
+
+        Self {
+            _uhci: uhci,
+            _uart: uart,
+            tx: UhciUartTx {
+                channel: tx_channel,
+                chain: DescriptorChain::new(tx_descriptors),
+                phantom: PhantomData,
+            },
+            rx: UhciUartRx {
+                channel: rx_channel,
+                chain: DescriptorChain::new(rx_descriptors),
+                phantom: PhantomData,
+            },
+        }
+    }
+}
+
+impl<'d, T, C> Drop for UhciUart<'d, T, C>
+where
+    C: DmaChannel,
+    C::P: UhciPeripheral,
+{
+    fn drop(&mut self) {
+        // Hand the UART back to the CPU
+        register_block()
+            .conf0()
+            .write(|w| w.clk_en().set_bit());
+    }
+}
+
+/// The transmitting half of a [UhciUart]
+pub struct UhciUartTx<'d, T, C>
+where
+    C: DmaChannel,
+    C::P: UhciPeripheral,
+{
+    channel: ChannelTx<'d, C>,
+    chain: DescriptorChain,
+    phantom: PhantomData<T>,
+}
+
+impl<'d, T, C> UhciUartTx<'d, T, C>
+where
+    T: Instance,
+    C: DmaChannel,
+    C::P: UhciPeripheral,
+{
+    /// Sends `words` via DMA.
+    ///
+    /// Waiting for the returned [DmaTransferTx] also waits until the UART
+    /// sent the last byte.
+    pub fn write_dma<'t, TXBUF>(
+        &'t mut self,
+        words: &'t TXBUF,
+    ) -> Result<DmaTransferTx<'t, Self>, DmaError>
+    where
+        TXBUF: ReadBuffer<Word = u8>,
+    {
+        let (ptr, len) = unsafe { words.read_buffer() };
+
+        reset_tx();
+
+        self.chain.fill_for_tx(false, ptr, len)?;
+        unsafe {
+            self.channel
+                .prepare_transfer_without_start(DmaPeripheral::Uhci0, &self.chain)
+                .and_then(|_| self.channel.start_transfer())?;
+        }
+
+        Ok(DmaTransferTx::new(self))
+    }
+}
+
+impl<'d, T, C> DmaSupport for UhciUartTx<'d, T, C>
+where
+    T: Instance,
+    C: DmaChannel,
+    C::P: UhciPeripheral,
+{
+    fn peripheral_wait_dma(&mut self, _is_tx: bool, _is_rx: bool) {
+        while !self.channel.is_done() && !self.channel.has_error() {}
+        while !T::is_tx_idle() {}
+    }
+
+    fn peripheral_dma_stop(&mut self) {
+        self.channel.stop_transfer();
+        reset_tx();
+    }
+}
+
+impl<'d, T, C> DmaSupportTx for UhciUartTx<'d, T, C>
+where
+    T: Instance,
+    C: DmaChannel,
+    C::P: UhciPeripheral,
+{
+    type TX = ChannelTx<'d, C>;
+
+    fn tx(&mut self) -> &mut Self::TX {
+        &mut self.channel
+    }
+
+    fn chain(&mut self) -> &mut DescriptorChain {
+        &mut self.chain
+    }
+}
+
+/// The receiving half of a [UhciUart]
+pub struct UhciUartRx<'d, T, C>
+where
+    C: DmaChannel,
+    C::P: UhciPeripheral,
+{
+    channel: ChannelRx<'d, C>,
+    chain: DescriptorChain,
+    phantom: PhantomData<T>,
+}
+
+impl<'d, T, C> UhciUartRx<'d, T, C>
+where
+    T: Instance,
+    C: DmaChannel,
+    C::P: UhciPeripheral,
+{
+    /// Receives into `words` via DMA.
+    ///
+    /// The transfer ends when the RX line becomes idle or `words` is full.
+    pub fn read_dma<'t, RXBUF>(
+        &'t mut self,
+        words: &'t mut RXBUF,
+    ) -> Result<DmaTransferRx<'t, Self>, DmaError>
+    where
+        RXBUF: WriteBuffer<Word = u8>,
+    {
+        self.start_rx_transfer(words, false)?;
+        Ok(DmaTransferRx::new(self))
+    }
+
+    /// Receives continuously into `words`, which is used as a ring.
+    ///
+    /// The received data is taken from the ring with
+    /// [DmaTransferRxCircular::available] and [DmaTransferRxCircular::pop].
+    pub fn read_dma_circular<'t, RXBUF>(
+        &'t mut self,
+        words: &'t mut RXBUF,
+    ) -> Result<DmaTransferRxCircular<'t, Self>, DmaError>
+    where
+        RXBUF: WriteBuffer<Word = u8>,
+    {
+        self.start_rx_transfer(words, true)?;
+        Ok(DmaTransferRxCircular::new(self))
+    }
+
+    fn start_rx_transfer<RXBUF>(
+        &mut self,
+        words: &mut RXBUF,
+        circular: bool,
+    ) -> Result<(), DmaError>
+    where
+        RXBUF: WriteBuffer<Word = u8>,
+    {
+        let (ptr, len) = unsafe { words.write_buffer() };
+
+        reset_rx();
+
+        self.chain.fill_for_rx(circular, ptr, len)?;
+        unsafe {
+            self.channel
+                .prepare_transfer_without_start(DmaPeripheral::Uhci0, &self.chain)
+                .and_then(|_| self.channel.start_transfer())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'d, T, C> DmaSupport for UhciUartRx<'d, T, C>
+where
+    T: Instance,
+    C: DmaChannel,
+    C::P: UhciPeripheral,
+{
+    fn peripheral_wait_dma(&mut self, _is_tx: bool, _is_rx: bool) {
+        while !self.channel.is_done() && !self.channel.has_error() {}
+    }
+
+    fn peripheral_dma_stop(&mut self) {
+        self.channel.stop_transfer();
+        reset_rx();
+    }
+}
+
+impl<'d, T, C> DmaSupportRx for UhciUartRx<'d, T, C>
+where
+    T: Instance,
+    C: DmaChannel,
+    C::P: UhciPeripheral,
+{
+    type RX = ChannelRx<'d, C>;
+
+    fn rx(&mut self) -> &mut Self::RX {
+        &mut self.channel
+    }
+
+    fn chain(&mut self) -> &mut DescriptorChain {
+        &mut self.chain
+    }
+}
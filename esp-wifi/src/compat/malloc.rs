@@ -1,13 +1,26 @@
-use core::alloc::Layout;
+use core::{alloc::Layout, mem::size_of};
 
 use crate::HEAP;
 
+/// Alignment of the memory returned by [malloc], the largest alignment of any
+/// C type on the supported targets
+const MALLOC_ALIGN: usize = 8;
+
+/// Size of the header in front of each allocation, it holds the total size of
+/// the allocation and is padded to keep the data aligned to [MALLOC_ALIGN]
+const HEADER_SIZE: usize = size_of::<usize>().next_multiple_of(MALLOC_ALIGN);
+
 pub unsafe extern "C" fn malloc(size: usize) -> *mut u8 {
     trace!("alloc {}", size);
 
-    let total_size = size + 4;
+    let Some(layout) = size
+        .checked_add(HEADER_SIZE)
+        .and_then(|total_size| Layout::from_size_align(total_size, MALLOC_ALIGN).ok())
+    else {
+        warn!("Unable to allocate {} bytes", size);
+        return core::ptr::null_mut();
+    };
 
-    let layout = Layout::from_size_align_unchecked(total_size, 4);
     let ptr = critical_section::with(|cs| {
         HEAP.borrow_ref_mut(cs)
             .allocate_first_fit(layout)
@@ -20,8 +33,10 @@ pub unsafe extern "C" fn malloc(size: usize) -> *mut u8 {
         return ptr;
     }
 
-    *(ptr as *mut usize) = total_size;
-    ptr.offset(4)
+    let data = ptr.add(HEADER_SIZE);
+    data.cast::<usize>().sub(1).write(layout.size());
+
+    data
 }
 
 pub unsafe extern "C" fn free(ptr: *mut u8) {
@@ -31,10 +46,10 @@ pub unsafe extern "C" fn free(ptr: *mut u8) {
         return;
     }
 
-    let ptr = ptr.offset(-4);
-    let total_size = *(ptr as *const usize);
+    let total_size = ptr.cast::<usize>().sub(1).read();
 
-    let layout = Layout::from_size_align_unchecked(total_size, 4);
+    let layout = Layout::from_size_align_unchecked(total_size, MALLOC_ALIGN);
+    let ptr = ptr.sub(HEADER_SIZE);
     critical_section::with(|cs| {
         HEAP.borrow_ref_mut(cs)
             .deallocate(core::ptr::NonNull::new_unchecked(ptr), layout)
@@ -49,9 +64,7 @@ pub unsafe extern "C" fn calloc(number: u32, size: usize) -> *mut u8 {
     let ptr = malloc(total_size);
 
     if !ptr.is_null() {
-        for i in 0..total_size as isize {
-            ptr.offset(i).write_volatile(0);
-        }
+        core::ptr::write_bytes(ptr, 0, total_size);
     }
 
     ptr
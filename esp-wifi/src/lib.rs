@@ -225,7 +225,7 @@ pub fn initialize(
 ) -> Result<EspWifiInitialization, InitializationError> {
     // A minimum clock of 80MHz is required to operate WiFi module.
     const MIN_CLOCK: u32 = 80;
-    if clocks.cpu_clock < MegahertzU32::MHz(MIN_CLOCK) {
+    if clocks.cpu_clock() < MegahertzU32::MHz(MIN_CLOCK) {
         return Err(InitializationError::WrongClockConfig);
     }
 
@@ -0,0 +1,487 @@
+//! Minimal DHCP server for the access point interface
+//!
+//! Hands out addresses from the subnet of the [ipv4::RouterConfiguration] to
+//! the stations connected to the access point, with the access point as the
+//! router.
+
+use smoltcp::{
+    socket::udp,
+    wire::{DhcpMessageType, DhcpPacket, DhcpRepr, EthernetAddress, IpEndpoint, Ipv4Address},
+};
+
+use crate::wifi::ipv4;
+
+const SERVER_PORT: u16 = 67;
+const CLIENT_PORT: u16 = 68;
+
+/// Lease time handed out to the stations in seconds
+const LEASE_SECS: u32 = 2 * 60 * 60;
+
+/// One lease for each station the access point accepts
+const MAX_LEASES: usize = crate::wifi::MAX_AP_STATIONS as usize;
+
+#[derive(Clone, Copy)]
+struct Lease {
+    mac: EthernetAddress,
+    ip: Ipv4Address,
+    expires: u64,
+}
+
+/// The fields of a request needed to answer it
+struct Request {
+    message_type: DhcpMessageType,
+    transaction_id: u32,
+    mac: EthernetAddress,
+    client_ip: Ipv4Address,
+    requested_ip: Option<Ipv4Address>,
+    server_identifier: Option<Ipv4Address>,
+}
+
+impl Request {
+    fn from_repr(repr: &DhcpRepr) -> Self {
+        Self {
+            message_type: repr.message_type,
+            transaction_id: repr.transaction_id,
+            mac: repr.client_hardware_address,
+            client_ip: repr.client_ip,
+            requested_ip: repr.requested_ip,
+            server_identifier: repr.server_identifier,
+        }
+    }
+}
+
+pub(crate) struct DhcpServer {
+    leases: [Option<Lease>; MAX_LEASES],
+}
+
+impl DhcpServer {
+    pub(crate) fn new() -> Self {
+        Self {
+            leases: [None; MAX_LEASES],
+        }
+    }
+
+    /// Forgets all leases
+    pub(crate) fn reset(&mut self) {
+        self.leases = [None; MAX_LEASES];
+    }
+
+    /// Answers the requests received on `socket`. `now` is in milliseconds.
+    pub(crate) fn poll(
+        &mut self,
+        socket: &mut udp::Socket,
+        config: &ipv4::RouterConfiguration,
+        now: u64,
+    ) {
+        if !socket.is_open() && socket.bind(SERVER_PORT).is_err() {
+            warn!("Unable to bind the DHCP server socket");
+            return;
+        }
+
+        while let Ok((data, _)) = socket.recv() {
+            let Ok(packet) = DhcpPacket::new_checked(data) else {
+                continue;
+            };
+            let Ok(repr) = DhcpRepr::parse(&packet) else {
+                continue;
+            };
+            let request = Request::from_repr(&repr);
+
+            if let Some((message_type, ip)) = self.handle(&request, config, now) {
+                let reply = reply(&request, message_type, ip, config);
+                let endpoint = IpEndpoint::new(Ipv4Address::BROADCAST.into(), CLIENT_PORT);
+                match socket.send(reply.buffer_len(), endpoint) {
+                    Ok(buffer) => {
+                        reply.emit(&mut DhcpPacket::new_unchecked(buffer)).ok();
+                    }
+                    Err(_) => warn!("Unable to send the DHCP reply"),
+                }
+            }
+        }
+    }
+
+    /// Updates the leases for `request` and returns the type of the reply and
+    /// the address to put in it, if the request needs an answer
+    fn handle(
+        &mut self,
+        request: &Request,
+        config: &ipv4::RouterConfiguration,
+        now: u64,
+    ) -> Option<(DhcpMessageType, Ipv4Address)> {
+        match request.message_type {
+            DhcpMessageType::Discover => self
+                .offer_address(request, config, now)
+                .map(|ip| (DhcpMessageType::Offer, ip)),
+            DhcpMessageType::Request => {
+                // the station chose the offer of another server
+                if request
+                    .server_identifier
+                    .is_some_and(|server| server != server_ip(config))
+                {
+                    return None;
+                }
+
+                let ip = request.requested_ip.unwrap_or(request.client_ip);
+                Some(if self.lease(request.mac, ip, config, now) {
+                    (DhcpMessageType::Ack, ip)
+                } else {
+                    (DhcpMessageType::Nak, Ipv4Address::UNSPECIFIED)
+                })
+            }
+            DhcpMessageType::Release | DhcpMessageType::Decline => {
+                self.release(request.mac);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// The address to offer: the one already leased to the station, the one
+    /// it asks for or the first free one
+    fn offer_address(
+        &self,
+        request: &Request,
+        config: &ipv4::RouterConfiguration,
+        now: u64,
+    ) -> Option<Ipv4Address> {
+        if let Some(lease) = self
+            .active_leases(now)
+            .find(|lease| lease.mac == request.mac)
+        {
+            return Some(lease.ip);
+        }
+
+        if let Some(ip) = request.requested_ip {
+            if self.is_free(request.mac, ip, config, now) {
+                return Some(ip);
+            }
+        }
+
+        let (first, last) = host_range(config);
+        (first..=last)
+            .map(|ip| Ipv4Address::from_bytes(&ip.to_be_bytes()))
+            .find(|&ip| self.is_free(request.mac, ip, config, now))
+    }
+
+    /// Leases `ip` to the station, returns `false` if it isn't available
+    fn lease(
+        &mut self,
+        mac: EthernetAddress,
+        ip: Ipv4Address,
+        config: &ipv4::RouterConfiguration,
+        now: u64,
+    ) -> bool {
+        if !self.is_free(mac, ip, config, now) {
+            return false;
+        }
+
+        self.release(mac);
+        let Some(slot) = self
+            .leases
+            .iter_mut()
+            .find(|lease| !lease.is_some_and(|lease| lease.expires > now))
+        else {
+            return false;
+        };
+
+        *slot = Some(Lease {
+            mac,
+            ip,
+            expires: now + LEASE_SECS as u64 * 1000,
+        });
+        debug!("DHCP server leased {:?} to {:?}", ip, mac);
+
+        true
+    }
+
+    fn release(&mut self, mac: EthernetAddress) {
+        for lease in self.leases.iter_mut() {
+            if lease.is_some_and(|lease| lease.mac == mac) {
+                *lease = None;
+            }
+        }
+    }
+
+    /// Whether `ip` can be leased to the station `mac`
+    fn is_free(
+        &self,
+        mac: EthernetAddress,
+        ip: Ipv4Address,
+        config: &ipv4::RouterConfiguration,
+        now: u64,
+    ) -> bool {
+        let (first, last) = host_range(config);
+        let ip_u32 = u32::from_be_bytes(ip.0);
+
+        (first..=last).contains(&ip_u32)
+            && ip.0 != config.subnet.gateway.octets()
+            && !self
+                .active_leases(now)
+                .any(|lease| lease.ip == ip && lease.mac != mac)
+    }
+
+    fn active_leases(&self, now: u64) -> impl Iterator<Item = &Lease> {
+        self.leases
+            .iter()
+            .flatten()
+            .filter(move |lease| lease.expires > now)
+    }
+}
+
+/// Builds the reply to `request`, `ip` is the address offered or leased to the
+/// station
+fn reply(
+    request: &Request,
+    message_type: DhcpMessageType,
+    ip: Ipv4Address,
+    config: &ipv4::RouterConfiguration,
+) -> DhcpRepr<'static> {
+    let server_ip = server_ip(config);
+    let ack = message_type != DhcpMessageType::Nak;
+
+    let mut dns_servers = heapless::Vec::new();
+    for dns in [config.dns, config.secondary_dns].into_iter().flatten() {
+        dns_servers
+            .push(Ipv4Address::from_bytes(&dns.octets()))
+            .ok();
+    }
+
+    DhcpRepr {
+        message_type,
+        transaction_id: request.transaction_id,
+        secs: 0,
+        client_hardware_address: request.mac,
+        client_ip: Ipv4Address::UNSPECIFIED,
+        your_ip: ip,
+        server_ip,
+        router: ack.then_some(server_ip),
+        subnet_mask: ack.then(|| subnet_mask(config)),
+        relay_agent_ip: Ipv4Address::UNSPECIFIED,
+        // the station has no address yet
+        broadcast: true,
+        requested_ip: None,
+        client_identifier: None,
+        server_identifier: Some(server_ip),
+        parameter_request_list: None,
+        dns_servers: (ack && !dns_servers.is_empty()).then_some(dns_servers),
+        max_size: None,
+        lease_duration: ack.then_some(LEASE_SECS),
+        renew_duration: None,
+        rebind_duration: None,
+        additional_options: &[],
+    }
+}
+
+fn server_ip(config: &ipv4::RouterConfiguration) -> Ipv4Address {
+    Ipv4Address::from_bytes(&config.subnet.gateway.octets())
+}
+
+fn subnet_mask(config: &ipv4::RouterConfiguration) -> Ipv4Address {
+    let mask = u32::MAX
+        .checked_shl(32 - config.subnet.mask.0 as u32)
+        .unwrap_or(0);
+    Ipv4Address::from_bytes(&mask.to_be_bytes())
+}
+
+/// The first and the last host address of the subnet
+fn host_range(config: &ipv4::RouterConfiguration) -> (u32, u32) {
+    let mask = u32::from_be_bytes(subnet_mask(config).0);
+    let network = u32::from_be_bytes(config.subnet.gateway.octets()) & mask;
+    let broadcast = network | !mask;
+
+    (network.saturating_add(1), broadcast.saturating_sub(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wifi::ipv4::Ipv4Addr;
+
+    const NOW: u64 = 1000;
+
+    fn config() -> ipv4::RouterConfiguration {
+        ipv4::RouterConfiguration {
+            subnet: ipv4::Subnet {
+                gateway: Ipv4Addr::new(192, 168, 2, 1),
+                mask: ipv4::Mask(24),
+            },
+            dhcp_enabled: true,
+            dns: Some(Ipv4Addr::new(8, 8, 8, 8)),
+            secondary_dns: None,
+        }
+    }
+
+    fn mac(n: u8) -> EthernetAddress {
+        EthernetAddress([2, 0, 0, 0, 0, n])
+    }
+
+    fn ip(n: u8) -> Ipv4Address {
+        Ipv4Address::new(192, 168, 2, n)
+    }
+
+    fn request(
+        message_type: DhcpMessageType,
+        mac: EthernetAddress,
+        requested_ip: Option<Ipv4Address>,
+    ) -> Request {
+        Request {
+            message_type,
+            transaction_id: 0x1234,
+            mac,
+            client_ip: Ipv4Address::UNSPECIFIED,
+            requested_ip,
+            server_identifier: None,
+        }
+    }
+
+    /// Runs DISCOVER and REQUEST for the offered address
+    fn join(server: &mut DhcpServer, mac: EthernetAddress, now: u64) -> Ipv4Address {
+        let discover = request(DhcpMessageType::Discover, mac, None);
+        let (message_type, offered) = server.handle(&discover, &config(), now).unwrap();
+        assert_eq!(message_type, DhcpMessageType::Offer);
+
+        let request = request(DhcpMessageType::Request, mac, Some(offered));
+        assert_eq!(
+            server.handle(&request, &config(), now),
+            Some((DhcpMessageType::Ack, offered))
+        );
+
+        offered
+    }
+
+    #[test]
+    fn stations_get_distinct_addresses() {
+        let mut server = DhcpServer::new();
+
+        assert_eq!(join(&mut server, mac(1), NOW), ip(2));
+        assert_eq!(join(&mut server, mac(2), NOW), ip(3));
+        // a station asking again keeps its address
+        assert_eq!(join(&mut server, mac(1), NOW), ip(2));
+    }
+
+    #[test]
+    fn requested_address_is_offered_if_free() {
+        let mut server = DhcpServer::new();
+        join(&mut server, mac(1), NOW);
+
+        let discover = request(DhcpMessageType::Discover, mac(2), Some(ip(50)));
+        assert_eq!(
+            server.handle(&discover, &config(), NOW),
+            Some((DhcpMessageType::Offer, ip(50)))
+        );
+
+        // taken by another station, or outside of the hosts of the subnet
+        for requested in [ip(2), ip(1), ip(255), Ipv4Address::new(10, 0, 0, 2)] {
+            let discover = request(DhcpMessageType::Discover, mac(2), Some(requested));
+            assert_eq!(
+                server.handle(&discover, &config(), NOW),
+                Some((DhcpMessageType::Offer, ip(3)))
+            );
+        }
+    }
+
+    #[test]
+    fn request_for_unavailable_address_is_refused() {
+        let mut server = DhcpServer::new();
+        join(&mut server, mac(1), NOW);
+
+        let nak = Some((DhcpMessageType::Nak, Ipv4Address::UNSPECIFIED));
+        for requested in [ip(2), ip(1), Ipv4Address::new(10, 0, 0, 2)] {
+            let request = request(DhcpMessageType::Request, mac(2), Some(requested));
+            assert_eq!(server.handle(&request, &config(), NOW), nak);
+        }
+    }
+
+    #[test]
+    fn request_for_another_server_is_ignored() {
+        let mut server = DhcpServer::new();
+
+        let mut request = request(DhcpMessageType::Request, mac(1), Some(ip(2)));
+        request.server_identifier = Some(Ipv4Address::new(192, 168, 2, 254));
+        assert_eq!(server.handle(&request, &config(), NOW), None);
+
+        // the address wasn't leased
+        assert_eq!(join(&mut server, mac(2), NOW), ip(2));
+    }
+
+    #[test]
+    fn released_and_expired_leases_are_reused() {
+        let mut server = DhcpServer::new();
+        join(&mut server, mac(1), NOW);
+        join(&mut server, mac(2), NOW);
+
+        let release = request(DhcpMessageType::Release, mac(1), None);
+        assert_eq!(server.handle(&release, &config(), NOW), None);
+        assert_eq!(join(&mut server, mac(3), NOW), ip(2));
+
+        let expired = NOW + LEASE_SECS as u64 * 1000;
+        assert_eq!(join(&mut server, mac(4), expired), ip(2));
+    }
+
+    #[test]
+    fn leases_are_limited() {
+        let mut server = DhcpServer::new();
+        for n in 0..MAX_LEASES as u8 {
+            assert_eq!(join(&mut server, mac(n), NOW), ip(n + 2));
+        }
+
+        // an offer can't be taken once all leases are in use
+        let extra = mac(MAX_LEASES as u8);
+        let request = request(DhcpMessageType::Request, extra, Some(ip(100)));
+        assert_eq!(
+            server.handle(&request, &config(), NOW),
+            Some((DhcpMessageType::Nak, Ipv4Address::UNSPECIFIED))
+        );
+
+        server.reset();
+        assert_eq!(join(&mut server, extra, NOW), ip(2));
+    }
+
+    #[test]
+    fn offer_and_ack_carry_the_network_settings() {
+        let config = config();
+
+        for message_type in [DhcpMessageType::Offer, DhcpMessageType::Ack] {
+            let request = request(DhcpMessageType::Discover, mac(1), None);
+            let repr = reply(&request, message_type, ip(2), &config);
+
+            let mut buffer = [0; 576];
+            let mut packet = DhcpPacket::new_unchecked(&mut buffer[..repr.buffer_len()]);
+            repr.emit(&mut packet).unwrap();
+            let packet = DhcpPacket::new_checked(&buffer[..repr.buffer_len()]).unwrap();
+            let parsed = DhcpRepr::parse(&packet).unwrap();
+
+            assert_eq!(parsed.message_type, message_type);
+            assert_eq!(parsed.transaction_id, 0x1234);
+            assert_eq!(parsed.client_hardware_address, mac(1));
+            assert_eq!(parsed.your_ip, ip(2));
+            assert_eq!(parsed.server_identifier, Some(ip(1)));
+            assert_eq!(parsed.router, Some(ip(1)));
+            assert_eq!(parsed.subnet_mask, Some(Ipv4Address::new(255, 255, 255, 0)));
+            assert_eq!(
+                parsed.dns_servers.as_deref(),
+                Some(&[Ipv4Address::new(8, 8, 8, 8)][..])
+            );
+            assert_eq!(parsed.lease_duration, Some(LEASE_SECS));
+            assert!(parsed.broadcast);
+        }
+    }
+
+    #[test]
+    fn nak_carries_no_network_settings() {
+        let request = request(DhcpMessageType::Request, mac(1), Some(ip(1)));
+        let repr = reply(
+            &request,
+            DhcpMessageType::Nak,
+            Ipv4Address::UNSPECIFIED,
+            &config(),
+        );
+
+        assert_eq!(repr.your_ip, Ipv4Address::UNSPECIFIED);
+        assert_eq!(repr.server_identifier, Some(ip(1)));
+        assert_eq!(repr.router, None);
+        assert_eq!(repr.subnet_mask, None);
+        assert_eq!(repr.dns_servers, None);
+        assert_eq!(repr.lease_duration, None);
+    }
+}
@@ -44,9 +44,15 @@ const ETHERNET_FRAME_HEADER_SIZE: usize = 18;
 
 const MTU: usize = crate::CONFIG.mtu;
 
+/// Maximum number of stations connected to the access point
+pub const MAX_AP_STATIONS: u8 = 10;
+
 #[cfg(feature = "utils")]
 pub mod utils;
 
+#[cfg(all(feature = "dhcpv4", feature = "udp"))]
+pub(crate) mod dhcp_server;
+
 #[cfg(coex)]
 use include::{coex_adapter_funcs_t, coex_pre_init, esp_coex_adapter_register};
 
@@ -176,6 +182,10 @@ pub struct AccessPointConfiguration {
     pub auth_method: AuthMethod,
     pub password: heapless::String<64>,
     pub max_connections: u16,
+    /// Only allow stations which use Protected Management Frames.
+    ///
+    /// PMF is always required with [AuthMethod::WPA3Personal].
+    pub pmf_required: bool,
 }
 
 impl Default for AccessPointConfiguration {
@@ -189,6 +199,7 @@ impl Default for AccessPointConfiguration {
             auth_method: AuthMethod::None,
             password: heapless::String::new(),
             max_connections: 255,
+            pmf_required: false,
         }
     }
 }
@@ -203,6 +214,10 @@ pub struct ClientConfiguration {
     pub auth_method: AuthMethod,
     pub password: heapless::String<64>,
     pub channel: Option<u8>,
+    /// Only connect to access points which use Protected Management Frames.
+    ///
+    /// PMF is always required with [AuthMethod::WPA3Personal].
+    pub pmf_required: bool,
 }
 
 impl Debug for ClientConfiguration {
@@ -212,6 +227,7 @@ impl Debug for ClientConfiguration {
             .field("bssid", &self.bssid)
             .field("auth_method", &self.auth_method)
             .field("channel", &self.channel)
+            .field("pmf_required", &self.pmf_required)
             .finish()
     }
 }
@@ -224,6 +240,7 @@ impl Default for ClientConfiguration {
             auth_method: Default::default(),
             password: heapless::String::new(),
             channel: None,
+            pmf_required: false,
         }
     }
 }
@@ -536,6 +553,52 @@ pub mod ipv4 {
         }
     }
 
+    /// Errors returned by [Configuration::static_ip]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    pub enum ConfigurationError {
+        /// The subnet isn't a valid mask like `255.255.255.0`
+        InvalidSubnetMask,
+        /// The gateway isn't in the subnet of the address
+        GatewayOutsideSubnet,
+    }
+
+    impl Display for ConfigurationError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self {
+                Self::InvalidSubnetMask => write!(f, "Invalid subnet mask"),
+                Self::GatewayOutsideSubnet => write!(f, "Gateway outside of the subnet"),
+            }
+        }
+    }
+
+    impl Configuration {
+        /// A fixed address instead of DHCP, usable for both the station and
+        /// the access point interface.
+        pub fn static_ip(
+            ip: Ipv4Addr,
+            subnet: Ipv4Addr,
+            gateway: Ipv4Addr,
+            dns: Option<Ipv4Addr>,
+        ) -> Result<Self, ConfigurationError> {
+            let mask = Mask::try_from(subnet).map_err(|_| ConfigurationError::InvalidSubnetMask)?;
+
+            let network = |addr: Ipv4Addr| {
+                u32::from_be_bytes(addr.octets()) & u32::from_be_bytes(subnet.octets())
+            };
+            if network(ip) != network(gateway) {
+                return Err(ConfigurationError::GatewayOutsideSubnet);
+            }
+
+            Ok(Self::Client(ClientConfiguration::Fixed(ClientSettings {
+                ip,
+                subnet: Subnet { gateway, mask },
+                dns,
+                secondary_dns: None,
+            })))
+        }
+    }
+
     #[derive(Copy, Clone, Debug, Eq, PartialEq)]
     #[cfg_attr(feature = "defmt", derive(defmt::Format))]
     pub struct IpInfo {
@@ -2069,7 +2132,7 @@ fn apply_ap_config(config: &AccessPointConfiguration) -> Result<(), WifiError> {
             ftm_responder: false,
             pmf_cfg: wifi_pmf_config_t {
                 capable: true,
-                required: false,
+                required: config.pmf_required || config.auth_method == AuthMethod::WPA3Personal,
             },
             sae_pwe_h2e: 0,
         },
@@ -2107,7 +2170,7 @@ fn apply_sta_config(config: &ClientConfiguration) -> Result<(), WifiError> {
             },
             pmf_cfg: wifi_pmf_config_t {
                 capable: true,
-                required: false,
+                required: config.pmf_required || config.auth_method == AuthMethod::WPA3Personal,
             },
             sae_pwe_h2e: 3,
             _bitfield_align_1: [0; 0],
@@ -2206,6 +2269,62 @@ impl WifiController<'_> {
         Ok(())
     }
 
+    /// Configure the access point and start the WiFi.
+    ///
+    /// The access point is open if `password` is empty and uses WPA2
+    /// otherwise. The controller needs to be created for the access point or
+    /// for both the access point and station (`WIFI_MODE_APSTA`), in the latter
+    /// case the station configuration is kept.
+    ///
+    /// At most [MAX_AP_STATIONS] stations can be connected, a higher
+    /// `max_stations` is rejected.
+    ///
+    /// The stations get their addresses from the DHCP server of the network
+    /// stack, see `WifiStack::start_dhcp_server`.
+    pub fn start_ap(
+        &mut self,
+        ssid: &str,
+        password: &str,
+        channel: u8,
+        max_stations: u8,
+    ) -> Result<(), WifiError> {
+        let invalid_arg = || WifiError::InternalError(InternalWifiError::EspErrInvalidArg);
+
+        if max_stations > MAX_AP_STATIONS {
+            return Err(invalid_arg());
+        }
+
+        let config = AccessPointConfiguration {
+            ssid: ssid.try_into().map_err(|_| invalid_arg())?,
+            channel,
+            auth_method: if password.is_empty() {
+                AuthMethod::None
+            } else {
+                AuthMethod::WPA2Personal
+            },
+            password: password.try_into().map_err(|_| invalid_arg())?,
+            max_connections: max_stations as u16,
+            ..Default::default()
+        };
+
+        let config = match &self.config {
+            Configuration::Mixed(client, _) => Configuration::Mixed(client.clone(), config),
+            _ => Configuration::AccessPoint(config),
+        };
+
+        self.set_configuration(&config)?;
+        crate::wifi::wifi_start()
+    }
+
+    /// The MAC addresses of the stations connected to the access point.
+    pub fn ap_stations(&self) -> Result<impl Iterator<Item = [u8; 6]>, WifiError> {
+        let mut list = MaybeUninit::<include::wifi_sta_list_t>::uninit();
+        esp_wifi_result!(unsafe { include::esp_wifi_ap_get_sta_list(list.as_mut_ptr()) })?;
+        let list = unsafe { list.assume_init() };
+
+        Ok((0..list.num as usize).map(move |i| list.sta[i].mac))
+    }
+
     pub(crate) fn stop_impl(&mut self) -> Result<(), WifiError> {
         esp_wifi_result!(unsafe { esp_wifi_stop() })
     }
@@ -2239,6 +2358,22 @@ impl WifiController<'_> {
             _ => Ok(false),
         }
     }
+
+    /// The signal strength of the access point the station is connected to in
+    /// dBm.
+    ///
+    /// Returns [WifiError::Disconnected] if the station isn't connected.
+    pub fn rssi(&self) -> Result<i8, WifiError> {
+        if !matches!(crate::wifi::get_sta_state(), WifiState::StaConnected) {
+            return Err(WifiError::Disconnected);
+        }
+
+        let mut record = MaybeUninit::<include::wifi_ap_record_t>::uninit();
+        esp_wifi_result!(unsafe { include::esp_wifi_sta_get_ap_info(record.as_mut_ptr()) })?;
+        let record = unsafe { record.assume_init() };
+
+        Ok(record.rssi)
+    }
 }
 
 #[cfg(not(feature = "async"))]
@@ -2401,6 +2536,21 @@ mod asynch {
             Ok((result, count))
         }
 
+        /// Scans for networks and returns an iterator over at most `N` of the
+        /// access points found.
+        ///
+        /// The scan is started with the default [`ScanConfig`] and awaited on
+        /// the [`WifiEvent::ScanDone`] event. Use
+        /// [`WifiController::scan_with_config`] to also get the total number
+        /// of access points found.
+        pub async fn scan_async<const N: usize>(
+            &mut self,
+        ) -> Result<impl Iterator<Item = AccessPointInfo>, WifiError> {
+            let (result, _) = self.scan_with_config::<N>(Default::default()).await?;
+
+            Ok(result.into_iter())
+        }
+
         /// Async version of [`crate::wifi::WifiController`]'s `start` method
         pub async fn start(&mut self) -> Result<(), WifiError> {
             let mode = WifiMode::try_from(&self.config)?;
@@ -2828,6 +2978,7 @@ mod embedded_svc_compat {
                         auth_method: conf.auth_method.into(),
                         password: conf.password.clone(),
                         channel: conf.channel,
+                        pmf_required: false,
                     })
                 }
                 embedded_svc::wifi::Configuration::AccessPoint(conf) => {
@@ -2846,6 +2997,7 @@ mod embedded_svc_compat {
                         auth_method: conf.auth_method.into(),
                         password: conf.password.clone(),
                         max_connections: conf.max_connections,
+                        pmf_required: false,
                     })
                 }
                 embedded_svc::wifi::Configuration::Mixed(client, ap) => Configuration::Mixed(
@@ -2855,6 +3007,7 @@ mod embedded_svc_compat {
                         auth_method: client.auth_method.into(),
                         password: client.password.clone(),
                         channel: client.channel,
+                        pmf_required: false,
                     },
                     AccessPointConfiguration {
                         ssid: ap.ssid.clone(),
@@ -2871,6 +3024,7 @@ mod embedded_svc_compat {
                         auth_method: ap.auth_method.into(),
                         password: ap.password.clone(),
                         max_connections: ap.max_connections,
+                        pmf_required: false,
                     },
                 ),
             }
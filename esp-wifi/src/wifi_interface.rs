@@ -20,6 +20,8 @@ use smoltcp::{
     wire::{IpAddress, IpCidr, Ipv4Address},
 };
 
+#[cfg(all(feature = "dhcpv4", feature = "udp"))]
+use crate::wifi::dhcp_server::DhcpServer;
 use crate::{
     current_millis,
     wifi::{ipv4, WifiDevice, WifiDeviceMode},
@@ -46,6 +48,8 @@ pub struct WifiStack<'a, MODE: WifiDeviceMode> {
     pub(crate) dhcp_socket_handle: RefCell<Option<SocketHandle>>,
     #[cfg(feature = "dhcpv4")]
     pub(crate) old_connected: RefCell<bool>,
+    #[cfg(all(feature = "dhcpv4", feature = "udp"))]
+    dhcp_server: RefCell<Option<(SocketHandle, DhcpServer)>>,
     #[cfg(feature = "dns")]
     dns_socket_handle: RefCell<Option<SocketHandle>>,
 }
@@ -87,6 +91,8 @@ impl<'a, MODE: WifiDeviceMode> WifiStack<'a, MODE> {
             dhcp_socket_handle: RefCell::new(dhcp_socket_handle),
             #[cfg(feature = "dhcpv4")]
             old_connected: RefCell::new(false),
+            #[cfg(all(feature = "dhcpv4", feature = "udp"))]
+            dhcp_server: RefCell::new(None),
             sockets: RefCell::new(sockets),
             current_millis_fn,
             #[cfg(feature = "tcp")]
@@ -119,6 +125,7 @@ impl<'a, MODE: WifiDeviceMode> WifiStack<'a, MODE> {
         info!("Set hardware address: {:?}", hw_address);
 
         self.reset(); // reset IP address
+        *self.ip_info.borrow_mut() = None;
 
         #[cfg(feature = "dhcpv4")]
         {
@@ -154,6 +161,12 @@ impl<'a, MODE: WifiDeviceMode> WifiStack<'a, MODE> {
             }
         }
 
+        // the leases are only valid for the old subnet
+        #[cfg(all(feature = "dhcpv4", feature = "udp"))]
+        if let Some((_, server)) = self.dhcp_server.borrow_mut().as_mut() {
+            server.reset();
+        }
+
         *self.network_config.borrow_mut() = conf.clone();
         Ok(())
     }
@@ -333,6 +346,37 @@ impl<'a, MODE: WifiDeviceMode> WifiStack<'a, MODE> {
         }
     }
 
+    /// Start a DHCP server which hands out addresses to the stations connected
+    /// to the access point
+    ///
+    /// The server only answers while the interface has a
+    /// [ipv4::Configuration::Router] configuration with `dhcp_enabled`. It
+    /// offers addresses of the router's subnet and the router's DNS servers,
+    /// the interface itself uses the gateway address of the subnet. The
+    /// buffers are used for a UDP socket on port 67, they need to hold at least
+    /// one DHCP message (576 bytes).
+    #[cfg(all(feature = "dhcpv4", feature = "udp"))]
+    pub fn start_dhcp_server(
+        &self,
+        rx_meta: &'a mut [smoltcp::socket::udp::PacketMetadata],
+        rx_buffer: &'a mut [u8],
+        tx_meta: &'a mut [smoltcp::socket::udp::PacketMetadata],
+        tx_buffer: &'a mut [u8],
+    ) {
+        let socket = smoltcp::socket::udp::Socket::new(
+            smoltcp::socket::udp::PacketBuffer::new(rx_meta, rx_buffer),
+            smoltcp::socket::udp::PacketBuffer::new(tx_meta, tx_buffer),
+        );
+
+        let handle = self.with_mut(|_interface, _device, sockets| {
+            if let Some((old_handle, _)) = self.dhcp_server.take() {
+                sockets.remove(old_handle);
+            }
+            sockets.add(socket)
+        });
+        self.dhcp_server.replace(Some((handle, DhcpServer::new())));
+    }
+
     /// Check if DNS is configured
     #[cfg(feature = "dns")]
     pub fn is_dns_configured(&self) -> bool {
@@ -445,6 +489,48 @@ impl<'a, MODE: WifiDeviceMode> WifiStack<'a, MODE> {
                         interface.update_ip_addrs(|addrs| {
                             unwrap!(addrs.push(IpCidr::new(addr.into(), settings.subnet.mask.0)));
                         });
+
+                        *self.ip_info.borrow_mut() = Some(ipv4::IpInfo {
+                            ip: settings.ip,
+                            subnet: settings.subnet,
+                            dns: settings.dns,
+                            secondary_dns: settings.secondary_dns,
+                        });
+
+                        #[cfg(feature = "dns")]
+                        if let (Some(dns), Some(dns_handle)) =
+                            (settings.dns, *self.dns_socket_handle.borrow())
+                        {
+                            let dns = Ipv4Address::from_bytes(&dns.octets());
+                            sockets
+                                .get_mut::<smoltcp::socket::dns::Socket>(dns_handle)
+                                .update_servers(&[dns.into()]);
+                        }
+                    }
+                } else if let ipv4::Configuration::Router(settings) = network_config {
+                    let addr = Ipv4Address::from_bytes(&settings.subnet.gateway.octets());
+                    if !interface.has_ip_addr(addr) {
+                        interface.update_ip_addrs(|addrs| {
+                            unwrap!(addrs.push(IpCidr::new(addr.into(), settings.subnet.mask.0)));
+                        });
+
+                        *self.ip_info.borrow_mut() = Some(ipv4::IpInfo {
+                            ip: settings.subnet.gateway,
+                            subnet: settings.subnet,
+                            dns: settings.dns,
+                            secondary_dns: settings.secondary_dns,
+                        });
+                    }
+
+                    #[cfg(all(feature = "dhcpv4", feature = "udp"))]
+                    if settings.dhcp_enabled {
+                        if let Some((handle, server)) = self.dhcp_server.borrow_mut().as_mut() {
+                            server.poll(
+                                sockets.get_mut::<smoltcp::socket::udp::Socket>(*handle),
+                                &settings,
+                                (self.current_millis_fn)(),
+                            );
+                        }
                     }
                 }
                 interface.poll(
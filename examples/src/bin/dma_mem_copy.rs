@@ -0,0 +1,79 @@
+//! Compares copying a framebuffer from PSRAM to internal RAM with the CPU and
+//! with the DMA.
+//!
+//! You need an ESP32-S3 with at least 2 MB of PSRAM memory.
+
+//% CHIPS: esp32s3
+//% FEATURES: psram-2m
+
+#![no_std]
+#![no_main]
+
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    dma::{mem_copy, Dma, DmaPriority, Mem2Mem},
+    dma_descriptors,
+    peripherals::Peripherals,
+    prelude::*,
+    psram,
+    system::SystemControl,
+};
+use esp_println::println;
+
+const FRAMEBUFFER_SIZE: usize = 200 * 1024;
+
+static mut INTERNAL: [u8; FRAMEBUFFER_SIZE] = [0; FRAMEBUFFER_SIZE];
+
+#[entry]
+fn main() -> ! {
+    #[cfg(debug_assertions)]
+    compile_error!("PSRAM example must be built in release mode!");
+
+    let peripherals = Peripherals::take();
+
+    psram::init_psram(peripherals.PSRAM);
+
+    let system = SystemControl::new(peripherals.SYSTEM);
+    let _clocks = ClockControl::max(system.clock_control).freeze();
+
+    let external = unsafe {
+        core::slice::from_raw_parts_mut(psram::psram_vaddr_start() as *mut u8, FRAMEBUFFER_SIZE)
+    };
+    let internal = unsafe { &mut *core::ptr::addr_of_mut!(INTERNAL) };
+
+    for (i, byte) in external.iter_mut().enumerate() {
+        *byte = (i ^ (i >> 8)) as u8;
+    }
+
+    let start = esp_hal::time::current_time();
+    internal.copy_from_slice(external);
+    let cpu = esp_hal::time::current_time() - start;
+    println!(
+        "CPU copy of {} bytes: {} us",
+        FRAMEBUFFER_SIZE,
+        cpu.to_micros()
+    );
+
+    internal.fill(0);
+
+    let (tx_descriptors, rx_descriptors) = dma_descriptors!(32 * 1024);
+    let dma = Dma::new(peripherals.DMA);
+    let channel = dma.channel0.configure(false, DmaPriority::Priority0);
+    let mut mem2mem =
+        Mem2Mem::new(channel, peripherals.SPI2, tx_descriptors, rx_descriptors).unwrap();
+
+    let start = esp_hal::time::current_time();
+    mem_copy(&mut mem2mem, internal, external).unwrap();
+    let dma = esp_hal::time::current_time() - start;
+    println!(
+        "DMA copy of {} bytes: {} us",
+        FRAMEBUFFER_SIZE,
+        dma.to_micros()
+    );
+
+    assert!(internal[..] == external[..]);
+    println!("done");
+
+    loop {}
+}
@@ -0,0 +1,132 @@
+//! This shows how to refill a circular I2S transfer whenever the DMA finished
+//! sending one of its descriptors.
+//!
+//! The buffer is split into chunks of one descriptor each. While the DMA sends
+//! one chunk, the chunk it finished before is refilled, similar to a classic
+//! ping-pong buffer. Note that a circular transfer always uses at least three
+//! descriptors.
+//!
+//! Without an additional I2S sink device you can inspect the BCLK, WS
+//! and DOUT with a logic analyzer.
+//!
+//! The following wiring is assumed:
+//! - BCLK => GPIO2
+//! - WS   => GPIO4
+//! - DOUT => GPIO5
+
+//% CHIPS: esp32 esp32c3 esp32c6 esp32h2 esp32s2 esp32s3
+//% FEATURES: async embassy embassy-generic-timers
+
+#![no_std]
+#![no_main]
+
+use embassy_executor::Spawner;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    dma::{Dma, DmaPriority},
+    dma_buffers,
+    gpio::Io,
+    i2s::{asynch::*, DataFormat, I2s, Standard},
+    peripherals::Peripherals,
+    prelude::*,
+    system::SystemControl,
+    timer::{timg::TimerGroup, ErasedTimer, OneShotTimer},
+};
+use esp_println::println;
+
+const SINE: [i16; 64] = [
+    0, 3211, 6392, 9511, 12539, 15446, 18204, 20787, 23169, 25329, 27244, 28897, 30272, 31356,
+    32137, 32609, 32767, 32609, 32137, 31356, 30272, 28897, 27244, 25329, 23169, 20787, 18204,
+    15446, 12539, 9511, 6392, 3211, 0, -3211, -6392, -9511, -12539, -15446, -18204, -20787, -23169,
+    -25329, -27244, -28897, -30272, -31356, -32137, -32609, -32767, -32609, -32137, -31356, -30272,
+    -28897, -27244, -25329, -23169, -20787, -18204, -15446, -12539, -9511, -6392, -3211,
+];
+
+// the largest amount of data a single descriptor can hold
+const CHUNK_SIZE: usize = 4092;
+
+// When you are okay with using a nightly compiler it's better to use https://docs.rs/static_cell/2.1.0/static_cell/macro.make_static.html
+macro_rules! mk_static {
+    ($t:ty,$val:expr) => {{
+        static STATIC_CELL: static_cell::StaticCell<$t> = static_cell::StaticCell::new();
+        #[deny(unused_attributes)]
+        let x = STATIC_CELL.uninit().write(($val));
+        x
+    }};
+}
+
+#[esp_hal_embassy::main]
+async fn main(_spawner: Spawner) {
+    println!("Init!");
+    let peripherals = Peripherals::take();
+    let system = SystemControl::new(peripherals.SYSTEM);
+    let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+    let timg0 = TimerGroup::new(peripherals.TIMG0, &clocks);
+    let timer0 = OneShotTimer::new(timg0.timer0.into());
+    let timers = [timer0];
+    let timers = mk_static!([OneShotTimer<ErasedTimer>; 1], timers);
+    esp_hal_embassy::init(&clocks, timers);
+
+    let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+    let dma = Dma::new(peripherals.DMA);
+    #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+    let dma_channel = dma.i2s0channel;
+    #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+    let dma_channel = dma.channel0;
+
+    let (tx_buffer, tx_descriptors, _, rx_descriptors) = dma_buffers!(3 * CHUNK_SIZE, 0);
+
+    let i2s = I2s::new(
+        peripherals.I2S0,
+        Standard::Philips,
+        DataFormat::Data16Channel16,
+        44100u32.Hz(),
+        dma_channel.configure_for_async(false, DmaPriority::Priority0),
+        tx_descriptors,
+        rx_descriptors,
+        &clocks,
+    );
+
+    let i2s_tx = i2s
+        .i2s_tx
+        .with_bclk(io.pins.gpio2)
+        .with_ws(io.pins.gpio4)
+        .with_dout(io.pins.gpio5)
+        .build();
+
+    let data =
+        unsafe { core::slice::from_raw_parts(&SINE as *const _ as *const u8, SINE.len() * 2) };
+
+    let mut idx = 0;
+    for byte in tx_buffer.iter_mut() {
+        *byte = data[idx];
+        idx = (idx + 1) % data.len();
+    }
+
+    let mut chunk = [0u8; CHUNK_SIZE];
+
+    println!("Start");
+    let mut transaction = i2s_tx.write_dma_circular_async(tx_buffer).unwrap();
+    loop {
+        let completed = transaction.wait_for_descriptor().await.unwrap();
+        if completed.skipped > 0 {
+            println!(
+                "Descriptor {} done, missed {} before",
+                completed.index, completed.skipped
+            );
+        }
+
+        // refill everything the DMA is done with
+        let available = transaction.available().await.unwrap();
+        let len = usize::min(available, chunk.len());
+        for byte in chunk[..len].iter_mut() {
+            *byte = data[idx];
+            idx = (idx + 1) % data.len();
+        }
+
+        transaction.push(&chunk[..len]).await.unwrap();
+    }
+}
@@ -0,0 +1,61 @@
+//! Runs the embassy executor with light sleep while all tasks are waiting.
+//!
+//! The task prints the `embassy-time` uptime every 2 seconds, the chip sleeps
+//! in between.
+
+//% CHIPS: esp32 esp32c3 esp32c6 esp32s3
+//% FEATURES: embassy esp-hal-embassy/integrated-timers
+
+#![no_std]
+#![no_main]
+
+use embassy_time::{Duration, Instant, Timer};
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    entry,
+    peripherals::Peripherals,
+    rtc_cntl::Rtc,
+    system::SystemControl,
+    timer::{timg::TimerGroup, ErasedTimer, OneShotTimer},
+};
+use esp_hal_embassy::Executor;
+use esp_println::println;
+
+// When you are okay with using a nightly compiler it's better to use https://docs.rs/static_cell/2.1.0/static_cell/macro.make_static.html
+macro_rules! mk_static {
+    ($t:ty,$val:expr) => {{
+        static STATIC_CELL: static_cell::StaticCell<$t> = static_cell::StaticCell::new();
+        #[deny(unused_attributes)]
+        let x = STATIC_CELL.uninit().write(($val));
+        x
+    }};
+}
+
+#[embassy_executor::task]
+async fn run() {
+    loop {
+        println!("Awake at {} ms", Instant::now().as_millis());
+        Timer::after(Duration::from_secs(2)).await;
+    }
+}
+
+#[entry]
+fn main() -> ! {
+    let peripherals = Peripherals::take();
+    let system = SystemControl::new(peripherals.SYSTEM);
+    let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+    let mut rtc = Rtc::new(peripherals.LPWR);
+
+    let timg0 = TimerGroup::new(peripherals.TIMG0, &clocks);
+    let timer0 = OneShotTimer::new(timg0.timer0.into());
+    let timers = [timer0];
+    let timers = mk_static!([OneShotTimer<ErasedTimer>; 1], timers);
+    esp_hal_embassy::init(&clocks, timers);
+
+    let executor = mk_static!(Executor, Executor::new());
+    executor.run_with_light_sleep(&mut rtc, &[], |spawner| {
+        spawner.spawn(run()).ok();
+    });
+}
@@ -102,9 +102,9 @@ async fn main(spawner: Spawner) {
 
     loop {
         println!("receive");
-        channel.receive(&mut data).await.unwrap();
+        let received = channel.receive(&mut data).await.unwrap();
         let mut total = 0usize;
-        for entry in &data[..data.len()] {
+        for entry in &data[..received] {
             if entry.length1 == 0 {
                 break;
             }
@@ -116,7 +116,7 @@ async fn main(spawner: Spawner) {
             total += entry.length2 as usize;
         }
 
-        for entry in &data[..data.len()] {
+        for entry in &data[..received] {
             if entry.length1 == 0 {
                 break;
             }
@@ -0,0 +1,85 @@
+//! This shows how to start a DMA transfer with the Event Task Matrix
+//!
+//! The TX side of a memory to memory transfer is started by the alarm of
+//! timer0, the transfer is prepared again after every alarm.
+//! Notice you need to import the traits esp_hal::dma::etm::{DmaEtmEvents,
+//! DmaEtmTasks} and esp_hal::timer::timg::etm::TimerEtmEvents
+
+//% FEATURES: esp-hal/log
+//% CHIPS: esp32c6 esp32h2
+
+#![no_std]
+#![no_main]
+
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    dma::{etm::DmaEtmTasks, Dma, DmaPriority, Mem2Mem},
+    dma_buffers,
+    etm::Etm,
+    peripherals::Peripherals,
+    prelude::*,
+    system::SystemControl,
+    timer::timg::{etm::TimerEtmEvents, TimerGroup},
+};
+use log::info;
+
+const DATA_SIZE: usize = 1024;
+
+#[entry]
+fn main() -> ! {
+    esp_println::logger::init_logger(log::LevelFilter::Info);
+
+    let peripherals = Peripherals::take();
+    let system = SystemControl::new(peripherals.SYSTEM);
+    let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+    let timg0 = TimerGroup::new(peripherals.TIMG0, &clocks);
+    let timer0 = timg0.timer0;
+
+    let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) = dma_buffers!(DATA_SIZE);
+
+    let dma = Dma::new(peripherals.DMA);
+
+    // Configure ETM to start the TX side of the DMA channel when the alarm is
+    // triggered
+    let event = timer0.on_alarm();
+    let task = dma.channel0.task_start_out();
+
+    let etm = Etm::new(peripherals.SOC_ETM);
+    let channel0 = etm.channel0;
+    let _configured_channel = channel0.setup(&event, &task);
+
+    let channel = dma.channel0.configure(false, DmaPriority::Priority0);
+    let mut mem2mem = Mem2Mem::new(
+        channel,
+        peripherals.MEM2MEM1,
+        tx_descriptors,
+        rx_descriptors,
+    )
+    .unwrap();
+
+    // Alarm every second, the counter is reloaded to 0 on alarm
+    // 80 / 2 (default divider) timer clock cycles == 1 us
+    timer0.load_alarm_value(1_000 * 1_000 * 40);
+    timer0.set_auto_reload(true);
+    timer0.set_counter_active(true);
+
+    let mut round = 0u8;
+    loop {
+        tx_buffer.fill(round);
+
+        // The transfer doesn't start before the alarm triggers the ETM task
+        let transfer = mem2mem.start_transfer(&tx_buffer, &mut rx_buffer).unwrap();
+        timer0.set_alarm_active(true);
+        transfer.wait().unwrap();
+
+        info!(
+            "Transfer {} completed, buffers equal: {}",
+            round,
+            rx_buffer == tx_buffer
+        );
+
+        round = round.wrapping_add(1);
+    }
+}
@@ -0,0 +1,51 @@
+//! Demonstrates deep sleep with timer wakeup, counting the wakeups in RTC
+//! memory
+
+//% CHIPS: esp32 esp32c3 esp32c6 esp32s3
+
+#![no_std]
+#![no_main]
+
+use core::time::Duration;
+
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    delay::Delay,
+    entry,
+    peripherals::Peripherals,
+    rtc_cntl::{
+        get_wakeup_cause,
+        sleep::{DeepSleep, TimerWakeupSource, WakeSource},
+        Rtc,
+    },
+    system::SystemControl,
+};
+use esp_println::println;
+
+#[entry]
+fn main() -> ! {
+    let peripherals = Peripherals::take();
+    let system = SystemControl::new(peripherals.SYSTEM);
+    let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+    let delay = Delay::new(&clocks);
+    let mut rtc = Rtc::new(peripherals.LPWR);
+
+    println!("up and runnning!");
+    let wake_reason = get_wakeup_cause();
+    println!("wake reason: {:?}", wake_reason);
+
+    // `None` after a power-on reset
+    let wakeups = DeepSleep::load_from_rtc::<u32>().unwrap_or(0);
+    println!("woke up {} times", wakeups);
+
+    let timer = TimerWakeupSource::new(Duration::from_secs(5));
+    let wake_sources: [&dyn WakeSource; 1] = [&timer];
+    let sleep = DeepSleep::new(&wake_sources);
+    sleep.save_to_rtc(&(wakeups + 1));
+
+    println!("sleeping!");
+    delay.delay_millis(100);
+    sleep.start(&mut rtc);
+}
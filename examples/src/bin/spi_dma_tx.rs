@@ -0,0 +1,80 @@
+//! SPI write-only DMA, e.g. to drive a display
+//!
+//! The following wiring is assumed:
+//! - SCLK => GPIO0
+//! - MOSI => GPIO4
+//! - CS   => GPIO5
+//!
+//! Depending on your target and the board you are using you have to change the
+//! pins.
+//!
+//! This example sends a 240x135 RGB565 frame repeatedly. Only the TX half of
+//! the DMA channel is used, so no RX descriptors are needed.
+
+//% CHIPS: esp32 esp32c2 esp32c3 esp32c6 esp32h2 esp32s2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    delay::Delay,
+    dma::{Dma, DmaPriority},
+    dma_buffers,
+    gpio::Io,
+    peripherals::Peripherals,
+    prelude::*,
+    spi::{master::Spi, SpiMode},
+    system::SystemControl,
+};
+use esp_println::println;
+
+const FRAME_SIZE: usize = 240 * 135 * 2;
+
+#[entry]
+fn main() -> ! {
+    let peripherals = Peripherals::take();
+    let system = SystemControl::new(peripherals.SYSTEM);
+    let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+    let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+    let sclk = io.pins.gpio0;
+    let mosi = io.pins.gpio4;
+    let cs = io.pins.gpio5;
+
+    let dma = Dma::new(peripherals.DMA);
+
+    #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+    let dma_channel = dma.spi2channel;
+    #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+    let dma_channel = dma.channel0;
+
+    let (frame, tx_descriptors, _, _) = dma_buffers!(FRAME_SIZE, 0);
+
+    let (dma_tx, _) = dma_channel.configure(false, DmaPriority::Priority0).split();
+
+    let mut spi = Spi::new(peripherals.SPI2, 20.MHz(), SpiMode::Mode0, &clocks)
+        .with_sck(sclk)
+        .with_mosi(mosi)
+        .with_cs(cs)
+        .with_dma_tx(dma_tx, tx_descriptors);
+
+    let delay = Delay::new(&clocks);
+
+    let mut color: u16 = 0;
+    loop {
+        for pixel in frame.chunks_exact_mut(2) {
+            pixel.copy_from_slice(&color.to_be_bytes());
+        }
+
+        // sent in multiple SPI transactions with CS kept asserted
+        let transfer = spi.dma_write(&frame).unwrap();
+        // here we could render the next frame while the DMA transfer is in progress
+        transfer.wait().unwrap();
+        println!("frame sent, color {:04x}", color);
+        color = color.wrapping_add(0x0841);
+
+        delay.delay_millis(500);
+    }
+}
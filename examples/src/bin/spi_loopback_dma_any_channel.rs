@@ -0,0 +1,107 @@
+//! SPI loopback test using a type-erased DMA channel
+//!
+//! The following wiring is assumed:
+//! - SCLK => GPIO0
+//! - MISO => GPIO2
+//! - MOSI => GPIO4
+//! - CS   => GPIO5
+//!
+//! Depending on your target and the board you are using you have to change the
+//! pins.
+//!
+//! This example degrades the DMA channel into an `AnyDmaChannel`, which allows
+//! storing the SPI driver in a `static` without naming the concrete channel.
+//! Connect MISO and MOSI pins to see the outgoing data is read as incoming
+//! data.
+
+//% CHIPS: esp32c2 esp32c3 esp32c6 esp32h2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use core::cell::RefCell;
+
+use critical_section::Mutex;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    delay::Delay,
+    dma::{AnyDmaChannel, Dma, DmaPriority},
+    dma_buffers,
+    gpio::Io,
+    peripherals::{Peripherals, SPI2},
+    prelude::*,
+    spi::{
+        master::{dma::SpiDma, prelude::*, Spi},
+        FullDuplexMode,
+        SpiMode,
+    },
+    system::SystemControl,
+    Blocking,
+};
+use esp_println::println;
+
+type SpiDmaDriver = SpiDma<'static, SPI2, AnyDmaChannel, FullDuplexMode, Blocking>;
+
+static SPI: Mutex<RefCell<Option<SpiDmaDriver>>> = Mutex::new(RefCell::new(None));
+
+#[entry]
+fn main() -> ! {
+    let peripherals = Peripherals::take();
+    let system = SystemControl::new(peripherals.SYSTEM);
+    let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+    let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+    let sclk = io.pins.gpio0;
+    let miso = io.pins.gpio2;
+    let mosi = io.pins.gpio4;
+    let cs = io.pins.gpio5;
+
+    let dma = Dma::new(peripherals.DMA);
+    // The type of the degraded channel doesn't depend on the channel number.
+    let dma_channel = dma
+        .channel0
+        .configure(false, DmaPriority::Priority0)
+        .degrade();
+
+    let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) = dma_buffers!(320);
+
+    let spi = Spi::new(peripherals.SPI2, 100.kHz(), SpiMode::Mode0, &clocks)
+        .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+        .with_dma(dma_channel, tx_descriptors, rx_descriptors);
+
+    critical_section::with(|cs| SPI.borrow_ref_mut(cs).replace(spi));
+
+    let delay = Delay::new(&clocks);
+
+    // DMA buffer require a static life-time
+    let mut send = tx_buffer;
+    let mut receive = rx_buffer;
+    let mut i = 0;
+
+    for (i, v) in send.iter_mut().enumerate() {
+        *v = (i % 255) as u8;
+    }
+
+    loop {
+        send[0] = i;
+        send[send.len() - 1] = i;
+        i = i.wrapping_add(1);
+
+        critical_section::with(|cs| {
+            let mut spi = SPI.borrow_ref_mut(cs);
+            let spi = spi.as_mut().unwrap();
+
+            let transfer = spi.dma_transfer(&mut send, &mut receive).unwrap();
+            transfer.wait().unwrap();
+        });
+
+        println!(
+            "{:x?} .. {:x?}",
+            &receive[..10],
+            &receive[receive.len() - 10..]
+        );
+
+        delay.delay_millis(250);
+    }
+}
@@ -0,0 +1,34 @@
+//! Periodically reads the temperature of the die using the built-in
+//! temperature sensor.
+
+//% CHIPS: esp32s3
+
+#![no_std]
+#![no_main]
+
+use esp_backtrace as _;
+use esp_hal::{
+    analog::temperature_sensor::TemperatureSensor,
+    clock::ClockControl,
+    delay::Delay,
+    peripherals::Peripherals,
+    prelude::*,
+    system::SystemControl,
+};
+use esp_println::println;
+
+#[entry]
+fn main() -> ! {
+    let peripherals = Peripherals::take();
+    let system = SystemControl::new(peripherals.SYSTEM);
+    let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+    let mut sensor = TemperatureSensor::new(peripherals.TSENS);
+
+    let delay = Delay::new(&clocks);
+
+    loop {
+        println!("Temperature = {:.1} °C", sensor.read_celsius());
+        delay.delay_millis(1500);
+    }
+}
@@ -1,7 +1,7 @@
 //! Access point
 //!
 //! Creates an open access-point with SSID `esp-wifi`.
-//! You can connect to it using a static IP in range 192.168.2.2 .. 192.168.2.255, gateway 192.168.2.1
+//! The connected stations get an address in range 192.168.2.2 .. 192.168.2.254 via DHCP, gateway 192.168.2.1
 //!
 //! Open http://192.168.2.1:8080/ in your browser
 //!
@@ -81,23 +81,31 @@ fn main() -> ! {
     println!("{:?}", controller.get_capabilities());
 
     wifi_stack
-        .set_iface_configuration(&esp_wifi::wifi::ipv4::Configuration::Client(
-            esp_wifi::wifi::ipv4::ClientConfiguration::Fixed(
-                esp_wifi::wifi::ipv4::ClientSettings {
-                    ip: esp_wifi::wifi::ipv4::Ipv4Addr::from(parse_ip("192.168.2.1")),
-                    subnet: esp_wifi::wifi::ipv4::Subnet {
-                        gateway: esp_wifi::wifi::ipv4::Ipv4Addr::from(parse_ip("192.168.2.1")),
-                        mask: esp_wifi::wifi::ipv4::Mask(24),
-                    },
-                    dns: None,
-                    secondary_dns: None,
+        .set_iface_configuration(&esp_wifi::wifi::ipv4::Configuration::Router(
+            esp_wifi::wifi::ipv4::RouterConfiguration {
+                subnet: esp_wifi::wifi::ipv4::Subnet {
+                    gateway: esp_wifi::wifi::ipv4::Ipv4Addr::from(parse_ip("192.168.2.1")),
+                    mask: esp_wifi::wifi::ipv4::Mask(24),
                 },
-            ),
+                dhcp_enabled: true,
+                dns: None,
+                secondary_dns: None,
+            },
         ))
         .unwrap();
 
+    let mut dhcp_rx_meta = [smoltcp::socket::udp::PacketMetadata::EMPTY; 4];
+    let mut dhcp_rx_buffer = [0u8; 1536];
+    let mut dhcp_tx_meta = [smoltcp::socket::udp::PacketMetadata::EMPTY; 4];
+    let mut dhcp_tx_buffer = [0u8; 1536];
+    wifi_stack.start_dhcp_server(
+        &mut dhcp_rx_meta,
+        &mut dhcp_rx_buffer,
+        &mut dhcp_tx_meta,
+        &mut dhcp_tx_buffer,
+    );
+
     println!("Start busy loop on main. Connect to the AP `esp-wifi` and point your browser to http://192.168.2.1:8080/");
-    println!("Use a static IP in the range 192.168.2.2 .. 192.168.2.255, use gateway 192.168.2.1");
 
     let mut rx_buffer = [0u8; 1536];
     let mut tx_buffer = [0u8; 1536];
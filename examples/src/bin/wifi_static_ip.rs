@@ -117,19 +117,15 @@ fn main() -> ! {
     println!("Setting static IP {}", STATIC_IP);
 
     wifi_stack
-        .set_iface_configuration(&esp_wifi::wifi::ipv4::Configuration::Client(
-            esp_wifi::wifi::ipv4::ClientConfiguration::Fixed(
-                esp_wifi::wifi::ipv4::ClientSettings {
-                    ip: esp_wifi::wifi::ipv4::Ipv4Addr::from(parse_ip(STATIC_IP)),
-                    subnet: esp_wifi::wifi::ipv4::Subnet {
-                        gateway: esp_wifi::wifi::ipv4::Ipv4Addr::from(parse_ip(GATEWAY_IP)),
-                        mask: esp_wifi::wifi::ipv4::Mask(24),
-                    },
-                    dns: None,
-                    secondary_dns: None,
-                },
-            ),
-        ))
+        .set_iface_configuration(
+            &esp_wifi::wifi::ipv4::Configuration::static_ip(
+                esp_wifi::wifi::ipv4::Ipv4Addr::from(parse_ip(STATIC_IP)),
+                esp_wifi::wifi::ipv4::Ipv4Addr::new(255, 255, 255, 0),
+                esp_wifi::wifi::ipv4::Ipv4Addr::from(parse_ip(GATEWAY_IP)),
+                None,
+            )
+            .unwrap(),
+        )
         .unwrap();
 
     println!(
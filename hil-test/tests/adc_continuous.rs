@@ -0,0 +1,195 @@
+//! Continuous ADC Test
+//!
+//! It's assumed GPIO2 is connected to GPIO3
+//!
+//! Samples GPIO2 using the RX half of a split DMA channel while GPIO3 drives
+//! the pin, once per read and continuously into a ring buffer. GPIO3 is also
+//! driven by SPI using the TX half of the same channel.
+
+//% CHIPS: esp32c3 esp32c6 esp32h2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    analog::adc::{AdcConfig, AdcContinuous, AdcSample, Attenuation},
+    clock::ClockControl,
+    dma::{Dma, DmaPriority},
+    dma_buffers,
+    gpio::{Io, Level, Output},
+    peripherals::Peripherals,
+    prelude::*,
+    spi::{master::Spi, SpiMode},
+    system::SystemControl,
+};
+
+/// The ADC1 channel of GPIO2
+#[cfg(any(esp32c3, esp32c6))]
+const GPIO2_CHANNEL: u8 = 2;
+#[cfg(any(esp32h2, esp32s3))]
+const GPIO2_CHANNEL: u8 = 1;
+
+#[cfg(test)]
+#[embedded_test::tests(executor = esp_hal_embassy::Executor::new())]
+mod tests {
+    use super::*;
+
+    #[init]
+    async fn init() {}
+
+    #[test]
+    #[timeout(3)]
+    async fn test_adc_continuous_on_rx_half() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let _driver = Output::new(io.pins.gpio3, Level::High);
+
+        let mut adc1_config = AdcConfig::new();
+        let _pin = adc1_config.enable_pin(io.pins.gpio2, Attenuation::Attenuation11dB);
+
+        let dma = Dma::new(peripherals.DMA);
+        let (_tx, rx) = dma
+            .channel0
+            .configure_for_async(false, DmaPriority::Priority0)
+            .split();
+
+        let (_, _, rx_buffer, rx_descriptors) = dma_buffers!(0, 400);
+
+        let mut adc = AdcContinuous::new(
+            peripherals.ADC1,
+            adc1_config,
+            rx,
+            rx_descriptors,
+            rx_buffer,
+            20.kHz(),
+            &clocks,
+        )
+        .unwrap();
+
+        for _ in 0..2 {
+            let samples = adc.read_samples().await.unwrap();
+
+            assert_eq!(samples.len(), 100);
+            // The pin is driven high, so the readings are close to full scale.
+            assert!(samples.iter().all(|&sample| sample > 3000));
+        }
+    }
+
+    #[test]
+    #[timeout(3)]
+    async fn test_spi_tx_and_adc_on_channel_halves() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let mut adc1_config = AdcConfig::new();
+        let _pin = adc1_config.enable_pin(io.pins.gpio2, Attenuation::Attenuation11dB);
+
+        let dma = Dma::new(peripherals.DMA);
+        let (tx, rx) = dma
+            .channel0
+            .configure_for_async(false, DmaPriority::Priority0)
+            .split();
+
+        let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) = dma_buffers!(32000, 400);
+
+        // SPI drives GPIO3 through the TX half while the ADC samples it through
+        // the RX half
+        let mut spi = Spi::new(peripherals.SPI2, 1.MHz(), SpiMode::Mode0, &clocks)
+            .with_sck(io.pins.gpio0)
+            .with_mosi(io.pins.gpio3)
+            .with_dma_tx(tx, tx_descriptors);
+
+        let mut adc = AdcContinuous::new(
+            peripherals.ADC1,
+            adc1_config,
+            rx,
+            rx_descriptors,
+            rx_buffer,
+            20.kHz(),
+            &clocks,
+        )
+        .unwrap();
+
+        tx_buffer.fill(0xff);
+        let transfer = spi.dma_write(&tx_buffer).unwrap();
+
+        for _ in 0..2 {
+            let samples = adc.read_samples().await.unwrap();
+
+            assert_eq!(samples.len(), 100);
+            assert!(samples.iter().all(|&sample| sample > 3000));
+        }
+
+        // sending 32000 bytes at 1 MHz takes much longer than the readings
+        assert!(!transfer.is_done());
+        transfer.wait().unwrap();
+    }
+
+    #[test]
+    #[timeout(3)]
+    async fn test_adc_continuous_ring() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let mut driver = Output::new(io.pins.gpio3, Level::High);
+
+        let mut adc1_config = AdcConfig::new();
+        let _pin = adc1_config.enable_pin(io.pins.gpio2, Attenuation::Attenuation11dB);
+
+        let dma = Dma::new(peripherals.DMA);
+        let (_tx, rx) = dma
+            .channel0
+            .configure_for_async(false, DmaPriority::Priority0)
+            .split();
+
+        let (_, _, rx_buffer, rx_descriptors) = dma_buffers!(0, 4000);
+
+        let mut adc = AdcContinuous::new(
+            peripherals.ADC1,
+            adc1_config,
+            rx,
+            rx_descriptors,
+            rx_buffer,
+            20.kHz(),
+            &clocks,
+        )
+        .unwrap();
+
+        // The pin is driven high first, then low. The readings are close to
+        // full scale resp. zero and hardly vary.
+        for (level, expected_mean) in [(Level::High, 3000..=4095), (Level::Low, 0..=500)] {
+            driver.set_level(level);
+
+            let mut transfer = adc.start().unwrap();
+            let mut samples = [AdcSample::default(); 2000];
+            let mut received = 0;
+            while received < samples.len() {
+                received += transfer.read_async(&mut samples[received..]).await.unwrap();
+            }
+            drop(transfer);
+
+            assert!(samples.iter().all(|sample| sample.channel == GPIO2_CHANNEL));
+
+            let count = samples.len() as u32;
+            let mean = samples.iter().map(|s| s.value as u32).sum::<u32>() / count;
+            let variance = samples
+                .iter()
+                .map(|s| (s.value as i32 - mean as i32).pow(2) as u32)
+                .sum::<u32>()
+                / count;
+
+            assert!(expected_mean.contains(&mean));
+            assert!(variance < 400);
+        }
+    }
+}
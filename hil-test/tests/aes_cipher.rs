@@ -0,0 +1,104 @@
+//! AES block cipher mode tests, using the `cipher` traits
+
+//% CHIPS: esp32 esp32c3 esp32c6 esp32h2 esp32s2 esp32s3
+//% FEATURES: cipher
+
+#![no_std]
+#![no_main]
+
+use cbc::cipher::{
+    generic_array::GenericArray,
+    BlockDecryptMut,
+    BlockEncryptMut,
+    InnerIvInit,
+    StreamCipher,
+    StreamCipherSeek,
+};
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    aes::{Aes, AesCipher},
+    peripherals::Peripherals,
+};
+use hex_literal::hex;
+
+// Test vectors of NIST SP 800-38A, F.2.1 and F.5.1
+const KEY: [u8; 16] = hex!("2b7e151628aed2a6abf7158809cf4f3c");
+const PLAINTEXT: [u8; 32] = hex!(
+    "6bc1bee22e409f96e93d7e117393172a"
+    "ae2d8a571e03ac9c9eb76fac45af8e51"
+);
+const CBC_IV: [u8; 16] = hex!("000102030405060708090a0b0c0d0e0f");
+const CBC_CIPHERTEXT: [u8; 32] = hex!(
+    "7649abac8119b246cee98e9b12e9197d"
+    "5086cb9b507219ee95db113a917678b2"
+);
+const CTR_COUNTER: [u8; 16] = hex!("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff");
+const CTR_CIPHERTEXT: [u8; 32] = hex!(
+    "874d6191b620e3261bef6864990db6ce"
+    "9806f66b7970fdff8617187bb9fffdff"
+);
+
+struct Context<'a> {
+    aes: Aes<'a>,
+}
+
+impl Context<'_> {
+    pub fn init() -> Self {
+        let peripherals = Peripherals::take();
+        let aes = Aes::new(peripherals.AES);
+
+        Context { aes }
+    }
+}
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::assert_eq;
+
+    use super::*;
+
+    #[init]
+    fn init() -> Context<'static> {
+        Context::init()
+    }
+
+    #[test]
+    fn test_aes_cbc(mut ctx: Context<'static>) {
+        let mut buffer = PLAINTEXT;
+
+        let mut encryptor =
+            cbc::Encryptor::inner_iv_init(AesCipher::new(&mut ctx.aes, KEY), &CBC_IV.into());
+        for block in buffer.chunks_exact_mut(16) {
+            encryptor.encrypt_block_mut(GenericArray::from_mut_slice(block));
+        }
+        assert_eq!(buffer, CBC_CIPHERTEXT);
+
+        let mut decryptor =
+            cbc::Decryptor::inner_iv_init(AesCipher::new(&mut ctx.aes, KEY), &CBC_IV.into());
+        for block in buffer.chunks_exact_mut(16) {
+            decryptor.decrypt_block_mut(GenericArray::from_mut_slice(block));
+        }
+        assert_eq!(buffer, PLAINTEXT);
+    }
+
+    #[test]
+    fn test_aes_ctr(mut ctx: Context<'static>) {
+        let mut buffer = PLAINTEXT;
+
+        let mut ctr =
+            ctr::Ctr128BE::inner_iv_init(AesCipher::new(&mut ctx.aes, KEY), &CTR_COUNTER.into());
+        ctr.apply_keystream(&mut buffer);
+        assert_eq!(buffer, CTR_CIPHERTEXT);
+
+        // Decrypt the second block only, after seeking to it
+        ctr.seek(16u32);
+        ctr.apply_keystream(&mut buffer[16..]);
+        assert_eq!(buffer[16..], PLAINTEXT[16..]);
+
+        ctr.seek(0u32);
+        ctr.apply_keystream(&mut buffer[..16]);
+        assert_eq!(buffer, PLAINTEXT);
+    }
+}
@@ -0,0 +1,82 @@
+//! CPU clock switch test
+//!
+//! Switches the CPU clock at runtime and checks that code runs faster at the
+//! higher frequency, and as fast as before after switching back. The CPU clock
+//! reported by `Clocks` is decoded from the clock registers.
+
+//% CHIPS: esp32 esp32c2 esp32c3 esp32c6 esp32s2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::{Clock, ClockControl, Clocks, CpuClock},
+    peripherals::Peripherals,
+    system::SystemControl,
+};
+
+struct Context {
+    clocks: Clocks<'static>,
+}
+
+impl Context {
+    pub fn init() -> Self {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::configure(system.clock_control, CpuClock::Clock80MHz).freeze();
+
+        Context { clocks }
+    }
+}
+
+/// Returns how many microseconds a fixed amount of work took.
+fn busy_loop_duration() -> u64 {
+    let start = esp_hal::time::current_time();
+    for i in 0..100_000u32 {
+        core::hint::black_box(i);
+    }
+    (esp_hal::time::current_time() - start).to_micros()
+}
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::{assert, assert_eq};
+
+    use super::*;
+
+    #[init]
+    fn init() -> Context {
+        Context::init()
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_switch_cpu_clock(mut ctx: Context) {
+        #[cfg(feature = "esp32c2")]
+        let fast = CpuClock::Clock120MHz;
+        #[cfg(any(feature = "esp32c3", feature = "esp32c6"))]
+        let fast = CpuClock::Clock160MHz;
+        #[cfg(any(feature = "esp32", feature = "esp32s2", feature = "esp32s3"))]
+        let fast = CpuClock::Clock240MHz;
+
+        // Decoded from the clock registers set up by `ClockControl::configure`
+        assert_eq!(ctx.clocks.cpu_clock().to_MHz(), 80);
+
+        // Fill the cache
+        busy_loop_duration();
+        let slow_duration = busy_loop_duration();
+
+        ctx.clocks.set_cpu_clock(fast);
+        assert_eq!(ctx.clocks.cpu_clock().to_MHz(), fast.mhz());
+        let fast_duration = busy_loop_duration();
+        assert!(fast_duration * 4 < slow_duration * 3);
+
+        ctx.clocks.set_cpu_clock(CpuClock::Clock80MHz);
+        assert_eq!(ctx.clocks.cpu_clock().to_MHz(), 80);
+        let duration = busy_loop_duration();
+        assert!(duration * 4 > slow_duration * 3 && duration * 3 < slow_duration * 4);
+    }
+}
@@ -0,0 +1,59 @@
+//! Async Delay Test
+
+//% CHIPS: esp32 esp32c2 esp32c3 esp32c6 esp32h2 esp32s2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    delay::Delay,
+    peripherals::Peripherals,
+    prelude::*,
+    system::SystemControl,
+    timer::{timg::TimerGroup, Timer as _},
+};
+
+#[cfg(test)]
+#[embedded_test::tests(executor = esp_hal_embassy::Executor::new())]
+mod tests {
+    use super::*;
+
+    #[init]
+    async fn init() {}
+
+    #[test]
+    #[timeout(2)]
+    async fn test_timg_delay_async() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let mut timg0 = TimerGroup::new_async(peripherals.TIMG0, &clocks);
+
+        let t1 = esp_hal::time::current_time();
+        timg0.timer0.delay_async(300.millis()).await;
+        let t2 = esp_hal::time::current_time();
+
+        assert!(t2 > t1);
+        assert!((t2 - t1).to_millis() >= 300u64);
+    }
+
+    #[test]
+    #[timeout(2)]
+    async fn test_timg_delay_async_cancelled() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let delay = Delay::new(&clocks);
+        let mut timg0 = TimerGroup::new_async(peripherals.TIMG0, &clocks);
+
+        // dropping the future before it completes disarms the alarm
+        let _ = embassy_futures::poll_once(timg0.timer0.delay_async(10.millis()));
+        delay.delay_millis(20);
+        assert!(!timg0.timer0.is_interrupt_set());
+    }
+}
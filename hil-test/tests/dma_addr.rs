@@ -0,0 +1,116 @@
+//! DMA memory region query tests
+
+//% CHIPS: esp32 esp32c2 esp32c3 esp32c6 esp32h2 esp32s2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::dma::{is_dma_capable, is_external, is_flash, memory_region, MemoryRegion};
+
+// using `static`, not `static mut`, places the array in .rodata
+static FLASH_DATA: [u8; 16] = [0xAA; 16];
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "esp32")] {
+        const DRAM: u32 = 0x3FFB_0000;
+        const DRAM_END: u32 = 0x4000_0000;
+        const DROM: u32 = 0x3F40_0000;
+        const IROM: u32 = 0x400D_0000;
+    } else if #[cfg(feature = "esp32s2")] {
+        const DRAM: u32 = 0x3FFB_0000;
+        const DRAM_END: u32 = 0x4000_0000;
+        const DROM: u32 = 0x3F00_0000;
+        const IROM: u32 = 0x4008_0000;
+    } else if #[cfg(feature = "esp32s3")] {
+        const DRAM: u32 = 0x3FC9_0000;
+        const DRAM_END: u32 = 0x3FD0_0000;
+        const DROM: u32 = 0x3D00_0000;
+        const IROM: u32 = 0x4200_0000;
+    } else if #[cfg(feature = "esp32c2")] {
+        const DRAM: u32 = 0x3FCA_0000;
+        const DRAM_END: u32 = 0x3FCE_0000;
+        const DROM: u32 = 0x3C00_0000;
+        const IROM: u32 = 0x4200_0000;
+    } else if #[cfg(feature = "esp32c3")] {
+        const DRAM: u32 = 0x3FC8_0000;
+        const DRAM_END: u32 = 0x3FCE_0000;
+        const DROM: u32 = 0x3C00_0000;
+        const IROM: u32 = 0x4200_0000;
+    } else if #[cfg(feature = "esp32c6")] {
+        const DRAM: u32 = 0x4080_0000;
+        const DRAM_END: u32 = 0x4088_0000;
+        const DROM: u32 = 0x4280_0000;
+        const IROM: u32 = 0x4200_0000;
+    } else if #[cfg(feature = "esp32h2")] {
+        const DRAM: u32 = 0x4080_0000;
+        const DRAM_END: u32 = 0x4085_0000;
+        const DROM: u32 = 0x4280_0000;
+        const IROM: u32 = 0x4200_0000;
+    }
+}
+
+// peripheral registers on all chips
+const PERIPHERALS: u32 = 0x6000_0000;
+
+fn ptr(address: u32) -> *const u8 {
+    address as *const u8
+}
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::{assert, assert_eq};
+
+    use super::*;
+
+    #[init]
+    fn init() {}
+
+    #[test]
+    fn test_known_addresses() {
+        assert_eq!(memory_region(ptr(DRAM)), MemoryRegion::InternalRam);
+        assert_eq!(memory_region(ptr(DRAM_END - 1)), MemoryRegion::InternalRam);
+        assert_eq!(memory_region(ptr(DROM)), MemoryRegion::Flash);
+        assert_eq!(memory_region(ptr(IROM)), MemoryRegion::Flash);
+        assert_eq!(memory_region(ptr(PERIPHERALS)), MemoryRegion::Other);
+
+        assert!(is_flash(ptr(DROM)));
+        assert!(!is_flash(ptr(DRAM)));
+        assert!(!is_external(ptr(DRAM)));
+        // no PSRAM is mapped in this test
+        assert!(!is_external(ptr(DROM)));
+    }
+
+    #[test]
+    #[cfg(feature = "esp32s2")]
+    fn test_external_ram_range_is_not_flash() {
+        // the data bus maps flash below 0x3F40_0000 and external RAM from
+        // 0x3F50_0000, which must not be reported as flash
+        assert_eq!(memory_region(ptr(0x3F3F_FFFF)), MemoryRegion::Flash);
+        assert_eq!(memory_region(ptr(0x3F50_0000)), MemoryRegion::Other);
+        assert!(!is_flash(ptr(0x3FF7_FFFF)));
+    }
+
+    #[test]
+    fn test_dma_capable_known_addresses() {
+        assert!(is_dma_capable(ptr(DRAM), 16));
+        assert!(is_dma_capable(ptr(DRAM_END - 16), 16));
+        assert!(!is_dma_capable(ptr(DRAM_END - 16), 17));
+        assert!(!is_dma_capable(ptr(DROM), 16));
+        assert!(!is_dma_capable(ptr(IROM), 16));
+        assert!(!is_dma_capable(ptr(PERIPHERALS), 16));
+    }
+
+    #[test]
+    fn test_buffers() {
+        let stack = [0u8; 16];
+
+        assert_eq!(memory_region(stack.as_ptr()), MemoryRegion::InternalRam);
+        assert!(is_dma_capable(stack.as_ptr(), stack.len()));
+
+        assert_eq!(memory_region(FLASH_DATA.as_ptr()), MemoryRegion::Flash);
+        assert!(!is_dma_capable(FLASH_DATA.as_ptr(), FLASH_DATA.len()));
+    }
+}
@@ -0,0 +1,89 @@
+//! GDMA ETM tests
+
+//% CHIPS: esp32c6 esp32h2
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    dma::{
+        etm::{DmaEtmEvents, DmaEtmTasks},
+        Dma,
+        DmaPriority,
+    },
+    etm::{Etm, EtmEvent, EtmTask},
+    peripherals::{Peripherals, DMA},
+    system::SystemControl,
+};
+
+fn in_etm_enabled(channel: usize) -> bool {
+    let dma = unsafe { &*DMA::PTR };
+    dma.ch(channel).in_conf0().read().in_etm_en().bit_is_set()
+}
+
+fn out_etm_enabled(channel: usize) -> bool {
+    let dma = unsafe { &*DMA::PTR };
+    dma.ch(channel).out_conf0().read().out_etm_en().bit_is_set()
+}
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::{assert, assert_eq};
+
+    use super::*;
+
+    #[init]
+    fn init() {}
+
+    #[test]
+    fn test_start_tasks_enable_etm() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let _clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let dma = Dma::new(peripherals.DMA);
+
+        assert!(!in_etm_enabled(1));
+        assert!(!out_etm_enabled(1));
+
+        let task = dma.channel1.task_start_out();
+        assert_eq!(task.id(), 152);
+        assert!(out_etm_enabled(1));
+        assert!(!in_etm_enabled(1));
+
+        let task = dma.channel1.task_start_in();
+        assert_eq!(task.id(), 149);
+        assert!(in_etm_enabled(1));
+
+        // configuring the channel keeps the ETM enabled
+        let _channel = dma.channel1.configure(false, DmaPriority::Priority0);
+        assert!(in_etm_enabled(1));
+        assert!(out_etm_enabled(1));
+
+        // the other channels are not affected
+        assert!(!in_etm_enabled(0));
+        assert!(!out_etm_enabled(0));
+    }
+
+    #[test]
+    fn test_events_can_be_used_with_etm() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let _clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let dma = Dma::new(peripherals.DMA);
+
+        let event = dma.channel0.on_out_total_eof();
+        assert_eq!(event.id(), 171);
+        assert_eq!(dma.channel2.on_in_suc_eof().id(), 158);
+
+        let task = dma.channel2.task_start_out();
+
+        let etm = Etm::new(peripherals.SOC_ETM);
+        let _configured_channel = etm.channel0.setup(&event, &task);
+    }
+}
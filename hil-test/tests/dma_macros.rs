@@ -22,10 +22,36 @@ pub(crate) const fn compute_circular_size(size: usize, chunk_size: usize) -> usi
     }
 }
 
+extern "C" {
+    static _data_start: u32;
+    static _data_end: u32;
+    static _bss_start: u32;
+    static _bss_end: u32;
+}
+
+/// Whether the buffer overlaps `.data`, which is copied from flash on startup,
+/// or `.bss`, which is zeroed on startup
+pub(crate) fn in_initialized_section(buffer: &[u8]) -> bool {
+    let start = buffer.as_ptr() as usize;
+    let end = start + buffer.len();
+    let overlaps =
+        |section_start: usize, section_end: usize| start < section_end && section_start < end;
+
+    unsafe {
+        overlaps(
+            core::ptr::addr_of!(_data_start) as usize,
+            core::ptr::addr_of!(_data_end) as usize,
+        ) || overlaps(
+            core::ptr::addr_of!(_bss_start) as usize,
+            core::ptr::addr_of!(_bss_end) as usize,
+        )
+    }
+}
+
 #[cfg(test)]
 #[embedded_test::tests]
 mod tests {
-    use defmt::assert_eq;
+    use defmt::{assert, assert_eq};
 
     use super::*;
 
@@ -94,6 +120,34 @@ mod tests {
         assert_eq!(rx_descriptors.len(), compute_size(DATA_SIZE, CHUNK_SIZE));
     }
 
+    #[test]
+    fn test_dma_buffers_zeroed() {
+        use esp_hal::dma::is_internal;
+        let (tx_buffer, _, rx_buffer, _) = esp_hal::dma_buffers!(DATA_SIZE, DATA_SIZE / 2);
+        // the buffers live in uninitialized memory and are zeroed when created
+        assert!(tx_buffer.iter().all(|&b| b == 0));
+        assert!(rx_buffer.iter().all(|&b| b == 0));
+        assert!(is_internal(tx_buffer.as_ptr()));
+        assert!(is_internal(rx_buffer.as_ptr()));
+    }
+
+    #[test]
+    fn test_dma_buffers_not_in_initialized_sections() {
+        // the buffers would take up flash space in `.data` and startup time in
+        // `.bss`
+        let (tx_buffer, _, rx_buffer, _) = esp_hal::dma_buffers!(DATA_SIZE, DATA_SIZE / 2);
+        assert!(!in_initialized_section(tx_buffer));
+        assert!(!in_initialized_section(rx_buffer));
+
+        let (tx_buffer, _, rx_buffer, _) = esp_hal::dma_circular_buffers!(DATA_SIZE);
+        assert!(!in_initialized_section(tx_buffer));
+        assert!(!in_initialized_section(rx_buffer));
+
+        let (tx_buffer, _, rx_buffer, _) = esp_hal::dma_buffers_aligned!(DATA_SIZE, DATA_SIZE, 32);
+        assert!(!in_initialized_section(tx_buffer));
+        assert!(!in_initialized_section(rx_buffer));
+    }
+
     #[test]
     fn test_dma_buffers_different_size() {
         use esp_hal::dma::CHUNK_SIZE;
@@ -202,4 +256,41 @@ mod tests {
             compute_circular_size(RX_SIZE, CHUNK_SIZE)
         );
     }
+
+    #[test]
+    fn test_dma_buffers_aligned() {
+        const ALIGN: usize = 32;
+        let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) =
+            esp_hal::dma_buffers_aligned!(DATA_SIZE, DATA_SIZE / 2, 32);
+        assert_eq!(tx_buffer.len(), DATA_SIZE);
+        assert_eq!(rx_buffer.len(), DATA_SIZE / 2);
+        assert_eq!(tx_buffer.as_ptr() as usize % ALIGN, 0);
+        assert_eq!(rx_buffer.as_ptr() as usize % ALIGN, 0);
+
+        const CHUNK_SIZE: usize = esp_hal::dma::CHUNK_SIZE / ALIGN * ALIGN;
+        assert_eq!(tx_descriptors.len(), compute_size(DATA_SIZE, CHUNK_SIZE));
+        assert_eq!(
+            rx_descriptors.len(),
+            compute_size(DATA_SIZE / 2, CHUNK_SIZE)
+        );
+    }
+
+    #[test]
+    fn test_dma_buffers_in_section() {
+        use esp_hal::dma::{is_internal, CHUNK_SIZE};
+        let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) =
+            esp_hal::dma_buffers_in_section!(".data.dma_buffers", DATA_SIZE, DATA_SIZE / 2);
+        assert_eq!(tx_buffer.len(), DATA_SIZE);
+        assert_eq!(rx_buffer.len(), DATA_SIZE / 2);
+        assert!(is_internal(tx_buffer.as_ptr()));
+        assert!(is_internal(rx_buffer.as_ptr()));
+
+        assert_eq!(tx_descriptors.len(), compute_size(DATA_SIZE, CHUNK_SIZE));
+        assert_eq!(
+            rx_descriptors.len(),
+            compute_size(DATA_SIZE / 2, CHUNK_SIZE)
+        );
+        assert!(is_internal(tx_descriptors.as_ptr().cast()));
+        assert!(is_internal(rx_descriptors.as_ptr().cast()));
+    }
 }
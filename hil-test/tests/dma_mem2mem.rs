@@ -9,9 +9,29 @@ use defmt_rtt as _;
 use esp_backtrace as _;
 use esp_hal::{
     clock::ClockControl,
-    dma::{Dma, DmaError, DmaPriority, Mem2Mem},
+    dma::{
+        descriptor_count,
+        mem_copy,
+        AnyDmaChannel,
+        BurstSize,
+        ChainError,
+        Channel,
+        ChannelMode,
+        DescriptorChain,
+        Dma,
+        DmaBurstConfig,
+        DmaDescriptor,
+        DmaEligible,
+        DmaError,
+        DmaPriority,
+        Mem2Mem,
+        RxPrivate,
+        TxPrivate,
+        CIRCULAR_MIN_DESCRIPTORS,
+    },
     dma_buffers,
     dma_buffers_chunk_size,
+    dma_circular_descriptors,
     dma_descriptors,
     peripherals::Peripherals,
     system::SystemControl,
@@ -22,7 +42,7 @@ const DATA_SIZE: usize = 1024 * 10;
 #[cfg(test)]
 #[embedded_test::tests]
 mod tests {
-    use defmt::assert_eq;
+    use defmt::{assert, assert_eq};
 
     use super::*;
 
@@ -57,6 +77,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_internal_mem2mem_degraded_channel() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let _clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) = dma_buffers!(DATA_SIZE);
+
+        let dma = Dma::new(peripherals.DMA);
+        let channel: Channel<'_, AnyDmaChannel, _> = dma
+            .channel0
+            .configure(false, DmaPriority::Priority0)
+            .degrade();
+        #[cfg(any(feature = "esp32c2", feature = "esp32c3", feature = "esp32s3"))]
+        let dma_peripheral = peripherals.SPI2;
+        #[cfg(not(any(feature = "esp32c2", feature = "esp32c3", feature = "esp32s3")))]
+        let dma_peripheral = peripherals.MEM2MEM1;
+
+        let mut mem2mem =
+            Mem2Mem::new(channel, dma_peripheral, tx_descriptors, rx_descriptors).unwrap();
+
+        for i in 0..core::mem::size_of_val(tx_buffer) {
+            tx_buffer[i] = (i % 256) as u8;
+        }
+        let dma_wait = mem2mem.start_transfer(&tx_buffer, &mut rx_buffer).unwrap();
+        dma_wait.wait().unwrap();
+        for i in 0..core::mem::size_of_val(tx_buffer) {
+            assert_eq!(rx_buffer[i], tx_buffer[i]);
+        }
+    }
+
     #[test]
     fn test_internal_mem2mem_chunk_size() {
         const CHUNK_SIZE: usize = 2048;
@@ -93,6 +144,130 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_internal_mem2mem_received_bytes() {
+        const SHORT_SIZE: usize = 5000;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let _clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) = dma_buffers!(DATA_SIZE);
+
+        let dma = Dma::new(peripherals.DMA);
+        let channel = dma.channel0.configure(false, DmaPriority::Priority0);
+        #[cfg(any(feature = "esp32c2", feature = "esp32c3", feature = "esp32s3"))]
+        let dma_peripheral = peripherals.SPI2;
+        #[cfg(not(any(feature = "esp32c2", feature = "esp32c3", feature = "esp32s3")))]
+        let dma_peripheral = peripherals.MEM2MEM1;
+
+        let mut mem2mem =
+            Mem2Mem::new(channel, dma_peripheral, tx_descriptors, rx_descriptors).unwrap();
+
+        tx_buffer.fill(0x55);
+
+        // The transfer ends with the TX data, in the middle of the second RX
+        // descriptor
+        let tx_data = &tx_buffer[..SHORT_SIZE];
+        let mut dma_wait = mem2mem.start_transfer(&tx_data, &mut rx_buffer).unwrap();
+        while !dma_wait.is_done() {}
+        assert_eq!(SHORT_SIZE, dma_wait.received_bytes());
+        dma_wait.wait().unwrap();
+
+        assert!(rx_buffer[..SHORT_SIZE].iter().all(|&b| b == 0x55));
+    }
+
+    #[test]
+    fn test_internal_mem2mem_burst_alignment() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let _clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) = dma_buffers!(DATA_SIZE);
+
+        let dma = Dma::new(peripherals.DMA);
+        let channel = dma.channel0.configure_with(
+            DmaBurstConfig {
+                in_burst: BurstSize::Enabled,
+                out_burst: BurstSize::Disabled,
+                ..Default::default()
+            },
+            DmaPriority::Priority0,
+        );
+        #[cfg(any(feature = "esp32c2", feature = "esp32c3", feature = "esp32s3"))]
+        let dma_peripheral = peripherals.SPI2;
+        #[cfg(not(any(feature = "esp32c2", feature = "esp32c3", feature = "esp32s3")))]
+        let dma_peripheral = peripherals.MEM2MEM1;
+
+        let mut mem2mem =
+            Mem2Mem::new(channel, dma_peripheral, tx_descriptors, rx_descriptors).unwrap();
+
+        tx_buffer.fill(0x55);
+        let tx_data = &tx_buffer[..1024];
+
+        // RX burst mode requires word-aligned buffers
+        let offset = rx_buffer.as_ptr().align_offset(4);
+        let (misaligned, aligned) = rx_buffer[offset..].split_at_mut(2048);
+        let mut misaligned = &mut misaligned[1..1025];
+        let mut aligned = &mut aligned[..1024];
+        let misaligned_address = misaligned.as_ptr() as usize;
+
+        assert!(matches!(
+            mem2mem.start_transfer(&tx_data, &mut misaligned),
+            Err(DmaError::InvalidAlignment { address }) if address == misaligned_address
+        ));
+
+        let dma_wait = mem2mem.start_transfer(&tx_data, &mut aligned).unwrap();
+        dma_wait.wait().unwrap();
+        assert!(aligned.iter().all(|&b| b == 0x55));
+    }
+
+    #[test]
+    fn test_mem_copy_odd_sizes() {
+        const CHUNK_SIZE: usize = 1024;
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let _clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let (tx_buffer, _, rx_buffer, _) = dma_buffers!(DATA_SIZE);
+        // a single descriptor each, so larger copies need several transfers
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(CHUNK_SIZE);
+
+        let dma = Dma::new(peripherals.DMA);
+        let channel = dma.channel0.configure(false, DmaPriority::Priority0);
+        #[cfg(any(feature = "esp32c2", feature = "esp32c3", feature = "esp32s3"))]
+        let dma_peripheral = peripherals.SPI2;
+        #[cfg(not(any(feature = "esp32c2", feature = "esp32c3", feature = "esp32s3")))]
+        let dma_peripheral = peripherals.MEM2MEM1;
+
+        let mut mem2mem = Mem2Mem::new_with_chunk_size(
+            channel,
+            dma_peripheral,
+            tx_descriptors,
+            rx_descriptors,
+            CHUNK_SIZE,
+        )
+        .unwrap();
+
+        for i in 0..core::mem::size_of_val(tx_buffer) {
+            tx_buffer[i] = (i % 251) as u8;
+        }
+
+        for len in [1, 3, 4, 1023, 1025, 3001, DATA_SIZE - 1] {
+            rx_buffer.fill(0);
+            mem_copy(&mut mem2mem, &mut rx_buffer[..], &tx_buffer[..len]).unwrap();
+
+            assert_eq!(&rx_buffer[..len], &tx_buffer[..len]);
+            // nothing after the copied bytes is written
+            assert_eq!(rx_buffer[len], 0);
+        }
+
+        assert_eq!(
+            mem_copy(&mut mem2mem, &mut rx_buffer[..2], &tx_buffer[..3]),
+            Err(DmaError::BufferTooSmall)
+        );
+    }
+
     #[test]
     fn test_mem2mem_errors_zero_tx() {
         use esp_hal::dma::CHUNK_SIZE;
@@ -116,7 +291,7 @@ mod tests {
             rx_descriptors,
             CHUNK_SIZE,
         ) {
-            Err(DmaError::OutOfDescriptors) => (),
+            Err(DmaError::OutOfDescriptors { required: 1 }) => (),
             _ => panic!("Expected OutOfDescriptors"),
         }
     }
@@ -144,7 +319,7 @@ mod tests {
             rx_descriptors,
             CHUNK_SIZE,
         ) {
-            Err(DmaError::OutOfDescriptors) => (),
+            Err(DmaError::OutOfDescriptors { required: 1 }) => (),
             _ => panic!("Expected OutOfDescriptors"),
         }
     }
@@ -200,4 +375,467 @@ mod tests {
             _ => panic!("Expected InvalidChunkSize"),
         }
     }
+
+    #[test]
+    fn test_channel_mode() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let _clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let dma = Dma::new(peripherals.DMA);
+        let mut channel = dma.channel0.configure(false, DmaPriority::Priority0);
+        assert_eq!(channel.mode(), ChannelMode::PeripheralTrigger);
+
+        channel.set_mode(ChannelMode::Mem2Mem);
+        assert_eq!(channel.mode(), ChannelMode::Mem2Mem);
+
+        channel.set_mode(ChannelMode::PeripheralTrigger);
+        assert_eq!(channel.mode(), ChannelMode::PeripheralTrigger);
+    }
+
+    #[test]
+    #[cfg(any(feature = "esp32c6", feature = "esp32h2"))]
+    fn test_wrong_channel_mode() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let _clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let (tx_buffer, tx_descriptors, mut rx_buffer, _) = dma_buffers!(1024);
+
+        let dma = Dma::new(peripherals.DMA);
+        let mut channel = dma.channel0.configure(false, DmaPriority::Priority0);
+        let mut chain = DescriptorChain::new(tx_descriptors);
+        chain
+            .fill_for_tx(false, tx_buffer.as_ptr(), tx_buffer.len())
+            .unwrap();
+
+        // the IDs reserved for mem2mem require the mem2mem mode
+        assert_eq!(
+            unsafe {
+                channel
+                    .tx
+                    .prepare_transfer_without_start(peripherals.MEM2MEM1.dma_peripheral(), &chain)
+            },
+            Err(DmaError::WrongChannelMode)
+        );
+
+        // and peripherals require it to be cleared
+        channel.set_mode(ChannelMode::Mem2Mem);
+        assert_eq!(
+            unsafe {
+                channel
+                    .tx
+                    .prepare_transfer_without_start(peripherals.SPI2.dma_peripheral(), &chain)
+            },
+            Err(DmaError::WrongChannelMode)
+        );
+
+        // Mem2Mem can't be used with a peripheral on chips with reserved IDs
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(1024);
+        let mut mem2mem =
+            Mem2Mem::new(channel, peripherals.SPI2, tx_descriptors, rx_descriptors).unwrap();
+        assert!(matches!(
+            mem2mem.start_transfer(&tx_buffer, &mut rx_buffer),
+            Err(DmaError::WrongChannelMode)
+        ));
+    }
+
+    #[test]
+    fn test_descriptor_chain_rejects_unencodable_chunk_sizes() {
+        let (descriptors, _) = dma_descriptors!(1024, 0);
+        assert!(matches!(
+            DescriptorChain::new_with_chunk_size(descriptors, 4093),
+            Err(DmaError::InvalidChunkSize)
+        ));
+
+        let (descriptors, _) = dma_descriptors!(1024, 0);
+        assert!(matches!(
+            DescriptorChain::new_with_chunk_size(descriptors, 65536),
+            Err(DmaError::InvalidChunkSize)
+        ));
+
+        let (descriptors, _) = dma_descriptors!(1024, 0);
+        assert!(DescriptorChain::new_with_chunk_size(descriptors, 4092).is_ok());
+
+        assert!(matches!(
+            DmaDescriptor::new(core::ptr::null_mut(), 65536),
+            Err(DmaError::InvalidChunkSize)
+        ));
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_descriptor_error_reports_descriptor_index() {
+        const SIZE: usize = 8000;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let _clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) = dma_buffers!(SIZE);
+
+        let dma = Dma::new(peripherals.DMA);
+        let mut channel = dma.channel0.configure(false, DmaPriority::Priority0);
+        #[cfg(any(feature = "esp32c2", feature = "esp32c3", feature = "esp32s3"))]
+        let dma_peripheral = peripherals.SPI2.dma_peripheral();
+        #[cfg(not(any(feature = "esp32c2", feature = "esp32c3", feature = "esp32s3")))]
+        let dma_peripheral = peripherals.MEM2MEM1.dma_peripheral();
+
+        // using `static`, not `static mut`, places the array in .rodata
+        static FLASH_DATA: [u8; SIZE] = [42u8; SIZE];
+
+        let mut tx_chain = DescriptorChain::new(tx_descriptors);
+        let mut rx_chain = DescriptorChain::new(rx_descriptors);
+        tx_chain
+            .fill_for_tx(false, tx_buffer.as_ptr(), tx_buffer.len())
+            .unwrap();
+        rx_chain
+            .fill_for_rx(false, rx_buffer.as_mut_ptr(), rx_buffer.len())
+            .unwrap();
+
+        // Point the second descriptor at flash, which the DMA can't access
+        let descriptor = unsafe { &mut *tx_chain.first().cast_mut().add(1) };
+        let mut patched =
+            DmaDescriptor::new(FLASH_DATA.as_ptr().cast_mut(), descriptor.size()).unwrap();
+        patched.set_length(descriptor.len()).unwrap();
+        patched.set_suc_eof(descriptor.has_suc_eof());
+        patched.set_owner(descriptor.owner());
+        *descriptor = patched;
+
+        unsafe {
+            channel
+                .tx
+                .prepare_transfer_without_start(dma_peripheral, &tx_chain)
+                .unwrap();
+            channel
+                .rx
+                .prepare_transfer_without_start(dma_peripheral, &rx_chain)
+                .unwrap();
+        }
+        channel.set_mode(ChannelMode::Mem2Mem);
+        channel.rx.start_transfer().unwrap();
+        channel.tx.start_transfer().unwrap();
+
+        while !channel.tx.has_error() {}
+        channel.tx.stop_transfer();
+        channel.rx.stop_transfer();
+
+        let address = channel.tx.error_descriptor_address().unwrap();
+        assert_eq!(tx_chain.index_of(address), Some(1));
+    }
+
+    #[test]
+    fn test_descriptor_chain_range() {
+        use esp_hal::dma::CHUNK_SIZE;
+
+        const SIZE: usize = 3 * CHUNK_SIZE;
+
+        /// Collects the buffers and sizes of the descriptors up to the last one
+        /// or until the chain links back to the first descriptor
+        fn regions(chain: &DescriptorChain, regions: &mut [(usize, usize); 8]) -> usize {
+            let mut count = 0;
+            let mut descriptor = chain.first();
+            loop {
+                let current = unsafe { &*descriptor };
+                regions[count] = (current.buffer() as usize, current.size());
+                count += 1;
+
+                descriptor = current.next().cast_const();
+                if descriptor.is_null() || descriptor == chain.first() {
+                    break;
+                }
+            }
+            count
+        }
+        let mut found = [(0, 0); 8];
+
+        let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) = dma_buffers!(SIZE);
+        let tx_start = tx_buffer.as_ptr() as usize;
+        let rx_start = rx_buffer.as_ptr() as usize;
+        let mut tx_chain = DescriptorChain::new(tx_descriptors);
+        let mut rx_chain = DescriptorChain::new(rx_descriptors);
+
+        // a window inside the buffer
+        tx_chain
+            .fill_for_tx_range(false, tx_buffer.as_ptr(), SIZE, 100, CHUNK_SIZE + 10)
+            .unwrap();
+        let count = regions(&tx_chain, &mut found);
+        assert_eq!(
+            found[..count],
+            [
+                (tx_start + 100, CHUNK_SIZE),
+                (tx_start + 100 + CHUNK_SIZE, 10)
+            ]
+        );
+
+        rx_chain
+            .fill_for_rx_range(false, rx_buffer.as_mut_ptr(), SIZE, SIZE - 8, 8)
+            .unwrap();
+        let count = regions(&rx_chain, &mut found);
+        assert_eq!(found[..count], [(rx_start + SIZE - 8, 8)]);
+
+        // a circular window spanning the whole buffer wraps around to its start
+        tx_chain
+            .fill_for_tx_range(true, tx_buffer.as_ptr(), SIZE, 100, SIZE)
+            .unwrap();
+        let count = regions(&tx_chain, &mut found);
+        assert_eq!(found[0].0, tx_start + 100);
+        assert_eq!(found[count - 1].0 + found[count - 1].1, tx_start + 100);
+        assert_eq!(found[..count].iter().map(|r| r.1).sum::<usize>(), SIZE);
+
+        rx_chain
+            .fill_for_rx_range(true, rx_buffer.as_mut_ptr(), SIZE, 4, SIZE)
+            .unwrap();
+        let count = regions(&rx_chain, &mut found);
+        assert_eq!(found[0].0, rx_start + 4);
+        assert_eq!(found[count - 1].0, rx_start);
+        assert_eq!(found[..count].iter().map(|r| r.1).sum::<usize>(), SIZE);
+
+        // windows exceeding the buffer are rejected
+        for (circular, offset, len) in [
+            (false, 0, SIZE + 1),
+            (false, 100, SIZE),
+            (false, usize::MAX, 2),
+            (true, SIZE, SIZE),
+            (true, 100, SIZE - 1),
+        ] {
+            assert!(matches!(
+                tx_chain.fill_for_tx_range(circular, tx_buffer.as_ptr(), SIZE, offset, len),
+                Err(DmaError::BufferTooSmall)
+            ));
+            assert!(matches!(
+                rx_chain.fill_for_rx_range(circular, rx_buffer.as_mut_ptr(), SIZE, offset, len),
+                Err(DmaError::BufferTooSmall)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_circular_descriptor_chain_boundary_sizes() {
+        use esp_hal::dma::CHUNK_SIZE;
+
+        /// Walks the ring of descriptors and returns their number
+        fn ring_len(chain: &DescriptorChain, len: usize) -> usize {
+            let mut count = 0;
+            let mut total = 0;
+            let mut descriptor = chain.first();
+            loop {
+                let current = unsafe { &*descriptor };
+                assert!(current.size() > 0 && current.size() <= CHUNK_SIZE);
+                total += current.size();
+                count += 1;
+
+                descriptor = current.next().cast_const();
+                if descriptor == chain.first() {
+                    break;
+                }
+            }
+            assert_eq!(total, len);
+            count
+        }
+
+        let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) =
+            dma_buffers!(2 * CHUNK_SIZE + 1);
+        let mut tx_chain = DescriptorChain::new(tx_descriptors);
+        let mut rx_chain = DescriptorChain::new(rx_descriptors);
+
+        for len in [3, 4, 5, 8, CHUNK_SIZE, 2 * CHUNK_SIZE, 2 * CHUNK_SIZE + 1] {
+            assert_eq!(
+                descriptor_count(len, CHUNK_SIZE, true),
+                CIRCULAR_MIN_DESCRIPTORS
+            );
+
+            tx_chain.fill_for_tx(true, tx_buffer.as_ptr(), len).unwrap();
+            assert_eq!(ring_len(&tx_chain, len), CIRCULAR_MIN_DESCRIPTORS);
+
+            rx_chain
+                .fill_for_rx(true, rx_buffer.as_mut_ptr(), len)
+                .unwrap();
+            assert_eq!(ring_len(&rx_chain, len), CIRCULAR_MIN_DESCRIPTORS);
+        }
+
+        for len in [0, 1, 2] {
+            assert!(matches!(
+                tx_chain.fill_for_tx(true, tx_buffer.as_ptr(), len),
+                Err(DmaError::BufferTooSmall)
+            ));
+            assert!(matches!(
+                rx_chain.fill_for_rx(true, rx_buffer.as_mut_ptr(), len),
+                Err(DmaError::BufferTooSmall)
+            ));
+        }
+
+        // Two chunks fit into two descriptors, unless the buffer is circular
+        let (descriptors, _) = dma_descriptors!(2 * CHUNK_SIZE, 0);
+        assert_eq!(descriptors.len(), 2);
+        let mut chain = DescriptorChain::new(descriptors);
+        chain
+            .fill_for_tx(false, tx_buffer.as_ptr(), 2 * CHUNK_SIZE)
+            .unwrap();
+        assert!(matches!(
+            chain.fill_for_tx(true, tx_buffer.as_ptr(), 2 * CHUNK_SIZE),
+            Err(DmaError::OutOfDescriptors { required: 3 })
+        ));
+        assert!(matches!(
+            chain.fill_for_tx(true, tx_buffer.as_ptr(), 8),
+            Err(DmaError::OutOfDescriptors { required: 3 })
+        ));
+
+        // The macro agrees with the chain
+        let (tx_descriptors, rx_descriptors) =
+            dma_circular_descriptors!(2 * CHUNK_SIZE, 2 * CHUNK_SIZE + 1);
+        assert_eq!(tx_descriptors.len(), CIRCULAR_MIN_DESCRIPTORS);
+        assert_eq!(rx_descriptors.len(), CIRCULAR_MIN_DESCRIPTORS);
+    }
+
+    #[test]
+    fn test_descriptor_chain_validation() {
+        let (tx_buffer, tx_descriptors, _, _) = dma_buffers!(3 * 4092, 0);
+        let mut chain = DescriptorChain::new(tx_descriptors);
+
+        chain
+            .fill_for_tx(false, tx_buffer.as_ptr(), tx_buffer.len())
+            .unwrap();
+        assert_eq!(chain.validate(), Ok(()));
+        chain
+            .fill_for_tx(true, tx_buffer.as_ptr(), tx_buffer.len())
+            .unwrap();
+        assert_eq!(chain.validate(), Ok(()));
+
+        let second = unsafe { &mut *chain.first_mut().add(1) };
+
+        // Sizes and lengths which don't fit into the descriptor are rejected
+        assert_eq!(second.set_size(4096), Err(DmaError::InvalidChunkSize));
+        assert_eq!(second.set_length(4096), Err(DmaError::InvalidChunkSize));
+
+        // A size of 4096 is truncated to zero
+        let size = second.size();
+        second.set_size(0).unwrap();
+        assert_eq!(
+            chain.validate(),
+            Err(ChainError::SizeOutOfRange { index: 1 })
+        );
+        second.set_size(size).unwrap();
+
+        let mut patched = DmaDescriptor::new(tx_buffer.as_mut_ptr(), size).unwrap();
+        patched.set_length(second.len()).unwrap();
+        unsafe { patched.set_next(second.next()) };
+        *second = patched;
+        assert_eq!(
+            chain.validate(),
+            Err(ChainError::OverlappingBuffers {
+                first: 0,
+                second: 1
+            })
+        );
+
+        let mut outside = DmaDescriptor::EMPTY;
+        unsafe { second.set_next(&mut outside) };
+        assert_eq!(
+            chain.validate(),
+            Err(ChainError::LinkOutOfBounds { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_descriptor_chain_reuse_time() {
+        const DESCRIPTORS: usize = 150;
+        const CHUNK_SIZE: usize = 64;
+        const FRAMES: u32 = 30;
+
+        let (tx_buffer, tx_descriptors, _, _) =
+            dma_buffers_chunk_size!(DESCRIPTORS * CHUNK_SIZE, 0, CHUNK_SIZE);
+        let mut chain = DescriptorChain::new_with_chunk_size(tx_descriptors, CHUNK_SIZE).unwrap();
+
+        // alternating the length defeats the reuse, every frame fills the
+        // whole chain
+        let start = esp_hal::time::current_time();
+        for frame in 0..FRAMES {
+            let len = tx_buffer.len() - (frame % 2) as usize;
+            chain.fill_for_tx(false, tx_buffer.as_ptr(), len).unwrap();
+        }
+        let refill = (esp_hal::time::current_time() - start).to_micros();
+
+        let start = esp_hal::time::current_time();
+        for _ in 0..FRAMES {
+            chain
+                .fill_for_tx(false, tx_buffer.as_ptr(), tx_buffer.len())
+                .unwrap();
+        }
+        let reuse = (esp_hal::time::current_time() - start).to_micros();
+
+        defmt::info!(
+            "{} descriptors: filling takes {} us, reusing {} us per frame",
+            DESCRIPTORS,
+            refill / FRAMES as u64,
+            reuse / FRAMES as u64
+        );
+        assert!(reuse < refill);
+        assert_eq!(chain.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_dma_error_and_descriptor_formatting() {
+        use core::fmt::Write;
+
+        /// Collects the formatted output in a fixed size buffer
+        struct Buffer {
+            data: [u8; 192],
+            len: usize,
+        }
+
+        impl Write for Buffer {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let end = self.len + s.len();
+                self.data
+                    .get_mut(self.len..end)
+                    .ok_or(core::fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        fn format(args: core::fmt::Arguments<'_>) -> Buffer {
+            let mut buffer = Buffer {
+                data: [0; 192],
+                len: 0,
+            };
+            buffer.write_fmt(args).unwrap();
+            buffer
+        }
+
+        let cases: [(DmaError, &str); 4] = [
+            (
+                DmaError::DescriptorError,
+                "The DMA rejected the descriptors, the data might not be in RAM, try the FlashSafeDma wrapper",
+            ),
+            (
+                DmaError::DescriptorErrorAt {
+                    index: 2,
+                    address: 0x3fc8_0000,
+                },
+                "The DMA rejected descriptor 2 at 0x3fc80000, the data might not be in RAM, try the FlashSafeDma wrapper",
+            ),
+            (
+                DmaError::OutOfDescriptors { required: 3 },
+                "The buffer needs 3 descriptors, more than are available",
+            ),
+            (
+                DmaError::InvalidAlignment { address: 0x3fc8_0001 },
+                "The buffer at 0x3fc80001 or its length is not aligned as required",
+            ),
+        ];
+        for (error, expected) in cases {
+            let buffer = format(format_args!("{}", error));
+            assert_eq!(&buffer.data[..buffer.len], expected.as_bytes());
+        }
+
+        let buffer = format(format_args!("{:?}", DmaDescriptor::EMPTY));
+        assert_eq!(
+            &buffer.data[..buffer.len],
+            b"DmaDescriptor { flags: DmaDescriptorFlags { size: 0, length: 0, suc_eof: false, owner: false }, buffer: 0x0, next: 0x0 }"
+                .as_slice()
+        );
+    }
 }
@@ -0,0 +1,136 @@
+//! DMA to PSRAM Tests
+//!
+//! Folowing pins are used:
+//! SCLK    GPIO0
+//! MISO    GPIO2
+//! MOSI    GPIO3
+//! CS      GPIO8
+//!
+//! Connect MISO (GPIO2) and MOSI (GPIO3) pins.
+
+//% CHIPS: esp32s3
+//% FEATURES: psram-2m
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    dma::{Dma, DmaBurstConfig, DmaError, DmaPriority, ExtMemBlkSize, Mem2Mem},
+    dma_buffers_aligned,
+    dma_descriptors,
+    gpio::Io,
+    peripherals::Peripherals,
+    prelude::*,
+    psram,
+    spi::{
+        master::{prelude::*, Spi},
+        SpiMode,
+    },
+    system::SystemControl,
+};
+
+const DATA_SIZE: usize = 1024 * 10;
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::assert_eq;
+
+    use super::*;
+
+    #[init]
+    fn init() {}
+
+    #[test]
+    fn test_mem2mem_into_psram() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let _clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        psram::init_psram(peripherals.PSRAM);
+
+        let (tx_buffer, tx_descriptors, _, rx_descriptors) = dma_buffers_aligned!(DATA_SIZE, 32);
+        assert_eq!(tx_buffer.as_ptr() as usize % 32, 0);
+
+        let mut rx_buffer = unsafe {
+            core::slice::from_raw_parts_mut(psram::psram_vaddr_start() as *mut u8, DATA_SIZE)
+        };
+
+        let dma = Dma::new(peripherals.DMA);
+        let channel = dma.channel0.configure_with(
+            DmaBurstConfig {
+                ext_mem_block: Some(ExtMemBlkSize::Size32),
+                ..Default::default()
+            },
+            DmaPriority::Priority0,
+        );
+
+        let mut mem2mem =
+            Mem2Mem::new(channel, peripherals.SPI2, tx_descriptors, rx_descriptors).unwrap();
+
+        for i in 0..core::mem::size_of_val(tx_buffer) {
+            tx_buffer[i] = (i % 256) as u8;
+        }
+
+        let misaligned_address = rx_buffer[1..].as_ptr() as usize;
+        let mut misaligned = &mut rx_buffer[1..1025];
+        assert!(matches!(
+            mem2mem.start_transfer(&tx_buffer, &mut misaligned),
+            Err(DmaError::InvalidAlignment { address }) if address == misaligned_address
+        ));
+
+        let dma_wait = mem2mem.start_transfer(&tx_buffer, &mut rx_buffer).unwrap();
+        dma_wait.wait().unwrap();
+        for i in 0..core::mem::size_of_val(tx_buffer) {
+            assert_eq!(rx_buffer[i], tx_buffer[i]);
+        }
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_spi_loopback_through_psram() {
+        const SIZE: usize = 4096;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        psram::init_psram(peripherals.PSRAM);
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(SIZE);
+
+        let psram_start = psram::psram_vaddr_start() as *mut u8;
+        let tx_buffer = unsafe { core::slice::from_raw_parts_mut(psram_start, SIZE) };
+        let mut rx_buffer = unsafe { core::slice::from_raw_parts_mut(psram_start.add(SIZE), SIZE) };
+
+        // Leaves dirty lines of the TX buffer and stale lines of the RX buffer in
+        // the cache
+        for (i, b) in tx_buffer.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        rx_buffer.fill(0xff);
+
+        let dma = Dma::new(peripherals.DMA);
+        let mut spi = Spi::new(peripherals.SPI2, 10.MHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma.channel0.configure(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        let transfer = spi.dma_transfer(&tx_buffer, &mut rx_buffer).unwrap();
+        transfer.wait().unwrap();
+
+        assert_eq!(tx_buffer, rx_buffer);
+    }
+}
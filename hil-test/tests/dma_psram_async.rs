@@ -0,0 +1,98 @@
+//! DMA to PSRAM Tests (Async)
+//!
+//! Folowing pins are used:
+//! SCLK    GPIO0
+//! MISO    GPIO2
+//! MOSI    GPIO3
+//! CS      GPIO8
+//!
+//! Connect MISO (GPIO2) and MOSI (GPIO3) pins.
+
+//% CHIPS: esp32s3
+//% FEATURES: psram-2m async embassy
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use embedded_hal_async::spi::SpiBus;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    dma::{Dma, DmaPriority},
+    dma_descriptors,
+    gpio::Io,
+    peripherals::Peripherals,
+    prelude::*,
+    psram,
+    spi::{
+        master::{prelude::*, Spi},
+        SpiMode,
+    },
+    system::SystemControl,
+};
+
+#[cfg(test)]
+#[embedded_test::tests(executor = esp_hal_embassy::Executor::new())]
+mod tests {
+    use defmt::{assert, assert_eq};
+
+    use super::*;
+
+    #[init]
+    async fn init() {}
+
+    #[test]
+    #[timeout(3)]
+    async fn test_async_spi_loopback_through_psram() {
+        const SIZE: usize = 4096;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        psram::init_psram(peripherals.PSRAM);
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(SIZE);
+
+        let psram_start = psram::psram_vaddr_start() as *mut u8;
+        let tx_buffer = unsafe { core::slice::from_raw_parts_mut(psram_start, SIZE) };
+        let rx_buffer = unsafe { core::slice::from_raw_parts_mut(psram_start.add(SIZE), SIZE) };
+
+        let dma = Dma::new(peripherals.DMA);
+        let mut spi = Spi::new(peripherals.SPI2, 10.MHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma.channel0
+                    .configure_for_async(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        for round in 0..3u8 {
+            for (i, b) in tx_buffer.iter_mut().enumerate() {
+                *b = (i as u8).wrapping_add(round);
+            }
+            rx_buffer.fill(0xff);
+
+            // Loads the stale RX data into the cache while the transfer is
+            // running, the completed future has to drop it again
+            let transfer = SpiBus::transfer(&mut spi, rx_buffer, tx_buffer);
+            let mut transfer = core::pin::pin!(transfer);
+            assert!(embassy_futures::poll_once(transfer.as_mut()).is_pending());
+            assert_eq!(
+                unsafe { core::ptr::read_volatile(psram_start.add(SIZE)) },
+                0xff
+            );
+            transfer.await.unwrap();
+
+            assert_eq!(tx_buffer, rx_buffer);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! DMA statistics tests
+
+//% CHIPS: esp32s3 esp32c2 esp32c3 esp32c6 esp32h2
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    dma::{Dma, DmaDirectionStats, DmaPriority, Mem2Mem},
+    dma_buffers,
+    peripherals::Peripherals,
+    system::SystemControl,
+};
+
+const DATA_SIZE: usize = 1024 * 2;
+const TRANSFERS: u32 = 3;
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::assert_eq;
+
+    use super::*;
+
+    #[init]
+    fn init() {}
+
+    #[test]
+    fn test_mem2mem_stats() {
+        let mut peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let _clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) = dma_buffers!(DATA_SIZE);
+
+        {
+            let dma = Dma::new(&mut peripherals.DMA);
+            let channel = dma.channel0.configure(false, DmaPriority::Priority0);
+            channel.reset_stats();
+
+            #[cfg(any(feature = "esp32c2", feature = "esp32c3", feature = "esp32s3"))]
+            let dma_peripheral = peripherals.SPI2;
+            #[cfg(not(any(feature = "esp32c2", feature = "esp32c3", feature = "esp32s3")))]
+            let dma_peripheral = peripherals.MEM2MEM1;
+
+            let mut mem2mem =
+                Mem2Mem::new(channel, dma_peripheral, tx_descriptors, rx_descriptors).unwrap();
+
+            for _ in 0..TRANSFERS {
+                let dma_wait = mem2mem.start_transfer(&tx_buffer, &mut rx_buffer).unwrap();
+                dma_wait.wait().unwrap();
+            }
+        }
+
+        let dma = Dma::new(&mut peripherals.DMA);
+        let channel = dma.channel0.configure(false, DmaPriority::Priority0);
+
+        let stats = channel.stats();
+        assert_eq!(stats.tx.transfers, TRANSFERS);
+        assert_eq!(stats.tx.descriptor_errors, 0);
+        assert_eq!(stats.rx.transfers, TRANSFERS);
+        assert_eq!(stats.rx.bytes, TRANSFERS * DATA_SIZE as u32);
+        assert_eq!(stats.rx.descriptor_errors, 0);
+
+        channel.reset_stats();
+        let stats = channel.stats();
+        assert_eq!(stats.tx, DmaDirectionStats::default());
+        assert_eq!(stats.rx, DmaDirectionStats::default());
+    }
+}
@@ -0,0 +1,77 @@
+//! Embassy time driver test
+//!
+//! All alarms are served by a single SYSTIMER alarm.
+
+//% CHIPS: esp32c2 esp32c3 esp32c6 esp32h2 esp32s2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use embassy_time::{Duration, Instant, Timer};
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    peripherals::Peripherals,
+    system::SystemControl,
+    timer::{systimer::SystemTimer, ErasedTimer, OneShotTimer},
+};
+
+macro_rules! mk_static {
+    ($t:ty,$val:expr) => {{
+        static STATIC_CELL: static_cell::StaticCell<$t> = static_cell::StaticCell::new();
+        #[deny(unused_attributes)]
+        let x = STATIC_CELL.uninit().write(($val));
+        x
+    }};
+}
+
+async fn wait_for(millis: u64) -> Duration {
+    let start = Instant::now();
+    Timer::after(Duration::from_millis(millis)).await;
+    start.elapsed()
+}
+
+#[cfg(test)]
+#[embedded_test::tests(executor = esp_hal_embassy::Executor::new())]
+mod tests {
+    use super::*;
+
+    #[init]
+    fn init() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let systimer = SystemTimer::new(peripherals.SYSTIMER);
+        esp_hal_embassy::init(
+            &clocks,
+            mk_static!(
+                [OneShotTimer<ErasedTimer>; 1],
+                [OneShotTimer::new(systimer.alarm0.into())]
+            ),
+        );
+    }
+
+    #[test]
+    #[timeout(2)]
+    async fn test_timer_after() {
+        let elapsed = wait_for(100).await;
+
+        assert!(elapsed >= Duration::from_millis(100));
+        assert!(elapsed < Duration::from_millis(110));
+    }
+
+    #[test]
+    #[timeout(2)]
+    async fn test_simultaneous_alarms() {
+        let (a, b, c, d) =
+            embassy_futures::join::join4(wait_for(50), wait_for(100), wait_for(150), wait_for(200))
+                .await;
+
+        for (elapsed, expected) in [(a, 50), (b, 100), (c, 150), (d, 200)] {
+            assert!(elapsed >= Duration::from_millis(expected));
+            assert!(elapsed < Duration::from_millis(expected + 10));
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! ETM Test
+//!
+//! Folowing pins are used:
+//! GPIO2
+//! GPIO3
+//! GPIO4 (latch and LEDC output, not connected)
+
+//% CHIPS: esp32c6 esp32h2
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    delay::Delay,
+    etm::{CombineMode, Etm, EtmAndCombiner, EtmEventCombiner},
+    gpio::{
+        etm::{GpioEtmChannels, GpioEtmOutputConfig},
+        Input,
+        Io,
+        Level,
+        Pull,
+    },
+    ledc::{
+        channel::{self, ChannelIFace},
+        etm::{LedcEtmEvent, LedcEtmTask},
+        timer::{self, TimerIFace},
+        LSGlobalClkSource,
+        Ledc,
+        LowSpeed,
+    },
+    peripherals::Peripherals,
+    prelude::*,
+    system::SystemControl,
+    timer::systimer::{etm::SysTimerEtmEvent, SystemTimer},
+};
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use super::*;
+
+    #[init]
+    fn init() {}
+
+    #[test]
+    #[timeout(3)]
+    fn test_timer_event_drives_gpio_task() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let delay = Delay::new(&clocks);
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let input = Input::new(io.pins.gpio2, Pull::Down);
+
+        let syst = SystemTimer::new(peripherals.SYSTIMER);
+        let mut alarm0 = syst.alarm0;
+
+        let gpio_ext = GpioEtmChannels::new(peripherals.GPIO_SD);
+        let set_task = gpio_ext.channel0_task.set(
+            io.pins.gpio3,
+            GpioEtmOutputConfig {
+                initial_state: Level::Low,
+                ..Default::default()
+            },
+        );
+        let timer_event = SysTimerEtmEvent::new(&mut alarm0);
+
+        let etm = Etm::new(peripherals.SOC_ETM);
+        let _configured_channel = etm.channel0.setup(&timer_event, &set_task);
+
+        assert!(input.is_low());
+
+        // the pin is driven by the timer without involving the CPU
+        timer_event.with(|alarm| {
+            alarm.set_target(SystemTimer::now() + SystemTimer::TICKS_PER_SECOND / 20)
+        });
+        delay.delay_millis(100);
+        assert!(input.is_high());
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_combined_events() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let delay = Delay::new(&clocks);
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let input = Input::new(io.pins.gpio2, Pull::Down);
+
+        let syst = SystemTimer::new(peripherals.SYSTIMER);
+        let mut alarm0 = syst.alarm0;
+        let mut alarm1 = syst.alarm1;
+
+        let gpio_ext = GpioEtmChannels::new(peripherals.GPIO_SD);
+        let toggle_task = gpio_ext.channel0_task.toggle(
+            io.pins.gpio3,
+            GpioEtmOutputConfig {
+                initial_state: Level::Low,
+                ..Default::default()
+            },
+        );
+        let event0 = SysTimerEtmEvent::new(&mut alarm0);
+        let event1 = SysTimerEtmEvent::new(&mut alarm1);
+        let events = EtmEventCombiner::new(&event0, &event1, CombineMode::Or);
+
+        let etm = Etm::new(peripherals.SOC_ETM);
+        let _configured_channels = etm
+            .channel0
+            .setup_combined(etm.channel1, &events, &toggle_task);
+
+        let now = SystemTimer::now();
+        event0.with(|alarm| alarm.set_target(now + SystemTimer::TICKS_PER_SECOND / 50));
+        event1.with(|alarm| alarm.set_target(now + SystemTimer::TICKS_PER_SECOND / 10));
+
+        // each of the events toggles the pin
+        delay.delay_millis(50);
+        assert!(input.is_high());
+        delay.delay_millis(100);
+        assert!(input.is_low());
+    }
+    #[test]
+    #[timeout(3)]
+    fn test_and_combined_events() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let delay = Delay::new(&clocks);
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let input = Input::new(io.pins.gpio2, Pull::Down);
+
+        let syst = SystemTimer::new(peripherals.SYSTIMER);
+        let mut alarm0 = syst.alarm0;
+        let mut alarm1 = syst.alarm1;
+
+        let gpio_ext = GpioEtmChannels::new(peripherals.GPIO_SD);
+        let set_task = gpio_ext.channel0_task.set(
+            io.pins.gpio3,
+            GpioEtmOutputConfig {
+                initial_state: Level::Low,
+                ..Default::default()
+            },
+        );
+        let latch = gpio_ext
+            .channel1_task
+            .latch(gpio_ext.channel0_event, io.pins.gpio4);
+        let event0 = SysTimerEtmEvent::new(&mut alarm0);
+        let event1 = SysTimerEtmEvent::new(&mut alarm1);
+        let events = EtmAndCombiner::new(&event0, &event1, &latch);
+
+        let etm = Etm::new(peripherals.SOC_ETM);
+        let _configured_channels =
+            etm.channel0
+                .setup_and(etm.channel1, etm.channel2, &events, &set_task);
+
+        // the second event alone doesn't trigger the task
+        let now = SystemTimer::now();
+        event1.with(|alarm| alarm.set_target(now + SystemTimer::TICKS_PER_SECOND / 50));
+        event0.with(|alarm| alarm.set_target(now + SystemTimer::TICKS_PER_SECOND / 20));
+        delay.delay_millis(100);
+        assert!(input.is_low());
+
+        // the first event happened, so the second one triggers the task
+        event1.with(|alarm| {
+            alarm.set_target(SystemTimer::now() + SystemTimer::TICKS_PER_SECOND / 50)
+        });
+        delay.delay_millis(50);
+        assert!(input.is_high());
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_ledc_duty_update_task_and_event() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let delay = Delay::new(&clocks);
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let input = Input::new(io.pins.gpio2, Pull::Down);
+
+        let mut ledc = Ledc::new(peripherals.LEDC, &clocks);
+        ledc.set_global_slow_clock(LSGlobalClkSource::APBClk);
+
+        let mut lstimer0 = ledc.get_timer::<LowSpeed>(timer::Number::Timer0);
+        lstimer0
+            .configure(timer::config::Config {
+                duty: timer::config::Duty::Duty5Bit,
+                clock_source: timer::LSClockSource::APBClk,
+                frequency: 24.kHz(),
+            })
+            .unwrap();
+
+        let mut channel0 = ledc.get_channel(channel::Number::Channel0, io.pins.gpio4);
+        channel0
+            .configure(channel::config::Config {
+                timer: &lstimer0,
+                duty_pct: 10,
+                pin_config: channel::config::PinConfig::PushPull,
+            })
+            .unwrap();
+
+        let syst = SystemTimer::new(peripherals.SYSTIMER);
+        let mut alarm0 = syst.alarm0;
+
+        let gpio_ext = GpioEtmChannels::new(peripherals.GPIO_SD);
+        let set_task = gpio_ext.channel0_task.set(
+            io.pins.gpio3,
+            GpioEtmOutputConfig {
+                initial_state: Level::Low,
+                ..Default::default()
+            },
+        );
+        let timer_event = SysTimerEtmEvent::new(&mut alarm0);
+        let update_duty = LedcEtmTask::update_duty(channel::Number::Channel0);
+        let duty_done = LedcEtmEvent::duty_change_done(channel::Number::Channel0);
+
+        let etm = Etm::new(peripherals.SOC_ETM);
+        let _update_channel = etm.channel0.setup(&timer_event, &update_duty);
+        let _done_channel = etm.channel1.setup(&duty_done, &set_task);
+
+        channel0.stage_duty(50).unwrap();
+        delay.delay_millis(10);
+        assert!(input.is_low());
+
+        // the timer applies the staged duty, and the LEDC reports the finished
+        // duty change, without involving the CPU
+        timer_event.with(|alarm| {
+            alarm.set_target(SystemTimer::now() + SystemTimer::TICKS_PER_SECOND / 20)
+        });
+        delay.delay_millis(100);
+        assert!(input.is_high());
+    }
+}
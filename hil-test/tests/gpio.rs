@@ -87,7 +87,7 @@ pub fn interrupt_handler() {
 mod tests {
     use defmt::assert_eq;
     use embassy_time::{Duration, Timer};
-    use esp_hal::gpio::{Event, Flex, OutputOpenDrain};
+    use esp_hal::gpio::{Event, Flex, OutputConfig, OutputOpenDrain};
     use portable_atomic::{AtomicUsize, Ordering};
 
     use super::*;
@@ -241,6 +241,46 @@ mod tests {
 
         assert_eq!(io2.is_low(), true);
         assert_eq!(io3.is_low(), true);
+
+        // toggling uses the output register, not the (pulled low) input level
+        io2.set_high();
+        io3.set_low();
+        ctx.delay.delay_millis(1);
+
+        io2.toggle();
+        assert_eq!(io2.is_set_low(), true);
+        io2.toggle();
+        assert_eq!(io2.is_set_high(), true);
+    }
+
+    #[test]
+    fn test_gpio_od_config_pullup(ctx: Context<'static>) {
+        // Only GPIO2 pulls the line up
+        let mut io2 = OutputOpenDrain::new_with_config(
+            unsafe { GpioPin::<2>::steal() },
+            OutputConfig::default().pullup(true),
+        );
+        let mut io3 = OutputOpenDrain::new_with_config(
+            unsafe { GpioPin::<3>::steal() },
+            OutputConfig::default().initial_output(Level::Low),
+        );
+
+        ctx.delay.delay_millis(1);
+
+        assert_eq!(io2.is_low(), true);
+        assert_eq!(io3.is_low(), true);
+
+        io3.set_high();
+        ctx.delay.delay_millis(1);
+
+        assert_eq!(io2.is_high(), true);
+        assert_eq!(io3.is_high(), true);
+
+        io2.set_low();
+        ctx.delay.delay_millis(1);
+
+        assert_eq!(io2.is_low(), true);
+        assert_eq!(io3.is_low(), true);
     }
 
     #[test]
@@ -15,10 +15,11 @@ use esp_backtrace as _;
 use esp_hal::{
     clock::ClockControl,
     delay::Delay,
-    dma::{Dma, DmaPriority},
+    dma::{Dma, DmaError, DmaPriority, DmaTxQueue, Fill, Left, Right, I24},
     dma_buffers,
+    dma_descriptors,
     gpio::Io,
-    i2s::{DataFormat, I2s, I2sReadDma, I2sWriteDma, Standard},
+    i2s::{BitsPerSample, DataFormat, Error, I2s, I2sConfig, I2sReadDma, I2sWriteDma, Standard},
     peripheral::Peripheral,
     peripherals::Peripherals,
     prelude::*,
@@ -29,6 +30,15 @@ use esp_hal::{
 const ADD: u8 = 5;
 const CUT_OFF: u8 = 113;
 
+/// The value sent in the 32 bit slot with the given index: the upper 24 bits
+/// are the index plus one, negated for odd slots, the lower 8 bits are a
+/// marker which 24 bit samples drop
+fn slot_value(index: u32) -> i32 {
+    let value = (index as i32).wrapping_add(1) & 0x3f_ffff;
+    let value = if index % 2 == 0 { value } else { -value };
+    (value << 8) | 0x5a
+}
+
 #[cfg(test)]
 #[embedded_test::tests]
 mod tests {
@@ -109,7 +119,7 @@ mod tests {
         assert_eq!(0, rx_transfer.pop(&mut rcv[..100]).unwrap());
 
         // no data available yet
-        assert_eq!(0, rx_transfer.available());
+        assert_eq!(0, rx_transfer.available().unwrap());
 
         let mut tx_transfer = i2s_tx.write_dma_circular(&tx_buffer).unwrap();
 
@@ -126,16 +136,37 @@ mod tests {
             }
 
             // test calling available multiple times doesn't break anything
-            rx_transfer.available();
-            rx_transfer.available();
-            rx_transfer.available();
-            rx_transfer.available();
-            rx_transfer.available();
-            rx_transfer.available();
-            let rx_avail = rx_transfer.available();
+            rx_transfer.available().unwrap();
+            rx_transfer.available().unwrap();
+            rx_transfer.available().unwrap();
+            rx_transfer.available().unwrap();
+            rx_transfer.available().unwrap();
+            rx_transfer.available().unwrap();
+            let rx_avail = rx_transfer.available().unwrap();
 
             // make sure there are more than one descriptor buffers ready to pop
-            if rx_avail > 0 {
+            if rx_avail > 0 && iteration % 2 == 1 {
+                // consume odd sized chunks straight from the DMA buffer
+                let len = rx_transfer
+                    .pop_with(|data| {
+                        let len = usize::min(data.len(), 1001);
+                        for &b in &data[..len] {
+                            if b != check_i {
+                                failed = true;
+                            }
+                            check_i = (check_i + ADD) % CUT_OFF;
+                        }
+                        len
+                    })
+                    .unwrap();
+                assert!(len > 0);
+
+                if failed {
+                    break 'outer;
+                }
+
+                iteration += 1;
+            } else if rx_avail > 0 {
                 // trying to pop less data than available is an error
                 assert_eq!(
                     Err(esp_hal::dma::DmaError::BufferTooSmall),
@@ -170,4 +201,1337 @@ mod tests {
 
         assert!(!failed);
     }
+
+    #[test]
+    fn test_i2s_rx_overrun() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let mut io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let delay = Delay::new(&clocks);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) = dma_buffers!(16000, 16000);
+
+        let i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        let mut i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(unsafe { io.pins.gpio0.clone_unchecked() })
+            .with_ws(unsafe { io.pins.gpio1.clone_unchecked() })
+            .with_dout(unsafe { io.pins.gpio2.clone_unchecked() })
+            .build();
+
+        let mut i2s_rx = i2s
+            .i2s_rx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_din(io.pins.gpio3)
+            .build();
+
+        // enable loopback testing
+        unsafe {
+            let i2s = esp_hal::peripherals::I2S0::steal();
+            i2s.tx_conf().modify(|_, w| w.sig_loopback().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_slave_mod().set_bit());
+
+            i2s.tx_conf().modify(|_, w| w.tx_update().clear_bit());
+            i2s.tx_conf().modify(|_, w| w.tx_update().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_update().clear_bit());
+            i2s.rx_conf().modify(|_, w| w.rx_update().set_bit());
+        }
+
+        tx_buffer.fill(0x55);
+
+        let mut rcv = [0u8; 16000];
+
+        let mut rx_transfer = i2s_rx.read_dma_circular(&mut rx_buffer).unwrap();
+        let _tx_transfer = i2s_tx.write_dma_circular(&tx_buffer).unwrap();
+
+        // 16000 bytes are received in 250ms - consume nothing for a lot longer
+        delay.delay_millis(1000);
+
+        assert_eq!(
+            Err(esp_hal::dma::DmaError::BufferOverrun),
+            rx_transfer.available()
+        );
+
+        // the transfer recovers and continues with new data
+        delay.delay_millis(50);
+        let rx_avail = rx_transfer.available().unwrap();
+        assert!(rx_avail > 0);
+        assert!(rx_avail < 16000);
+
+        let len = rx_transfer.pop(&mut rcv).unwrap();
+        assert_eq!(rx_avail, len);
+    }
+
+    #[test]
+    fn test_i2s_push_partial() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let delay = Delay::new(&clocks);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, _, rx_descriptors) = dma_buffers!(16000, 0);
+
+        let i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        let mut i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_dout(io.pins.gpio2)
+            .build();
+
+        tx_buffer.fill(0x55);
+
+        let mut data = [0u8; 16000];
+        for (i, b) in data.iter_mut().enumerate() {
+            *b = (i % CUT_OFF as usize) as u8;
+        }
+
+        // 16000 bytes take 250ms to transmit, the descriptors of 4092 bytes each
+        // are done after 64ms, 128ms, 192ms and 250ms
+        let mut tx_transfer = i2s_tx.write_dma_circular(&tx_buffer).unwrap();
+
+        delay.delay_millis(220);
+
+        // three descriptors are free again, not all of the data fits
+        assert_eq!(
+            Err(esp_hal::dma::DmaError::Overflow),
+            tx_transfer.push(&data)
+        );
+
+        let first = tx_transfer.push_partial(&data).unwrap();
+        assert!(first > 0);
+        assert!(first < data.len());
+        assert_eq!(0, tx_transfer.available());
+        assert_eq!(&data[..first], &tx_buffer[..first]);
+
+        // a full buffer is not an error, nothing is written
+        assert_eq!(Ok(0), tx_transfer.push_partial(&data));
+
+        // let the DMA wrap around to the start of the buffer
+        delay.delay_millis(130);
+
+        let second = tx_transfer.push_partial(&data).unwrap();
+        assert!(first + second > tx_buffer.len());
+
+        for (i, &b) in data[..second].iter().enumerate() {
+            assert_eq!(b, tx_buffer[(first + i) % tx_buffer.len()]);
+        }
+    }
+
+    #[test]
+    fn test_i2s_tx_underrun() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let delay = Delay::new(&clocks);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, _, rx_descriptors) = dma_buffers!(16000, 0);
+
+        let i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        let mut i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_dout(io.pins.gpio2)
+            .build();
+
+        tx_buffer.fill(0x55);
+
+        let data = [0x55u8; 16000];
+
+        // 16000 bytes take 250ms to transmit
+        let mut tx_transfer = i2s_tx.write_dma_circular(&tx_buffer).unwrap();
+        tx_transfer.on_underrun(Fill::Zero);
+
+        // the data the transfer was started with isn't an underrun, neither is
+        // data pushed in time
+        let start = esp_hal::time::current_time();
+        while (esp_hal::time::current_time() - start).to_millis() < 600u64 {
+            let available = tx_transfer.available();
+            tx_transfer.push(&data[..available]).unwrap();
+            assert!(!tx_transfer.take_underrun());
+            delay.delay_millis(1);
+        }
+
+        // starve the producer for more than one pass through the buffer
+        let mut underrun = false;
+        let start = esp_hal::time::current_time();
+        while (esp_hal::time::current_time() - start).to_millis() < 600u64 {
+            underrun |= tx_transfer.take_underrun();
+            delay.delay_millis(1);
+        }
+        assert!(underrun);
+
+        // everything sent was cleared, except for the descriptors the DMA is
+        // still working on
+        let zeros = tx_buffer.iter().filter(|&&b| b == 0).count();
+        assert!(zeros >= tx_buffer.len() - 2 * 4092);
+    }
+
+    #[test]
+    fn test_i2s_tx_completed_descriptors() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let delay = Delay::new(&clocks);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        // four descriptors of about 4000 bytes, each takes about 64ms to transmit
+        let (tx_buffer, tx_descriptors, _, rx_descriptors) = dma_buffers!(16000, 0);
+        const DESCRIPTORS: usize = 4;
+
+        let i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        let mut i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_dout(io.pins.gpio2)
+            .build();
+
+        tx_buffer.fill(0x55);
+
+        let data = [0x55u8; 16000];
+
+        let mut tx_transfer = i2s_tx.write_dma_circular(&tx_buffer).unwrap();
+
+        // the descriptors are reported in order, starting with the first one
+        let mut last = None;
+        let mut reported = 0;
+        let start = esp_hal::time::current_time();
+        while (esp_hal::time::current_time() - start).to_millis() < 600u64 {
+            let available = tx_transfer.available();
+            tx_transfer.push(&data[..available]).unwrap();
+
+            if let Some(completed) = tx_transfer.completed_descriptor() {
+                let expected = last.map_or(0, |last| (last + 1) % DESCRIPTORS);
+                assert_eq!(completed.index, expected);
+                assert_eq!(completed.skipped, 0);
+
+                last = Some(completed.index);
+                reported += 1;
+            }
+
+            delay.delay_millis(1);
+        }
+        assert!(reported >= 8);
+
+        // descriptors completed while nobody was looking are skipped
+        let last = last.unwrap();
+        delay.delay_millis(150);
+
+        let completed = tx_transfer.completed_descriptor().unwrap();
+        assert!(completed.skipped >= 1);
+        assert_eq!(
+            completed.index,
+            (last + completed.skipped + 1) % DESCRIPTORS
+        );
+    }
+
+    #[test]
+    fn test_i2s_tx_queue() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, _, rx_descriptors) = dma_buffers!(12000, 0);
+        // one descriptor for each of the two slots
+        let (queue_descriptors, _) = dma_descriptors!(8000, 0);
+
+        let i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        let mut i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_dout(io.pins.gpio2)
+            .build();
+
+        tx_buffer.fill(0x55);
+        let tx_buffer: &'static [u8] = tx_buffer;
+        let (first, rest) = tx_buffer.split_at(4000);
+        let (second, third) = rest.split_at(4000);
+
+        let mut queue = DmaTxQueue::<2>::new(queue_descriptors).unwrap();
+        queue.enqueue(first).unwrap();
+        queue.enqueue(second).unwrap();
+        assert_eq!(Err(DmaError::QueueFull), queue.enqueue(third));
+
+        // 4000 bytes take 62.5ms to transmit
+        let t1 = esp_hal::time::current_time();
+        let mut transfer = i2s_tx.write_dma_queue(&mut queue).unwrap();
+
+        let mut completed = transfer.wait_completed().unwrap();
+        assert!(completed >= 1);
+        transfer.enqueue(third).unwrap();
+
+        while completed < 3 {
+            completed += transfer.wait_completed().unwrap();
+        }
+        let t2 = esp_hal::time::current_time();
+
+        assert_eq!(0, transfer.pending());
+        // the buffers are sent back to back
+        assert!((t2 - t1).to_millis() < 200u64);
+
+        // the DMA is started again when a buffer is queued after the queue ran
+        // empty
+        transfer.enqueue(first).unwrap();
+        assert_eq!(1, transfer.wait_completed().unwrap());
+    }
+
+    #[test]
+    #[timeout(5)]
+    fn test_i2s_tx_queue_loopback() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let mut io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) = dma_buffers!(12000, 16000);
+        // one descriptor for each of the three slots
+        let (queue_descriptors, _) = dma_descriptors!(12000, 0);
+
+        let i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        let mut i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(unsafe { io.pins.gpio0.clone_unchecked() })
+            .with_ws(unsafe { io.pins.gpio1.clone_unchecked() })
+            .with_dout(unsafe { io.pins.gpio2.clone_unchecked() })
+            .build();
+
+        let mut i2s_rx = i2s
+            .i2s_rx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_din(io.pins.gpio3)
+            .build();
+
+        // enable loopback testing
+        unsafe {
+            let i2s = esp_hal::peripherals::I2S0::steal();
+            i2s.tx_conf().modify(|_, w| w.sig_loopback().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_slave_mod().set_bit());
+
+            i2s.tx_conf().modify(|_, w| w.tx_update().clear_bit());
+            i2s.tx_conf().modify(|_, w| w.tx_update().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_update().clear_bit());
+            i2s.rx_conf().modify(|_, w| w.rx_update().set_bit());
+        }
+
+        // Every word is one more than the one before across all three buffers,
+        // a gap between two buffers would show up as zeros in between
+        for (i, word) in tx_buffer.chunks_exact_mut(2).enumerate() {
+            word.copy_from_slice(&(i as u16 + 1).to_le_bytes());
+        }
+        let last_word = (tx_buffer.len() / 2) as u16;
+        let tx_buffer: &'static [u8] = tx_buffer;
+        let (first, rest) = tx_buffer.split_at(4000);
+        let (second, third) = rest.split_at(4000);
+
+        let mut queue = DmaTxQueue::<3>::new(queue_descriptors).unwrap();
+        queue.enqueue(first).unwrap();
+        queue.enqueue(second).unwrap();
+        queue.enqueue(third).unwrap();
+
+        let mut rx_transfer = i2s_rx.read_dma_circular(&mut rx_buffer).unwrap();
+        let mut transfer = i2s_tx.write_dma_queue(&mut queue).unwrap();
+
+        let mut expected: Option<u16> = None;
+        let mut words = [0i16; 256];
+        while expected != Some(last_word + 1) {
+            let n = rx_transfer.pop_samples(&mut words).unwrap();
+            for word in words[..n].iter().map(|&w| w as u16) {
+                match expected {
+                    None if word == 0 => {}
+                    None => {
+                        assert_eq!(word, 1);
+                        expected = Some(2);
+                    }
+                    Some(e) if e <= last_word => {
+                        assert_eq!(word, e);
+                        expected = Some(e + 1);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        let mut completed = 0;
+        while completed < 3 {
+            completed += transfer.wait_completed().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_i2s_abort() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, _, rx_descriptors) = dma_buffers!(16000, 0);
+
+        let i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        let mut i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_dout(io.pins.gpio2)
+            .build();
+
+        tx_buffer.fill(0x55);
+
+        // 16000 bytes take 250ms to transmit - aborting must not wait for that
+        let transfer = i2s_tx.write_dma(&tx_buffer).unwrap();
+
+        let t1 = esp_hal::time::current_time();
+        transfer.abort();
+        let t2 = esp_hal::time::current_time();
+
+        assert!(t2 > t1);
+        assert!((t2 - t1).to_millis() < 50u64);
+
+        // the channel can be used again after aborting
+        i2s_tx.write_dma(&tx_buffer).unwrap().wait().unwrap();
+    }
+
+    #[test]
+    fn test_i2s_wait_with_timeout() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, _, rx_descriptors) = dma_buffers!(16000, 0);
+
+        let i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        let mut i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_dout(io.pins.gpio2)
+            .build();
+
+        tx_buffer.fill(0x55);
+
+        // 16000 bytes take 250ms to transmit
+        let mut transfer = i2s_tx.write_dma(&tx_buffer).unwrap();
+
+        assert_eq!(
+            Err(esp_hal::dma::DmaError::Timeout),
+            transfer.wait_with_timeout(10.millis())
+        );
+
+        // the transfer is still running and can be waited for again
+        assert_eq!(Ok(()), transfer.wait_with_timeout(500.millis()));
+    }
+
+    #[test]
+    #[timeout(15)]
+    fn test_i2s_tx_rx_circular_latency() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let mut io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) = dma_buffers!(16000, 16000);
+
+        let mut i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        i2s.i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(unsafe { io.pins.gpio0.clone_unchecked() })
+            .with_ws(unsafe { io.pins.gpio1.clone_unchecked() })
+            .with_dout(unsafe { io.pins.gpio2.clone_unchecked() });
+
+        i2s.i2s_rx = i2s
+            .i2s_rx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_din(io.pins.gpio3);
+
+        let mut i2s = i2s.build_tx_rx();
+
+        // enable loopback testing
+        unsafe {
+            let i2s = esp_hal::peripherals::I2S0::steal();
+            i2s.tx_conf().modify(|_, w| w.sig_loopback().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_slave_mod().set_bit());
+
+            i2s.tx_conf().modify(|_, w| w.tx_update().clear_bit());
+            i2s.tx_conf().modify(|_, w| w.tx_update().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_update().clear_bit());
+            i2s.rx_conf().modify(|_, w| w.rx_update().set_bit());
+        }
+
+        // every sample is the number of samples sent before it plus one, so
+        // nothing received before the first sample is mistaken for data
+        let mut next_sample: u16 = 1;
+        for sample in tx_buffer.chunks_exact_mut(2) {
+            sample.copy_from_slice(&next_sample.to_le_bytes());
+            next_sample = next_sample.wrapping_add(1);
+        }
+
+        let mut received: i32 = 0;
+        let mut latency = None;
+        let mut failed = false;
+
+        let mut transfer = i2s
+            .write_read_dma_circular(&tx_buffer, &mut rx_buffer)
+            .unwrap();
+
+        let start = esp_hal::time::current_time();
+        while (esp_hal::time::current_time() - start).to_millis() < 10_000u64 {
+            if transfer.available_tx() > 0 {
+                transfer
+                    .push_with(|buffer| {
+                        let len = buffer.len() & !1;
+                        for sample in buffer[..len].chunks_exact_mut(2) {
+                            sample.copy_from_slice(&next_sample.to_le_bytes());
+                            next_sample = next_sample.wrapping_add(1);
+                        }
+                        len
+                    })
+                    .unwrap();
+            }
+
+            if transfer.available_rx().unwrap() > 0 {
+                transfer
+                    .pop_with(|data| {
+                        let len = data.len() & !1;
+                        for sample in data[..len].chunks_exact(2) {
+                            let sample = u16::from_le_bytes([sample[0], sample[1]]);
+                            match latency {
+                                None if sample != 0 => {
+                                    latency = Some(received + 1 - sample as i32);
+                                }
+                                None => {}
+                                Some(latency) => {
+                                    if sample != (received + 1 - latency) as u16 {
+                                        failed = true;
+                                    }
+                                }
+                            }
+                            received += 1;
+                        }
+                        len
+                    })
+                    .unwrap();
+            }
+
+            if failed {
+                break;
+            }
+        }
+
+        transfer.stop().unwrap();
+
+        assert!(!failed);
+        // the first sample sent is received within the first frame
+        let latency = latency.unwrap();
+        assert!((0..=2).contains(&latency));
+        // 16000 stereo frames per second
+        assert!(received > 9 * 32000);
+    }
+
+    #[test]
+    #[timeout(5)]
+    fn test_i2s_pop_samples() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let mut io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) = dma_buffers!(16000, 16000);
+
+        let mut i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data32Channel32,
+            16000.Hz(),
+            dma_channel.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        i2s.i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(unsafe { io.pins.gpio0.clone_unchecked() })
+            .with_ws(unsafe { io.pins.gpio1.clone_unchecked() })
+            .with_dout(unsafe { io.pins.gpio2.clone_unchecked() });
+
+        i2s.i2s_rx = i2s
+            .i2s_rx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_din(io.pins.gpio3);
+
+        let mut i2s = i2s.build_tx_rx();
+
+        // enable loopback testing
+        unsafe {
+            let i2s = esp_hal::peripherals::I2S0::steal();
+            i2s.tx_conf().modify(|_, w| w.sig_loopback().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_slave_mod().set_bit());
+
+            i2s.tx_conf().modify(|_, w| w.tx_update().clear_bit());
+            i2s.tx_conf().modify(|_, w| w.tx_update().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_update().clear_bit());
+            i2s.rx_conf().modify(|_, w| w.rx_update().set_bit());
+        }
+
+        let mut next_slot: u32 = 0;
+        for slot in tx_buffer.chunks_exact_mut(4) {
+            slot.copy_from_slice(&slot_value(next_slot).to_le_bytes());
+            next_slot += 1;
+        }
+
+        let mut transfer = i2s
+            .write_read_dma_circular(&tx_buffer, &mut rx_buffer)
+            .unwrap();
+
+        // Received bytes and the difference between the index of a received slot
+        // and the index of the slot sent, known after the first slot with data.
+        let mut position: usize = 0;
+        let mut offset: Option<u32> = None;
+        let expected = |position: usize, offset: u32| slot_value((position / 4) as u32 + offset);
+
+        let mut round = 0;
+        while round < 600 {
+            if transfer.available_tx() > 0 {
+                transfer
+                    .push_with(|buffer| {
+                        let len = buffer.len() & !3;
+                        for slot in buffer[..len].chunks_exact_mut(4) {
+                            slot.copy_from_slice(&slot_value(next_slot).to_le_bytes());
+                            next_slot += 1;
+                        }
+                        len
+                    })
+                    .unwrap();
+            }
+
+            let Some(offset) = offset else {
+                // skip the silence received before the first slot
+                let mut slot = [0i32; 1];
+                if transfer.pop_samples(&mut slot).unwrap() == 1 {
+                    if slot[0] != 0 {
+                        let index = (slot[0] >> 8).unsigned_abs() - 1;
+                        offset = Some(index.wrapping_sub((position / 4) as u32));
+                    }
+                    position += 4;
+                }
+                continue;
+            };
+
+            // 16 bit samples can leave the position in the middle of a slot,
+            // stereo samples have to start at the first slot of a frame
+            if position % 4 != 0 || (round % 6 >= 3 && position % 8 != 0) {
+                let mut half = [0i16; 1];
+                position += 2 * transfer.pop_samples(&mut half).unwrap();
+                continue;
+            }
+
+            match round % 6 {
+                0 => {
+                    let mut samples = [0i32; 32];
+                    let n = transfer.pop_samples(&mut samples).unwrap();
+                    for &sample in &samples[..n] {
+                        assert_eq!(sample, expected(position, offset));
+                        position += 4;
+                    }
+                }
+                1 => {
+                    let mut samples = [I24::default(); 32];
+                    let n = transfer.pop_samples(&mut samples).unwrap();
+                    for &sample in &samples[..n] {
+                        assert_eq!(sample.0, expected(position, offset) >> 8);
+                        position += 4;
+                    }
+                }
+                2 => {
+                    let mut samples = [0i16; 63];
+                    let n = transfer.pop_samples(&mut samples).unwrap();
+                    for &sample in &samples[..n] {
+                        let slot = expected(position & !3, offset);
+                        let half = if position % 4 == 0 { slot } else { slot >> 16 };
+                        assert_eq!(sample, half as i16);
+                        position += 2;
+                    }
+                }
+                3 => {
+                    let mut samples = [Left(I24::default()); 16];
+                    let n = transfer.pop_samples(&mut samples).unwrap();
+                    for &sample in &samples[..n] {
+                        assert_eq!(sample.0 .0, expected(position, offset) >> 8);
+                        position += 8;
+                    }
+                }
+                4 => {
+                    let mut samples = [Right(0i32); 16];
+                    let n = transfer.pop_samples(&mut samples).unwrap();
+                    for &sample in &samples[..n] {
+                        assert_eq!(sample.0, expected(position + 4, offset));
+                        position += 8;
+                    }
+                }
+                _ => {
+                    // 16 bit stereo frames take up one 32 bit slot
+                    let mut samples = [Left(0i16); 16];
+                    let n = transfer.pop_samples(&mut samples).unwrap();
+                    for &sample in &samples[..n] {
+                        assert_eq!(sample.0, expected(position, offset) as i16);
+                        position += 4;
+                    }
+                }
+            }
+
+            round += 1;
+        }
+
+        transfer.stop().unwrap();
+
+        // every format got data
+        assert!(position > 600 * 8);
+    }
+
+    #[test]
+    #[timeout(5)]
+    fn test_i2s_tx_pause_resume() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let mut io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let delay = Delay::new(&clocks);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, _, mut rx_buffer, rx_descriptors) = dma_buffers!(16000, 16000);
+        // resuming in the middle of the buffer takes one more descriptor
+        let (tx_descriptors, _) = dma_descriptors!(16000 + 4092, 0);
+
+        let i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        let mut i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(unsafe { io.pins.gpio0.clone_unchecked() })
+            .with_ws(unsafe { io.pins.gpio1.clone_unchecked() })
+            .with_dout(unsafe { io.pins.gpio2.clone_unchecked() })
+            .build();
+
+        let mut i2s_rx = i2s
+            .i2s_rx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_din(io.pins.gpio3)
+            .build();
+
+        // enable loopback testing
+        unsafe {
+            let i2s = esp_hal::peripherals::I2S0::steal();
+            i2s.tx_conf().modify(|_, w| w.sig_loopback().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_slave_mod().set_bit());
+
+            i2s.tx_conf().modify(|_, w| w.tx_update().clear_bit());
+            i2s.tx_conf().modify(|_, w| w.tx_update().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_update().clear_bit());
+            i2s.rx_conf().modify(|_, w| w.rx_update().set_bit());
+        }
+
+        // Every sent word is one more than the one before, zeros are never sent
+        // so the idle line can be told apart from the data.
+        fn push_words(buffer: &mut [u8], next_word: &mut u16) -> usize {
+            let len = buffer.len() & !1;
+            for word in buffer[..len].chunks_exact_mut(2) {
+                word.copy_from_slice(&next_word.to_le_bytes());
+                *next_word += 1;
+            }
+            len
+        }
+
+        let mut next_word: u16 = 1;
+        push_words(tx_buffer, &mut next_word);
+
+        let mut rx_transfer = i2s_rx.read_dma_circular(&mut rx_buffer).unwrap();
+        let mut tx_transfer = i2s_tx.write_dma_circular(&tx_buffer).unwrap();
+
+        let mut expected: Option<u16> = None;
+        let mut discontinuities = 0;
+        let mut check = |words: &[i16]| {
+            for word in words.iter().map(|&w| w as u16).filter(|&w| w != 0) {
+                if let Some(expected) = expected.filter(|&e| e != word) {
+                    // The data in the FIFO when pausing is lost and the
+                    // descriptor which was being sent is sent again, nothing
+                    // else is skipped or repeated.
+                    assert!(word.abs_diff(expected) <= 4092 / 2);
+                    discontinuities += 1;
+                }
+                expected = Some(word + 1);
+            }
+        };
+        let mut words = [0i16; 256];
+
+        // push until the write position wrapped around the buffer
+        while next_word < 12000 {
+            let n = rx_transfer.pop_samples(&mut words).unwrap();
+            check(&words[..n]);
+
+            if tx_transfer.available() > 0 {
+                tx_transfer
+                    .push_with(|buffer| push_words(buffer, &mut next_word))
+                    .unwrap();
+            }
+        }
+
+        let snapshot = tx_transfer.pause().unwrap();
+        assert_eq!(snapshot.buffer_len, tx_buffer.len());
+        assert_eq!(
+            snapshot.write_offset,
+            (usize::from(next_word) - 1) * 2 % tx_buffer.len()
+        );
+
+        delay.delay_millis(50);
+
+        // a snapshot of another buffer is rejected
+        let other: &[u8] = &[0u8; 16];
+        assert!(i2s_tx.write_dma_circular_resume(&other, &snapshot).is_err());
+
+        let mut tx_transfer = i2s_tx
+            .write_dma_circular_resume(&tx_buffer, &snapshot)
+            .unwrap();
+
+        while next_word < 24000 {
+            let n = rx_transfer.pop_samples(&mut words).unwrap();
+            check(&words[..n]);
+
+            if tx_transfer.available() > 0 {
+                tx_transfer
+                    .push_with(|buffer| push_words(buffer, &mut next_word))
+                    .unwrap();
+            }
+        }
+
+        // the data pushed before pausing was received after resuming
+        let received = expected.unwrap();
+        assert!(received > 12000);
+        assert!(discontinuities <= 2);
+
+        tx_transfer.stop().unwrap();
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_i2s_circular_from_offset() {
+        const TX_OFFSET: usize = 4000;
+        const RX_OFFSET: usize = 8000;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let mut io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, _, mut rx_buffer, _) = dma_buffers!(16000, 16000);
+        // starting in the middle of the buffer takes one more descriptor
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(16000 + 4092, 16000 + 4092);
+
+        let i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        let mut i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(unsafe { io.pins.gpio0.clone_unchecked() })
+            .with_ws(unsafe { io.pins.gpio1.clone_unchecked() })
+            .with_dout(unsafe { io.pins.gpio2.clone_unchecked() })
+            .build();
+
+        let mut i2s_rx = i2s
+            .i2s_rx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_din(io.pins.gpio3)
+            .build();
+
+        // enable loopback testing
+        unsafe {
+            let i2s = esp_hal::peripherals::I2S0::steal();
+            i2s.tx_conf().modify(|_, w| w.sig_loopback().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_slave_mod().set_bit());
+
+            i2s.tx_conf().modify(|_, w| w.tx_update().clear_bit());
+            i2s.tx_conf().modify(|_, w| w.tx_update().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_update().clear_bit());
+            i2s.rx_conf().modify(|_, w| w.rx_update().set_bit());
+        }
+
+        // The buffer holds the words 1 to 8000, zeros are never sent so the
+        // idle line can be told apart from the data.
+        for (i, word) in tx_buffer.chunks_exact_mut(2).enumerate() {
+            word.copy_from_slice(&(i as u16 + 1).to_le_bytes());
+        }
+        let words = (tx_buffer.len() / 2) as u16;
+
+        // offsets outside of the buffer and unaligned RX offsets are rejected
+        assert!(matches!(
+            i2s_tx.write_dma_circular_from(&tx_buffer, tx_buffer.len()),
+            Err(Error::DmaError(DmaError::BufferTooSmall))
+        ));
+        assert!(matches!(
+            i2s_rx.read_dma_circular_from(&mut rx_buffer, 2),
+            Err(Error::IllegalArgument)
+        ));
+
+        let rx_start = rx_buffer.as_ptr() as usize + RX_OFFSET;
+        let mut rx_transfer = i2s_rx
+            .read_dma_circular_from(&mut rx_buffer, RX_OFFSET)
+            .unwrap();
+        let _tx_transfer = i2s_tx
+            .write_dma_circular_from(&tx_buffer, TX_OFFSET)
+            .unwrap();
+
+        let mut first_block = true;
+        let mut expected: Option<u16> = None;
+        let mut received = 0;
+        // receive every word twice, which wraps around both buffers
+        while received < 2 * words as usize {
+            rx_transfer
+                .pop_with(|data| {
+                    if data.is_empty() {
+                        return 0;
+                    }
+
+                    // the data is received into the buffer starting at the
+                    // offset
+                    if first_block {
+                        assert_eq!(data.as_ptr() as usize, rx_start);
+                        first_block = false;
+                    }
+
+                    let len = data.len() & !1;
+                    for word in data[..len].chunks_exact(2) {
+                        let word = u16::from_le_bytes([word[0], word[1]]);
+                        if word == 0 && expected.is_none() {
+                            continue;
+                        }
+
+                        // the first word sent is the one at the offset, the
+                        // buffer is sent from its start after its end
+                        assert_eq!(word, expected.unwrap_or(TX_OFFSET as u16 / 2 + 1));
+                        expected = Some(word % words + 1);
+                        received += 1;
+                    }
+                    len
+                })
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_i2s_tdm_slot_limit() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let dma = Dma::new(peripherals.DMA);
+        let mut i2s_peripheral = peripherals.I2S0;
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(4092, 4092);
+        let i2s = I2s::new(
+            unsafe { i2s_peripheral.clone_unchecked() },
+            Standard::Philips,
+            DataFormat::Data32Channel32,
+            16000.Hz(),
+            dma.channel0.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        // Half of the frame has to fit the half sample bits field
+        #[cfg(not(feature = "esp32h2"))]
+        const MAX_SLOTS: u8 = 4;
+        #[cfg(feature = "esp32h2")]
+        const MAX_SLOTS: u8 = 16;
+
+        assert!(matches!(
+            i2s.into_tdm(MAX_SLOTS + 1, 0b1, 16000.Hz(), &clocks),
+            Err(esp_hal::i2s::Error::IllegalArgument)
+        ));
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(4092, 4092);
+        let i2s = I2s::new(
+            i2s_peripheral,
+            Standard::Philips,
+            DataFormat::Data32Channel32,
+            16000.Hz(),
+            dma.channel1.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+        assert!(i2s.into_tdm(MAX_SLOTS, 0b1, 16000.Hz(), &clocks).is_ok());
+    }
+
+    #[test]
+    fn test_i2s_split_channel_loopback() {
+        const WORDS: usize = 2000;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let mut io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let dma = Dma::new(peripherals.DMA);
+        let mut i2s_peripheral = peripherals.I2S0;
+
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) =
+            dma_buffers!(WORDS * 2, 16000);
+
+        // each direction is driven by its own half of the channel
+        let (tx, rx) = dma
+            .channel0
+            .configure(false, DmaPriority::Priority0)
+            .split();
+
+        let mut i2s_tx = I2s::<_, _, esp_hal::Blocking>::new_tx(
+            &mut i2s_peripheral,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            tx,
+            tx_descriptors,
+            &clocks,
+        )
+        .with_bclk(unsafe { io.pins.gpio0.clone_unchecked() })
+        .with_ws(unsafe { io.pins.gpio1.clone_unchecked() })
+        .with_dout(io.pins.gpio2)
+        .build();
+
+        let mut i2s_rx = I2s::<_, _, esp_hal::Blocking>::new_rx(
+            &mut i2s_peripheral,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            rx,
+            rx_descriptors,
+            &clocks,
+        )
+        .with_bclk(io.pins.gpio0)
+        .with_ws(io.pins.gpio1)
+        .with_din(io.pins.gpio3)
+        .build();
+
+        // enable loopback testing
+        unsafe {
+            let i2s = esp_hal::peripherals::I2S0::steal();
+            i2s.tx_conf().modify(|_, w| w.sig_loopback().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_slave_mod().set_bit());
+
+            i2s.tx_conf().modify(|_, w| w.tx_update().clear_bit());
+            i2s.tx_conf().modify(|_, w| w.tx_update().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_update().clear_bit());
+            i2s.rx_conf().modify(|_, w| w.rx_update().set_bit());
+        }
+
+        // the words count from 1 to WORDS and start over
+        for (i, word) in tx_buffer.chunks_exact_mut(2).enumerate() {
+            word.copy_from_slice(&(i as u16 + 1).to_le_bytes());
+        }
+
+        let mut rx_transfer = i2s_rx.read_dma_circular(&mut rx_buffer).unwrap();
+        let mut tx_transfer = i2s_tx.write_dma_circular(&tx_buffer).unwrap();
+
+        let mut previous: Option<u16> = None;
+        let mut checked = 0;
+        let mut words = [0i16; 256];
+        while checked < 2 * WORDS {
+            let n = rx_transfer.pop_samples(&mut words).unwrap();
+            for word in words[..n].iter().map(|&w| w as u16) {
+                match previous {
+                    None if word == 0 => {}
+                    None => previous = Some(word),
+                    Some(p) => {
+                        assert_eq!(word, p % WORDS as u16 + 1);
+                        previous = Some(word);
+                        checked += 1;
+                    }
+                }
+            }
+            // hand the unchanged words back to the DMA, so the count goes on
+            tx_transfer.push_with(|buffer| buffer.len()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_i2s_typed_writer() {
+        const SAMPLES: usize = 1000;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let mut io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let dma = Dma::new(peripherals.DMA);
+        let mut i2s_peripheral = peripherals.I2S0;
+
+        // a writer has to match the configured sample width
+        {
+            let (tx_descriptors, rx_descriptors) = dma_descriptors!(4096, 4096);
+            let i2s = I2s::new_with_config(
+                &mut i2s_peripheral,
+                I2sConfig::new(16000.Hz()),
+                dma.channel1.configure(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+                &clocks,
+            );
+            assert!(matches!(
+                i2s.i2s_tx.build_writer::<32>(),
+                Err(Error::IllegalArgument)
+            ));
+        }
+
+        let (_, tx_descriptors, mut rx_buffer, rx_descriptors) = dma_buffers!(4 * SAMPLES, 16000);
+        let i2s = I2s::new_with_config(
+            &mut i2s_peripheral,
+            I2sConfig::new(16000.Hz()).bits_per_sample(BitsPerSample::Bits24),
+            dma.channel0.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        let mut writer = i2s
+            .i2s_tx
+            .with_bclk(unsafe { io.pins.gpio0.clone_unchecked() })
+            .with_ws(unsafe { io.pins.gpio1.clone_unchecked() })
+            .with_dout(io.pins.gpio2)
+            .build_writer::<24>()
+            .unwrap();
+
+        let mut i2s_rx = i2s
+            .i2s_rx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_din(io.pins.gpio3)
+            .build();
+
+        // enable loopback testing
+        unsafe {
+            let i2s = esp_hal::peripherals::I2S0::steal();
+            i2s.tx_conf().modify(|_, w| w.sig_loopback().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_slave_mod().set_bit());
+
+            i2s.tx_conf().modify(|_, w| w.tx_update().clear_bit());
+            i2s.tx_conf().modify(|_, w| w.tx_update().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_update().clear_bit());
+            i2s.rx_conf().modify(|_, w| w.rx_update().set_bit());
+        }
+
+        // the samples count from 1, odd ones are negated to check the sign bit
+        // makes it through the 24 bits
+        let mut samples = [0i32; SAMPLES];
+        for (i, sample) in samples.iter_mut().enumerate() {
+            let value = i as i32 + 1;
+            *sample = (if i % 2 == 0 { value } else { -value }) & 0xff_ffff;
+        }
+
+        let mut rx_transfer = i2s_rx.read_dma_circular(&mut rx_buffer).unwrap();
+        writer.write(&samples).unwrap();
+
+        let mut checked = 0;
+        let mut bytes = [0u8; 4 * SAMPLES];
+        while checked < SAMPLES {
+            let n = rx_transfer.pop(&mut bytes).unwrap();
+            for word in bytes[..n].chunks_exact(4) {
+                let received = i32::from_le_bytes(word.try_into().unwrap()) & 0xff_ffff;
+                if checked == 0 && received == 0 {
+                    continue;
+                }
+                if checked < SAMPLES {
+                    assert_eq!(received, samples[checked]);
+                    checked += 1;
+                }
+            }
+        }
+    }
 }
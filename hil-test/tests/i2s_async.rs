@@ -16,11 +16,12 @@ use esp_hal::{
     clock::ClockControl,
     dma::{Dma, DmaChannel0, DmaPriority},
     gpio::Io,
-    i2s::{asynch::*, DataFormat, I2s, Standard},
+    i2s::{asynch::*, DataFormat, I2s, I2sReadDma, I2sWriteDma, Standard},
     peripheral::Peripheral,
     peripherals::Peripherals,
     prelude::*,
     system::SystemControl,
+    timer::timg::TimerGroup,
 };
 
 // choose values which DON'T restart on every descriptor buffer's start
@@ -150,4 +151,227 @@ mod tests {
 
         assert!(!failed);
     }
+
+    #[test]
+    async fn test_i2s_loopback_circular_transfer() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let mut io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) =
+            esp_hal::dma_circular_buffers!(2000, 2000);
+
+        let i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure_for_async(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        let mut i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(unsafe { io.pins.gpio0.clone_unchecked() })
+            .with_ws(unsafe { io.pins.gpio1.clone_unchecked() })
+            .with_dout(unsafe { io.pins.gpio2.clone_unchecked() })
+            .build();
+
+        let mut i2s_rx = i2s
+            .i2s_rx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_din(io.pins.gpio3)
+            .build();
+
+        // enable loopback testing
+        unsafe {
+            let i2s = esp_hal::peripherals::I2S0::steal();
+            i2s.tx_conf().modify(|_, w| w.sig_loopback().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_slave_mod().set_bit());
+
+            i2s.tx_conf().modify(|_, w| w.tx_update().clear_bit());
+            i2s.tx_conf().modify(|_, w| w.tx_update().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_update().clear_bit());
+            i2s.rx_conf().modify(|_, w| w.rx_update().set_bit());
+        }
+
+        let mut i = 0;
+        for b in tx_buffer.iter_mut() {
+            *b = i;
+            i = (i + ADD) % CUT_OFF;
+        }
+
+        let mut rx_transfer = i2s_rx.read_dma_circular(&mut rx_buffer).unwrap();
+        let mut tx_transfer = i2s_tx.write_dma_circular(&tx_buffer).unwrap();
+
+        let writer = async {
+            let mut filler = [0u8; 1000];
+            for _ in 0..40 {
+                for b in filler.iter_mut() {
+                    *b = i;
+                    i = (i + ADD) % CUT_OFF;
+                }
+                tx_transfer.push_async(&filler).await.unwrap();
+            }
+        };
+
+        let reader = async {
+            // a buffer smaller than the DMA buffer is fine for `pop_async`
+            let mut rcv = [0u8; 500];
+            let mut check_i: u8 = 0;
+            let mut received = 0;
+            while received < 20000 {
+                let len = rx_transfer.pop_async(&mut rcv).await.unwrap();
+                assert!(len > 0);
+                for &b in &rcv[..len] {
+                    if b != check_i {
+                        return false;
+                    }
+                    check_i = (check_i + ADD) % CUT_OFF;
+                }
+                received += len;
+            }
+            true
+        };
+
+        let (_, ok) = embassy_futures::join::join(writer, reader).await;
+        assert!(ok);
+    }
+
+    #[test]
+    async fn test_i2s_circular_audio() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let mut io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) =
+            esp_hal::dma_circular_buffers!(2000, 2000);
+
+        let mut i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure_for_async(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        i2s.i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(unsafe { io.pins.gpio0.clone_unchecked() })
+            .with_ws(unsafe { io.pins.gpio1.clone_unchecked() })
+            .with_dout(unsafe { io.pins.gpio2.clone_unchecked() });
+
+        i2s.i2s_rx = i2s
+            .i2s_rx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_din(io.pins.gpio3);
+
+        // enable loopback testing
+        unsafe {
+            let i2s = esp_hal::peripherals::I2S0::steal();
+            i2s.tx_conf().modify(|_, w| w.sig_loopback().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_slave_mod().set_bit());
+
+            i2s.tx_conf().modify(|_, w| w.tx_update().clear_bit());
+            i2s.tx_conf().modify(|_, w| w.tx_update().set_bit());
+
+            i2s.rx_conf().modify(|_, w| w.rx_update().clear_bit());
+            i2s.rx_conf().modify(|_, w| w.rx_update().set_bit());
+        }
+
+        // the initial buffer content holds the first samples
+        let mut next_sample: i16 = 0;
+        for chunk in tx_buffer.chunks_exact_mut(2) {
+            chunk.copy_from_slice(&next_sample.to_le_bytes());
+            next_sample = next_sample.wrapping_add(1);
+        }
+
+        let mut circular = i2s.into_circular_dma(tx_buffer, rx_buffer).unwrap();
+
+        let mut samples = [0i16; 250];
+        let mut expected: i16 = 0;
+        for _ in 0..20 {
+            for sample in samples.iter_mut() {
+                *sample = next_sample;
+                next_sample = next_sample.wrapping_add(1);
+            }
+            circular.write_audio(&samples).await.unwrap();
+
+            circular.read_audio(&mut samples).await.unwrap();
+            for &sample in samples.iter() {
+                assert_eq!(sample, expected);
+                expected = expected.wrapping_add(1);
+            }
+        }
+    }
+
+    #[test]
+    async fn test_i2s_write_cancelled() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let mut timg0 = TimerGroup::new_async(peripherals.TIMG0, &clocks);
+
+        let dma = Dma::new(peripherals.DMA);
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, _, rx_descriptors) = esp_hal::dma_buffers!(16000, 0);
+
+        let i2s = I2s::new(
+            peripherals.I2S0,
+            Standard::Philips,
+            DataFormat::Data16Channel16,
+            16000.Hz(),
+            dma_channel.configure_for_async(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+            &clocks,
+        );
+
+        let mut i2s_tx = i2s
+            .i2s_tx
+            .with_bclk(io.pins.gpio0)
+            .with_ws(io.pins.gpio1)
+            .with_dout(io.pins.gpio2)
+            .build();
+
+        tx_buffer.fill(0x55);
+
+        // 16000 bytes take 250ms to transmit, the write is cancelled long before
+        let res = embassy_futures::select::select(
+            i2s_tx.write_dma_async(&mut tx_buffer[..]),
+            timg0.timer0.delay_async(10.millis()),
+        )
+        .await;
+        assert!(matches!(res, embassy_futures::select::Either::Second(())));
+
+        // the channel was stopped when the write was dropped and can be reused
+        i2s_tx
+            .write_dma_async(&mut tx_buffer[..1600])
+            .await
+            .unwrap();
+    }
 }
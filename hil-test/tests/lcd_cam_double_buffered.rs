@@ -0,0 +1,148 @@
+//! Camera double buffered capture Test
+//!
+//! Folowing pins are used:
+//! VSYNC OUT (LEDC) GPIO2
+//! VSYNC            GPIO3
+//! MCLK             GPIO5
+//! PCLK             GPIO6
+//! H_ENABLE OUT     GPIO1
+//! H_ENABLE         GPIO21
+//!
+//! Connect VSYNC OUT (GPIO2) and VSYNC (GPIO3), MCLK (GPIO5) and PCLK (GPIO6),
+//! H_ENABLE OUT (GPIO1) and H_ENABLE (GPIO21) pins.
+
+//% CHIPS: esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    delay::Delay,
+    dma::{Dma, DmaPriority},
+    dma_buffers,
+    gpio::{Io, Level, Output},
+    lcd_cam::{
+        cam::{Camera, RxEightBits},
+        LcdCam,
+    },
+    ledc::{
+        channel::{self, ChannelIFace},
+        timer::{self, TimerIFace},
+        LSGlobalClkSource,
+        Ledc,
+        LowSpeed,
+    },
+    peripherals::Peripherals,
+    prelude::*,
+    system::SystemControl,
+};
+
+// each buffer holds two descriptors
+const FRAME_BUFFER_SIZE: usize = 8000;
+
+// a frame every 5 ms with a pixel clock of 1 MHz
+const VSYNC_FREQUENCY_HZ: u32 = 200;
+const PCLK_FREQUENCY_MHZ: u32 = 1;
+const FRAME_PERIOD_BYTES: usize = 5000;
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::assert_eq;
+
+    use super::*;
+
+    #[init]
+    fn init() {}
+
+    #[test]
+    #[timeout(3)]
+    fn test_double_buffered_capture() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+        let delay = Delay::new(&clocks);
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        // VSYNC pulses generated by LEDC
+        let mut ledc = Ledc::new(peripherals.LEDC, &clocks);
+        ledc.set_global_slow_clock(LSGlobalClkSource::APBClk);
+        let mut lstimer0 = ledc.get_timer::<LowSpeed>(timer::Number::Timer0);
+        lstimer0
+            .configure(timer::config::Config {
+                duty: timer::config::Duty::Duty10Bit,
+                clock_source: timer::LSClockSource::APBClk,
+                frequency: VSYNC_FREQUENCY_HZ.Hz(),
+            })
+            .unwrap();
+        let mut vsync_out = ledc.get_channel(channel::Number::Channel0, io.pins.gpio2);
+        vsync_out
+            .configure(channel::config::Config {
+                timer: &lstimer0,
+                duty_pct: 10,
+                pin_config: channel::config::PinConfig::PushPull,
+            })
+            .unwrap();
+
+        let _h_enable_out = Output::new(io.pins.gpio1, Level::High);
+
+        let dma = Dma::new(peripherals.DMA);
+        let (_, _, rx_buffer, rx_descriptors) = dma_buffers!(0, 2 * FRAME_BUFFER_SIZE);
+
+        let data_pins = RxEightBits::new(
+            io.pins.gpio7,
+            io.pins.gpio8,
+            io.pins.gpio9,
+            io.pins.gpio10,
+            io.pins.gpio11,
+            io.pins.gpio12,
+            io.pins.gpio13,
+            io.pins.gpio14,
+        );
+
+        let lcd_cam = LcdCam::new(peripherals.LCD_CAM);
+        let mut camera = Camera::new(
+            lcd_cam.cam,
+            dma.channel0.configure(false, DmaPriority::Priority0).rx,
+            rx_descriptors,
+            data_pins,
+            PCLK_FREQUENCY_MHZ.MHz(),
+            &clocks,
+        )
+        .with_master_clock(io.pins.gpio5)
+        .with_pixel_clock(io.pins.gpio6)
+        .with_ctrl_pins(io.pins.gpio3, io.pins.gpio21);
+
+        let (first, second) = rx_buffer.split_at_mut(FRAME_BUFFER_SIZE);
+        let mut stream = camera.start_double_buffered([first, second]).unwrap();
+
+        // Frames which are handed back in time are not dropped, only the first
+        // frame is.
+        for _ in 0..5 {
+            let frame = stream.wait_for_frame().unwrap();
+            defmt::assert!(
+                frame.len() > FRAME_PERIOD_BYTES / 2 && frame.len() <= FRAME_PERIOD_BYTES,
+                "unexpected frame length {}",
+                frame.len()
+            );
+        }
+        assert_eq!(stream.dropped_frames(), 1);
+
+        // Holding on to a frame for several frame periods drops the frames
+        // which arrive while both buffers are in use, they are counted at least
+        // once.
+        {
+            let _frame = stream.wait_for_frame().unwrap();
+            delay.delay_millis(30);
+        }
+        for _ in 0..2 {
+            let frame = stream.wait_for_frame().unwrap();
+            defmt::assert!(frame.len() > FRAME_PERIOD_BYTES / 2);
+        }
+        defmt::assert!(stream.dropped_frames() >= 2);
+    }
+}
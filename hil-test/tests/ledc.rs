@@ -0,0 +1,238 @@
+//! LEDC tests
+
+//% CHIPS: esp32 esp32c2 esp32c3 esp32c6 esp32h2 esp32s2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{clock::Clocks, gpio::GpioPin, peripherals::LEDC};
+
+struct Context<'d> {
+    ledc: LEDC,
+    gpio2: GpioPin<2>,
+    gpio3: GpioPin<3>,
+    gpio4: GpioPin<4>,
+    clocks: Clocks<'d>,
+}
+
+/// The raw duty of a low speed channel, including a running fade
+fn current_duty(channel: usize) -> u32 {
+    let ledc = unsafe { LEDC::steal() };
+    #[cfg(feature = "esp32")]
+    let duty = ledc.lsch(channel).duty_r().read().duty_r().bits();
+    #[cfg(not(feature = "esp32"))]
+    let duty = ledc.ch(channel).duty_r().read().duty_r().bits();
+
+    // the lowest 4 bits are the fractional part
+    duty >> 4
+}
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::{assert, assert_eq};
+    use esp_hal::{
+        clock::ClockControl,
+        gpio::Io,
+        ledc::{
+            channel::{self, ChannelIFace, FadeError},
+            timer::{self, TimerIFace},
+            LSGlobalClkSource,
+            Ledc,
+            LowSpeed,
+        },
+        peripherals::Peripherals,
+        prelude::*,
+        system::SystemControl,
+    };
+
+    use super::*;
+
+    #[init]
+    fn init() -> Context<'static> {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        Context {
+            ledc: peripherals.LEDC,
+            gpio2: io.pins.gpio2,
+            gpio3: io.pins.gpio3,
+            gpio4: io.pins.gpio4,
+            clocks,
+        }
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_fade_blocking(ctx: Context<'static>) {
+        let mut ledc = Ledc::new(ctx.ledc, &ctx.clocks);
+        ledc.set_global_slow_clock(LSGlobalClkSource::APBClk);
+
+        let mut timer0 = ledc.get_timer::<LowSpeed>(timer::Number::Timer0);
+        timer0
+            .configure(timer::config::Config {
+                duty: timer::config::Duty::Duty8Bit,
+                clock_source: timer::LSClockSource::APBClk,
+                frequency: 1.kHz(),
+            })
+            .unwrap();
+
+        let mut channel0 = ledc.get_channel(channel::Number::Channel0, ctx.gpio2);
+        channel0
+            .configure(channel::config::Config {
+                timer: &timer0,
+                duty_pct: 0,
+                pin_config: channel::config::PinConfig::PushPull,
+            })
+            .unwrap();
+
+        // 50 steps of 4, each taking 2 PWM cycles of 1 ms
+        let start = esp_hal::time::current_time();
+        channel0.fade_blocking(200, 4, 2).unwrap();
+        let elapsed = (esp_hal::time::current_time() - start).to_millis();
+
+        assert!((95..=110).contains(&elapsed));
+        assert_eq!(current_duty(0), 200);
+
+        // fading down ends on the target even if the change isn't a multiple
+        // of the step
+        channel0.fade_blocking(97, 10, 1).unwrap();
+        assert_eq!(current_duty(0), 97);
+
+        // the duty is already within one step of the target
+        let start = esp_hal::time::current_time();
+        channel0.fade_blocking(100, 5, 1000).unwrap();
+        assert!((esp_hal::time::current_time() - start).to_millis() < 5);
+        assert_eq!(current_duty(0), 100);
+    }
+
+    #[test]
+    fn test_fade_rejects_invalid_parameters(ctx: Context<'static>) {
+        let mut ledc = Ledc::new(ctx.ledc, &ctx.clocks);
+        ledc.set_global_slow_clock(LSGlobalClkSource::APBClk);
+
+        let mut timer0 = ledc.get_timer::<LowSpeed>(timer::Number::Timer0);
+        timer0
+            .configure(timer::config::Config {
+                duty: timer::config::Duty::Duty12Bit,
+                clock_source: timer::LSClockSource::APBClk,
+                frequency: 1.kHz(),
+            })
+            .unwrap();
+
+        let mut channel0 = ledc.get_channel(channel::Number::Channel0, ctx.gpio2);
+        channel0
+            .configure(channel::config::Config {
+                timer: &timer0,
+                duty_pct: 0,
+                pin_config: channel::config::PinConfig::PushPull,
+            })
+            .unwrap();
+
+        assert_eq!(
+            channel0.fade_blocking(4097, 1, 1),
+            Err(channel::Error::Duty)
+        );
+        assert_eq!(
+            channel0.fade_blocking(100, 0, 1),
+            Err(channel::Error::Fade(FadeError::DutyRange))
+        );
+        assert_eq!(
+            channel0.fade_blocking(100, 1, 0),
+            Err(channel::Error::Fade(FadeError::Duration))
+        );
+        assert_eq!(
+            channel0.fade_blocking(100, 1, 1024),
+            Err(channel::Error::Fade(FadeError::Duration))
+        );
+        // more than 1023 steps
+        assert_eq!(
+            channel0.fade_blocking(1024, 1, 1),
+            Err(channel::Error::Fade(FadeError::DutyRange))
+        );
+
+        // nothing was started
+        assert_eq!(current_duty(0), 0);
+    }
+
+    #[test]
+    fn test_synchronized_update(ctx: Context<'static>) {
+        let mut ledc = Ledc::new(ctx.ledc, &ctx.clocks);
+        ledc.set_global_slow_clock(LSGlobalClkSource::APBClk);
+
+        let config = timer::config::Config {
+            duty: timer::config::Duty::Duty10Bit,
+            clock_source: timer::LSClockSource::APBClk,
+            frequency: 1.kHz(),
+        };
+        let mut timer0 = ledc.get_timer::<LowSpeed>(timer::Number::Timer0);
+        timer0.configure(config).unwrap();
+        let mut timer1 = ledc.get_timer::<LowSpeed>(timer::Number::Timer1);
+        timer1.configure(config).unwrap();
+        let timer2 = ledc.get_timer::<LowSpeed>(timer::Number::Timer2);
+
+        let mut red = ledc.get_channel(channel::Number::Channel0, ctx.gpio2);
+        let mut green = ledc.get_channel(channel::Number::Channel1, ctx.gpio3);
+        let mut blue = ledc.get_channel(channel::Number::Channel2, ctx.gpio4);
+        red.configure(channel::config::Config {
+            timer: &timer0,
+            duty_pct: 0,
+            pin_config: channel::config::PinConfig::PushPull,
+        })
+        .unwrap();
+        green
+            .configure(channel::config::Config {
+                timer: &timer0,
+                duty_pct: 0,
+                pin_config: channel::config::PinConfig::PushPull,
+            })
+            .unwrap();
+        blue.configure(channel::config::Config {
+            timer: &timer1,
+            duty_pct: 0,
+            pin_config: channel::config::PinConfig::PushPull,
+        })
+        .unwrap();
+
+        // a channel driven by another timer or an out of range duty changes
+        // nothing
+        assert_eq!(
+            timer0.synchronized_update(&mut [&mut red, &mut green, &mut blue], &[1, 2, 3]),
+            Err(channel::Error::TimerMismatch)
+        );
+        assert_eq!(
+            timer0.synchronized_update(&mut [&mut red, &mut green], &[1, 1025]),
+            Err(channel::Error::Duty)
+        );
+        assert_eq!(
+            timer2.synchronized_update(&mut [&mut red], &[1]),
+            Err(channel::Error::Timer)
+        );
+        assert_eq!(current_duty(0), 0);
+        assert_eq!(current_duty(1), 0);
+
+        // the duties change on the same PWM cycle, whenever the duty read
+        // first has changed the one read second has changed too
+        for (red_duty, green_duty) in [(100, 900), (1024, 0), (512, 256)] {
+            timer0
+                .synchronized_update(&mut [&mut red, &mut green], &[red_duty, green_duty])
+                .unwrap();
+
+            let start = esp_hal::time::current_time();
+            loop {
+                let first = current_duty(0);
+                let second = current_duty(1);
+                if first == red_duty {
+                    assert_eq!(second, green_duty);
+                    break;
+                }
+                assert!((esp_hal::time::current_time() - start).to_millis() < 5);
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! LEDC tests (Async)
+
+//% CHIPS: esp32 esp32c2 esp32c3 esp32c6 esp32h2 esp32s2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{clock::Clocks, gpio::GpioPin, peripherals::LEDC};
+
+struct Context<'d> {
+    ledc: LEDC,
+    gpio2: GpioPin<2>,
+    clocks: Clocks<'d>,
+}
+
+/// The raw duty of low speed channel 0, including a running fade
+fn current_duty() -> u32 {
+    let ledc = unsafe { LEDC::steal() };
+    #[cfg(feature = "esp32")]
+    let duty = ledc.lsch(0).duty_r().read().duty_r().bits();
+    #[cfg(not(feature = "esp32"))]
+    let duty = ledc.ch(0).duty_r().read().duty_r().bits();
+
+    // the lowest 4 bits are the fractional part
+    duty >> 4
+}
+
+#[cfg(test)]
+#[embedded_test::tests(executor = esp_hal_embassy::Executor::new())]
+mod tests {
+    use defmt::{assert, assert_eq};
+    use esp_hal::{
+        clock::ClockControl,
+        gpio::Io,
+        ledc::{
+            channel::{self, ChannelIFace},
+            timer::{self, TimerIFace},
+            LSGlobalClkSource,
+            Ledc,
+            LowSpeed,
+        },
+        peripherals::Peripherals,
+        prelude::*,
+        system::SystemControl,
+    };
+
+    use super::*;
+
+    #[init]
+    async fn init() -> Context<'static> {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        Context {
+            ledc: peripherals.LEDC,
+            gpio2: io.pins.gpio2,
+            clocks,
+        }
+    }
+
+    #[test]
+    #[timeout(3)]
+    async fn test_start_fade(ctx: Context<'static>) {
+        let mut ledc = Ledc::new_async(ctx.ledc, &ctx.clocks);
+        ledc.set_global_slow_clock(LSGlobalClkSource::APBClk);
+
+        let mut timer0 = ledc.get_timer::<LowSpeed>(timer::Number::Timer0);
+        timer0
+            .configure(timer::config::Config {
+                duty: timer::config::Duty::Duty8Bit,
+                clock_source: timer::LSClockSource::APBClk,
+                frequency: 1.kHz(),
+            })
+            .unwrap();
+
+        let mut channel0 = ledc.get_channel(channel::Number::Channel0, ctx.gpio2);
+        channel0
+            .configure(channel::config::Config {
+                timer: &timer0,
+                duty_pct: 0,
+                pin_config: channel::config::PinConfig::PushPull,
+            })
+            .unwrap();
+
+        // 50 steps of 4, each taking 2 PWM cycles of 1 ms
+        let start = esp_hal::time::current_time();
+        let fade = channel0.start_fade(200, 4, 2);
+        let mut fade = core::pin::pin!(fade);
+
+        // the fade runs in hardware and the future waits for its end
+        assert!(embassy_futures::poll_once(fade.as_mut()).is_pending());
+        fade.await.unwrap();
+        let elapsed = (esp_hal::time::current_time() - start).to_millis();
+
+        assert!((95..=110).contains(&elapsed));
+        assert_eq!(current_duty(), 200);
+
+        // consecutive fades in both directions
+        channel0.start_fade(50, 10, 1).await.unwrap();
+        assert_eq!(current_duty(), 50);
+        channel0.start_fade(256, 16, 1).await.unwrap();
+        assert_eq!(current_duty(), 256);
+    }
+}
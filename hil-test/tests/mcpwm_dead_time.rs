@@ -0,0 +1,177 @@
+//! MCPWM dead time tests
+
+//% CHIPS: esp32 esp32c6 esp32h2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::Clocks,
+    gpio::{GpioPin, Io},
+    peripherals::MCPWM0,
+};
+
+struct Context<'d> {
+    mcpwm: MCPWM0,
+    gpio2: GpioPin<2>,
+    gpio3: GpioPin<3>,
+    clocks: Clocks<'d>,
+}
+
+/// The frequency of the peripheral clock in MHz
+#[cfg(feature = "esp32h2")]
+const CLOCK_MHZ: u32 = 40;
+#[cfg(not(feature = "esp32h2"))]
+const CLOCK_MHZ: u32 = 32;
+
+/// The rising and falling edge delay of `operator` in clock cycles
+fn dead_time_cycles(operator: usize) -> (u16, u16) {
+    let mcpwm = unsafe { MCPWM0::steal() };
+    let ch = mcpwm.ch(operator);
+
+    #[cfg(feature = "esp32s3")]
+    let (red, fed) = (
+        ch.db_red_cfg().read().red().bits(),
+        ch.db_fed_cfg().read().fed().bits(),
+    );
+    #[cfg(not(feature = "esp32s3"))]
+    let (red, fed) = (
+        ch.dt_red_cfg().read().red().bits(),
+        ch.dt_fed_cfg().read().fed().bits(),
+    );
+
+    (red, fed)
+}
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::{assert, assert_eq};
+    use esp_hal::{
+        clock::ClockControl,
+        mcpwm::{
+            operator::{DeadTimeCfg, DeadTimeMode, PwmPinConfig},
+            timer::PwmWorkingMode,
+            McPwm,
+            PeripheralClockConfig,
+        },
+        peripherals::Peripherals,
+        prelude::*,
+        system::SystemControl,
+    };
+
+    use super::*;
+
+    #[init]
+    fn init() -> Context<'static> {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        Context {
+            mcpwm: peripherals.MCPWM0,
+            gpio2: io.pins.gpio2,
+            gpio3: io.pins.gpio3,
+            clocks,
+        }
+    }
+
+    #[test]
+    fn test_dead_time(ctx: Context<'static>) {
+        let clock_cfg =
+            PeripheralClockConfig::with_frequency(&ctx.clocks, CLOCK_MHZ.MHz()).unwrap();
+        let mut mcpwm = McPwm::new(ctx.mcpwm, clock_cfg);
+
+        // a period of 100 clock cycles
+        mcpwm.operator0.set_timer(&mcpwm.timer0);
+        mcpwm
+            .timer0
+            .start(clock_cfg.timer_clock_with_prescaler(99, PwmWorkingMode::Increase, 0));
+
+        // the delays are rounded up to the next clock cycle
+        mcpwm
+            .operator0
+            .set_dead_time(100, 250, DeadTimeMode::ActiveHighComplement)
+            .unwrap();
+        let expected = (
+            (100 * CLOCK_MHZ).div_ceil(1000),
+            (250 * CLOCK_MHZ).div_ceil(1000),
+        );
+        assert_eq!(dead_time_cycles(0), (expected.0 as u16, expected.1 as u16));
+
+        // both delays together may take up the whole period, but not more
+        let half_period_ns = 50 * 1000 / CLOCK_MHZ;
+        mcpwm
+            .operator0
+            .set_dead_time(
+                half_period_ns,
+                half_period_ns,
+                DeadTimeMode::ActiveLowComplement,
+            )
+            .unwrap();
+        assert_eq!(dead_time_cycles(0), (50, 50));
+
+        assert!(mcpwm
+            .operator0
+            .set_dead_time(
+                half_period_ns,
+                half_period_ns + 100,
+                DeadTimeMode::ActiveHigh
+            )
+            .is_err());
+        // a rejected dead time doesn't change the registers
+        assert_eq!(dead_time_cycles(0), (50, 50));
+
+        // the delays don't fit into the registers, even with a long period
+        mcpwm.timer0.start(clock_cfg.timer_clock_with_prescaler(
+            u16::MAX,
+            PwmWorkingMode::Increase,
+            255,
+        ));
+        mcpwm
+            .operator0
+            .set_dead_time(1_500_000, 0, DeadTimeMode::Bypass)
+            .unwrap();
+        assert_eq!(dead_time_cycles(0), ((1_500 * CLOCK_MHZ) as u16, 0));
+        assert!(mcpwm
+            .operator0
+            .set_dead_time(3_000_000, 0, DeadTimeMode::Bypass)
+            .is_err());
+    }
+
+    #[test]
+    fn test_linked_pins_dead_time(ctx: Context<'static>) {
+        let clock_cfg =
+            PeripheralClockConfig::with_frequency(&ctx.clocks, CLOCK_MHZ.MHz()).unwrap();
+        let mut mcpwm = McPwm::new(ctx.mcpwm, clock_cfg);
+
+        mcpwm.operator1.set_timer(&mcpwm.timer1);
+        mcpwm
+            .timer1
+            .start(clock_cfg.timer_clock_with_prescaler(99, PwmWorkingMode::UpDown, 0));
+
+        let mut pins = mcpwm.operator1.with_linked_pins(
+            ctx.gpio2,
+            PwmPinConfig::UP_DOWN_ACTIVE_HIGH,
+            ctx.gpio3,
+            PwmPinConfig::EMPTY,
+            DeadTimeCfg::new_bypass(),
+        );
+
+        // counting up and down doubles the period to 198 cycles
+        let ns = 90 * 1000 / CLOCK_MHZ;
+        pins.set_dead_time(ns, ns, DeadTimeMode::ActiveHighComplement)
+            .unwrap();
+        assert_eq!(dead_time_cycles(1), (90, 90));
+
+        let ns = 100 * 1000 / CLOCK_MHZ;
+        assert!(pins
+            .set_dead_time(ns, ns, DeadTimeMode::ActiveHighComplement)
+            .is_err());
+        assert_eq!(dead_time_cycles(1), (90, 90));
+    }
+}
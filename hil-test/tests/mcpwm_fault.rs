@@ -0,0 +1,92 @@
+//! MCPWM fault detection tests
+//!
+//! It's assumed GPIO2 is connected to GPIO3
+
+//% CHIPS: esp32 esp32c6 esp32h2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::Clocks,
+    delay::Delay,
+    gpio::{GpioPin, Level, Output},
+    peripherals::MCPWM0,
+};
+
+struct Context<'d> {
+    mcpwm: MCPWM0,
+    gpio2: GpioPin<2>,
+    fault_pin: Output<'d, GpioPin<3>>,
+    delay: Delay,
+    clocks: Clocks<'d>,
+}
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use esp_hal::{
+        clock::ClockControl,
+        gpio::Io,
+        mcpwm::{
+            fault::{BrakeMode, FaultEvent, Polarity},
+            McPwm,
+            PeripheralClockConfig,
+        },
+        peripherals::Peripherals,
+        prelude::*,
+        system::SystemControl,
+    };
+
+    use super::*;
+
+    #[init]
+    fn init() -> Context<'static> {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        Context {
+            mcpwm: peripherals.MCPWM0,
+            gpio2: io.pins.gpio2,
+            fault_pin: Output::new(io.pins.gpio3, Level::Low),
+            delay: Delay::new(&clocks),
+            clocks,
+        }
+    }
+
+    #[test]
+    fn test_fault_events(mut ctx: Context<'static>) {
+        #[cfg(feature = "esp32h2")]
+        let clock_cfg = PeripheralClockConfig::with_frequency(&ctx.clocks, 40.MHz()).unwrap();
+        #[cfg(not(feature = "esp32h2"))]
+        let clock_cfg = PeripheralClockConfig::with_frequency(&ctx.clocks, 32.MHz()).unwrap();
+
+        let mut mcpwm = McPwm::new(ctx.mcpwm, clock_cfg);
+        let fault = mcpwm.fault0.with_pin(ctx.gpio2, Polarity::ActiveHigh);
+        mcpwm.operator0.set_brake_mode(&fault, BrakeMode::OneShot);
+
+        ctx.delay.delay_micros(10);
+        fault.clear_event(FaultEvent::Fault);
+        fault.clear_event(FaultEvent::FaultCleared);
+        assert!(!fault.is_active());
+
+        ctx.fault_pin.set_high();
+        ctx.delay.delay_micros(10);
+        assert!(fault.is_active());
+        assert!(fault.is_event_set(FaultEvent::Fault));
+        assert!(!fault.handle_fault_cleared());
+        assert!(!fault.release_brake());
+
+        ctx.fault_pin.set_low();
+        ctx.delay.delay_micros(10);
+        assert!(!fault.is_active());
+        assert!(fault.is_event_set(FaultEvent::FaultCleared));
+        assert!(fault.handle_fault_cleared());
+        assert!(!fault.is_event_set(FaultEvent::FaultCleared));
+    }
+}
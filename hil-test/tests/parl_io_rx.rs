@@ -0,0 +1,122 @@
+//! PARL_IO RX Test
+//!
+//! Folowing pins are used:
+//! ENABLE OUT GPIO2
+//! VALID      GPIO3
+//! DATA       GPIO4
+//!
+//! Connect ENABLE OUT (GPIO2) and VALID (GPIO3) pins.
+
+//% CHIPS: esp32c6 esp32h2
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    delay::Delay,
+    dma::{Dma, DmaPriority},
+    dma_buffers,
+    gpio::{Io, Level, Output},
+    parl_io::{
+        no_clk_pin,
+        BitPackOrder,
+        EnableMode,
+        EofMode,
+        ParlIoRxOnly,
+        RxOneBit,
+        RxPinConfigWithValidPin,
+    },
+    peripherals::Peripherals,
+    prelude::*,
+    system::SystemControl,
+};
+
+// fits into a single descriptor
+const FRAME_BUFFER_SIZE: usize = 4000;
+
+// one bit is sampled every microsecond
+const BYTES_PER_MS: usize = 1000 / 8;
+
+fn assert_frame_len(len: usize, millis: usize) {
+    let expected = millis * BYTES_PER_MS;
+    defmt::assert!(
+        len > expected - BYTES_PER_MS / 2 && len < expected + BYTES_PER_MS / 2,
+        "received {} bytes, expected about {}",
+        len,
+        expected
+    );
+}
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use super::*;
+
+    #[init]
+    fn init() {}
+
+    #[test]
+    #[timeout(3)]
+    fn test_frames_ended_by_enable_signal() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+        let delay = Delay::new(&clocks);
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let mut enable = Output::new(io.pins.gpio2, Level::Low);
+        let mut rx_pins = RxPinConfigWithValidPin::new(
+            RxOneBit::new(io.pins.gpio4),
+            io.pins.gpio3,
+            EnableMode::HighLevel,
+            EofMode::EnableSignal,
+        );
+
+        let dma = Dma::new(peripherals.DMA);
+        let (_, _, rx_buffer, rx_descriptors) = dma_buffers!(0, 2 * FRAME_BUFFER_SIZE);
+
+        let parl_io = ParlIoRxOnly::new(
+            peripherals.PARL_IO,
+            dma.channel0.configure(false, DmaPriority::Priority0),
+            rx_descriptors,
+            1.MHz(),
+            &clocks,
+        )
+        .unwrap();
+
+        let mut parl_io_rx = parl_io
+            .rx
+            .with_config(&mut rx_pins, no_clk_pin(), BitPackOrder::Msb, None)
+            .unwrap();
+
+        // a single frame
+        {
+            let mut buffer = &mut rx_buffer[..FRAME_BUFFER_SIZE];
+            let transfer = parl_io_rx.read_dma(&mut buffer).unwrap();
+
+            enable.set_high();
+            delay.delay_millis(8);
+            enable.set_low();
+
+            while !transfer.is_done() {}
+            assert_frame_len(transfer.received_bytes(), 8);
+            transfer.wait().unwrap();
+        }
+
+        // frames received into two buffers in turn
+        let (first, second) = rx_buffer.split_at_mut(FRAME_BUFFER_SIZE);
+        let mut frames = parl_io_rx.read_frames_dma([first, second]).unwrap();
+
+        for millis in [4, 8, 2, 6] {
+            enable.set_high();
+            delay.delay_millis(millis as u32);
+            enable.set_low();
+
+            let frame = frames.wait_frame().unwrap();
+            assert_frame_len(frame.len(), millis);
+        }
+    }
+}
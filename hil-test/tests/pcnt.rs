@@ -9,13 +9,15 @@
 
 use defmt_rtt as _;
 use esp_backtrace as _;
-use esp_hal::{delay::Delay, gpio::GpioPin, pcnt::Pcnt};
+use esp_hal::{clock::Clocks, delay::Delay, gpio::GpioPin, pcnt::Pcnt, peripherals::LEDC};
 
 struct Context<'d> {
     pcnt: Pcnt<'d>,
     gpio2: GpioPin<2>,
     gpio3: GpioPin<3>,
     delay: Delay,
+    ledc: LEDC,
+    clocks: Clocks<'d>,
 }
 
 #[cfg(test)]
@@ -25,8 +27,19 @@ mod tests {
         clock::ClockControl,
         delay::Delay,
         gpio::{Io, Level, Output, Pull},
-        pcnt::channel::{EdgeMode, PcntInputConfig, PcntSource},
+        ledc::{
+            channel::{self, ChannelIFace},
+            timer::{self, TimerIFace},
+            LSGlobalClkSource,
+            Ledc,
+            LowSpeed,
+        },
+        pcnt::{
+            channel::{EdgeMode, PcntInputConfig, PcntSource},
+            unit::CountEdges,
+        },
         peripherals::Peripherals,
+        prelude::*,
         system::SystemControl,
     };
 
@@ -45,6 +58,8 @@ mod tests {
             gpio2: io.pins.gpio2,
             gpio3: io.pins.gpio3,
             delay: Delay::new(&clocks),
+            ledc: peripherals.LEDC,
+            clocks,
         }
     }
 
@@ -358,4 +373,51 @@ mod tests {
             ctx.delay.delay_micros(1);
         }
     }
+
+    #[test]
+    fn test_frequency_counter(ctx: Context<'static>) {
+        // Generate a 1kHz signal on gpio3
+        let mut ledc = Ledc::new(ctx.ledc, &ctx.clocks);
+        ledc.set_global_slow_clock(LSGlobalClkSource::APBClk);
+
+        let mut timer0 = ledc.get_timer::<LowSpeed>(timer::Number::Timer0);
+        timer0
+            .configure(timer::config::Config {
+                duty: timer::config::Duty::Duty10Bit,
+                clock_source: timer::LSClockSource::APBClk,
+                frequency: 1.kHz(),
+            })
+            .unwrap();
+
+        let mut channel0 = ledc.get_channel(channel::Number::Channel0, ctx.gpio3);
+        channel0
+            .configure(channel::config::Config {
+                timer: &timer0,
+                duty_pct: 50,
+                pin_config: channel::config::PinConfig::PushPull,
+            })
+            .unwrap();
+
+        let mut counter = ctx.pcnt.unit0.into_frequency_counter(
+            PcntSource::from_pin(ctx.gpio2, PcntInputConfig { pull: Pull::Down }),
+            CountEdges::Rising,
+        );
+
+        let pulses = counter.count_pulses(100);
+        assert!((99..=101).contains(&pulses));
+
+        let mut counter = counter.release().into_frequency_counter(
+            PcntSource::from_pin(
+                unsafe { GpioPin::<2>::steal() },
+                PcntInputConfig { pull: Pull::Down },
+            ),
+            CountEdges::Both,
+        );
+
+        let pulses = counter.count_pulses(100);
+        assert!((199..=201).contains(&pulses));
+
+        let frequency = counter.frequency(100);
+        assert!((999..=1001).contains(&frequency.raw()));
+    }
 }
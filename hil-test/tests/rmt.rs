@@ -14,7 +14,15 @@ use esp_hal::{
     gpio::Io,
     peripherals::Peripherals,
     prelude::*,
-    rmt::{PulseCode, Rmt, RxChannel, RxChannelConfig, TxChannel, TxChannelConfig},
+    rmt::{
+        protocols::ws2812::{buffer_size, Ws2812},
+        PulseCode,
+        Rmt,
+        RxChannel,
+        RxChannelConfig,
+        TxChannel,
+        TxChannelConfig,
+    },
     system::SystemControl,
 };
 
@@ -111,4 +119,84 @@ mod tests {
         // can't be equal
         assert_eq!(&tx_data[..18], &rcv_data[..18]);
     }
+
+    #[test]
+    #[timeout(1)]
+    fn rmt_ws2812_latches_with_reset() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "esp32h2")] {
+                let freq = 32.MHz();
+            } else {
+                let freq = 80.MHz();
+            }
+        };
+
+        let rmt = Rmt::new(peripherals.RMT, freq, &clocks).unwrap();
+
+        let tx_config = TxChannelConfig {
+            clk_divider: 4,
+            ..TxChannelConfig::default()
+        };
+
+        let tx_channel = {
+            use esp_hal::rmt::TxChannelCreator;
+            rmt.channel0.configure(io.pins.gpio2, tx_config).unwrap()
+        };
+
+        // the reception ends well within the reset time
+        let rx_config = RxChannelConfig {
+            clk_divider: 4,
+            idle_threshold: 800,
+            ..RxChannelConfig::default()
+        };
+
+        cfg_if::cfg_if! {
+            if #[cfg(any(feature = "esp32", feature = "esp32s2"))] {
+                let  rx_channel = {
+                    use esp_hal::rmt::RxChannelCreator;
+                    rmt.channel1.configure(io.pins.gpio3, rx_config).unwrap()
+                };
+            } else if #[cfg(feature = "esp32s3")] {
+                let  rx_channel = {
+                    use esp_hal::rmt::RxChannelCreator;
+                    rmt.channel7.configure(io.pins.gpio3, rx_config).unwrap()
+                };
+            } else {
+                let  rx_channel = {
+                    use esp_hal::rmt::RxChannelCreator;
+                    rmt.channel2.configure(io.pins.gpio3, rx_config).unwrap()
+                };
+            }
+        }
+
+        let mut buffer = [PulseCode::default(); buffer_size(1)];
+        let mut leds = Ws2812::new(tx_channel, freq / 4, &mut buffer).unwrap();
+
+        let mut rcv_data = [PulseCode::default(); 48];
+
+        let rx_transaction = rx_channel.receive(&mut rcv_data).unwrap();
+        let start = esp_hal::time::current_time();
+        leds.write_pixels(&[(0x0f, 0xa5, 0x3c)]).unwrap();
+        let end = esp_hal::time::current_time();
+        rx_transaction.wait().unwrap();
+
+        // the line is held low for the reset time after the 24 bits of 1.25µs
+        assert!((end - start).to_micros() >= 300u64);
+
+        // a `1` bit is high twice as long as a `0` bit, the bits are sent as
+        // green, red, blue
+        let bits = 0xa5_0f_3cu32;
+        let threshold = rcv_data[..24].iter().map(|code| code.length1).sum::<u16>() / 24;
+        for (i, code) in rcv_data[..24].iter().enumerate() {
+            assert!(code.level1);
+            let one = bits & (1 << (23 - i)) != 0;
+            assert_eq!(code.length1 > threshold, one);
+        }
+    }
 }
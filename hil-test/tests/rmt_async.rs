@@ -0,0 +1,114 @@
+//! RMT Loopback Test (Async)
+//!
+//! It's assumed GPIO2 is connected to GPIO3
+
+//% CHIPS: esp32 esp32c3 esp32c6 esp32h2 esp32s2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    gpio::Io,
+    peripherals::Peripherals,
+    prelude::*,
+    rmt::{
+        asynch::{RxChannelAsync, TxChannelAsync},
+        PulseCode,
+        Rmt,
+        RxChannelConfig,
+        RxChannelCreatorAsync,
+        TxChannelConfig,
+        TxChannelCreatorAsync,
+    },
+    system::SystemControl,
+};
+
+#[cfg(test)]
+#[embedded_test::tests(executor = esp_hal_embassy::Executor::new())]
+mod tests {
+    use defmt::{assert, assert_eq};
+
+    use super::*;
+
+    #[init]
+    async fn init() {}
+
+    #[test]
+    #[timeout(1)]
+    async fn rmt_loopback_async() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "esp32h2")] {
+                let freq = 32.MHz();
+            } else {
+                let freq = 80.MHz();
+            }
+        };
+
+        let rmt = Rmt::new_async(peripherals.RMT, freq, &clocks).unwrap();
+
+        let tx_config = TxChannelConfig {
+            clk_divider: 255,
+            ..TxChannelConfig::default()
+        };
+
+        let mut tx_channel = rmt.channel0.configure(io.pins.gpio2, tx_config).unwrap();
+
+        let rx_config = RxChannelConfig {
+            clk_divider: 255,
+            idle_threshold: 1000,
+            ..RxChannelConfig::default()
+        };
+
+        cfg_if::cfg_if! {
+            if #[cfg(any(feature = "esp32", feature = "esp32s2"))] {
+                let mut rx_channel = rmt.channel1.configure(io.pins.gpio3, rx_config).unwrap();
+            } else if #[cfg(feature = "esp32s3")] {
+                let mut rx_channel = rmt.channel7.configure(io.pins.gpio3, rx_config).unwrap();
+            } else {
+                let mut rx_channel = rmt.channel2.configure(io.pins.gpio3, rx_config).unwrap();
+            }
+        }
+
+        let mut tx_data = [PulseCode {
+            level1: true,
+            length1: 200,
+            level2: false,
+            length2: 50,
+        }; 20];
+
+        tx_data[tx_data.len() - 2] = PulseCode {
+            level1: true,
+            length1: 3000,
+            level2: false,
+            length2: 500,
+        };
+        tx_data[tx_data.len() - 1] = PulseCode::default();
+
+        let mut rcv_data = [PulseCode::default(); 48];
+
+        let (received, sent) = embassy_futures::join::join(
+            rx_channel.receive(&mut rcv_data),
+            tx_channel.transmit(&tx_data),
+        )
+        .await;
+        sent.unwrap();
+        let received = received.unwrap();
+
+        // the receiver stops at the idle threshold, which is hit in the second
+        // to last pulse code
+        assert!(received >= 18 && received <= tx_data.len());
+
+        // the last two pulse-codes are the ones which wait for the timeout so they
+        // can't be equal
+        assert_eq!(&tx_data[..18], &rcv_data[..18]);
+    }
+}
@@ -0,0 +1,125 @@
+//! SHA tests, using the `digest` traits
+
+//% CHIPS: esp32 esp32c2 esp32c3 esp32c6 esp32h2 esp32s2 esp32s3
+//% FEATURES: digest
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use digest::{FixedOutput, FixedOutputReset, Update};
+use esp_backtrace as _;
+use esp_hal::{
+    peripherals::Peripherals,
+    sha::{Sha1, Sha256, ShaDigest},
+};
+use hex_literal::hex;
+
+// Test vectors of FIPS 180-2, appendix A and B
+const ONE_BLOCK: &[u8] = b"abc";
+const TWO_BLOCKS: &[u8] = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::assert_eq;
+
+    use super::*;
+
+    #[init]
+    fn init() {}
+
+    #[test]
+    fn test_sha_1() {
+        let peripherals = Peripherals::take();
+        let mut hasher = ShaDigest::<Sha1>::new(peripherals.SHA);
+        hasher.update(ONE_BLOCK);
+
+        assert_eq!(
+            hasher.finalize_fixed()[..],
+            hex!("a9993e364706816aba3e25717850c26c9cd0d89d")
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "esp32"))]
+    fn test_sha_224() {
+        let peripherals = Peripherals::take();
+        let mut hasher = ShaDigest::<esp_hal::sha::Sha224>::new(peripherals.SHA);
+        hasher.update(ONE_BLOCK);
+
+        assert_eq!(
+            hasher.finalize_fixed()[..],
+            hex!("23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7")
+        );
+    }
+
+    #[test]
+    fn test_sha_256() {
+        let peripherals = Peripherals::take();
+        let mut hasher = ShaDigest::<Sha256>::new(peripherals.SHA);
+        hasher.update(ONE_BLOCK);
+
+        assert_eq!(
+            hasher.finalize_fixed()[..],
+            hex!("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+    }
+
+    #[test]
+    fn test_sha_256_split_and_reset() {
+        let peripherals = Peripherals::take();
+        const EXPECTED: [u8; 32] =
+            hex!("248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+
+        let mut hasher = ShaDigest::<Sha256>::new(peripherals.SHA);
+
+        // Unaligned updates, the padding needs a second block
+        let (first, second) = TWO_BLOCKS.split_at(13);
+        hasher.update(first);
+        hasher.update(second);
+        assert_eq!(hasher.finalize_fixed_reset()[..], EXPECTED);
+
+        // The state is reset for the next hash
+        hasher.update(TWO_BLOCKS);
+        assert_eq!(hasher.finalize_fixed_reset()[..], EXPECTED);
+
+        // Data discarded by `reset` doesn't contribute to the hash
+        hasher.update(b"discarded");
+        digest::Reset::reset(&mut hasher);
+        hasher.update(TWO_BLOCKS);
+        assert_eq!(hasher.finalize_fixed()[..], EXPECTED);
+    }
+
+    #[test]
+    #[cfg(any(feature = "esp32", feature = "esp32s2", feature = "esp32s3"))]
+    fn test_sha_384() {
+        let peripherals = Peripherals::take();
+        let mut hasher = ShaDigest::<esp_hal::sha::Sha384>::new(peripherals.SHA);
+        hasher.update(ONE_BLOCK);
+
+        assert_eq!(
+            hasher.finalize_fixed()[..],
+            hex!(
+                "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded163"
+                "1a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "esp32", feature = "esp32s2", feature = "esp32s3"))]
+    fn test_sha_512() {
+        let peripherals = Peripherals::take();
+        let mut hasher = ShaDigest::<esp_hal::sha::Sha512>::new(peripherals.SHA);
+        hasher.update(ONE_BLOCK);
+
+        assert_eq!(
+            hasher.finalize_fixed()[..],
+            hex!(
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a"
+                "2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+            )
+        );
+    }
+}
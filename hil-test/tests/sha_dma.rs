@@ -0,0 +1,110 @@
+//! SHA DMA Test
+
+//% CHIPS: esp32c2 esp32c3 esp32c6 esp32h2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    dma::{Dma, DmaPriority},
+    dma_buffers,
+    peripherals::Peripherals,
+    sha::{dma::WithDmaSha, Sha, ShaMode},
+};
+use nb::block;
+
+// spans three descriptors
+const DMA_BUFFER_SIZE: usize = 10000;
+
+// SHA-256 of `(i * 7 + 3) as u8` for `i` in `0..DMA_BUFFER_SIZE`
+const EXPECTED_OUTPUT: [u8; 32] = [
+    0x6e, 0x97, 0xd8, 0x60, 0x1c, 0xb1, 0x79, 0x06, 0xa4, 0x81, 0x9e, 0x0f, 0xcc, 0x8d, 0x03, 0x15,
+    0x0d, 0x3e, 0x43, 0x31, 0x35, 0x3e, 0xca, 0xa5, 0x16, 0xc0, 0x08, 0x4c, 0xad, 0xad, 0x54, 0xdd,
+];
+
+fn fill(buffer: &mut [u8]) {
+    for (i, byte) in buffer.iter_mut().enumerate() {
+        *byte = (i * 7 + 3) as u8;
+    }
+}
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::assert_eq;
+
+    use super::*;
+
+    #[init]
+    fn init() {}
+
+    #[test]
+    fn test_sha_256_dma() {
+        let peripherals = Peripherals::take();
+
+        let dma = Dma::new(peripherals.DMA);
+        let (input, tx_descriptors, _, _) = dma_buffers!(DMA_BUFFER_SIZE, 0);
+        fill(input);
+
+        let mut sha = Sha::new(peripherals.SHA, ShaMode::SHA256).with_dma(
+            dma.channel0.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+        );
+
+        let (transfer, mut remaining) = sha.update_dma(input).unwrap();
+        transfer.wait().unwrap();
+
+        assert_eq!(remaining.len(), DMA_BUFFER_SIZE % 64);
+        while remaining.len() > 0 {
+            remaining = block!(sha.sha.update(remaining)).unwrap();
+        }
+
+        let mut output = [0u8; 32];
+        block!(sha.sha.finish(output.as_mut_slice())).unwrap();
+
+        assert_eq!(EXPECTED_OUTPUT, output);
+    }
+
+    #[test]
+    fn test_sha_256_mixed_cpu_and_dma() {
+        let peripherals = Peripherals::take();
+
+        let dma = Dma::new(peripherals.DMA);
+        let (input, tx_descriptors, _, _) = dma_buffers!(DMA_BUFFER_SIZE, 0);
+        fill(input);
+
+        let mut sha = Sha::new(peripherals.SHA, ShaMode::SHA256).with_dma(
+            dma.channel0.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+        );
+
+        // Start with a partial block fed by the CPU
+        let (head, tail) = input.split_at(13);
+        let mut remaining: &[u8] = head;
+        while remaining.len() > 0 {
+            remaining = block!(sha.sha.update(remaining)).unwrap();
+        }
+
+        // Hash the rest by DMA in two parts, the end of the first part which
+        // doesn't fill a block is fed by the CPU in between
+        let (first, second) = tail.split_at(5000);
+        let (transfer, mut remaining) = sha.update_dma(first).unwrap();
+        transfer.wait().unwrap();
+        while remaining.len() > 0 {
+            remaining = block!(sha.sha.update(remaining)).unwrap();
+        }
+
+        let (transfer, mut remaining) = sha.update_dma(second).unwrap();
+        transfer.wait().unwrap();
+        while remaining.len() > 0 {
+            remaining = block!(sha.sha.update(remaining)).unwrap();
+        }
+
+        let mut output = [0u8; 32];
+        block!(sha.sha.finish(output.as_mut_slice())).unwrap();
+
+        assert_eq!(EXPECTED_OUTPUT, output);
+    }
+}
@@ -14,11 +14,14 @@
 #![no_main]
 
 use defmt_rtt as _;
+use embedded_hal::spi::SpiBus;
 use esp_backtrace as _;
 use esp_hal::{
     clock::ClockControl,
-    dma::{Dma, DmaPriority},
+    dma::{Dma, DmaDescriptor, DmaPriority},
     dma_buffers,
+    dma_buffers_chunk_size,
+    dma_descriptors,
     gpio::Io,
     peripherals::Peripherals,
     prelude::*,
@@ -27,12 +30,26 @@ use esp_hal::{
         SpiMode,
     },
     system::SystemControl,
+    FlashSafeDma,
+};
+
+const FLASH_BUFFER_SIZE: usize = 200;
+
+// using `static`, not `static mut`, places the array in .rodata
+static FLASH_BUFFER: [u8; FLASH_BUFFER_SIZE] = {
+    let mut buffer = [0u8; FLASH_BUFFER_SIZE];
+    let mut i = 0;
+    while i < FLASH_BUFFER_SIZE {
+        buffer[i] = i as u8 ^ 0x5a;
+        i += 1;
+    }
+    buffer
 };
 
 #[cfg(test)]
 #[embedded_test::tests]
 mod tests {
-    use defmt::assert_eq;
+    use defmt::{assert, assert_eq};
 
     use super::*;
 
@@ -79,6 +96,54 @@ mod tests {
         assert_eq!(send, receive);
     }
 
+    #[test]
+    #[timeout(3)]
+    fn test_dma_transfer_with_local_descriptors() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        // no descriptors are needed outside of `with_descriptors`
+        let mut spi = Spi::new(peripherals.SPI2, 100.kHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure(false, DmaPriority::Priority0),
+                &mut [],
+                &mut [],
+            );
+
+        let mut tx_descriptors = [DmaDescriptor::EMPTY; 2];
+        let mut rx_descriptors = [DmaDescriptor::EMPTY; 2];
+
+        let mut send = [0u8; 6000];
+        for (i, byte) in send.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        let mut receive = [0u8; 6000];
+
+        spi.with_descriptors(&mut tx_descriptors, &mut rx_descriptors, |spi| {
+            SpiBus::transfer(spi, &mut receive, &send)
+        })
+        .unwrap();
+        assert_eq!(receive, send);
+
+        // the descriptors passed to `with_dma` are used again afterwards
+        assert!(SpiBus::transfer(&mut spi, &mut receive, &send).is_err());
+    }
+
     #[test]
     #[timeout(3)]
     fn test_asymmetric_dma_transfer() {
@@ -166,6 +231,162 @@ mod tests {
         assert_eq!(send, receive);
     }
 
+    // the buffers don't fit into the RAM of the ESP32 and ESP32-C2
+    #[cfg(not(any(feature = "esp32", feature = "esp32c2")))]
+    #[test]
+    #[timeout(5)]
+    fn test_dma_transfer_larger_than_transaction() {
+        // the SPI transaction limit is 32736 bytes
+        const DMA_BUFFER_SIZE: usize = 40000;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) =
+            dma_buffers!(DMA_BUFFER_SIZE);
+
+        let mut spi = Spi::new(peripherals.SPI2, 1.MHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        for (i, byte) in tx_buffer.iter_mut().enumerate() {
+            *byte = (i ^ (i >> 8)) as u8;
+        }
+
+        // sent in two SPI transactions
+        let transfer = spi.dma_transfer(&tx_buffer, &mut rx_buffer).unwrap();
+        transfer.wait().unwrap();
+        assert!(tx_buffer == rx_buffer);
+
+        rx_buffer.fill(0);
+
+        let mut transfer = spi.dma_transfer(&tx_buffer, &mut rx_buffer).unwrap();
+        while !transfer.is_done() {}
+        transfer.wait().unwrap();
+        assert!(tx_buffer == rx_buffer);
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_back_to_back_dma_transfers_reusing_buffers() {
+        const DMA_BUFFER_SIZE: usize = 8000;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) = dma_buffers!(DMA_BUFFER_SIZE);
+
+        let mut spi = Spi::new(peripherals.SPI2, 10.MHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        // DMA buffer require a static life-time
+        let mut send = tx_buffer;
+        let mut receive = rx_buffer;
+
+        // The descriptor chains are only filled for the first transfer, the
+        // following ones hand the same chains back to the DMA.
+        for i in 0..10u8 {
+            for (byte, value) in send.iter_mut().enumerate() {
+                *value = (byte as u8).wrapping_add(i);
+            }
+            receive.fill(0);
+
+            let transfer = spi.dma_transfer(&mut send, &mut receive).unwrap();
+            transfer.wait().unwrap();
+            assert_eq!(send, receive);
+
+            let transfer = spi.dma_write(&mut send).unwrap();
+            transfer.wait().unwrap();
+        }
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_scattered_dma_transfer() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        // The header and the payload need a descriptor each
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(2 * 4092, 4092);
+        let (header, _, rx_buffer, _) = dma_buffers!(4, 12);
+        let (payload, _, _, _) = dma_buffers!(8, 0);
+
+        let mut spi = Spi::new(peripherals.SPI2, 100.kHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        // DMA buffer require a static life-time
+        let mut receive = rx_buffer;
+
+        header.copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+        payload.copy_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7]);
+
+        let parts: [&mut [u8]; 2] = [header, payload];
+        let transfer = spi.dma_transfer_scattered(&parts, &mut receive).unwrap();
+        transfer.wait().unwrap();
+        assert_eq!(receive, &[0xde, 0xad, 0xbe, 0xef, 0, 1, 2, 3, 4, 5, 6, 7]);
+
+        let transfer = spi.dma_write_scattered_owned(parts).unwrap();
+        let (_spi, [header, payload]) = transfer.wait().unwrap();
+        assert_eq!(header, &[0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(payload, &[0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
     #[test]
     #[timeout(3)]
     fn test_try_using_non_dma_memory_tx_buffer() {
@@ -306,4 +527,250 @@ mod tests {
         let (_, send, receive) = transfer.wait().unwrap();
         assert_eq!(send, receive);
     }
+
+    #[test]
+    #[timeout(3)]
+    fn test_flash_safe_dma_rodata_buffer() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(FLASH_BUFFER_SIZE);
+
+        let spi = Spi::new(peripherals.SPI2, 100.kHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        // a bounce buffer smaller than the data, so copied data is sent in chunks
+        let mut spi = FlashSafeDma::<_, 64>::new(spi);
+
+        let mut receive = [0u8; FLASH_BUFFER_SIZE];
+        SpiBus::transfer(&mut spi, &mut receive, &FLASH_BUFFER).unwrap();
+        assert_eq!(receive, FLASH_BUFFER);
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_flash_safe_dma_stack_buffer() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(FLASH_BUFFER_SIZE);
+
+        let spi = Spi::new(peripherals.SPI2, 100.kHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        // a bounce buffer smaller than the data, so copied data is sent in chunks
+        let mut spi = FlashSafeDma::<_, 64>::new(spi);
+
+        let mut send = [0u8; FLASH_BUFFER_SIZE];
+        for (i, byte) in send.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        let mut receive = [0u8; FLASH_BUFFER_SIZE];
+        SpiBus::transfer(&mut spi, &mut receive, &send).unwrap();
+        assert_eq!(receive, send);
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_flash_safe_dma_mixed_buffers() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(FLASH_BUFFER_SIZE);
+
+        let spi = Spi::new(peripherals.SPI2, 100.kHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        // a bounce buffer smaller than the data, so copied data is sent in chunks
+        let mut spi = FlashSafeDma::<_, 64>::new(spi);
+
+        let mut send = [0u8; FLASH_BUFFER_SIZE];
+        for (i, byte) in send.iter_mut().enumerate() {
+            *byte = !(i as u8);
+        }
+
+        let mut receive = [0u8; FLASH_BUFFER_SIZE];
+        for _ in 0..2 {
+            SpiBus::transfer(&mut spi, &mut receive, &FLASH_BUFFER).unwrap();
+            assert_eq!(receive, FLASH_BUFFER);
+
+            SpiBus::transfer(&mut spi, &mut receive, &send).unwrap();
+            assert_eq!(receive, send);
+        }
+
+        // reading more than is written still receives the written data
+        let mut receive = [0u8; FLASH_BUFFER_SIZE + 100];
+        SpiBus::transfer(&mut spi, &mut receive, &FLASH_BUFFER).unwrap();
+        assert_eq!(&receive[..FLASH_BUFFER_SIZE], &FLASH_BUFFER[..]);
+
+        SpiBus::write(&mut spi, &FLASH_BUFFER).unwrap();
+        SpiBus::write(&mut spi, &send).unwrap();
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_dma_write_progress() {
+        const DMA_BUFFER_SIZE: usize = 2000;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        // ten descriptors, sending all of them takes 160ms at 100kHz
+        let (tx_buffer, tx_descriptors, _, rx_descriptors) =
+            dma_buffers_chunk_size!(DMA_BUFFER_SIZE, 200);
+
+        let mut spi = Spi::new(peripherals.SPI2, 100.kHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        tx_buffer.fill(0x55);
+
+        let mut transfer = spi.dma_write(&tx_buffer).unwrap();
+
+        let mut last = 0;
+        let mut seen_partial = false;
+        while !transfer.is_done() {
+            let transferred = transfer.transferred_bytes();
+            assert!(transferred >= last);
+            assert!(transferred <= DMA_BUFFER_SIZE);
+
+            seen_partial |= transferred > 0 && transferred < DMA_BUFFER_SIZE;
+            last = transferred;
+        }
+
+        assert_eq!(DMA_BUFFER_SIZE, transfer.transferred_bytes());
+        transfer.wait().unwrap();
+        assert!(seen_partial);
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_abort_dma_transfer_does_not_wait() {
+        const DMA_BUFFER_SIZE: usize = 4000;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) = dma_buffers!(DMA_BUFFER_SIZE);
+
+        let mut spi = Spi::new(peripherals.SPI2, 100.kHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        // DMA buffer require a static life-time
+        let mut send = tx_buffer;
+        let mut receive = rx_buffer;
+
+        // the transaction takes 320ms at 100kHz
+        send.fill(0x55);
+        let transfer = spi.dma_transfer(&mut send, &mut receive).unwrap();
+
+        let start = esp_hal::time::current_time();
+        transfer.abort();
+        assert!((esp_hal::time::current_time() - start).to_millis() < 10);
+
+        // the next transfer starts once the aborted transaction ended
+        let mut send = &mut send[..4];
+        let mut receive = &mut receive[..4];
+        send.copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+        receive.fill(0);
+
+        let transfer = spi.dma_transfer(&mut send, &mut receive).unwrap();
+        transfer.wait().unwrap();
+        assert_eq!(send, receive);
+    }
 }
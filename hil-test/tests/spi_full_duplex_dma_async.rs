@@ -0,0 +1,467 @@
+//! SPI Full Duplex DMA Test (Async)
+//!
+//! Folowing pins are used:
+//! SCLK    GPIO0
+//! MISO    GPIO2
+//! MOSI    GPIO3
+//! CS      GPIO8
+//!
+//! Connect MISO (GPIO2) and MOSI (GPIO3) pins.
+
+//% CHIPS: esp32 esp32c2 esp32c3 esp32c6 esp32h2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use core::{
+    cell::RefCell,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+use critical_section::Mutex;
+use defmt_rtt as _;
+use embedded_hal_async::spi::SpiBus;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    dma::{Dma, DmaPriority},
+    dma_buffers,
+    dma_descriptors,
+    gpio::Io,
+    interrupt::{self, Priority},
+    peripherals::{Interrupt, Peripherals, TIMG0},
+    prelude::*,
+    spi::{
+        master::{prelude::*, Spi},
+        SpiMode,
+    },
+    system::SystemControl,
+    timer::timg::{Timer, Timer0, TimerGroup},
+};
+
+static TIMER0: Mutex<RefCell<Option<Timer<Timer0<TIMG0>, esp_hal::Blocking>>>> =
+    Mutex::new(RefCell::new(None));
+static TIMER_INTERRUPTS: AtomicU32 = AtomicU32::new(0);
+
+#[handler]
+fn tg0_t0_level() {
+    // Keep the CPU busy at a priority above the DMA interrupt, so DMA
+    // interrupts are regularly delayed until this handler returns.
+    let start = esp_hal::time::current_time();
+    while (esp_hal::time::current_time() - start).to_micros() < 200 {}
+
+    TIMER_INTERRUPTS.fetch_add(1, Ordering::Relaxed);
+
+    critical_section::with(|cs| {
+        let mut timer0 = TIMER0.borrow_ref_mut(cs);
+        let timer0 = timer0.as_mut().unwrap();
+
+        timer0.clear_interrupt();
+        timer0.load_value(1u64.millis()).unwrap();
+        timer0.start();
+    });
+}
+
+#[cfg(test)]
+#[embedded_test::tests(executor = esp_hal_embassy::Executor::new())]
+mod tests {
+    use defmt::{assert, assert_eq};
+
+    use super::*;
+
+    #[init]
+    async fn init() {}
+
+    #[test]
+    #[timeout(10)]
+    async fn test_many_short_async_transfers() {
+        const TRANSFERS: usize = 5000;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(32000);
+
+        // A high clock keeps the transfers short, which makes it likely for a
+        // transfer to finish while the future is being polled.
+        let mut spi = Spi::new(peripherals.SPI2, 10.MHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure_for_async(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        for i in 0..TRANSFERS {
+            let send = (i as u32).to_le_bytes();
+            let mut receive = [0; 4];
+
+            SpiBus::transfer(&mut spi, &mut receive, &send)
+                .await
+                .unwrap();
+
+            assert_eq!(send, receive);
+        }
+    }
+
+    #[test]
+    #[timeout(10)]
+    async fn test_async_transfers_preempted_by_higher_priority_interrupt() {
+        const TRANSFERS: usize = 1000;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(32000);
+
+        let mut spi = Spi::new(peripherals.SPI2, 10.MHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure_for_async_with_priority(
+                    false.into(),
+                    DmaPriority::Priority0,
+                    Priority::Priority1,
+                ),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        let timg0 = TimerGroup::new(peripherals.TIMG0, &clocks);
+        let timer0 = timg0.timer0;
+        timer0.set_interrupt_handler(tg0_t0_level);
+        interrupt::enable(Interrupt::TG0_T0_LEVEL, Priority::Priority2).unwrap();
+        timer0.load_value(1u64.millis()).unwrap();
+        timer0.start();
+        timer0.listen();
+
+        critical_section::with(|cs| {
+            TIMER0.borrow_ref_mut(cs).replace(timer0);
+        });
+
+        for i in 0..TRANSFERS {
+            let send = [i as u8; 64];
+            let mut receive = [0; 64];
+
+            SpiBus::transfer(&mut spi, &mut receive, &send)
+                .await
+                .unwrap();
+
+            assert_eq!(send, receive);
+        }
+
+        interrupt::disable(esp_hal::get_core(), Interrupt::TG0_T0_LEVEL);
+        critical_section::with(|cs| TIMER0.borrow_ref_mut(cs).take());
+
+        assert!(TIMER_INTERRUPTS.load(Ordering::Relaxed) > 0);
+    }
+
+    #[test]
+    #[timeout(3)]
+    async fn test_async_transfer_on_converted_blocking_channel() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(32000);
+
+        let dma_channel = dma_channel
+            .configure(false, DmaPriority::Priority0)
+            .into_async_with_priority(Priority::Priority1);
+
+        let mut spi = Spi::new(peripherals.SPI2, 1.MHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(dma_channel, tx_descriptors, rx_descriptors);
+
+        let send = [0xde, 0xad, 0xbe, 0xef];
+        let mut receive = [0; 4];
+
+        SpiBus::transfer(&mut spi, &mut receive, &send)
+            .await
+            .unwrap();
+
+        assert_eq!(send, receive);
+    }
+
+    #[test]
+    #[timeout(3)]
+    async fn test_blocking_async_blocking_round_trip() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(32000);
+
+        let mut spi = Spi::new(peripherals.SPI2, 1.MHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        let send = [0xde, 0xad, 0xbe, 0xef];
+        let mut receive = [0; 4];
+        embedded_hal::spi::SpiBus::transfer(&mut spi, &mut receive, &send).unwrap();
+        assert_eq!(send, receive);
+
+        // the same channel wakes async transfers after the conversion
+        let mut spi = spi.into_async();
+        let send = [0xca, 0xfe, 0xba, 0xbe];
+        let mut receive = [0; 4];
+        SpiBus::transfer(&mut spi, &mut receive, &send)
+            .await
+            .unwrap();
+        assert_eq!(send, receive);
+
+        let mut spi = spi.into_blocking();
+        let send = [0x01, 0x23, 0x45, 0x67];
+        let mut receive = [0; 4];
+        embedded_hal::spi::SpiBus::transfer(&mut spi, &mut receive, &send).unwrap();
+        assert_eq!(send, receive);
+    }
+
+    #[test]
+    #[timeout(3)]
+    async fn test_blocking_transfer_on_converted_async_channel() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(32000);
+
+        // converting back and forth leaves a usable blocking channel
+        let dma_channel = dma_channel
+            .configure_for_async(false, DmaPriority::Priority0)
+            .into_blocking()
+            .into_async()
+            .into_blocking();
+
+        let mut spi = Spi::new(peripherals.SPI2, 1.MHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(dma_channel, tx_descriptors, rx_descriptors);
+
+        let send = [0xde, 0xad, 0xbe, 0xef];
+        let mut receive = [0; 4];
+
+        embedded_hal::spi::SpiBus::transfer(&mut spi, &mut receive, &send).unwrap();
+
+        assert_eq!(send, receive);
+    }
+
+    #[test]
+    #[timeout(3)]
+    async fn test_async_flash_safe_dma_chunked_transfers() {
+        const LEN: usize = 200;
+
+        // using `static`, not `static mut`, places the array in .rodata
+        static FLASH_BUFFER: [u8; LEN] = [0x5a; LEN];
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(LEN);
+
+        let spi = Spi::new(peripherals.SPI2, 1.MHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure_for_async(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        // a bounce buffer smaller than the data, so the data is sent in chunks
+        let mut spi = esp_hal::FlashSafeDma::<_, 64>::new(spi);
+
+        let mut receive = [0u8; LEN];
+        SpiBus::transfer(&mut spi, &mut receive, &FLASH_BUFFER)
+            .await
+            .unwrap();
+        assert_eq!(receive, FLASH_BUFFER);
+
+        // a RAM buffer is passed through, reading more than is written
+        let send = [0xa5u8; LEN];
+        let mut receive = [0u8; LEN + 100];
+        SpiBus::transfer(&mut spi, &mut receive, &send)
+            .await
+            .unwrap();
+        assert_eq!(&receive[..LEN], &send[..]);
+    }
+
+    #[test]
+    #[timeout(3)]
+    async fn test_dropped_async_transfer_is_aborted() {
+        const LEN: usize = 4096;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_descriptors, rx_descriptors) = dma_descriptors!(LEN);
+
+        // A slow clock, so the long transfer is still running when it's dropped
+        let mut spi = Spi::new(peripherals.SPI2, 100.kHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure_for_async(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        let send = [0x55u8; LEN];
+        let mut receive = [0u8; LEN];
+        let poll = embassy_futures::poll_once(SpiBus::transfer(&mut spi, &mut receive, &send));
+        assert!(poll.is_pending());
+
+        // The next transfer must neither hang nor receive the aborted data
+        let send = [0xa5u8; 4];
+        let mut receive = [0u8; 4];
+        SpiBus::transfer(&mut spi, &mut receive, &send)
+            .await
+            .unwrap();
+        assert_eq!(receive, send);
+    }
+
+    #[test]
+    #[timeout(5)]
+    async fn test_async_transfer_larger_than_transaction() {
+        // the SPI transaction limit is 32736 bytes
+        const DMA_BUFFER_SIZE: usize = 40000;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let miso = io.pins.gpio2;
+        let mosi = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, rx_buffer, rx_descriptors) = dma_buffers!(DMA_BUFFER_SIZE);
+
+        let mut spi = Spi::new(peripherals.SPI2, 1.MHz(), SpiMode::Mode0, &clocks)
+            .with_pins(Some(sclk), Some(mosi), Some(miso), Some(cs))
+            .with_dma(
+                dma_channel.configure_for_async(false, DmaPriority::Priority0),
+                tx_descriptors,
+                rx_descriptors,
+            );
+
+        for (i, byte) in tx_buffer.iter_mut().enumerate() {
+            *byte = (i ^ (i >> 8)) as u8;
+        }
+
+        // sent in two SPI transactions
+        SpiBus::transfer(&mut spi, rx_buffer, tx_buffer)
+            .await
+            .unwrap();
+        assert!(tx_buffer == rx_buffer);
+
+        rx_buffer.copy_from_slice(tx_buffer);
+        SpiBus::transfer_in_place(&mut spi, rx_buffer)
+            .await
+            .unwrap();
+        assert!(tx_buffer == rx_buffer);
+    }
+}
@@ -0,0 +1,179 @@
+//! SPI Full Duplex TX-only DMA Test
+//!
+//! Following pins are used:
+//! SCLK    GPIO0
+//! MOSI    GPIO2
+//!
+//! PCNT    GPIO3
+//!
+//! Connect MOSI (GPIO2) and PCNT (GPIO3) pins.
+
+//% CHIPS: esp32 esp32c6 esp32h2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::assert_eq;
+    use esp_hal::{
+        clock::ClockControl,
+        dma::{Dma, DmaPriority, DmaTxQueue},
+        dma_buffers,
+        dma_descriptors,
+        gpio::{Io, Pull},
+        pcnt::{
+            channel::{EdgeMode, PcntInputConfig, PcntSource},
+            Pcnt,
+        },
+        peripherals::Peripherals,
+        prelude::_fugit_RateExtU32,
+        spi::{master::Spi, SpiMode},
+        system::SystemControl,
+    };
+
+    #[init]
+    fn init() {}
+
+    #[test]
+    #[timeout(3)]
+    fn test_dma_write_frame_without_rx_descriptors() {
+        // a 240x135 RGB565 frame
+        const DMA_BUFFER_SIZE: usize = 240 * 135 * 2;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let pcnt = Pcnt::new(peripherals.PCNT);
+        let dma = Dma::new(peripherals.DMA);
+
+        let sclk = io.pins.gpio0;
+        let mosi = io.pins.gpio2;
+        let mosi_mirror = io.pins.gpio3;
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, _, _) = dma_buffers!(DMA_BUFFER_SIZE, 0);
+
+        let (dma_tx, _) = dma_channel.configure(false, DmaPriority::Priority0).split();
+
+        let mut spi = Spi::new(peripherals.SPI2, 10.MHz(), SpiMode::Mode0, &clocks)
+            .with_sck(sclk)
+            .with_mosi(mosi)
+            .with_dma_tx(dma_tx, tx_descriptors);
+
+        let unit = pcnt.unit0;
+        unit.channel0.set_edge_signal(PcntSource::from_pin(
+            mosi_mirror,
+            PcntInputConfig { pull: Pull::Down },
+        ));
+        unit.channel0
+            .set_input_mode(EdgeMode::Hold, EdgeMode::Increment);
+
+        // One positive edge every four bytes, the counter can't count one per
+        // byte of the frame.
+        for (i, byte) in tx_buffer.iter_mut().enumerate() {
+            *byte = if i % 4 == 0 { 0b0000_0001 } else { 0 };
+        }
+
+        let transfer = spi.dma_write(&tx_buffer).unwrap();
+        transfer.wait().unwrap();
+
+        assert_eq!(unit.get_value(), (DMA_BUFFER_SIZE / 4) as _);
+
+        let mut transfer = spi.dma_write(&tx_buffer).unwrap();
+        while !transfer.is_done() {}
+        transfer.wait().unwrap();
+
+        assert_eq!(unit.get_value(), (2 * DMA_BUFFER_SIZE / 4) as _);
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_dma_write_queue() {
+        const BUFFER_SIZE: usize = 4000;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let pcnt = Pcnt::new(peripherals.PCNT);
+        let dma = Dma::new(peripherals.DMA);
+
+        let sclk = io.pins.gpio0;
+        let mosi = io.pins.gpio2;
+        let mosi_mirror = io.pins.gpio3;
+
+        #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(any(feature = "esp32", feature = "esp32s2")))]
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, _, _) = dma_buffers!(3 * BUFFER_SIZE, 0);
+        // one descriptor for each of the three slots
+        let (queue_descriptors, _) = dma_descriptors!(3 * BUFFER_SIZE, 0);
+
+        let (dma_tx, _) = dma_channel.configure(false, DmaPriority::Priority0).split();
+
+        let mut spi = Spi::new(peripherals.SPI2, 10.MHz(), SpiMode::Mode0, &clocks)
+            .with_sck(sclk)
+            .with_mosi(mosi)
+            .with_dma_tx(dma_tx, tx_descriptors);
+
+        let unit = pcnt.unit0;
+        unit.channel0.set_edge_signal(PcntSource::from_pin(
+            mosi_mirror,
+            PcntInputConfig { pull: Pull::Down },
+        ));
+        unit.channel0
+            .set_input_mode(EdgeMode::Hold, EdgeMode::Increment);
+
+        // A different number of positive edges in each buffer, one every 4, 8
+        // and 16 bytes
+        for (i, byte) in tx_buffer.iter_mut().enumerate() {
+            let every = 4 << (i / BUFFER_SIZE);
+            *byte = if i % every == 0 { 0b0000_0001 } else { 0 };
+        }
+        let edges = BUFFER_SIZE / 4 + BUFFER_SIZE / 8 + BUFFER_SIZE / 16;
+
+        let tx_buffer: &'static [u8] = tx_buffer;
+        let (first, rest) = tx_buffer.split_at(BUFFER_SIZE);
+        let (second, third) = rest.split_at(BUFFER_SIZE);
+
+        let mut queue = DmaTxQueue::<3>::new(queue_descriptors).unwrap();
+        queue.enqueue(first).unwrap();
+        queue.enqueue(second).unwrap();
+
+        // 12000 bytes take 9.6ms at 10MHz
+        let t1 = esp_hal::time::current_time();
+        let mut transfer = spi.dma_write_queue(&mut queue).unwrap();
+        transfer.enqueue(third).unwrap();
+
+        let mut completed = 0;
+        while completed < 3 {
+            completed += transfer.wait_completed().unwrap();
+        }
+        transfer.wait().unwrap();
+        let t2 = esp_hal::time::current_time();
+
+        assert_eq!(unit.get_value(), edges as _);
+        // the buffers are sent back to back
+        assert!((t2 - t1).to_millis() < 12u64);
+
+        // the queue can be sent again
+        queue.enqueue(third).unwrap();
+        spi.dma_write_queue(&mut queue).unwrap().wait().unwrap();
+
+        assert_eq!(unit.get_value(), (edges + BUFFER_SIZE / 16) as _);
+    }
+}
@@ -0,0 +1,91 @@
+//! SPI slave DMA drop test
+//!
+//! Drops a SPI slave transfer while no master provides the clock.
+//!
+//! Following pins are used:
+//! SCLK    GPIO0
+//! MOSI    GPIO2
+//! MISO    GPIO3
+//! CS      GPIO8
+//!
+//! No connections are needed.
+
+//% CHIPS: esp32c2 esp32c3 esp32c6 esp32h2 esp32s2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::assert;
+    use esp_hal::{
+        clock::ClockControl,
+        dma::{set_drop_timeout, Dma, DmaPriority},
+        dma_buffers,
+        gpio::Io,
+        peripherals::Peripherals,
+        prelude::*,
+        spi::{
+            slave::{prelude::*, Spi},
+            SpiMode,
+        },
+        system::SystemControl,
+    };
+
+    #[init]
+    fn init() {}
+
+    #[test]
+    #[timeout(3)]
+    fn test_drop_transfer_without_master() {
+        const DMA_BUFFER_SIZE: usize = 64;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let _clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let sclk = io.pins.gpio0;
+        let mosi = io.pins.gpio2;
+        let miso = io.pins.gpio3;
+        let cs = io.pins.gpio8;
+
+        let dma = Dma::new(peripherals.DMA);
+
+        #[cfg(feature = "esp32s2")]
+        let dma_channel = dma.spi2channel;
+        #[cfg(not(feature = "esp32s2"))]
+        let dma_channel = dma.channel0;
+
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) =
+            dma_buffers!(DMA_BUFFER_SIZE);
+
+        let mut spi = Spi::new(peripherals.SPI2, sclk, mosi, miso, cs, SpiMode::Mode0).with_dma(
+            dma_channel.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+        );
+
+        set_drop_timeout(100.millis());
+
+        let start = esp_hal::time::current_time();
+        let transfer = spi.dma_transfer(&tx_buffer, &mut rx_buffer).unwrap();
+        core::mem::drop(transfer);
+        let elapsed = (esp_hal::time::current_time() - start).to_millis();
+
+        assert!(elapsed >= 100);
+        assert!(elapsed < 200);
+
+        // the peripheral is reset for the next transfer, the aborted transfer
+        // doesn't keep it from starting
+        let start = esp_hal::time::current_time();
+        let transfer = spi.dma_read(&mut rx_buffer).unwrap();
+        core::mem::drop(transfer);
+
+        assert!((esp_hal::time::current_time() - start).to_millis() < 200);
+    }
+}
@@ -0,0 +1,106 @@
+//! TIMG Alarms Test
+
+//% CHIPS: esp32 esp32c2 esp32c3 esp32c6 esp32h2 esp32s2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    delay::Delay,
+    peripherals::Peripherals,
+    prelude::*,
+    system::SystemControl,
+    timer::{timg::TimerGroup, Timer as _},
+};
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use super::*;
+
+    #[init]
+    fn init() {}
+
+    #[test]
+    #[timeout(3)]
+    fn test_independent_alarms() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let delay = Delay::new(&clocks);
+        let timg0 = TimerGroup::new(peripherals.TIMG0, &clocks);
+        let timer0 = timg0.timer0;
+
+        let mut alarm0 = timer0.create_alarm().unwrap();
+        let mut alarm1 = timer0.create_alarm().unwrap();
+        assert_ne!(alarm0.index(), alarm1.index());
+
+        let now = timer0.now();
+        alarm0.set_target(now + 200.millis());
+        alarm1.set_target(now + 50.millis());
+
+        alarm1.wait();
+        assert!(!alarm0.has_fired());
+        assert!((timer0.now() - now).to_millis() >= 50u64);
+
+        alarm0.wait();
+        assert!((timer0.now() - now).to_millis() >= 200u64);
+
+        // Cancelling one alarm leaves the other one armed
+        let now = timer0.now();
+        alarm0.set_target(now + 50.millis());
+        alarm1.set_target(now + 50.millis());
+        alarm0.cancel();
+
+        delay.delay_millis(100);
+        assert!(!alarm0.has_fired());
+        assert!(alarm1.has_fired());
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_alarm_limit() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let timg0 = TimerGroup::new(peripherals.TIMG0, &clocks);
+        let timer0 = timg0.timer0;
+
+        let alarms: [_; esp_hal::timer::timg::MAX_ALARMS_PER_TIMER] =
+            core::array::from_fn(|_| timer0.create_alarm().unwrap());
+        assert!(timer0.create_alarm().is_none());
+
+        // Dropping an alarm frees its slot
+        drop(alarms);
+        assert!(timer0.create_alarm().is_some());
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_alarm_with_prescaler() {
+        let mut peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        assert!(TimerGroup::new_with_prescaler(&mut peripherals.TIMG0, &clocks, 1).is_err());
+
+        let timg0 = TimerGroup::new_with_prescaler(peripherals.TIMG0, &clocks, 800).unwrap();
+        let timer0 = timg0.timer0;
+
+        #[cfg(not(esp32h2))]
+        assert_eq!(timer0.tick_hz(), clocks.apb_clock.to_Hz() / 800);
+
+        let mut alarm = timer0.create_alarm().unwrap();
+
+        let start = esp_hal::time::current_time();
+        alarm.set_target(timer0.now() + 100.millis());
+        alarm.wait();
+
+        assert!((esp_hal::time::current_time() - start).to_millis() >= 100u64);
+    }
+}
@@ -0,0 +1,102 @@
+//! TWAI async test
+//!
+//! Drops the TWAI futures before they complete. Nothing acknowledges the
+//! transmitted frame, so the controller keeps retrying it and the transmit
+//! buffer stays busy.
+//!
+//! Also checks that transmitting in monitor mode fails.
+//!
+//! Following pins are used:
+//! TX    GPIO2
+//! RX    GPIO3
+//!
+//! No connections are needed.
+
+//% CHIPS: esp32 esp32c3 esp32c6 esp32s2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+
+#[cfg(test)]
+#[embedded_test::tests(executor = esp_hal_embassy::Executor::new())]
+mod tests {
+    use defmt::assert;
+    use embassy_futures::poll_once;
+    use esp_hal::{
+        clock::ClockControl,
+        gpio::Io,
+        peripherals::{Peripherals, TWAI0},
+        system::SystemControl,
+        twai::{self, EspTwaiError, EspTwaiFrame, StandardId},
+    };
+
+    #[init]
+    async fn init() {}
+
+    #[test]
+    #[timeout(3)]
+    async fn test_dropped_futures_disarm_interrupts() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let mut twai = twai::TwaiConfiguration::new_async_no_transceiver(
+            peripherals.TWAI0,
+            io.pins.gpio2,
+            io.pins.gpio3,
+            &clocks,
+            twai::BaudRate::B1000K,
+        )
+        .start();
+
+        let register_block = unsafe { TWAI0::steal() };
+
+        let poll = poll_once(twai.receive_async());
+        assert!(poll.is_pending());
+        assert!(register_block.int_ena().read().rx_int_ena().bit_is_clear());
+
+        let frame = EspTwaiFrame::new(StandardId::ZERO.into(), &[1, 2, 3]).unwrap();
+        twai.transmit_async(&frame).await.unwrap();
+
+        // The first frame is never acknowledged and occupies the buffer
+        let poll = poll_once(twai.transmit_async(&frame));
+        assert!(poll.is_pending());
+        assert!(register_block.int_ena().read().tx_int_ena().bit_is_clear());
+    }
+
+    #[test]
+    #[timeout(3)]
+    async fn test_transmit_in_monitor_mode_fails() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+
+        let mut twai = twai::TwaiConfiguration::new_async_no_transceiver(
+            peripherals.TWAI0,
+            io.pins.gpio2,
+            io.pins.gpio3,
+            &clocks,
+            twai::BaudRate::B1000K,
+        )
+        .start()
+        .into_monitor_mode();
+
+        let frame = EspTwaiFrame::new(StandardId::ZERO.into(), &[1, 2, 3]).unwrap();
+
+        assert_eq!(
+            twai.transmit_async(&frame).await,
+            Err(EspTwaiError::NotInTransmitMode)
+        );
+        assert_eq!(
+            twai.transmit(&frame),
+            Err(nb::Error::Other(EspTwaiError::NotInTransmitMode))
+        );
+    }
+}
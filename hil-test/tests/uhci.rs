@@ -0,0 +1,240 @@
+//! UHCI (UART DMA) Test
+//!
+//! Folowing pins are used:
+//! TX    GPIO2
+//! RX    GPIO3
+//!
+//! Connect TX (GPIO2) and RX (GPIO3) pins.
+
+//% CHIPS: esp32c3 esp32c6 esp32h2 esp32s3
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use esp_backtrace as _;
+use esp_hal::{
+    clock::ClockControl,
+    dma::{Dma, DmaPriority},
+    dma_buffers,
+    gpio::Io,
+    peripherals::Peripherals,
+    system::SystemControl,
+    uart::{config::Config, Uart},
+    uhci::UhciUart,
+};
+
+// spans several descriptors
+const DMA_BUFFER_SIZE: usize = 8192;
+
+const BAUDRATE: u32 = 4_000_000;
+
+fn fill(buffer: &mut [u8]) {
+    for (i, byte) in buffer.iter_mut().enumerate() {
+        *byte = (i * 7 + 3) as u8;
+    }
+}
+
+#[cfg(test)]
+#[embedded_test::tests]
+mod tests {
+    use defmt::assert_eq;
+
+    use super::*;
+
+    #[init]
+    fn init() {}
+
+    #[test]
+    #[timeout(3)]
+    fn test_loopback() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let uart = Uart::new_with_config(
+            peripherals.UART1,
+            Config::default().baudrate(BAUDRATE),
+            &clocks,
+            io.pins.gpio2,
+            io.pins.gpio3,
+        )
+        .unwrap();
+
+        let dma = Dma::new(peripherals.DMA);
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) =
+            dma_buffers!(DMA_BUFFER_SIZE);
+        fill(tx_buffer);
+
+        let mut uhci = UhciUart::new(
+            peripherals.UHCI0,
+            uart,
+            dma.channel0.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+        );
+
+        let mut rx_transfer = uhci.rx.read_dma(&mut rx_buffer).unwrap();
+        uhci.tx.write_dma(&tx_buffer).unwrap().wait().unwrap();
+
+        // the transfer ends when the line becomes idle
+        while !rx_transfer.is_done() {}
+        assert_eq!(rx_transfer.received_bytes(), DMA_BUFFER_SIZE);
+        rx_transfer.wait().unwrap();
+
+        assert_eq!(&tx_buffer[..], &rx_buffer[..]);
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_idle_eof() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let uart = Uart::new_with_config(
+            peripherals.UART1,
+            Config::default().baudrate(BAUDRATE),
+            &clocks,
+            io.pins.gpio2,
+            io.pins.gpio3,
+        )
+        .unwrap();
+
+        let dma = Dma::new(peripherals.DMA);
+        // a frame shorter than the receive buffer
+        let (frame, tx_descriptors, mut rx_buffer, rx_descriptors) =
+            dma_buffers!(100, DMA_BUFFER_SIZE);
+        fill(frame);
+
+        let mut uhci = UhciUart::new(
+            peripherals.UHCI0,
+            uart,
+            dma.channel0.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+        );
+
+        let mut rx_transfer = uhci.rx.read_dma(&mut rx_buffer).unwrap();
+        uhci.tx.write_dma(&frame).unwrap().wait().unwrap();
+
+        while !rx_transfer.is_done() {}
+        assert_eq!(rx_transfer.received_bytes(), frame.len());
+        rx_transfer.wait().unwrap();
+
+        assert_eq!(&frame[..], &rx_buffer[..frame.len()]);
+    }
+
+    #[test]
+    #[timeout(3)]
+    fn test_circular() {
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let uart = Uart::new_with_config(
+            peripherals.UART1,
+            Config::default().baudrate(BAUDRATE),
+            &clocks,
+            io.pins.gpio2,
+            io.pins.gpio3,
+        )
+        .unwrap();
+
+        let dma = Dma::new(peripherals.DMA);
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) =
+            dma_buffers!(DMA_BUFFER_SIZE);
+        fill(tx_buffer);
+
+        let mut uhci = UhciUart::new(
+            peripherals.UHCI0,
+            uart,
+            dma.channel0.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+        );
+
+        let mut rx_transfer = uhci.rx.read_dma_circular(&mut rx_buffer).unwrap();
+
+        // send the data twice, the ring wraps around
+        let mut received = [0u8; DMA_BUFFER_SIZE];
+        for _ in 0..2 {
+            uhci.tx.write_dma(&tx_buffer).unwrap().wait().unwrap();
+
+            let mut total = 0;
+            while total < DMA_BUFFER_SIZE {
+                if rx_transfer.available().unwrap() > 0 {
+                    total += rx_transfer.pop(&mut received[total..]).unwrap();
+                }
+            }
+
+            assert_eq!(total, DMA_BUFFER_SIZE);
+            assert_eq!(&tx_buffer[..], &received[..]);
+        }
+    }
+
+    #[test]
+    #[timeout(10)]
+    fn test_one_megabyte_without_loss() {
+        // takes 2.6s at 4 Mbaud
+        const TOTAL: usize = 1024 * 1024;
+        const CHUNK: usize = 4096;
+
+        let peripherals = Peripherals::take();
+        let system = SystemControl::new(peripherals.SYSTEM);
+        let clocks = ClockControl::boot_defaults(system.clock_control).freeze();
+
+        let io = Io::new(peripherals.GPIO, peripherals.IO_MUX);
+        let uart = Uart::new_with_config(
+            peripherals.UART1,
+            Config::default().baudrate(BAUDRATE),
+            &clocks,
+            io.pins.gpio2,
+            io.pins.gpio3,
+        )
+        .unwrap();
+
+        let dma = Dma::new(peripherals.DMA);
+        let (tx_buffer, tx_descriptors, mut rx_buffer, rx_descriptors) =
+            dma_buffers!(CHUNK, 4 * CHUNK);
+        // the pattern repeats every 256 bytes, so the chunks form one stream
+        fill(tx_buffer);
+
+        let mut uhci = UhciUart::new(
+            peripherals.UHCI0,
+            uart,
+            dma.channel0.configure(false, DmaPriority::Priority0),
+            tx_descriptors,
+            rx_descriptors,
+        );
+
+        let mut rx_transfer = uhci.rx.read_dma_circular(&mut rx_buffer).unwrap();
+
+        let mut received = [0u8; CHUNK];
+        let mut tx_transfer = Some(uhci.tx.write_dma(&tx_buffer).unwrap());
+        let mut sent = CHUNK;
+        let mut total = 0;
+        while total < TOTAL {
+            if tx_transfer.as_mut().is_some_and(|t| t.is_done()) {
+                tx_transfer.take().unwrap().wait().unwrap();
+                if sent < TOTAL {
+                    tx_transfer = Some(uhci.tx.write_dma(&tx_buffer).unwrap());
+                    sent += CHUNK;
+                }
+            }
+
+            if rx_transfer.available().unwrap() > 0 {
+                let n = rx_transfer.pop(&mut received).unwrap();
+                for (i, byte) in received[..n].iter().enumerate() {
+                    assert_eq!(*byte, ((total + i) * 7 + 3) as u8);
+                }
+                total += n;
+            }
+        }
+
+        assert_eq!(total, TOTAL);
+    }
+}
@@ -0,0 +1,43 @@
+PROVIDE(WIFI_MAC = DefaultHandler);
+PROVIDE(WIFI_MAC_NMI = DefaultHandler);
+PROVIDE(WIFI_PWR = DefaultHandler);
+PROVIDE(WIFI_BB = DefaultHandler);
+PROVIDE(BT_MAC = DefaultHandler);
+PROVIDE(BT_BB = DefaultHandler);
+PROVIDE(BT_BB_NMI = DefaultHandler);
+PROVIDE(LP_TIMER = DefaultHandler);
+PROVIDE(COEX = DefaultHandler);
+PROVIDE(BLE_TIMER = DefaultHandler);
+PROVIDE(BLE_SEC = DefaultHandler);
+PROVIDE(I2C_MST = DefaultHandler);
+PROVIDE(APB_CTRL = DefaultHandler);
+PROVIDE(GPIO = DefaultHandler);
+PROVIDE(GPIO_NMI = DefaultHandler);
+PROVIDE(SPI1 = DefaultHandler);
+PROVIDE(SPI2 = DefaultHandler);
+PROVIDE(UART0 = DefaultHandler);
+PROVIDE(UART1 = DefaultHandler);
+PROVIDE(LEDC = DefaultHandler);
+PROVIDE(EFUSE = DefaultHandler);
+PROVIDE(RTC_CORE = DefaultHandler);
+PROVIDE(I2C_EXT0 = DefaultHandler);
+PROVIDE(TG0_T0_LEVEL = DefaultHandler);
+PROVIDE(TG0_WDT_LEVEL = DefaultHandler);
+PROVIDE(CACHE_IA = DefaultHandler);
+PROVIDE(SYSTIMER_TARGET0 = DefaultHandler);
+PROVIDE(SYSTIMER_TARGET1 = DefaultHandler);
+PROVIDE(SYSTIMER_TARGET2 = DefaultHandler);
+PROVIDE(SPI_MEM_REJECT_CACHE = DefaultHandler);
+PROVIDE(ICACHE_PRELOAD0 = DefaultHandler);
+PROVIDE(ICACHE_SYNC0 = DefaultHandler);
+PROVIDE(APB_ADC = DefaultHandler);
+PROVIDE(DMA_CH0 = DefaultHandler);
+PROVIDE(SHA = DefaultHandler);
+PROVIDE(ECC = DefaultHandler);
+PROVIDE(FROM_CPU_INTR0 = DefaultHandler);
+PROVIDE(FROM_CPU_INTR1 = DefaultHandler);
+PROVIDE(FROM_CPU_INTR2 = DefaultHandler);
+PROVIDE(FROM_CPU_INTR3 = DefaultHandler);
+PROVIDE(ASSIST_DEBUG = DefaultHandler);
+PROVIDE(ETS_CORE0_PIF_PMS_SIZE = DefaultHandler);
+